@@ -20,6 +20,9 @@ const DEFAULT_OPENDA_FS_DIR: &str = "openda-fs";
 // Only a few database operations are needed to catch up with the latest block numbers after a restart,
 // so it's okay to have a small interval.
 pub const DEFAULT_DA_BACKGROUND_SUBMIT_INTERVAL: u64 = 5;
+// Interval(seconds) for full node (`--sync-from-da`) to poll DA backends for new batches.
+// DA layers produce blocks every few seconds, no need to poll too frequently.
+pub const DEFAULT_DA_SYNC_INTERVAL: u64 = 10;
 
 /// This enum specifies the strategy for submitting DA data.
 ///
@@ -101,6 +104,7 @@ pub enum OpenDAScheme {
     /// - `turbo_endpoint`: The TurboDA service endpoint.
     /// - `turbo_auth_token`: The authentication token for TurboDA.
     /// - `light_endpoint`: The Light Client service endpoint.
+    /// - `start_height` (optional): The Avail block to start scanning from when reading segments.
     Avail,
 
     /// Celestia backend.
@@ -108,6 +112,7 @@ pub enum OpenDAScheme {
     /// Main configuration:
     /// - `endpoint`: The Celestia service endpoint.
     /// - `auth_token` (optional): The authentication token for accessing the Celestia backend.
+    /// - `start_height` (optional): The Celestia height to start scanning from when reading segments.
     Celestia,
 }
 
@@ -179,6 +184,10 @@ pub struct DAConfig {
     /// If not set, the default value is `DEFAULT_DA_BACKGROUND_SUBMIT_INTERVAL`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub background_submit_interval: Option<u64>,
+    /// Specifies the interval for syncing batches from DA backends in seconds, only used by `--sync-from-da`.
+    /// If not set, the default value is `DEFAULT_DA_SYNC_INTERVAL`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_interval: Option<u64>,
 
     /// Internal reference to the base configuration.
     ///
@@ -216,6 +225,7 @@ impl DAConfig {
 
         self.background_submit_interval
             .get_or_insert(DEFAULT_DA_BACKGROUND_SUBMIT_INTERVAL);
+        self.sync_interval.get_or_insert(DEFAULT_DA_SYNC_INTERVAL);
 
         let default_fs_root = self.get_openda_fs_dir();
 
@@ -382,6 +392,20 @@ pub struct DABackendOpenDAConfig {
     /// - This configuration can help fine-tune the reliability of segment submission in case of transient errors.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_retries: Option<usize>,
+
+    /// Specifies the chunk version of the submitted data.
+    ///
+    /// - If not set or 0, each block is submitted as a ChunkV0 compressed by lz4.
+    /// - If 1, ChunkV1 compressed by zstd is submitted, and consecutive blocks submitted together
+    ///   (e.g., when the backend is catching up) are packed into one chunk.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunk_version: Option<u8>,
+
+    /// Specifies the zstd compression level of ChunkV1.
+    ///
+    /// - If not set or 0, the default level of zstd is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compression_level: Option<i32>,
 }
 
 /// Derives a namespace from the genesis hash for the DA backend.
@@ -410,6 +434,8 @@ mod tests {
                     namespace: None,
                     max_segment_size: None,
                     max_retries: None,
+                    chunk_version: None,
+                    compression_level: None,
                 }),
                 DABackendConfigType::OpenDa(DABackendOpenDAConfig {
                     scheme: OpenDAScheme::Fs,
@@ -417,6 +443,8 @@ mod tests {
                     namespace: None,
                     max_segment_size: None,
                     max_retries: None,
+                    chunk_version: None,
+                    compression_level: None,
                 }),
             ],
         };
@@ -453,6 +481,8 @@ mod tests {
             namespace: None,
             max_segment_size: None,
             max_retries: None,
+            chunk_version: None,
+            compression_level: None,
         };
         let exp_celestia_config = DABackendOpenDAConfig {
             scheme: OpenDAScheme::Celestia,
@@ -467,6 +497,8 @@ mod tests {
             ),
             max_segment_size: None,
             max_retries: None,
+            chunk_version: None,
+            compression_level: None,
         };
         let exp_fs_config = DABackendOpenDAConfig {
             scheme: OpenDAScheme::Fs,
@@ -474,6 +506,8 @@ mod tests {
             namespace: None,
             max_segment_size: None,
             max_retries: None,
+            chunk_version: None,
+            compression_level: None,
         };
         let exp_da_config = DAConfig {
             da_backend: Some(DABackendConfig {
@@ -486,6 +520,7 @@ mod tests {
             }),
             da_min_block_to_submit: Some(340282366920938463463374607431768211455),
             background_submit_interval: None,
+            sync_interval: None,
             base: None,
         };
         match DAConfig::from_str(da_config_str) {
@@ -509,6 +544,7 @@ mod tests {
             }),
             da_min_block_to_submit: None,
             background_submit_interval: None,
+            sync_interval: None,
             base: None,
         };
         match DAConfig::from_str(da_config_str) {
@@ -542,6 +578,8 @@ mod tests {
                         namespace: None,
                         max_segment_size: None,
                         max_retries: None,
+                        chunk_version: None,
+                        compression_level: None,
                     }),
                     DABackendConfigType::OpenDa(DABackendOpenDAConfig {
                         scheme: OpenDAScheme::Avail,
@@ -560,11 +598,14 @@ mod tests {
                         namespace: None,
                         max_segment_size: None,
                         max_retries: None,
+                        chunk_version: None,
+                        compression_level: None,
                     }),
                 ],
             }),
             da_min_block_to_submit: None,
             background_submit_interval: None,
+            sync_interval: None,
             base: None,
         };
         match DAConfig::from_str(da_config_str) {
@@ -3,6 +3,7 @@
 
 use crate::da_config::DAConfig;
 use crate::proposer_config::ProposerConfig;
+use crate::pruner_config::PrunerConfig;
 use crate::store_config::StoreConfig;
 use anyhow::Result;
 use clap::Parser;
@@ -23,6 +24,7 @@ use std::{fmt::Debug, path::Path, path::PathBuf};
 pub mod config;
 pub mod da_config;
 pub mod proposer_config;
+pub mod pruner_config;
 pub mod server_config;
 pub mod settings;
 pub mod store_config;
@@ -34,6 +36,7 @@ pub const ROOCH_KEYSTORE_FILENAME: &str = "rooch.keystore";
 
 const DEFAULT_BTC_REORG_AWARE_BLOCK_STORE_DIR: &str = "btc-reorg-aware-block-store";
 const DEFAULT_BTC_REORG_AWARE_HEIGHT: usize = 16; // much larger than bitcoin_reorg_block_count, no need to be too large
+const DEFAULT_ETH_CONFIRMATION_DEPTH: u64 = 6;
 
 pub static R_DEFAULT_BASE_DATA_DIR: Lazy<PathBuf> = Lazy::new(|| {
     dirs_next::home_dir()
@@ -107,6 +110,10 @@ pub struct RoochOpt {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long, env = "ETH_RPC_URL")]
     pub eth_rpc_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "ETH_CONFIRMATION_DEPTH")]
+    /// The number of confirmations an Ethereum block needs before it is relayed, default is 6.
+    pub eth_confirmation_depth: Option<u64>,
 
     /// The Bitcoin RPC URL to connect to for relay L1 block and transaction to L2.
     /// If not set, the relayer service will not start.
@@ -152,6 +159,10 @@ pub struct RoochOpt {
     #[clap(flatten)]
     pub proposer: ProposerConfig,
 
+    #[serde(default)]
+    #[clap(flatten)]
+    pub pruner: PrunerConfig,
+
     #[clap(long, default_value_t, value_enum)]
     pub service_status: ServiceStatus,
 
@@ -172,6 +183,27 @@ pub struct RoochOpt {
     #[clap(long, default_value_t, value_enum)]
     pub service_type: ServiceType,
 
+    /// The max number of transactions in the queue of `sendRawTransaction`, default is 10000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub tx_queue_capacity: Option<usize>,
+
+    /// The max number of pending transactions of a sender in the queue of `sendRawTransaction`, default is 64.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub tx_queue_max_per_sender: Option<usize>,
+
+    /// Experimental: the max number of L2 transactions of different senders executed in parallel, the transactions are executed sequentially if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub parallel_execution_window: Option<usize>,
+
+    /// Run as a full node which follows the sequencer by syncing transactions from DA backends (in `--da`),
+    /// instead of sequencing transactions itself. The service is in read-only mode.
+    #[serde(default)]
+    #[clap(long)]
+    pub sync_from_da: bool,
+
     #[serde(skip)]
     #[clap(skip)]
     base: Option<Arc<BaseConfig>>,
@@ -196,6 +228,7 @@ impl RoochOpt {
             store: StoreConfig::default(),
             port: None,
             eth_rpc_url: None,
+            eth_confirmation_depth: None,
             btc_rpc_url: None,
             btc_rpc_username: None,
             btc_rpc_password: None,
@@ -207,11 +240,16 @@ impl RoochOpt {
             proposer_account: None,
             da: DAConfig::default(),
             proposer: ProposerConfig::default(),
+            pruner: PrunerConfig::default(),
             service_status: ServiceStatus::default(),
             traffic_per_second: None,
             traffic_burst_size: None,
             base: None,
             service_type: ServiceType::default(),
+            tx_queue_capacity: None,
+            tx_queue_max_per_sender: None,
+            parallel_execution_window: None,
+            sync_from_da: false,
         };
         opt.init()?;
         Ok(opt)
@@ -249,6 +287,9 @@ impl RoochOpt {
             .as_ref()
             .map(|eth_rpc_url| EthereumRelayerConfig {
                 eth_rpc_url: eth_rpc_url.clone(),
+                eth_confirmation_depth: self
+                    .eth_confirmation_depth
+                    .unwrap_or(DEFAULT_ETH_CONFIRMATION_DEPTH),
             })
     }
 
@@ -340,6 +381,7 @@ impl RoochOpt {
 #[derive(Debug, Clone)]
 pub struct EthereumRelayerConfig {
     pub eth_rpc_url: String,
+    pub eth_confirmation_depth: u64,
 }
 
 #[derive(Debug, Clone)]
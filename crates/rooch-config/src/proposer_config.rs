@@ -23,6 +23,13 @@ pub struct ProposerConfig {
         help = "The proposer check avail block to propose interval"
     )]
     pub interval: Option<u64>,
+    #[serde(default)]
+    #[clap(
+        name = "proposer-submit-to-chain",
+        long,
+        help = "Submit proposed blocks to the on-chain State Commitment Chain, the last proposed block only advances after the submission is confirmed"
+    )]
+    pub submit_to_chain: bool,
 }
 
 impl Config for ProposerConfig {}
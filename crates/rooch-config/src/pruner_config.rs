@@ -0,0 +1,89 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use clap::Parser;
+use moveos_types::h256::H256;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+pub const DEFAULT_PRUNER_KEEP_RECENT_ROOTS: u64 = 10000;
+pub const DEFAULT_PRUNER_INTERVAL_SECONDS: u64 = 3600;
+pub const DEFAULT_PRUNER_BATCH_SIZE: u64 = 10000;
+
+#[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, Parser)]
+#[serde(deny_unknown_fields)]
+pub struct PrunerConfig {
+    #[serde(default)]
+    #[clap(
+        name = "pruner-enable",
+        long,
+        help = "Enable the background state pruner, it removes the state tree nodes which are only referenced by the pruned state roots"
+    )]
+    pub enable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "pruner-keep-recent-roots",
+        long,
+        help = "The number of recent state roots(by tx order) to keep, default is 10000"
+    )]
+    pub keep_recent_roots: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[clap(
+        name = "pruner-pinned-roots",
+        long,
+        value_delimiter = ',',
+        help = "The state roots to keep forever, separated by comma"
+    )]
+    pub pinned_roots: Vec<H256>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "pruner-interval",
+        long,
+        help = "The interval of the background state pruner in seconds, default is 3600"
+    )]
+    pub interval: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "pruner-batch-size",
+        long,
+        help = "The max number of stale state nodes checked in one round, default is 10000"
+    )]
+    pub batch_size: Option<u64>,
+}
+
+impl PrunerConfig {
+    pub fn keep_recent_roots(&self) -> u64 {
+        self.keep_recent_roots
+            .unwrap_or(DEFAULT_PRUNER_KEEP_RECENT_ROOTS)
+    }
+
+    pub fn interval(&self) -> u64 {
+        self.interval.unwrap_or(DEFAULT_PRUNER_INTERVAL_SECONDS)
+    }
+
+    pub fn batch_size(&self) -> u64 {
+        self.batch_size.unwrap_or(DEFAULT_PRUNER_BATCH_SIZE)
+    }
+}
+
+impl Config for PrunerConfig {}
+
+impl std::fmt::Display for PrunerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err(|_e| std::fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for PrunerConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self, Self::Err> {
+        let deserialized: PrunerConfig = serde_json::from_str(s)?;
+        Ok(deserialized)
+    }
+}
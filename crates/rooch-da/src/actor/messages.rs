@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use coerce::actor::message::Message;
+use moveos_types::h256::H256;
+use rooch_types::da::batch::BlockRange;
 use rooch_types::da::status::DAServerStatus;
 use serde::{Deserialize, Serialize};
 
@@ -30,3 +32,14 @@ impl AppendTransactionMessage {
         }
     }
 }
+
+/// Append a block which has been synced from DA backends, keeping local block numbers same as the sequencer's
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AppendSyncedBlockMessage {
+    pub block_range: BlockRange,
+    pub batch_hash: H256,
+}
+
+impl Message for AppendSyncedBlockMessage {
+    type Result = anyhow::Result<()>;
+}
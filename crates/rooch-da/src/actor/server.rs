@@ -1,7 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::actor::messages::{AppendTransactionMessage, GetServerStatusMessage};
+use crate::actor::messages::{
+    AppendSyncedBlockMessage, AppendTransactionMessage, GetServerStatusMessage,
+};
 use crate::backend::{DABackend, DABackends};
 use crate::batcher::BatchMaker;
 use anyhow::anyhow;
@@ -16,13 +18,15 @@ use rooch_store::transaction_store::TransactionStore;
 use rooch_store::RoochStore;
 use rooch_types::crypto::RoochKeyPair;
 use rooch_types::da::batch::{BlockRange, DABatch, SignedDABatchMeta};
+use rooch_types::da::chunk::CHUNK_V1_MAX_BATCHES;
 use rooch_types::da::status::DAServerStatus;
 use rooch_types::transaction::LedgerTransaction;
+use std::cmp::{max, min};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time;
 use std::time::{Duration, SystemTime};
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, mpsc, Mutex};
 
 pub struct DAServerActor {
     rooch_store: RoochStore,
@@ -70,11 +74,20 @@ impl DAServerActor {
         };
 
         if submit_threshold != 0 {
-            Self::run_background_submitter(
-                rooch_store,
+            let submitter = Submitter {
                 sequencer_key,
-                backends,
+                rooch_store: rooch_store.clone(),
+                backends: backends
+                    .into_iter()
+                    .map(|backend| BackendSubmitter::new(backend, rooch_store.clone()))
+                    .collect(),
                 submit_threshold,
+                min_block_to_submit,
+            };
+            submitter.init_backend_cursors()?;
+            Self::run_background_submitter(
+                rooch_store,
+                Arc::new(submitter),
                 background_last_block_update_time,
                 min_block_to_submit,
                 background_submit_interval,
@@ -150,27 +163,62 @@ impl DAServerActor {
         Ok(())
     }
 
+    // append block synced from DA backends as a done block,
+    // block number must be the next one of local, because it's assigned by the sequencer
+    pub fn append_synced_block(&mut self, msg: AppendSyncedBlockMessage) -> anyhow::Result<()> {
+        let AppendSyncedBlockMessage {
+            block_range,
+            batch_hash,
+        } = msg;
+        let exp_block_number = self.last_block_number.map_or(0, |n| n + 1);
+        if block_range.block_number != exp_block_number {
+            return Err(anyhow!(
+                "synced block number mismatched, expect: {}, actual: {}",
+                exp_block_number,
+                block_range.block_number
+            ));
+        }
+
+        let block_number = self
+            .rooch_store
+            .append_submitting_block(block_range.tx_order_start, block_range.tx_order_end)?;
+        self.rooch_store.set_submitting_block_done(
+            block_number,
+            block_range.tx_order_start,
+            block_range.tx_order_end,
+            batch_hash,
+        )?;
+        self.last_block_number = Some(block_number);
+        self.last_block_update_time = SystemTime::now()
+            .duration_since(time::UNIX_EPOCH)?
+            .as_secs();
+        Ok(())
+    }
+
     // Spawns a background submitter to handle unsubmitted blocks off the main thread.
     // This prevents blocking other actor handlers and maintains the actor's responsiveness.
+    // Each backend also has its own catch-up task, so a lagging backend won't hold up the others.
     fn run_background_submitter(
         rooch_store: RoochStore,
-        sequencer_key: RoochKeyPair,
-        backends: Vec<Arc<dyn DABackend>>,
-        submit_threshold: usize,
+        submitter: Arc<Submitter>,
         background_last_block_update_time: Arc<AtomicU64>,
         min_block_to_submit_opt: Option<u128>,
         background_submit_interval: u64,
         mut shutdown_rx: broadcast::Receiver<()>,
     ) {
+        for backend in submitter.backends.clone() {
+            Self::run_backend_catch_up(
+                submitter.clone(),
+                backend,
+                background_submit_interval,
+                shutdown_rx.resubscribe(),
+            );
+        }
+
         tokio::spawn(async move {
             let background_submitter = BackgroundSubmitter {
                 rooch_store: rooch_store.clone(),
-                submitter: Submitter {
-                    sequencer_key: sequencer_key.copy(),
-                    rooch_store: rooch_store.clone(),
-                    backends: backends.clone(),
-                    submit_threshold,
-                },
+                submitter,
                 last_block_update_time: background_last_block_update_time.clone(),
             };
 
@@ -218,6 +266,47 @@ impl DAServerActor {
             }
         });
     }
+
+    fn run_backend_catch_up(
+        submitter: Arc<Submitter>,
+        backend: BackendSubmitter,
+        catch_up_interval: u64,
+        mut shutdown_rx: broadcast::Receiver<()>,
+    ) {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(catch_up_interval));
+            loop {
+                tokio::select! {
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!(
+                            "da: backend {} catch-up thread received shutdown signal, exiting...",
+                            backend.identifier
+                        );
+                        break;
+                    }
+                    _ = ticker.tick() => {
+                        match submitter.catch_up_backend(&backend).await {
+                            Ok(0) => {}
+                            Ok(count) => {
+                                tracing::info!(
+                                    "da: backend {} caught up {} blocks",
+                                    backend.identifier,
+                                    count
+                                );
+                            }
+                            Err(e) => {
+                                tracing::warn!(
+                                    "da: backend {} catch-up failed, will retry: {:?}",
+                                    backend.identifier,
+                                    e
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    }
 }
 
 #[async_trait]
@@ -242,12 +331,73 @@ impl Handler<AppendTransactionMessage> for DAServerActor {
     }
 }
 
+#[async_trait]
+impl Handler<AppendSyncedBlockMessage> for DAServerActor {
+    async fn handle(
+        &mut self,
+        msg: AppendSyncedBlockMessage,
+        _ctx: &mut ActorContext,
+    ) -> anyhow::Result<()> {
+        self.append_synced_block(msg)
+    }
+}
+
+// DA backend with its own submit cursor: [0, cursor] are submitted to the backend.
+// Submissions to the same backend are serialized by the lock,
+// so a slow backend won't be piled up with submissions.
+#[derive(Clone)]
+pub(crate) struct BackendSubmitter {
+    backend: Arc<dyn DABackend>,
+    identifier: String,
+    rooch_store: RoochStore,
+    lock: Arc<Mutex<()>>,
+}
+
+impl BackendSubmitter {
+    fn new(backend: Arc<dyn DABackend>, rooch_store: RoochStore) -> Self {
+        let identifier = backend.get_identifier();
+        Self {
+            backend,
+            identifier,
+            rooch_store,
+            lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    fn get_cursor(&self) -> anyhow::Result<Option<u128>> {
+        self.rooch_store
+            .get_backend_submit_block_cursor(&self.identifier)
+    }
+
+    // submit batch to the backend and move the cursor forward, the caller must hold the lock
+    async fn submit_batch(&self, batch: Arc<DABatch>) -> anyhow::Result<()> {
+        let block_number = batch.meta.block_range.block_number;
+        self.backend.submit_batch(batch).await?;
+        self.rooch_store
+            .set_backend_submit_block_cursor(&self.identifier, block_number)
+    }
+
+    // submit consecutive batches to the backend and move the cursor to the last one, the caller must hold the lock
+    async fn submit_batches(&self, batches: Vec<Arc<DABatch>>) -> anyhow::Result<()> {
+        let Some(block_number) = batches
+            .last()
+            .map(|batch| batch.meta.block_range.block_number)
+        else {
+            return Ok(());
+        };
+        self.backend.submit_batches(batches).await?;
+        self.rooch_store
+            .set_backend_submit_block_cursor(&self.identifier, block_number)
+    }
+}
+
 pub(crate) struct Submitter {
     sequencer_key: RoochKeyPair,
     rooch_store: RoochStore,
 
-    backends: Vec<Arc<dyn DABackend>>,
+    backends: Vec<BackendSubmitter>,
     submit_threshold: usize,
+    min_block_to_submit: Option<u128>,
 }
 
 impl Submitter {
@@ -261,13 +411,7 @@ impl Submitter {
         let tx_order_end = block_range.tx_order_end;
 
         // create batch
-        let batch = DABatch::new(
-            block_number,
-            tx_order_start,
-            tx_order_end,
-            &tx_list,
-            self.sequencer_key.copy(),
-        );
+        let batch = self.make_batch(block_range, &tx_list);
         let batch_meta = batch.meta.clone();
         let meta_signature = batch.meta_signature.clone();
         let batch_hash = batch.get_hash();
@@ -293,26 +437,111 @@ impl Submitter {
         })
     }
 
+    fn make_batch(&self, block_range: BlockRange, tx_list: &[LedgerTransaction]) -> DABatch {
+        DABatch::new(
+            block_range.block_number,
+            block_range.tx_order_start,
+            block_range.tx_order_end,
+            tx_list,
+            self.sequencer_key.copy(),
+        )
+    }
+
+    fn get_tx_list(&self, block_range: &BlockRange) -> anyhow::Result<Vec<LedgerTransaction>> {
+        // collect tx from start to end for rooch_store
+        let tx_orders: Vec<u64> = (block_range.tx_order_start..=block_range.tx_order_end).collect();
+        let tx_hashes = self.rooch_store.get_tx_hashes(tx_orders.clone())?;
+        let tx_order_hash_pairs = pair_tx_order_hash(tx_orders, tx_hashes)?;
+
+        let mut tx_list: Vec<LedgerTransaction> = Vec::new();
+        for (tx_order, tx_hash) in tx_order_hash_pairs {
+            let tx = self
+                .rooch_store
+                .get_transaction_by_hash(tx_hash)?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "fail to get transaction by tx_hash: {:?}, tx_order: {}",
+                        tx_hash,
+                        tx_order
+                    )
+                })?; // should not happen
+            tx_list.push(tx);
+        }
+        Ok(tx_list)
+    }
+
+    // submit batch to backends concurrently, return as soon as the submit threshold is met or it can't be met.
+    // backends which are lagging behind or still busy with the previous batch are skipped,
+    // they will catch up in their own catch-up tasks.
     async fn submit_batch_to_backends(&self, batch: DABatch) -> anyhow::Result<()> {
-        let backends = self.backends.clone();
+        let block_number = batch.meta.block_range.block_number;
         let submit_threshold = self.submit_threshold;
 
         let batch = Arc::new(batch);
+        let (result_tx, mut result_rx) = mpsc::unbounded_channel();
 
-        // submit to backend in order until meet submit_threshold
         let mut success_count = 0;
-        for backend in &backends {
-            let submit_fut = backend.submit_batch(batch.clone());
-            match submit_fut.await {
+        let mut pending_count = 0;
+        for backend in &self.backends {
+            let next_block_number =
+                next_block_to_submit(backend.get_cursor()?, self.min_block_to_submit);
+            if next_block_number > block_number {
+                // submitted before restarting
+                success_count += 1;
+                continue;
+            }
+            if next_block_number < block_number {
+                tracing::debug!(
+                    "da: backend {} is lagging behind, next block: {}, skip submitting block: {}",
+                    backend.identifier,
+                    next_block_number,
+                    block_number
+                );
+                continue;
+            }
+            let guard = match backend.lock.clone().try_lock_owned() {
+                Ok(guard) => guard,
+                Err(_) => {
+                    tracing::debug!(
+                        "da: backend {} is busy, skip submitting block: {}",
+                        backend.identifier,
+                        block_number
+                    );
+                    continue;
+                }
+            };
+
+            pending_count += 1;
+            let backend = backend.clone();
+            let batch = batch.clone();
+            let result_tx = result_tx.clone();
+            // submission keeps going after the threshold is met, so slow backends could still get the batch
+            tokio::spawn(async move {
+                let ret = backend.submit_batch(batch).await;
+                drop(guard);
+                let _ = result_tx.send((backend.identifier, ret));
+            });
+        }
+        drop(result_tx);
+
+        // check the threshold as results come in
+        while success_count < submit_threshold && success_count + pending_count >= submit_threshold
+        {
+            let Some((identifier, ret)) = result_rx.recv().await else {
+                break;
+            };
+            pending_count -= 1;
+            match ret {
                 Ok(_) => {
                     success_count += 1;
-                    // TODO parallel submit
-                    // if success_count >= submit_threshold {
-                    //     break;
-                    // }
                 }
                 Err(e) => {
-                    tracing::warn!("{:?}, fail to submit batch to backend.", e);
+                    tracing::warn!(
+                        "{:?}, fail to submit batch: {} to backend: {}.",
+                        e,
+                        block_number,
+                        identifier
+                    );
                 }
             }
         }
@@ -326,11 +555,84 @@ impl Submitter {
         };
         Ok(())
     }
+
+    // submit done blocks which haven't been submitted to the backend, return how many blocks are submitted.
+    // blocks are submitted in groups of at most CHUNK_V1_MAX_BATCHES, so the backend could pack them into one chunk.
+    async fn catch_up_backend(&self, backend: &BackendSubmitter) -> anyhow::Result<usize> {
+        let _guard = backend.lock.lock().await;
+        let mut count = 0;
+        loop {
+            let mut block_number =
+                next_block_to_submit(backend.get_cursor()?, self.min_block_to_submit);
+            let mut batches = Vec::new();
+            while batches.len() < CHUNK_V1_MAX_BATCHES {
+                // only done blocks are caught up, the others are submitted by the background submitter
+                let block_range = match self.rooch_store.try_get_block_state(block_number)? {
+                    Some(block_state) if block_state.done => block_state.block_range,
+                    _ => break,
+                };
+                let tx_list = self.get_tx_list(&block_range)?;
+                batches.push(Arc::new(self.make_batch(block_range, &tx_list)));
+                block_number += 1;
+            }
+            if batches.is_empty() {
+                break;
+            }
+            count += batches.len();
+            backend.submit_batches(batches).await?;
+        }
+        Ok(count)
+    }
+
+    // init backend cursors on starting:
+    // 1. not set: upgraded from the version without backend cursor, all backends were submitted together,
+    //    so take the background submit cursor as the backend cursor.
+    // 2. ahead of the last done block (caused by rollback/revert): reset it to the last done block.
+    fn init_backend_cursors(&self) -> anyhow::Result<()> {
+        let background_cursor = self.rooch_store.get_background_submit_block_cursor()?;
+        let start_block =
+            BackgroundSubmitter::adjust_cursor(background_cursor, self.min_block_to_submit)
+                .unwrap_or(0);
+        let last_done_block = match self
+            .rooch_store
+            .get_submitting_blocks(start_block, Some(1))?
+            .first()
+        {
+            Some(first_unsubmitted_block) => first_unsubmitted_block.block_number.checked_sub(1),
+            None => self.rooch_store.get_last_block_number()?,
+        };
+
+        for backend in &self.backends {
+            let origin_cursor = backend.get_cursor()?;
+            let cursor = match (origin_cursor.or(background_cursor), last_done_block) {
+                (Some(cursor), Some(last_done_block)) => Some(min(cursor, last_done_block)),
+                _ => None,
+            };
+            if cursor == origin_cursor {
+                continue;
+            }
+            match cursor {
+                Some(cursor) => self
+                    .rooch_store
+                    .set_backend_submit_block_cursor(&backend.identifier, cursor)?,
+                None => self
+                    .rooch_store
+                    .remove_backend_submit_block_cursor(&backend.identifier)?,
+            }
+            tracing::info!(
+                "da: init backend {} submit cursor: {:?} -> {:?}",
+                backend.identifier,
+                origin_cursor,
+                cursor
+            );
+        }
+        Ok(())
+    }
 }
 
 struct BackgroundSubmitter {
     rooch_store: RoochStore,
-    submitter: Submitter,
+    submitter: Arc<Submitter>,
     last_block_update_time: Arc<AtomicU64>,
 }
 
@@ -377,27 +679,7 @@ impl BackgroundSubmitter {
         let mut max_block_number_submitted: u128 = 0;
         for unsubmitted_block_range in unsubmitted_blocks {
             let block_number = unsubmitted_block_range.block_number;
-            let tx_order_start = unsubmitted_block_range.tx_order_start;
-            let tx_order_end = unsubmitted_block_range.tx_order_end;
-            // collect tx from start to end for rooch_store
-            let tx_orders: Vec<u64> = (tx_order_start..=tx_order_end).collect();
-            let tx_hashes = self.rooch_store.get_tx_hashes(tx_orders.clone())?;
-            let tx_order_hash_pairs = pair_tx_order_hash(tx_orders, tx_hashes)?;
-
-            let mut tx_list: Vec<LedgerTransaction> = Vec::new();
-            for (tx_order, tx_hash) in tx_order_hash_pairs {
-                let tx = self
-                    .rooch_store
-                    .get_transaction_by_hash(tx_hash)?
-                    .ok_or_else(|| {
-                        anyhow!(
-                            "fail to get transaction by tx_hash: {:?}, tx_order: {}",
-                            tx_hash,
-                            tx_order
-                        )
-                    })?; // should not happen
-                tx_list.push(tx);
-            }
+            let tx_list = self.submitter.get_tx_list(&unsubmitted_block_range)?;
             self.submitter
                 .submit_batch_raw(unsubmitted_block_range, tx_list)
                 .await?;
@@ -454,6 +736,15 @@ impl BackgroundSubmitter {
     }
 }
 
+// the next block to submit to a backend with the given cursor, blocks before min_block_to_submit are skipped
+fn next_block_to_submit(cursor: Option<u128>, min_block_to_submit_opt: Option<u128>) -> u128 {
+    let next_block = cursor.map_or(0, |cursor| cursor + 1);
+    match min_block_to_submit_opt {
+        Some(min_block_to_submit) => max(next_block, min_block_to_submit),
+        None => next_block,
+    }
+}
+
 fn pair_tx_order_hash(
     tx_orders: Vec<u64>,
     tx_hashes: Vec<Option<H256>>,
@@ -474,8 +765,277 @@ fn pair_tx_order_hash(
 
 #[cfg(test)]
 mod tests {
-    use crate::actor::server::{pair_tx_order_hash, BackgroundSubmitter};
+    use crate::actor::server::{
+        next_block_to_submit, pair_tx_order_hash, BackendSubmitter, BackgroundSubmitter, Submitter,
+    };
+    use crate::backend::DABackend;
+    use anyhow::anyhow;
+    use async_trait::async_trait;
     use moveos_types::h256::H256;
+    use rooch_store::da_store::DAMetaStore;
+    use rooch_store::RoochStore;
+    use rooch_types::crypto::RoochKeyPair;
+    use rooch_types::da::batch::{BlockRange, DABatch};
+    use rooch_types::da::chunk::Chunk;
+    use rooch_types::sequencer::SequencerInfo;
+    use rooch_types::test_utils::random_accumulator_info;
+    use rooch_types::transaction::{LedgerTransaction, RoochTransaction, TransactionSequenceInfo};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::Semaphore;
+    use tokio::time::timeout;
+
+    // records submitted blocks, fails every submission if `fail` is set,
+    // and waits for a permit of the gate before finishing if `gate` is set
+    struct MockBackend {
+        identifier: String,
+        fail: bool,
+        gate: Option<Arc<Semaphore>>,
+        submitted: std::sync::Mutex<Vec<u128>>,
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    impl MockBackend {
+        fn new(identifier: &str, fail: bool, gate: Option<Arc<Semaphore>>) -> Arc<Self> {
+            Arc::new(Self {
+                identifier: identifier.to_string(),
+                fail,
+                gate,
+                submitted: std::sync::Mutex::new(vec![]),
+                in_flight: AtomicUsize::new(0),
+                max_in_flight: AtomicUsize::new(0),
+            })
+        }
+
+        fn submitted(&self) -> Vec<u128> {
+            self.submitted.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl DABackend for MockBackend {
+        async fn submit_batch(&self, batch: Arc<DABatch>) -> anyhow::Result<()> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            if let Some(gate) = &self.gate {
+                gate.acquire().await?.forget();
+            }
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            if self.fail {
+                return Err(anyhow!("mock backend {} failed", self.identifier));
+            }
+            self.submitted
+                .lock()
+                .unwrap()
+                .push(batch.meta.block_range.block_number);
+            Ok(())
+        }
+
+        async fn get_chunk(&self, _chunk_id: u128) -> anyhow::Result<Option<Box<dyn Chunk>>> {
+            Ok(None)
+        }
+
+        fn get_identifier(&self) -> String {
+            self.identifier.clone()
+        }
+    }
+
+    fn new_submitter(
+        rooch_store: &RoochStore,
+        backends: &[Arc<MockBackend>],
+        submit_threshold: usize,
+    ) -> Submitter {
+        Submitter {
+            sequencer_key: RoochKeyPair::generate_secp256k1(),
+            rooch_store: rooch_store.clone(),
+            backends: backends
+                .iter()
+                .map(|backend| BackendSubmitter::new(backend.clone(), rooch_store.clone()))
+                .collect(),
+            submit_threshold,
+            min_block_to_submit: None,
+        }
+    }
+
+    fn new_batch(submitter: &Submitter, block_number: u128) -> DABatch {
+        let block_range = BlockRange {
+            block_number,
+            tx_order_start: block_number as u64,
+            tx_order_end: block_number as u64,
+        };
+        submitter.make_batch(block_range, &[])
+    }
+
+    fn get_cursor(rooch_store: &RoochStore, identifier: &str) -> Option<u128> {
+        rooch_store
+            .get_backend_submit_block_cursor(identifier)
+            .unwrap()
+    }
+
+    async fn wait_cursor(rooch_store: &RoochStore, identifier: &str, exp_cursor: u128) {
+        timeout(Duration::from_secs(10), async {
+            while get_cursor(rooch_store, identifier) != Some(exp_cursor) {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("backend cursor should be updated");
+    }
+
+    // save txs with tx_order in [0, count)
+    fn save_txs(rooch_store: &RoochStore, count: u64) {
+        for tx_order in 0..count {
+            let sequence_info =
+                TransactionSequenceInfo::new(tx_order, vec![], random_accumulator_info(), 0);
+            let mut tx = LedgerTransaction::new_l2_tx(RoochTransaction::mock(), sequence_info);
+            let tx_hash = tx.tx_hash();
+            rooch_store
+                .save_sequenced_tx(
+                    tx_hash,
+                    tx,
+                    SequencerInfo::new(tx_order, random_accumulator_info()),
+                    None,
+                )
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_batch_threshold_met_before_slow_backend() {
+        let (rooch_store, _tmpdir) = RoochStore::mock_rooch_store().unwrap();
+        let gate = Arc::new(Semaphore::new(0));
+        let fast1 = MockBackend::new("fast1", false, None);
+        let fast2 = MockBackend::new("fast2", false, None);
+        let slow = MockBackend::new("slow", false, Some(gate.clone()));
+        let submitter = new_submitter(
+            &rooch_store,
+            &[fast1.clone(), fast2.clone(), slow.clone()],
+            2,
+        );
+
+        // returns once the fast backends meet the threshold, the slow one is still submitting
+        timeout(
+            Duration::from_secs(10),
+            submitter.submit_batch_to_backends(new_batch(&submitter, 0)),
+        )
+        .await
+        .expect("should not wait for the slow backend")
+        .unwrap();
+        assert_eq!(fast1.submitted(), vec![0]);
+        assert_eq!(fast2.submitted(), vec![0]);
+        assert!(slow.submitted().is_empty());
+        assert_eq!(get_cursor(&rooch_store, "fast1"), Some(0));
+        assert_eq!(get_cursor(&rooch_store, "fast2"), Some(0));
+        assert_eq!(get_cursor(&rooch_store, "slow"), None);
+
+        // the slow backend is busy: it's skipped instead of getting another submission in flight,
+        // and the fast backends count as submitted by their cursors
+        submitter
+            .submit_batch_to_backends(new_batch(&submitter, 0))
+            .await
+            .unwrap();
+        assert_eq!(fast1.submitted(), vec![0]);
+        assert_eq!(slow.max_in_flight.load(Ordering::SeqCst), 1);
+
+        // the slow backend finishes in the background
+        gate.add_permits(1);
+        wait_cursor(&rooch_store, "slow", 0).await;
+        assert_eq!(slow.submitted(), vec![0]);
+        assert_eq!(slow.max_in_flight.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_submit_batch_threshold_not_met() {
+        let (rooch_store, _tmpdir) = RoochStore::mock_rooch_store().unwrap();
+        let gate = Arc::new(Semaphore::new(0));
+        let fast = MockBackend::new("fast", false, None);
+        let failed = MockBackend::new("failed", true, None);
+        let slow = MockBackend::new("slow", false, Some(gate.clone()));
+        let submitter = new_submitter(
+            &rooch_store,
+            &[fast.clone(), failed.clone(), slow.clone()],
+            3,
+        );
+
+        // fails as soon as the threshold can't be met, without waiting for the slow backend
+        let ret = timeout(
+            Duration::from_secs(10),
+            submitter.submit_batch_to_backends(new_batch(&submitter, 0)),
+        )
+        .await
+        .expect("should not wait for the slow backend");
+        assert!(ret.is_err());
+        assert_eq!(fast.submitted(), vec![0]);
+        assert!(failed.submitted().is_empty());
+        assert_eq!(get_cursor(&rooch_store, "failed"), None);
+
+        gate.add_permits(1);
+        wait_cursor(&rooch_store, "slow", 0).await;
+
+        // the failed backend is lagging behind now, it's skipped and the others meet the threshold
+        let submitter = new_submitter(&rooch_store, &[fast.clone(), failed, slow.clone()], 2);
+        gate.add_permits(1);
+        submitter
+            .submit_batch_to_backends(new_batch(&submitter, 1))
+            .await
+            .unwrap();
+        assert_eq!(fast.submitted(), vec![0, 1]);
+        assert_eq!(slow.submitted(), vec![0, 1]);
+        assert_eq!(get_cursor(&rooch_store, "failed"), None);
+    }
+
+    #[tokio::test]
+    async fn test_catch_up_lagging_backend() {
+        let (rooch_store, _tmpdir) = RoochStore::mock_rooch_store().unwrap();
+        save_txs(&rooch_store, 4);
+        // blocks [0, 2] are done, block 3 is still submitting
+        for tx_order in 0..4 {
+            let block_number = rooch_store
+                .append_submitting_block(tx_order, tx_order)
+                .unwrap();
+            if block_number < 3 {
+                rooch_store
+                    .set_submitting_block_done(block_number, tx_order, tx_order, H256::random())
+                    .unwrap();
+            }
+        }
+
+        let synced = MockBackend::new("synced", false, None);
+        let lagging = MockBackend::new("lagging", false, None);
+        rooch_store
+            .set_backend_submit_block_cursor("synced", 2)
+            .unwrap();
+        rooch_store
+            .set_backend_submit_block_cursor("lagging", 0)
+            .unwrap();
+        let submitter = new_submitter(&rooch_store, &[synced.clone(), lagging.clone()], 1);
+
+        // only the lagging backend gets the done blocks, the submitting block is left to the background submitter
+        let count = submitter
+            .catch_up_backend(&submitter.backends[1])
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(lagging.submitted(), vec![1, 2]);
+        assert_eq!(get_cursor(&rooch_store, "lagging"), Some(2));
+
+        let count = submitter
+            .catch_up_backend(&submitter.backends[0])
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+        assert!(synced.submitted().is_empty());
+
+        // nothing more to catch up
+        let count = submitter
+            .catch_up_backend(&submitter.backends[1])
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+        assert_eq!(lagging.submitted(), vec![1, 2]);
+    }
 
     #[test]
     fn test_background_submitter_adjust_cursor() {
@@ -505,6 +1065,15 @@ mod tests {
         assert_eq!(ret, None);
     }
 
+    #[test]
+    fn test_next_block_to_submit() {
+        assert_eq!(next_block_to_submit(None, None), 0);
+        assert_eq!(next_block_to_submit(Some(10), None), 11);
+        assert_eq!(next_block_to_submit(None, Some(5)), 5);
+        assert_eq!(next_block_to_submit(Some(10), Some(5)), 11);
+        assert_eq!(next_block_to_submit(Some(10), Some(15)), 15);
+    }
+
     #[test]
     fn pair_tx_order_hash_failed() {
         let tx_orders = vec![1, 2, 3];
@@ -5,7 +5,9 @@ use crate::backend::openda::OpenDABackendManager;
 use anyhow::anyhow;
 use async_trait::async_trait;
 use rooch_config::da_config::{DABackendConfig, DABackendConfigType};
+use rooch_types::crypto::PublicKey;
 use rooch_types::da::batch::DABatch;
+use rooch_types::da::chunk::{Chunk, CHUNK_V1_MAX_BATCHES};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -25,6 +27,15 @@ pub const UNKNOWN_BACKEND_PRIORITY: usize = usize::MAX;
 #[async_trait]
 pub trait DABackend: Sync + Send {
     async fn submit_batch(&self, batch: Arc<DABatch>) -> anyhow::Result<()>;
+    /// submit consecutive batches, the backend may pack them into fewer chunks
+    async fn submit_batches(&self, batches: Vec<Arc<DABatch>>) -> anyhow::Result<()> {
+        for batch in batches {
+            self.submit_batch(batch).await?;
+        }
+        Ok(())
+    }
+    /// get chunk by chunk_id (block number of the first batch in the chunk), return None if not found
+    async fn get_chunk(&self, chunk_id: u128) -> anyhow::Result<Option<Box<dyn Chunk>>>;
     fn get_identifier(&self) -> String;
 }
 
@@ -75,6 +86,81 @@ impl DABackends {
         Ok(this)
     }
 
+    /// Gets the batch of block_number from backends in priority order.
+    /// Batch will be verified (tx_list_hash, meta signature, tx order) before returning,
+    /// and its meta must be signed by `sequencer_public_key` if it's provided.
+    /// Try the next backend if the batch is not found or is invalid in the current one.
+    pub async fn get_batch(
+        &self,
+        block_number: u128,
+        sequencer_public_key: Option<&PublicKey>,
+    ) -> anyhow::Result<Option<DABatch>> {
+        let mut last_err = None;
+        for backend in &self.backends {
+            match Self::get_batch_from_backend(backend.as_ref(), block_number, sequencer_public_key)
+                .await
+            {
+                Ok(Some(batch)) => return Ok(Some(batch)),
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(
+                        "fail to get batch: {} from backend: {}, error: {:?}",
+                        block_number,
+                        backend.get_identifier(),
+                        e
+                    );
+                    last_err = Some(e);
+                }
+            }
+        }
+
+        match last_err {
+            Some(e) => Err(e),
+            None => Ok(None),
+        }
+    }
+
+    async fn get_batch_from_backend(
+        backend: &dyn DABackend,
+        block_number: u128,
+        sequencer_public_key: Option<&PublicKey>,
+    ) -> anyhow::Result<Option<DABatch>> {
+        let chunk = match Self::get_chunk_of_block(backend, block_number).await? {
+            Some(chunk) => chunk,
+            None => return Ok(None),
+        };
+        // the block is not submitted yet if it's not in the nearest chunk
+        let batch = match chunk
+            .get_batches()
+            .into_iter()
+            .find(|batch| batch.meta.block_range.block_number == block_number)
+        {
+            Some(batch) => batch,
+            None => return Ok(None),
+        };
+        batch.verify(true)?;
+        if let Some(sequencer_public_key) = sequencer_public_key {
+            batch.verify_signer(sequencer_public_key)?;
+        }
+        Ok(Some(batch))
+    }
+
+    // chunk_id is the block number of the first batch in the chunk (ChunkV0 has only one batch),
+    // so the chunk which the block belongs to is the nearest one at or before the block,
+    // and it's at most CHUNK_V1_MAX_BATCHES - 1 blocks before the block.
+    async fn get_chunk_of_block(
+        backend: &dyn DABackend,
+        block_number: u128,
+    ) -> anyhow::Result<Option<Box<dyn Chunk>>> {
+        let min_chunk_id = block_number.saturating_sub(CHUNK_V1_MAX_BATCHES as u128 - 1);
+        for chunk_id in (min_chunk_id..=block_number).rev() {
+            if let Some(chunk) = backend.get_chunk(chunk_id).await? {
+                return Ok(Some(chunk));
+            }
+        }
+        Ok(None)
+    }
+
     // sort backends by their priority
     fn sort_backends(&mut self) {
         let priority_map: HashMap<&str, usize> = BACKENDS_PRIORITY
@@ -9,12 +9,14 @@ use crate::backend::openda::celestia::{
     DEFAULT_CELESTIA_MAX_SEGMENT_SIZE,
 };
 use crate::backend::openda::opendal::BACK_OFF_MIN_DELAY;
+use crate::backend::openda::scanner::START_HEIGHT_CONFIG_KEY;
 use anyhow::anyhow;
 use async_trait::async_trait;
 use opendal::layers::{LoggingLayer, RetryLayer};
 use opendal::Scheme;
 use rooch_config::da_config::{DABackendOpenDAConfig, OpenDAScheme};
 use rooch_config::retrieve_map_config_value;
+use rooch_types::da::chunk::{ChunkV1, ChunkVersion, DEFAULT_CHUNK_V1_COMPRESSION_LEVEL};
 use rooch_types::da::segment::SegmentID;
 use std::collections::HashMap;
 
@@ -29,6 +31,8 @@ pub(crate) trait OpenDAAdapter: Sync + Send {
         segment_id: SegmentID,
         segment_bytes: &[u8],
     ) -> anyhow::Result<()>;
+    /// get segment bytes by segment_id, return None if not found
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>>;
 }
 
 #[derive(Clone)]
@@ -36,6 +40,8 @@ pub(crate) struct OpenDAAdapterConfig {
     pub(crate) namespace: String,
     pub(crate) max_segment_size: usize,
     pub(crate) max_retries: usize,
+    pub(crate) chunk_version: ChunkVersion,
+    pub(crate) compression_level: i32,
     pub(crate) scheme: OpenDAScheme,
     pub(crate) scheme_config: HashMap<String, String>,
 }
@@ -64,10 +70,21 @@ impl OpenDAAdapterConfig {
             .max_segment_size
             .unwrap_or(default_max_segment_size) as usize;
 
+        let chunk_version = ChunkVersion::from(open_da_config.chunk_version.unwrap_or(0));
+        if !matches!(chunk_version, ChunkVersion::V0 | ChunkVersion::V1) {
+            return Err(anyhow!("unsupported chunk version: {:?}", chunk_version));
+        }
+        let compression_level = open_da_config
+            .compression_level
+            .unwrap_or(DEFAULT_CHUNK_V1_COMPRESSION_LEVEL);
+        ChunkV1::check_compression_level(compression_level)?;
+
         Ok(OpenDAAdapterConfig {
             namespace,
             max_segment_size,
             max_retries,
+            chunk_version,
+            compression_level,
             scheme,
             scheme_config,
         })
@@ -80,8 +97,11 @@ impl OpenDAAdapterConfig {
 
         let operator: Box<dyn OpenDAAdapter> = match scheme {
             OpenDAScheme::Avail => {
-                let avail_fusion_config =
-                    AvailFusionClientConfig::from_scheme_config(scheme_config, max_retries)?;
+                let avail_fusion_config = AvailFusionClientConfig::from_scheme_config(
+                    scheme_config,
+                    max_retries,
+                    self.start_height()?,
+                )?;
                 let avail_fusion_client = avail_fusion_config.build_client()?;
                 Box::new(avail_fusion_client)
             }
@@ -93,6 +113,7 @@ impl OpenDAAdapterConfig {
                         &scheme_config["endpoint"],
                         scheme_config.get("auth_token").map(|s| s.as_str()),
                         max_retries,
+                        self.start_height()?,
                     )
                     .await?,
                 )
@@ -112,6 +133,21 @@ impl OpenDAAdapterConfig {
         };
         Ok(operator)
     }
+
+    // DA layer height to start scanning segments from, for backends without key-value read API
+    fn start_height(&self) -> anyhow::Result<u64> {
+        match self.scheme_config.get(START_HEIGHT_CONFIG_KEY) {
+            Some(height) => height.parse::<u64>().map_err(|e| {
+                anyhow!(
+                    "invalid {} in config for scheme {:?}: {}",
+                    START_HEIGHT_CONFIG_KEY,
+                    self.scheme,
+                    e
+                )
+            }),
+            None => Ok(1),
+        }
+    }
 }
 
 fn check_scheme_config(
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::backend::openda::adapter::OpenDAAdapter;
+use crate::backend::openda::scanner::{HeightIndexedSource, SegmentScanner};
 use anyhow::anyhow;
 use async_trait::async_trait;
 use base64::engine::general_purpose;
@@ -11,6 +12,7 @@ use rooch_types::da::segment::SegmentID;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::HashMap;
+use std::sync::Arc;
 use tokio::time::{sleep, Duration};
 
 // small blob size for transaction to get included in a block quickly
@@ -21,12 +23,15 @@ const MAX_BACKOFF_DELAY: Duration = Duration::from_secs(30);
 
 const MIN_BACKOFF_DELAY: Duration = Duration::from_millis(3000);
 const SUBMIT_API_PATH: &str = "v2/submit";
+const STATUS_API_PATH: &str = "v2/status";
+const BLOCK_DATA_API_PATH: &str = "v2/blocks";
 
 const TURBO_MIN_BACKOFF_DELAY: Duration = Duration::from_millis(500);
 const TURBO_SUBMIT_API_PATH: &str = "user/submit_raw_data";
 
 /// Avail client: A turbo and Light
 /// Turbo client has higher priority, if not available, use the Light client
+/// Reading segments is only supported by the Light client
 #[derive(Clone)]
 pub struct AvailFusionAdapter {
     turbo_client: Option<AvailTurboClient>,
@@ -66,6 +71,15 @@ impl OpenDAAdapter for AvailFusionAdapter {
             Err(anyhow!("Both turbo and light clients are not available"))
         }
     }
+
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        match &self.light_client {
+            Some(light_client) => light_client.get_segment(segment_id).await,
+            None => Err(anyhow!(
+                "light_endpoint must be provided for reading segments from Avail"
+            )),
+        }
+    }
 }
 
 pub struct AvailFusionClientConfig {
@@ -73,12 +87,14 @@ pub struct AvailFusionClientConfig {
     pub turbo_auth_token: Option<String>,
     pub light_endpoint: Option<String>,
     pub max_retries: usize,
+    pub start_height: u64,
 }
 
 impl AvailFusionClientConfig {
     pub fn from_scheme_config(
         scheme_config: HashMap<String, String>,
         max_retries: usize,
+        start_height: u64,
     ) -> anyhow::Result<Self> {
         let turbo_endpoint = scheme_config.get("turbo_endpoint").cloned();
         let turbo_auth_token = scheme_config.get("turbo_auth_token").cloned();
@@ -96,6 +112,7 @@ impl AvailFusionClientConfig {
             turbo_auth_token,
             light_endpoint,
             max_retries,
+            start_height,
         })
     }
 
@@ -110,7 +127,11 @@ impl AvailFusionClientConfig {
             None
         };
         let light_client = if let Some(endpoint) = &self.light_endpoint {
-            Some(AvailLightClient::new(endpoint, self.max_retries)?)
+            Some(AvailLightClient::new(
+                endpoint,
+                self.max_retries,
+                self.start_height,
+            )?)
         } else {
             None
         };
@@ -233,6 +254,10 @@ impl OpenDAAdapter for AvailTurboClient {
             ));
         }
     }
+
+    async fn get_segment(&self, _segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        Err(anyhow!("Avail Turbo doesn't support reading segments"))
+    }
 }
 
 #[derive(Clone)]
@@ -240,20 +265,88 @@ pub struct AvailLightClient {
     endpoint: String,
     http_client: Client,
     max_retries: usize,
+    scanner: Arc<SegmentScanner>,
 }
 
 impl AvailLightClient {
-    pub fn new(endpoint: &str, max_retries: usize) -> anyhow::Result<Self> {
+    pub fn new(endpoint: &str, max_retries: usize, start_height: u64) -> anyhow::Result<Self> {
         let client = Client::new();
 
         Ok(AvailLightClient {
             endpoint: endpoint.to_string(),
             http_client: client,
             max_retries,
+            scanner: Arc::new(SegmentScanner::new(start_height)),
         })
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AvailLightClientStatusResponse {
+    blocks: AvailLightClientStatusBlocks,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AvailLightClientStatusBlocks {
+    latest: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AvailLightClientBlockDataResponse {
+    block_number: u64,
+    data_transactions: Vec<AvailLightClientDataTransaction>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AvailLightClientDataTransaction {
+    data: String,
+}
+
+#[async_trait]
+impl HeightIndexedSource for AvailLightClient {
+    async fn latest_height(&self) -> anyhow::Result<u64> {
+        let status_url = format!("{}/{}", self.endpoint, STATUS_API_PATH);
+        let response = self.http_client.get(&status_url).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Failed to get status from Avail: {}",
+                response.status()
+            ));
+        }
+        let status: AvailLightClientStatusResponse = response.json().await?;
+        Ok(status.blocks.latest)
+    }
+
+    async fn get_blobs(&self, height: u64) -> anyhow::Result<Vec<Vec<u8>>> {
+        let data_url = format!("{}/{}/{}/data", self.endpoint, BLOCK_DATA_API_PATH, height);
+        let response = self
+            .http_client
+            .get(&data_url)
+            .query(&[("fields", "data")])
+            .send()
+            .await?;
+        match response.status() {
+            StatusCode::OK => {
+                let block_data: AvailLightClientBlockDataResponse = response.json().await?;
+                block_data
+                    .data_transactions
+                    .into_iter()
+                    .map(|tx| {
+                        general_purpose::STANDARD
+                            .decode(tx.data)
+                            .map_err(|e| anyhow!("Failed to decode Avail data: {:?}", e))
+                    })
+                    .collect()
+            }
+            _ => Err(anyhow!(
+                "Failed to get block data at height: {} from Avail: {}",
+                height,
+                response.status(),
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AvailLightClientSubmitResponse {
     block_number: u32,
@@ -325,4 +418,8 @@ impl OpenDAAdapter for AvailLightClient {
             }
         }
     }
+
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        self.scanner.get_segment(self, segment_id).await
+    }
 }
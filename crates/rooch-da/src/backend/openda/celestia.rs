@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::backend::openda::adapter::OpenDAAdapter;
+use crate::backend::openda::scanner::{HeightIndexedSource, SegmentScanner};
 use anyhow::anyhow;
 use async_trait::async_trait;
-use celestia_rpc::{BlobClient, Client};
+use celestia_rpc::{BlobClient, Client, HeaderClient};
 use celestia_types::blob::SubmitOptions;
 use celestia_types::nmt::Namespace;
 use celestia_types::Blob;
@@ -24,6 +25,7 @@ pub(crate) struct CelestiaAdapter {
     namespace: Namespace,
     client: Client,
     max_retries: usize,
+    scanner: SegmentScanner,
 }
 
 impl CelestiaAdapter {
@@ -32,16 +34,39 @@ impl CelestiaAdapter {
         endpoint: &str,
         auth_token: Option<&str>,
         max_retries: usize,
+        start_height: u64,
     ) -> anyhow::Result<Self> {
         let celestia_client = Client::new(endpoint, auth_token).await?;
         Ok(CelestiaAdapter {
             namespace,
             client: celestia_client,
             max_retries,
+            scanner: SegmentScanner::new(start_height),
         })
     }
 }
 
+#[async_trait]
+impl HeightIndexedSource for CelestiaAdapter {
+    async fn latest_height(&self) -> anyhow::Result<u64> {
+        let head = self.client.header_network_head().await?;
+        Ok(head.height().value())
+    }
+
+    async fn get_blobs(&self, height: u64) -> anyhow::Result<Vec<Vec<u8>>> {
+        match self.client.blob_get_all(height, &[self.namespace]).await {
+            Ok(blobs) => Ok(blobs.into_iter().map(|blob| blob.data).collect()),
+            // celestia node returns error rather than empty list if there is no blob in namespace
+            Err(e) if e.to_string().contains("not found") => Ok(vec![]),
+            Err(e) => Err(anyhow!(
+                "Failed to get blobs from Celestia at height: {}: {:?}",
+                height,
+                e
+            )),
+        }
+    }
+}
+
 #[async_trait]
 impl OpenDAAdapter for CelestiaAdapter {
     async fn submit_segment(
@@ -93,6 +118,10 @@ impl OpenDAAdapter for CelestiaAdapter {
             }
         }
     }
+
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        self.scanner.get_segment(self, segment_id).await
+    }
 }
 
 pub(crate) struct WrappedNamespace(Namespace);
@@ -4,10 +4,14 @@
 use crate::backend::openda::adapter::{OpenDAAdapter, OpenDAAdapterConfig};
 use crate::backend::openda::derive_identifier;
 use crate::backend::DABackend;
+use anyhow::anyhow;
 use async_trait::async_trait;
 use rooch_config::da_config::DABackendOpenDAConfig;
 use rooch_types::da::batch::DABatch;
-use rooch_types::da::chunk::{Chunk, ChunkV0};
+use rooch_types::da::chunk::{
+    chunk_from_segments, Chunk, ChunkV0, ChunkV1, ChunkVersion, CHUNK_V1_MAX_BATCHES,
+};
+use rooch_types::da::segment::{segment_from_bytes, SegmentID};
 use std::sync::Arc;
 
 /// manage OpenDA backends while integrating specific adapter logic
@@ -30,13 +34,30 @@ impl OpenDABackendManager {
             adapter,
         })
     }
-}
 
-#[async_trait]
-impl DABackend for OpenDABackendManager {
-    async fn submit_batch(&self, batch: Arc<DABatch>) -> anyhow::Result<()> {
-        let chunk: ChunkV0 = (*batch).clone().into();
+    // ChunkV0: a chunk for each batch; ChunkV1: consecutive batches are packed into chunks
+    fn make_chunks(&self, batches: Vec<Arc<DABatch>>) -> anyhow::Result<Vec<Box<dyn Chunk>>> {
+        let batches = batches
+            .into_iter()
+            .map(|batch| (*batch).clone())
+            .collect::<Vec<_>>();
+        match self.adapter_config.chunk_version {
+            ChunkVersion::V1 => batches
+                .chunks(CHUNK_V1_MAX_BATCHES)
+                .map(|batches| {
+                    let chunk = ChunkV1::new(batches.to_vec())?
+                        .with_compression_level(self.adapter_config.compression_level);
+                    Ok(Box::new(chunk) as Box<dyn Chunk>)
+                })
+                .collect(),
+            _ => Ok(batches
+                .into_iter()
+                .map(|batch| Box::new(ChunkV0::from(batch)) as Box<dyn Chunk>)
+                .collect()),
+        }
+    }
 
+    async fn submit_chunk(&self, chunk: &dyn Chunk) -> anyhow::Result<()> {
         let max_segment_size = self.adapter_config.max_segment_size;
 
         let segments = chunk.to_segments(max_segment_size);
@@ -59,8 +80,217 @@ impl DABackend for OpenDABackendManager {
 
         Ok(())
     }
+}
+
+#[async_trait]
+impl DABackend for OpenDABackendManager {
+    async fn submit_batch(&self, batch: Arc<DABatch>) -> anyhow::Result<()> {
+        self.submit_batches(vec![batch]).await
+    }
+
+    async fn submit_batches(&self, batches: Vec<Arc<DABatch>>) -> anyhow::Result<()> {
+        for chunk in self.make_chunks(batches)? {
+            self.submit_chunk(chunk.as_ref()).await?;
+        }
+        Ok(())
+    }
+
+    async fn get_chunk(&self, chunk_id: u128) -> anyhow::Result<Option<Box<dyn Chunk>>> {
+        let mut segments = Vec::new();
+        loop {
+            let segment_id = SegmentID {
+                chunk_id,
+                segment_number: segments.len() as u64,
+            };
+            let bytes = match self.adapter.get_segment(segment_id).await? {
+                Some(bytes) => bytes,
+                None if segments.is_empty() => return Ok(None),
+                None => {
+                    return Err(anyhow!(
+                        "missing segment: {} in {}, chunk is incomplete",
+                        segment_id,
+                        self.identifier
+                    ))
+                }
+            };
+            let segment = segment_from_bytes(&bytes)?;
+            if segment.get_id() != segment_id {
+                return Err(anyhow!(
+                    "segment id mismatch in {}, exp: {}, act: {}",
+                    self.identifier,
+                    segment_id,
+                    segment.get_id()
+                ));
+            }
+            let is_last = segment.is_last();
+            segments.push(segment);
+            if is_last {
+                break;
+            }
+        }
+
+        chunk_from_segments(segments).map(Some)
+    }
 
     fn get_identifier(&self) -> String {
         self.identifier.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::DABackends;
+    use moveos_types::h256;
+    use rooch_config::da_config::OpenDAScheme;
+    use rooch_types::crypto::{RoochKeyPair, Signature};
+    use rooch_types::test_utils::random_ledger_transaction;
+    use std::collections::HashMap;
+
+    fn fs_config(
+        root: &std::path::Path,
+        chunk_version: Option<u8>,
+        max_segment_size: u64,
+    ) -> DABackendOpenDAConfig {
+        DABackendOpenDAConfig {
+            scheme: OpenDAScheme::Fs,
+            config: HashMap::from([("root".to_string(), root.display().to_string())]),
+            namespace: Some("test_namespace".to_string()),
+            max_segment_size: Some(max_segment_size),
+            max_retries: None,
+            chunk_version,
+            compression_level: None,
+        }
+    }
+
+    // consecutive batches of blocks, tx orders are signed by the sequencer
+    fn new_batches(
+        keypair: &RoochKeyPair,
+        block_numbers: std::ops::Range<u128>,
+        txs_per_block: u64,
+    ) -> Vec<DABatch> {
+        let mut batches = Vec::new();
+        let mut tx_order = 0;
+        for block_number in block_numbers {
+            let tx_order_start = tx_order;
+            let tx_list = (0..txs_per_block)
+                .map(|_| {
+                    let mut tx = random_ledger_transaction();
+                    let mut witness_data = tx.data.tx_hash().as_ref().to_vec();
+                    witness_data.extend(tx_order.to_le_bytes().iter());
+                    let witness_hash = h256::sha3_256_of(&witness_data);
+                    tx.sequence_info.tx_order = tx_order;
+                    tx.sequence_info.tx_order_signature =
+                        Signature::sign(&witness_hash.0, keypair).as_ref().to_vec();
+                    tx_order += 1;
+                    tx
+                })
+                .collect::<Vec<_>>();
+            batches.push(DABatch::new(
+                block_number,
+                tx_order_start,
+                tx_order - 1,
+                &tx_list,
+                keypair.copy(),
+            ));
+        }
+        batches
+    }
+
+    #[tokio::test]
+    async fn test_fs_submit_and_get_chunk() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let mut config = HashMap::new();
+        config.insert(
+            "root".to_string(),
+            tmp_dir.path().to_str().unwrap().to_string(),
+        );
+        let open_da_config = DABackendOpenDAConfig {
+            scheme: OpenDAScheme::Fs,
+            config,
+            namespace: Some("test_namespace".to_string()),
+            max_segment_size: Some(1024),
+            max_retries: None,
+            chunk_version: None,
+            compression_level: None,
+        };
+        let backend = OpenDABackendManager::new(&open_da_config).await.unwrap();
+
+        let tx_list = (0..64)
+            .map(|_| random_ledger_transaction())
+            .collect::<Vec<_>>();
+        let batch = DABatch::new(7, 1, 64, &tx_list, RoochKeyPair::generate_secp256k1());
+        backend.submit_batch(Arc::new(batch.clone())).await.unwrap();
+
+        let chunk = backend.get_chunk(7).await.unwrap().unwrap();
+        assert_eq!(chunk.get_batches(), vec![batch]);
+        assert!(backend.get_chunk(8).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fs_submit_and_get_batches_of_mixed_chunks() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let keypair = RoochKeyPair::generate_secp256k1();
+        let batches = new_batches(&keypair, 0..6, 4)
+            .into_iter()
+            .map(Arc::new)
+            .collect::<Vec<_>>();
+
+        // block 0, 1 are submitted as ChunkV0, then the backend is upgraded to ChunkV1,
+        // block [2, 4] are packed into a ChunkV1, block 5 is submitted alone as a ChunkV1
+        let backend_v0 = OpenDABackendManager::new(&fs_config(tmp_dir.path(), None, 512))
+            .await
+            .unwrap();
+        backend_v0
+            .submit_batches(batches[..2].to_vec())
+            .await
+            .unwrap();
+        let backend_v1 = OpenDABackendManager::new(&fs_config(tmp_dir.path(), Some(1), 512))
+            .await
+            .unwrap();
+        backend_v1
+            .submit_batches(batches[2..5].to_vec())
+            .await
+            .unwrap();
+        backend_v1.submit_batch(batches[5].clone()).await.unwrap();
+
+        let chunk = backend_v1.get_chunk(1).await.unwrap().unwrap();
+        assert_eq!(chunk.get_version(), ChunkVersion::V0);
+        let chunk = backend_v1.get_chunk(2).await.unwrap().unwrap();
+        assert_eq!(chunk.get_version(), ChunkVersion::V1);
+        assert_eq!(chunk.get_batches().len(), 3);
+        // block 3 is in the chunk of block 2
+        assert!(backend_v1.get_chunk(3).await.unwrap().is_none());
+
+        let backends = DABackends {
+            backends: vec![Arc::new(backend_v1)],
+            submit_threshold: 1,
+        };
+        for batch in &batches {
+            let block_number = batch.meta.block_range.block_number;
+            let act_batch = backends
+                .get_batch(block_number, Some(&keypair.public()))
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(&act_batch, batch.as_ref());
+        }
+        assert!(backends.get_batch(6, None).await.unwrap().is_none());
+        assert!(backends
+            .get_batch(5, Some(&RoochKeyPair::generate_secp256k1().public()))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_chunk_config() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let mut config = fs_config(tmp_dir.path(), Some(2), 512);
+        assert!(OpenDABackendManager::new(&config).await.is_err());
+        config.chunk_version = Some(1);
+        config.compression_level = Some(100);
+        assert!(OpenDABackendManager::new(&config).await.is_err());
+        config.compression_level = Some(19);
+        assert!(OpenDABackendManager::new(&config).await.is_ok());
+    }
+}
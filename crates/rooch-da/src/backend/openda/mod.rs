@@ -6,6 +6,7 @@ mod avail;
 mod celestia;
 mod manager;
 mod opendal;
+mod scanner;
 
 pub use self::manager::OpenDABackendManager;
 use rooch_config::da_config::OpenDAScheme;
@@ -21,4 +21,13 @@ impl OpenDAAdapter for opendal::Operator {
         w.close().await?;
         Ok(())
     }
+
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        let path = segment_id.to_string();
+        match self.read(&path).await {
+            Ok(buf) => Ok(Some(buf.to_vec())),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
 }
@@ -0,0 +1,287 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use rooch_types::da::segment::{segment_from_bytes, SegmentID};
+use std::collections::{BTreeMap, HashMap};
+use tokio::sync::Mutex;
+
+// scheme config key of the DA layer height to start scanning segments from
+pub(crate) const START_HEIGHT_CONFIG_KEY: &str = "start_height";
+// max segments cached but not consumed yet, avoiding OOM when scanning a busy namespace
+const MAX_CACHED_SEGMENTS: usize = 4096;
+// max segment heights indexed, the index of the oldest chunks is evicted first
+const MAX_INDEXED_SEGMENTS: usize = 1 << 20;
+
+/// A DA layer which has no key-value read API (e.g. Celestia, Avail),
+/// segments could only be listed by the DA layer height.
+#[async_trait]
+pub(crate) trait HeightIndexedSource: Sync + Send {
+    /// latest height of the DA layer which could be read
+    async fn latest_height(&self) -> anyhow::Result<u64>;
+    /// all blobs (raw segment bytes) submitted by us at the given height
+    async fn get_blobs(&self, height: u64) -> anyhow::Result<Vec<Vec<u8>>>;
+}
+
+struct ScanState {
+    next_height: u64,
+    found: HashMap<SegmentID, Vec<u8>>,
+    // DA layer height of every segment scanned, keyed by (chunk_id, segment_number),
+    // so segments dropped from the cache or consumed already could be fetched again
+    heights: BTreeMap<(u128, u64), u64>,
+    max_chunk_id: Option<u128>,
+}
+
+impl ScanState {
+    fn index(&mut self, segment_id: SegmentID, height: u64, max_indexed: usize) {
+        self.heights
+            .insert((segment_id.chunk_id, segment_id.segment_number), height);
+        if self.heights.len() > max_indexed {
+            self.heights.pop_first();
+        }
+        self.max_chunk_id = Some(
+            self.max_chunk_id
+                .map_or(segment_id.chunk_id, |id| id.max(segment_id.chunk_id)),
+        );
+    }
+}
+
+/// SegmentScanner finds segments by walking the DA layer height forward.
+///
+/// Segments are submitted in order, so readers (e.g. DA syncer) asking for segments in order
+/// only need to scan each height once.
+/// Segments found but not requested yet are cached until they are asked for. When the cache is full,
+/// the segments are dropped but their heights are indexed, and the height is read again on request.
+/// Segments behind the scanned heights but not indexed (evicted) are found by rescanning from the start height.
+pub(crate) struct SegmentScanner {
+    start_height: u64,
+    max_cached: usize,
+    max_indexed: usize,
+    state: Mutex<ScanState>,
+}
+
+impl SegmentScanner {
+    pub(crate) fn new(start_height: u64) -> Self {
+        Self::with_capacity(start_height, MAX_CACHED_SEGMENTS, MAX_INDEXED_SEGMENTS)
+    }
+
+    fn with_capacity(start_height: u64, max_cached: usize, max_indexed: usize) -> Self {
+        Self {
+            start_height,
+            max_cached,
+            max_indexed,
+            state: Mutex::new(ScanState {
+                next_height: start_height,
+                found: HashMap::new(),
+                heights: BTreeMap::new(),
+                max_chunk_id: None,
+            }),
+        }
+    }
+
+    pub(crate) async fn get_segment(
+        &self,
+        source: &dyn HeightIndexedSource,
+        segment_id: SegmentID,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let mut state = self.state.lock().await;
+        if let Some(bytes) = state.found.remove(&segment_id) {
+            return Ok(Some(bytes));
+        }
+        let indexed_height = state
+            .heights
+            .get(&(segment_id.chunk_id, segment_id.segment_number))
+            .copied();
+        if let Some(height) = indexed_height {
+            if let Some(bytes) = Self::find_at(source, height, segment_id).await? {
+                return Ok(Some(bytes));
+            }
+        }
+
+        let latest_height = source.latest_height().await?;
+        while state.next_height <= latest_height {
+            let height = state.next_height;
+            let mut requested = None;
+            for blob in source.get_blobs(height).await? {
+                match segment_from_bytes(&blob) {
+                    Ok(segment) => {
+                        let id = segment.get_id();
+                        state.index(id, height, self.max_indexed);
+                        if id == segment_id {
+                            requested = Some(blob);
+                        } else if state.found.len() < self.max_cached {
+                            state.found.insert(id, blob);
+                        } else {
+                            tracing::debug!(
+                                "too many unconsumed segments cached, drop segment: {} at height: {}, it will be read again on request",
+                                id,
+                                height
+                            );
+                        }
+                    }
+                    Err(e) => {
+                        // namespace may be shared with others, skip blobs which are not segments
+                        tracing::debug!(
+                            "skip invalid segment at height: {}, error: {:?}",
+                            height,
+                            e
+                        );
+                    }
+                }
+            }
+            state.next_height = height + 1;
+
+            if requested.is_some() {
+                return Ok(requested);
+            }
+        }
+
+        // The segment is behind the scanned heights but its height index is evicted
+        let is_behind =
+            matches!(state.max_chunk_id, Some(max_chunk_id) if segment_id.chunk_id < max_chunk_id);
+        if indexed_height.is_none() && is_behind {
+            for height in self.start_height..state.next_height {
+                if let Some(bytes) = Self::find_at(source, height, segment_id).await? {
+                    state.index(segment_id, height, self.max_indexed);
+                    return Ok(Some(bytes));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn find_at(
+        source: &dyn HeightIndexedSource,
+        height: u64,
+        segment_id: SegmentID,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        for blob in source.get_blobs(height).await? {
+            if let Ok(segment) = segment_from_bytes(&blob) {
+                if segment.get_id() == segment_id {
+                    return Ok(Some(blob));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rooch_types::da::segment::{Segment, SegmentV0};
+
+    struct MockSource {
+        blobs: Vec<Vec<Vec<u8>>>,
+    }
+
+    #[async_trait]
+    impl HeightIndexedSource for MockSource {
+        async fn latest_height(&self) -> anyhow::Result<u64> {
+            Ok(self.blobs.len() as u64 - 1)
+        }
+
+        async fn get_blobs(&self, height: u64) -> anyhow::Result<Vec<Vec<u8>>> {
+            Ok(self.blobs[height as usize].clone())
+        }
+    }
+
+    fn segment_bytes(chunk_id: u128, segment_number: u64) -> Vec<u8> {
+        SegmentV0 {
+            id: SegmentID {
+                chunk_id,
+                segment_number,
+            },
+            is_last: false,
+            data_len: 3,
+            data_checksum: 0,
+            checksum: 0,
+            data: vec![1, 2, 3],
+        }
+        .to_bytes()
+    }
+
+    #[tokio::test]
+    async fn test_segment_scanner() {
+        let source = MockSource {
+            blobs: vec![
+                vec![segment_bytes(0, 0), vec![0xff; 8]],
+                vec![],
+                vec![segment_bytes(1, 0), segment_bytes(0, 1)],
+            ],
+        };
+        let scanner = SegmentScanner::new(0);
+
+        let id = |chunk_id, segment_number| SegmentID {
+            chunk_id,
+            segment_number,
+        };
+        assert_eq!(
+            scanner.get_segment(&source, id(0, 1)).await.unwrap(),
+            Some(segment_bytes(0, 1))
+        );
+        // cached while scanning for 0_1
+        assert_eq!(
+            scanner.get_segment(&source, id(0, 0)).await.unwrap(),
+            Some(segment_bytes(0, 0))
+        );
+        assert_eq!(
+            scanner.get_segment(&source, id(1, 0)).await.unwrap(),
+            Some(segment_bytes(1, 0))
+        );
+        assert_eq!(scanner.get_segment(&source, id(2, 0)).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_segment_scanner_cache_full() {
+        let source = MockSource {
+            blobs: vec![
+                vec![segment_bytes(0, 0), segment_bytes(0, 1)],
+                vec![segment_bytes(1, 0)],
+                vec![segment_bytes(2, 0), segment_bytes(3, 0)],
+                vec![segment_bytes(4, 0)],
+            ],
+        };
+        // cache only one segment and index only three segments
+        let scanner = SegmentScanner::with_capacity(0, 1, 3);
+
+        let id = |chunk_id, segment_number| SegmentID {
+            chunk_id,
+            segment_number,
+        };
+        // 0_0 is cached, 0_1, 1_0 and 2_0 are dropped while scanning for 3_0
+        assert_eq!(
+            scanner.get_segment(&source, id(3, 0)).await.unwrap(),
+            Some(segment_bytes(3, 0))
+        );
+        // dropped segments are read again by the indexed height, out of order
+        assert_eq!(
+            scanner.get_segment(&source, id(2, 0)).await.unwrap(),
+            Some(segment_bytes(2, 0))
+        );
+        assert_eq!(
+            scanner.get_segment(&source, id(1, 0)).await.unwrap(),
+            Some(segment_bytes(1, 0))
+        );
+        // index of 0_1 is evicted, found by rescanning
+        assert_eq!(
+            scanner.get_segment(&source, id(0, 1)).await.unwrap(),
+            Some(segment_bytes(0, 1))
+        );
+        assert_eq!(
+            scanner.get_segment(&source, id(0, 0)).await.unwrap(),
+            Some(segment_bytes(0, 0))
+        );
+        // consumed segments could be read again
+        assert_eq!(
+            scanner.get_segment(&source, id(3, 0)).await.unwrap(),
+            Some(segment_bytes(3, 0))
+        );
+        assert_eq!(
+            scanner.get_segment(&source, id(4, 0)).await.unwrap(),
+            Some(segment_bytes(4, 0))
+        );
+        assert_eq!(scanner.get_segment(&source, id(5, 0)).await.unwrap(), None);
+    }
+}
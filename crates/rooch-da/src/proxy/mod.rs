@@ -1,7 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::actor::messages::{AppendTransactionMessage, GetServerStatusMessage};
+use crate::actor::messages::{
+    AppendSyncedBlockMessage, AppendTransactionMessage, GetServerStatusMessage,
+};
 use crate::actor::server::DAServerActor;
 use coerce::actor::ActorRef;
 use rooch_types::da::status::DAServerStatus;
@@ -23,4 +25,8 @@ impl DAServerProxy {
     pub async fn append_tx(&self, msg: AppendTransactionMessage) -> anyhow::Result<()> {
         self.actor.send(msg).await?
     }
+
+    pub async fn append_synced_block(&self, msg: AppendSyncedBlockMessage) -> anyhow::Result<()> {
+        self.actor.send(msg).await?
+    }
 }
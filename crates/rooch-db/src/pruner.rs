@@ -0,0 +1,182 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use moveos_store::state_store::statedb::StatePruneResult;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::pruner_store::PrunerStore;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_store::RoochStore;
+
+/// StatePruner removes the state tree nodes which are not referenced by the kept state roots.
+/// The state store counts the references of the nodes in the latest state, and indexes the node which is not
+/// referenced any more by the state version since then. The pruner keeps the state roots of the recent
+/// `keep_recent_roots` tx orders, and pages through the stale index up to the version of the oldest kept state root.
+/// The `pinned_roots` are pinned in the state store, their nodes are referenced until they are unpinned.
+#[derive(Clone)]
+pub struct StatePruner {
+    moveos_store: MoveOSStore,
+    rooch_store: RoochStore,
+    keep_recent_roots: u64,
+    pinned_roots: Vec<H256>,
+    batch_size: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct PruneRoundResult {
+    /// The state roots up to this tx order are pruned
+    pub last_pruned_order: Option<u64>,
+    /// The number of the checked stale node indexes
+    pub stale_indexes: u64,
+    pub pruned_nodes: u64,
+    pub reclaimed_bytes: u64,
+    /// There are no more stale nodes to prune
+    pub done: bool,
+}
+
+impl StatePruner {
+    pub fn new(
+        moveos_store: MoveOSStore,
+        rooch_store: RoochStore,
+        keep_recent_roots: u64,
+        pinned_roots: Vec<H256>,
+        batch_size: u64,
+    ) -> Self {
+        Self {
+            moveos_store,
+            rooch_store,
+            keep_recent_roots: keep_recent_roots.max(1),
+            pinned_roots,
+            batch_size: batch_size.max(1),
+        }
+    }
+
+    /// Check at most `batch_size` stale node indexes, and remove the nodes not referenced by the kept state roots.
+    /// It runs concurrently with the executor, the state store serializes the node writes and deletions.
+    pub fn prune_round(&self) -> Result<PruneRoundResult> {
+        self.sync_pinned_roots()?;
+        let mut result = PruneRoundResult {
+            last_pruned_order: self.rooch_store.get_last_pruned_order()?,
+            ..Default::default()
+        };
+        let last_order = match self.rooch_store.get_sequencer_info()? {
+            Some(sequencer_info) => sequencer_info.last_order,
+            None => {
+                result.done = true;
+                return Ok(result);
+            }
+        };
+        let oldest_kept_order = last_order.saturating_sub(self.keep_recent_roots - 1);
+        // The nodes not referenced since the version of the oldest kept state root are not referenced by the kept state roots,
+        // the state root written before the versions are tracked has no stale nodes indexed.
+        let max_version = match self.state_root(oldest_kept_order)? {
+            Some(state_root) => self
+                .moveos_store
+                .get_state_store()
+                .get_state_root_version(state_root)?,
+            None => None,
+        };
+        let (Some(max_version), Some(last_pruned_order)) =
+            (max_version, oldest_kept_order.checked_sub(1))
+        else {
+            result.done = true;
+            return Ok(result);
+        };
+
+        let StatePruneResult {
+            stale_indexes,
+            pruned_nodes,
+            reclaimed_bytes,
+        } = self
+            .moveos_store
+            .get_state_store()
+            .prune(max_version, self.batch_size as usize)?;
+        result.stale_indexes = stale_indexes;
+        result.pruned_nodes = pruned_nodes;
+        result.reclaimed_bytes = reclaimed_bytes;
+        result.done = stale_indexes < self.batch_size;
+        if result.done {
+            self.rooch_store.set_last_pruned_order(last_pruned_order)?;
+            result.last_pruned_order = Some(last_pruned_order);
+        }
+        Ok(result)
+    }
+
+    /// Rebuild the node reference counts from the latest state root and the `pinned_roots`, and remove all the other nodes,
+    /// including the nodes written before the reference counts are tracked. Only the latest state root and the
+    /// `pinned_roots` are readable after rebuilding, it must be called when the executor is not running.
+    pub fn rebuild(&self) -> Result<PruneRoundResult> {
+        let mut result = PruneRoundResult {
+            done: true,
+            ..Default::default()
+        };
+        let Some(startup_info) = self.moveos_store.config_store.get_startup_info()? else {
+            return Ok(result);
+        };
+        let StatePruneResult {
+            stale_indexes,
+            pruned_nodes,
+            reclaimed_bytes,
+        } = self
+            .moveos_store
+            .get_state_store()
+            .rebuild_ref_counts(startup_info.state_root, &self.pinned_roots)?;
+        self.rooch_store
+            .set_pinned_roots(self.pinned_roots.clone())?;
+        if let Some(last_pruned_order) = self
+            .rooch_store
+            .get_sequencer_info()?
+            .and_then(|sequencer_info| sequencer_info.last_order.checked_sub(1))
+        {
+            self.rooch_store.set_last_pruned_order(last_pruned_order)?;
+            result.last_pruned_order = Some(last_pruned_order);
+        }
+        result.stale_indexes = stale_indexes;
+        result.pruned_nodes = pruned_nodes;
+        result.reclaimed_bytes = reclaimed_bytes;
+        Ok(result)
+    }
+
+    /// Pin the configured `pinned_roots` and unpin the state roots removed from them.
+    /// A state root is saved as pinned after all its nodes are pinned, if pinning fails halfway,
+    /// the pinned nodes are never pruned.
+    fn sync_pinned_roots(&self) -> Result<()> {
+        let state_store = self.moveos_store.get_state_store();
+        let mut pinned_roots = self.rooch_store.get_pinned_roots()?;
+        for state_root in pinned_roots.clone() {
+            if !self.pinned_roots.contains(&state_root) {
+                state_store.unpin_state_root(state_root)?;
+                pinned_roots.retain(|pinned_root| *pinned_root != state_root);
+                self.rooch_store.set_pinned_roots(pinned_roots.clone())?;
+            }
+        }
+        for state_root in &self.pinned_roots {
+            if !pinned_roots.contains(state_root) {
+                state_store.pin_state_root(*state_root)?;
+                pinned_roots.push(*state_root);
+                self.rooch_store.set_pinned_roots(pinned_roots.clone())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The state root after executing the transaction of the `tx_order`
+    fn state_root(&self, tx_order: u64) -> Result<Option<H256>> {
+        let Some(tx_hash) = self
+            .rooch_store
+            .get_tx_hashes(vec![tx_order])?
+            .into_iter()
+            .flatten()
+            .next()
+        else {
+            return Ok(None);
+        };
+        Ok(self
+            .moveos_store
+            .get_tx_execution_info(tx_hash)?
+            .map(|execution_info| execution_info.state_root))
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::event::{GasUpgradeEvent, ServiceStatusEvent};
+use crate::event::{GasUpgradeEvent, ServiceStatusEvent, TransactionIndexedEvent};
 use async_trait::async_trait;
 use coerce::actor::context::ActorContext;
 use coerce::actor::message::{Handler, Message};
@@ -68,6 +68,31 @@ impl Handler<UpdateServiceStatusMessage> for EventActor {
     }
 }
 
+#[derive(Default, Clone, Debug)]
+pub struct TransactionIndexedMessage {
+    pub tx_order: u64,
+}
+
+impl Message for TransactionIndexedMessage {
+    type Result = anyhow::Result<()>;
+}
+
+#[async_trait]
+impl Handler<TransactionIndexedMessage> for EventActor {
+    async fn handle(
+        &mut self,
+        message: TransactionIndexedMessage,
+        _ctx: &mut ActorContext,
+    ) -> anyhow::Result<()> {
+        tracing::debug!("EventActor receive message {:?}", message);
+        self.event_bus
+            .notify::<TransactionIndexedEvent>(TransactionIndexedEvent {
+                tx_order: message.tx_order,
+            })?;
+        Ok(())
+    }
+}
+
 pub struct EventActorSubscribeMessage<T: Send + Sync + 'static> {
     event_type: T,
     subscriber: String,
@@ -10,3 +10,9 @@ pub struct GasUpgradeEvent {}
 pub struct ServiceStatusEvent {
     pub status: ServiceStatus,
 }
+
+/// The transaction with the tx_order is indexed, including its events and object states
+#[derive(Default, Clone, Debug)]
+pub struct TransactionIndexedEvent {
+    pub tx_order: u64,
+}
@@ -3,27 +3,36 @@
 
 use super::messages::{
     ConvertL2TransactionData, DryRunTransactionMessage, DryRunTransactionResult,
-    ExecuteTransactionMessage, ExecuteTransactionResult, GetRootMessage, SaveStateChangeSetMessage,
-    ValidateL1BlockMessage, ValidateL1TxMessage, ValidateL2TxMessage,
+    ExecuteTransactionMessage, ExecuteTransactionResult, ExecuteTransactionsMessage,
+    GetRootMessage, SaveStateChangeSetMessage, ValidateL1BlockMessage, ValidateL1TxMessage,
+    ValidateL2TxMessage,
 };
 use crate::metrics::ExecutorMetrics;
 use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor, LocalActorRef};
 use function_name::named;
-use move_core_types::vm_status::VMStatus;
-use moveos::moveos::{MoveOS, MoveOSConfig};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::Op;
+use move_core_types::vm_status::{KeptVMStatus, VMStatus};
+use moveos::moveos::{ExecutionOutputWithReadSet, MoveOS, MoveOSConfig};
 use moveos::vm::vm_status_explainer::explain_vm_status;
 use moveos_eventbus::bus::EventData;
 use moveos_store::MoveOSStore;
 use moveos_types::function_return_value::FunctionResult;
+use moveos_types::h256::H256;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_std::option::MoveOption;
-use moveos_types::moveos_std::object::ObjectMeta;
+use moveos_types::moveos_std::object::{DynamicField, ObjectID, ObjectMeta};
+use moveos_types::moveos_std::timestamp::Timestamp;
 use moveos_types::moveos_std::tx_context::TxContext;
-use moveos_types::state::{ObjectState, StateChangeSetExt};
-use moveos_types::state_resolver::RootObjectResolver;
-use moveos_types::transaction::{FunctionCall, MoveOSTransaction, VerifiedMoveAction};
+use moveos_types::state::{
+    MoveState, ObjectChange, ObjectState, StateChangeSet, StateChangeSetExt,
+};
+use moveos_types::state_resolver::{MoveOSResolver, RootObjectResolver, StateResolver};
+use moveos_types::transaction::{
+    FunctionCall, MoveOSTransaction, RawTransactionOutput, VerifiedMoveAction,
+};
 use moveos_types::transaction::{MoveAction, VerifiedMoveOSTransaction};
 use prometheus::Registry;
 use rooch_event::actor::{EventActor, EventActorSubscribeMessage, GasUpgradeMessage};
@@ -36,7 +45,10 @@ use rooch_types::bitcoin::BitcoinModule;
 use rooch_types::framework::auth_validator::{
     AuthValidatorCaller, BuiltinAuthValidator, TxValidateResult,
 };
+use rooch_types::framework::coin_store::CoinStore;
 use rooch_types::framework::ethereum::EthereumModule;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transaction_fee::TransactionFeePool;
 use rooch_types::framework::transaction_validator::TransactionValidator;
 use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
 use rooch_types::multichain_id::RoochMultiChainID;
@@ -44,6 +56,7 @@ use rooch_types::transaction::{
     AuthenticatorInfo, L1Block, L1BlockWithBody, L1Transaction, RoochTransaction,
     RoochTransactionData,
 };
+use std::collections::BTreeSet;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -56,7 +69,7 @@ pub struct ExecutorActor {
     event_actor: Option<LocalActorRef<EventActor>>,
 }
 
-type ValidateAuthenticatorResult = Result<TxValidateResult, VMStatus>;
+pub type ValidateAuthenticatorResult = Result<TxValidateResult, VMStatus>;
 
 impl ExecutorActor {
     pub fn new(
@@ -124,6 +137,152 @@ impl ExecutorActor {
         let tx_hash = tx.ctx.tx_hash();
         let size = tx.ctx.tx_size;
         let (raw_output, _) = self.moveos.execute_only(tx)?;
+        self.apply_output(tx_hash, size, raw_output, fn_name)
+    }
+
+    /// Execute the sequenced transactions in a Block-STM style.
+    /// The transactions are executed speculatively in parallel against the current root,
+    /// then committed in order. If a transaction reads an Object changed by the previous transactions in the batch,
+    /// it is executed again against the latest root, so the state root is the same as the sequential execution.
+    /// The Timestamp and transaction fee Objects written by every transaction are not conflicts,
+    /// their changes are rebased on the latest root when committing, see `CommutativeObjects`.
+    /// The results are in the order of the transactions, and stop at the first failed transaction,
+    /// the following transactions are not executed.
+    #[named]
+    pub fn execute_batch(
+        &mut self,
+        txs: Vec<VerifiedMoveOSTransaction>,
+    ) -> Vec<Result<ExecuteTransactionResult>> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .executor_execute_batch_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let base_root = self.root.clone();
+        let speculative_results = self.moveos.execute_speculatively(&base_root, txs.clone());
+        // The module and gas schedule upgrades change the caches of the VM,
+        // the speculative outputs can not be trusted, so fall back to sequential execution.
+        let mut sequential = speculative_results.iter().any(|result| {
+            result
+                .as_ref()
+                .is_ok_and(|(output, _, _)| output.is_upgrade || output.is_gas_upgrade)
+        });
+        if sequential {
+            self.flush_vm_caches();
+        }
+
+        let commutative = match CommutativeObjects::load(
+            &RootObjectResolver::new(base_root.clone(), &self.moveos_store),
+            &speculative_results,
+        ) {
+            Ok(commutative) => commutative,
+            Err(e) => return vec![Err(e)],
+        };
+
+        let mut written = BTreeSet::new();
+        let mut results = Vec::with_capacity(txs.len());
+        for (mut tx, speculative_result) in txs.into_iter().zip(speculative_results) {
+            let rebased_output = match speculative_result {
+                Ok((raw_output, _, read_set))
+                    if !sequential
+                        && read_set
+                            .iter()
+                            .all(|id| commutative.contains(id) || !written.contains(id)) =>
+                {
+                    self.rebase_speculative_output(&base_root, &commutative, raw_output)
+                }
+                _ => Ok(None),
+            };
+            let result = match rebased_output {
+                Ok(Some(raw_output)) => {
+                    self.metrics
+                        .executor_parallel_tx_count
+                        .with_label_values(&["committed"])
+                        .inc();
+                    self.apply_output(tx.ctx.tx_hash(), tx.ctx.tx_size, raw_output, fn_name)
+                }
+                Ok(None) => {
+                    self.metrics
+                        .executor_parallel_tx_count
+                        .with_label_values(&["reexecuted"])
+                        .inc();
+                    tx.root = self.root.clone();
+                    self.execute(tx)
+                }
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(result) => {
+                    written.extend(
+                        result
+                            .output
+                            .changeset
+                            .write_set()
+                            .into_iter()
+                            .filter(|id| !commutative.contains(id)),
+                    );
+                    if !sequential && result.output.is_upgrade {
+                        self.flush_vm_caches();
+                        sequential = true;
+                    }
+                    results.push(Ok(result));
+                }
+                Err(e) => {
+                    results.push(Err(e));
+                    break;
+                }
+            }
+        }
+        results
+    }
+
+    /// Rebase the output executed against the `base_root` on the current root. The read set of the transaction
+    /// is not changed since the `base_root` except the commutative Objects, so only the root Object
+    /// and the changes of the commutative Objects need to be rebased.
+    /// Return None if the commutative changes can not be applied in order, the transaction must be executed again.
+    fn rebase_speculative_output(
+        &self,
+        base_root: &ObjectMeta,
+        commutative: &CommutativeObjects,
+        mut raw_output: RawTransactionOutput,
+    ) -> Result<Option<RawTransactionOutput>> {
+        // The failed transaction may abort on the value of a commutative Object,
+        // such as withdrawing more gas revenue than the balance in the `base_root`.
+        if raw_output.status != KeptVMStatus::Executed {
+            return Ok(None);
+        }
+        let base = RootObjectResolver::new(base_root.clone(), &self.moveos_store);
+        let current = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
+        let changeset = &mut raw_output.changeset;
+        for change in changeset.changes.values_mut() {
+            if commutative.contains(&change.metadata.id)
+                && !commutative.rebase_change(&base, &current, change)?
+            {
+                return Ok(None);
+            }
+        }
+        if !commutative.is_timestamp_in_order(&base, &current, changeset)? {
+            return Ok(None);
+        }
+        changeset.state_root = self.root.state_root();
+        changeset.global_size = self.root.size + changeset.global_size - base_root.size;
+        Ok(Some(raw_output))
+    }
+
+    fn flush_vm_caches(&self) {
+        self.moveos.flush_cost_table();
+        // flush_module_cache only marks the cache as invalid, it never fails
+        let _ = self.moveos.flush_module_cache(true);
+    }
+
+    fn apply_output(
+        &mut self,
+        tx_hash: H256,
+        size: u64,
+        raw_output: RawTransactionOutput,
+        fn_name: &str,
+    ) -> Result<ExecuteTransactionResult> {
         let is_gas_upgrade = raw_output.is_gas_upgrade;
 
         let (output, execution_info) = self.moveos_store.handle_tx_output(tx_hash, raw_output)?;
@@ -172,49 +331,8 @@ impl ExecutorActor {
             .executor_validate_tx_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
-        let tx_hash = l1_block.block.tx_hash();
         let tx_size = l1_block.block.tx_size();
-        let ctx = TxContext::new_system_call_ctx(tx_hash, tx_size);
-        //TODO we should call the contract to validate the l1 block has been executed
-        //In the future, we should verify the block PoW difficulty or PoS validator signature before the sequencer decentralized
-        let L1BlockWithBody {
-            block:
-                L1Block {
-                    chain_id,
-                    block_height,
-                    block_hash,
-                },
-            block_body,
-        } = l1_block;
-        let result = match RoochMultiChainID::try_from(chain_id.id())? {
-            RoochMultiChainID::Bitcoin => {
-                let action = VerifiedMoveAction::Function {
-                    call: BitcoinModule::create_execute_l1_block_call_bytes(
-                        block_height,
-                        block_hash,
-                        block_body,
-                    )?,
-                    bypass_visibility: true,
-                };
-                Ok(VerifiedMoveOSTransaction::new(
-                    self.root.clone(),
-                    ctx,
-                    action,
-                ))
-            }
-            RoochMultiChainID::Ether => {
-                let action = VerifiedMoveAction::Function {
-                    call: EthereumModule::create_execute_l1_block_call_bytes(block_body),
-                    bypass_visibility: true,
-                };
-                Ok(VerifiedMoveOSTransaction::new(
-                    self.root.clone(),
-                    ctx,
-                    action,
-                ))
-            }
-            id => Err(anyhow::anyhow!("Chain {} not supported yet", id)),
-        };
+        let result = l1_block_to_verified_tx(self.root.clone(), l1_block);
 
         self.metrics
             .executor_validate_tx_bytes
@@ -231,24 +349,8 @@ impl ExecutorActor {
             .executor_validate_tx_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
-        let tx_hash = l1_tx.tx_hash();
         let tx_size = l1_tx.tx_size();
-        let ctx = TxContext::new_system_call_ctx(tx_hash, tx_size);
-        //TODO we should call the contract to validate the l1 tx has been executed
-        let result = match RoochMultiChainID::try_from(l1_tx.chain_id.id())? {
-            RoochMultiChainID::Bitcoin => {
-                let action = VerifiedMoveAction::Function {
-                    call: BitcoinModule::create_execute_l1_tx_call(l1_tx.block_hash, l1_tx.txid)?,
-                    bypass_visibility: true,
-                };
-                Ok(VerifiedMoveOSTransaction::new(
-                    self.root.clone(),
-                    ctx,
-                    action,
-                ))
-            }
-            id => Err(anyhow::anyhow!("Chain {} not supported yet", id)),
-        };
+        let result = l1_tx_to_verified_tx(self.root.clone(), l1_tx);
 
         self.metrics
             .executor_validate_tx_bytes
@@ -336,32 +438,7 @@ impl ExecutorActor {
             .executor_validate_tx_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
-        let tx_validator = self.as_module_binding::<TransactionValidator>();
-        let tx_validate_function_result = tx_validator
-            .validate(ctx, authenticator.clone())?
-            .into_result();
-
-        let vm_result = match tx_validate_function_result {
-            Ok(tx_validate_result) => {
-                let auth_validator_option = tx_validate_result.auth_validator();
-                match auth_validator_option {
-                    Some(auth_validator) => {
-                        let auth_validator_caller = AuthValidatorCaller::new(self, auth_validator);
-                        let auth_validator_function_result = auth_validator_caller
-                            .validate(ctx, authenticator.authenticator.payload)?
-                            .into_result();
-                        match auth_validator_function_result {
-                            Ok(_) => Ok(tx_validate_result),
-                            Err(vm_status) => Err(vm_status),
-                        }
-                    }
-                    None => Ok(tx_validate_result),
-                }
-            }
-            Err(vm_status) => Err(vm_status),
-        };
-
-        Ok(vm_result)
+        validate_authenticator_by_caller(self, ctx, authenticator)
     }
 
     pub fn convert_to_verified_tx(
@@ -482,6 +559,17 @@ impl Handler<ExecuteTransactionMessage> for ExecutorActor {
     }
 }
 
+#[async_trait]
+impl Handler<ExecuteTransactionsMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ExecuteTransactionsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Vec<Result<ExecuteTransactionResult>> {
+        self.execute_batch(msg.txs)
+    }
+}
+
 #[async_trait]
 impl Handler<GetRootMessage> for ExecutorActor {
     async fn handle(
@@ -554,3 +642,297 @@ impl Handler<DryRunTransactionMessage> for ExecutorActor {
         self.dry_run(msg.tx)
     }
 }
+
+/// Build the system call executing the L1 block at the `root`.
+pub fn l1_block_to_verified_tx(
+    root: ObjectMeta,
+    l1_block: L1BlockWithBody,
+) -> Result<VerifiedMoveOSTransaction> {
+    let tx_hash = l1_block.block.tx_hash();
+    let tx_size = l1_block.block.tx_size();
+    let ctx = TxContext::new_system_call_ctx(tx_hash, tx_size);
+    //TODO we should call the contract to validate the l1 block has been executed
+    //In the future, we should verify the block PoW difficulty or PoS validator signature before the sequencer decentralized
+    let L1BlockWithBody {
+        block:
+            L1Block {
+                chain_id,
+                block_height,
+                block_hash,
+            },
+        block_body,
+    } = l1_block;
+    match RoochMultiChainID::try_from(chain_id.id())? {
+        RoochMultiChainID::Bitcoin => {
+            let action = VerifiedMoveAction::Function {
+                call: BitcoinModule::create_execute_l1_block_call_bytes(
+                    block_height,
+                    block_hash,
+                    block_body,
+                )?,
+                bypass_visibility: true,
+            };
+            Ok(VerifiedMoveOSTransaction::new(root, ctx, action))
+        }
+        RoochMultiChainID::Ether => {
+            let action = VerifiedMoveAction::Function {
+                call: EthereumModule::create_execute_l1_block_call_bytes(block_body),
+                bypass_visibility: true,
+            };
+            Ok(VerifiedMoveOSTransaction::new(root, ctx, action))
+        }
+        id => Err(anyhow::anyhow!("Chain {} not supported yet", id)),
+    }
+}
+
+/// Build the system call executing the L1 tx at the `root`.
+pub fn l1_tx_to_verified_tx(
+    root: ObjectMeta,
+    l1_tx: L1Transaction,
+) -> Result<VerifiedMoveOSTransaction> {
+    let tx_hash = l1_tx.tx_hash();
+    let tx_size = l1_tx.tx_size();
+    let ctx = TxContext::new_system_call_ctx(tx_hash, tx_size);
+    //TODO we should call the contract to validate the l1 tx has been executed
+    match RoochMultiChainID::try_from(l1_tx.chain_id.id())? {
+        RoochMultiChainID::Bitcoin => {
+            let action = VerifiedMoveAction::Function {
+                call: BitcoinModule::create_execute_l1_tx_call(l1_tx.block_hash, l1_tx.txid)?,
+                bypass_visibility: true,
+            };
+            Ok(VerifiedMoveOSTransaction::new(root, ctx, action))
+        }
+        RoochMultiChainID::Ether => {
+            let action = VerifiedMoveAction::Function {
+                call: EthereumModule::create_execute_l1_tx_call(l1_tx.block_hash, l1_tx.txid),
+                bypass_visibility: true,
+            };
+            Ok(VerifiedMoveOSTransaction::new(root, ctx, action))
+        }
+        id => Err(anyhow::anyhow!("Chain {} not supported yet", id)),
+    }
+}
+
+/// Validate the authenticator by calling the TransactionValidator and the auth validator of the transaction
+/// through the `caller`, which reads the states the transaction is validated against.
+pub fn validate_authenticator_by_caller<C: MoveFunctionCaller>(
+    caller: &C,
+    ctx: &TxContext,
+    authenticator: AuthenticatorInfo,
+) -> Result<ValidateAuthenticatorResult> {
+    let tx_validator = caller.as_module_binding::<TransactionValidator>();
+    let tx_validate_function_result = tx_validator
+        .validate(ctx, authenticator.clone())?
+        .into_result();
+
+    let vm_result = match tx_validate_function_result {
+        Ok(tx_validate_result) => {
+            let auth_validator_option = tx_validate_result.auth_validator();
+            match auth_validator_option {
+                Some(auth_validator) => {
+                    let auth_validator_caller = AuthValidatorCaller::new(caller, auth_validator);
+                    let auth_validator_function_result = auth_validator_caller
+                        .validate(ctx, authenticator.authenticator.payload)?
+                        .into_result();
+                    match auth_validator_function_result {
+                        Ok(_) => Ok(tx_validate_result),
+                        Err(vm_status) => Err(vm_status),
+                    }
+                }
+                None => Ok(tx_validate_result),
+            }
+        }
+        Err(vm_status) => Err(vm_status),
+    };
+
+    Ok(vm_result)
+}
+
+/// ResolverFunctionCaller calls the Move functions and validates the L2 transaction
+/// against the states of the `resolver`, instead of the latest states of the executor.
+pub struct ResolverFunctionCaller<'a, R> {
+    moveos: &'a MoveOS,
+    resolver: &'a R,
+}
+
+impl<'a, R> ResolverFunctionCaller<'a, R>
+where
+    R: MoveOSResolver + Sync,
+{
+    pub fn new(moveos: &'a MoveOS, resolver: &'a R) -> Self {
+        Self { moveos, resolver }
+    }
+
+    pub fn validate_l2_tx(&self, tx: RoochTransaction) -> Result<VerifiedMoveOSTransaction> {
+        let authenticator = tx.authenticator_info();
+        let mut moveos_tx = tx.into_moveos_transaction(self.resolver.root().clone());
+        let tx_validate_result =
+            validate_authenticator_by_caller(self, &moveos_tx.ctx, authenticator)?.map_err(
+                |vm_status| anyhow::anyhow!("Validate transaction failed: {:?}", vm_status),
+            )?;
+        moveos_tx.ctx.add(tx_validate_result)?;
+        Ok(self.moveos.verify_with_resolver(self.resolver, moveos_tx)?)
+    }
+}
+
+impl<R> MoveFunctionCaller for ResolverFunctionCaller<'_, R>
+where
+    R: MoveOSResolver + Sync,
+{
+    fn call_function(&self, ctx: &TxContext, call: FunctionCall) -> Result<FunctionResult> {
+        Ok(self
+            .moveos
+            .execute_readonly_function_with_resolver(self.resolver, ctx, call))
+    }
+}
+
+/// The Objects written by the system pre_execute and post_execute functions of every L2 transaction:
+/// the global Timestamp, the TransactionFeePool with its gas revenue fields, and the CoinStores of the fee and the gas revenues.
+/// The transactions only advance the Timestamp and deposit the gas fee to the CoinStores, so the writes are commutative.
+/// They are excluded from the conflict detection of the parallel execution, and applied in order by rebasing on the latest root.
+struct CommutativeObjects {
+    timestamp_id: ObjectID,
+    fee_pool_id: ObjectID,
+    /// The CoinStores of the fee pool and the gas revenues read by the transactions
+    coin_store_ids: BTreeSet<ObjectID>,
+}
+
+impl CommutativeObjects {
+    fn load<R: StateResolver>(
+        base: &R,
+        speculative_results: &[Result<ExecutionOutputWithReadSet>],
+    ) -> Result<Self> {
+        let fee_pool_id = TransactionFeePool::object_id();
+        let mut coin_store_ids = BTreeSet::new();
+        if let Some(fee_pool) = base.get_object(&fee_pool_id)? {
+            coin_store_ids.insert(fee_pool.value_as::<TransactionFeePool>()?.fee);
+        }
+        let revenue_field_ids = speculative_results
+            .iter()
+            .flatten()
+            .flat_map(|(_, _, read_set)| read_set.iter())
+            .filter(|id| id.is_child(fee_pool_id.clone()))
+            .collect::<BTreeSet<_>>();
+        for field_id in revenue_field_ids {
+            if let Some(field) = base.get_object(field_id)? {
+                let revenue_store =
+                    field.value_as_uncheck::<DynamicField<AccountAddress, ObjectID>>()?;
+                coin_store_ids.insert(revenue_store.value);
+            }
+        }
+        Ok(Self {
+            timestamp_id: Timestamp::object_id(),
+            fee_pool_id,
+            coin_store_ids,
+        })
+    }
+
+    fn contains(&self, id: &ObjectID) -> bool {
+        *id == self.timestamp_id
+            || *id == self.fee_pool_id
+            || id.is_child(self.fee_pool_id.clone())
+            || self.coin_store_ids.contains(id)
+    }
+
+    /// Rebase the change of a commutative Object and its fields on the `current` state.
+    /// The `state_root` and the `size` are taken from the `current` state, and the deposit of the transaction
+    /// is added to the balance of the CoinStore in the `current` state. Return false if the change is not commutative,
+    /// such as a withdrawal, or an Object created by both the transaction and the previous transactions.
+    fn rebase_change<R: StateResolver>(
+        &self,
+        base: &R,
+        current: &R,
+        change: &mut ObjectChange,
+    ) -> Result<bool> {
+        let id = change.metadata.id.clone();
+        let (base_obj, current_obj) = match (base.get_object(&id)?, current.get_object(&id)?) {
+            (Some(base_obj), Some(current_obj)) => (base_obj, current_obj),
+            (None, None) => return Ok(matches!(change.value, Some(Op::New(_)))),
+            _ => return Ok(false),
+        };
+        match &mut change.value {
+            Some(Op::Modify(value)) => {
+                if !self.rebase_value(&base_obj, &current_obj, value)? {
+                    return Ok(false);
+                }
+            }
+            Some(Op::New(_)) | Some(Op::Delete) => return Ok(false),
+            None => {}
+        }
+        let Some(size) =
+            (current_obj.metadata.size + change.metadata.size).checked_sub(base_obj.metadata.size)
+        else {
+            return Ok(false);
+        };
+        change.metadata.state_root = current_obj.metadata.state_root;
+        change.metadata.size = size;
+        for field_change in change.fields.values_mut() {
+            if !self.rebase_change(base, current, field_change)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn rebase_value(
+        &self,
+        base_obj: &ObjectState,
+        current_obj: &ObjectState,
+        value: &mut Vec<u8>,
+    ) -> Result<bool> {
+        let id = &base_obj.metadata.id;
+        if *id == self.timestamp_id {
+            // The Timestamp is checked by `is_timestamp_in_order`
+            return Ok(true);
+        }
+        if self.coin_store_ids.contains(id) {
+            let base_store = base_obj.value_as::<CoinStore<RGas>>()?;
+            let current_store = current_obj.value_as::<CoinStore<RGas>>()?;
+            let store = CoinStore::<RGas>::from_bytes(&*value)?;
+            // The previous transactions in the batch only deposit to the CoinStore, so a withdrawal of the transaction
+            // which succeeds against the `base_root` also succeeds against the current root.
+            if store.balance() < base_store.balance()
+                || current_store.balance() < base_store.balance()
+                || store.frozen() != current_store.frozen()
+            {
+                return Ok(false);
+            }
+            let Some(balance) = current_store
+                .balance()
+                .checked_add(store.balance() - base_store.balance())
+            else {
+                return Ok(false);
+            };
+            *value = CoinStore::<RGas>::new(balance, store.frozen()).to_bytes();
+            return Ok(true);
+        }
+        // The TransactionFeePool and its fields only refer to the CoinStores, they are borrowed mutably but not changed
+        Ok(*value == base_obj.value && base_obj.value == current_obj.value)
+    }
+
+    /// The `updated_at` of the changed Objects is the Timestamp after the transaction, it is the same as
+    /// the sequential execution only if the previous transactions do not advance the Timestamp beyond it.
+    fn is_timestamp_in_order<R: StateResolver>(
+        &self,
+        base: &R,
+        current: &R,
+        changeset: &StateChangeSet,
+    ) -> Result<bool> {
+        let timestamp = match changeset
+            .changes
+            .get(&self.timestamp_id.field_key())
+            .and_then(|change| change.value.as_ref())
+        {
+            Some(Op::Modify(value)) => Timestamp::from_bytes(value)?,
+            _ => self.load_timestamp(base)?,
+        };
+        Ok(self.load_timestamp(current)?.milliseconds <= timestamp.milliseconds)
+    }
+
+    fn load_timestamp<R: StateResolver>(&self, resolver: &R) -> Result<Timestamp> {
+        resolver
+            .get_object(&self.timestamp_id)?
+            .ok_or_else(|| anyhow::format_err!("Timestamp Object not found"))?
+            .value_as::<Timestamp>()
+    }
+}
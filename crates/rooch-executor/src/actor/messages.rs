@@ -9,16 +9,17 @@ use moveos_types::access_path::AccessPath;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
-use moveos_types::moveos_std::object::ObjectMeta;
+use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
 use moveos_types::state::{AnnotatedState, FieldKey, ObjectState, StateChangeSetExt};
+use moveos_types::state_proof::StateWithProof;
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
 use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::transaction::TransactionOutput;
 use moveos_types::transaction::VerifiedMoveOSTransaction;
-use moveos_types::transaction::{FunctionCall, RawTransactionOutput, VMErrorInfo};
+use moveos_types::transaction::{CallTrace, FunctionCall, RawTransactionOutput, VMErrorInfo};
 use rooch_types::address::MultiChainAddress;
 use rooch_types::transaction::{
-    L1BlockWithBody, L1Transaction, RoochTransaction, RoochTransactionData,
+    L1BlockWithBody, L1Transaction, LedgerTransaction, RoochTransaction, RoochTransactionData,
 };
 use serde::{Deserialize, Serialize};
 
@@ -64,9 +65,20 @@ impl Message for ExecuteTransactionMessage {
     type Result = Result<ExecuteTransactionResult>;
 }
 
+#[derive(Debug)]
+pub struct ExecuteTransactionsMessage {
+    pub txs: Vec<VerifiedMoveOSTransaction>,
+}
+
+impl Message for ExecuteTransactionsMessage {
+    type Result = Vec<Result<ExecuteTransactionResult>>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ExecuteViewFunctionMessage {
     pub call: FunctionCall,
+    /// The sender of the readonly TxContext, default is 0x0
+    pub caller: Option<AccountAddress>,
 }
 
 impl Message for ExecuteViewFunctionMessage {
@@ -83,6 +95,16 @@ impl Message for StatesMessage {
     type Result = Result<Vec<Option<ObjectState>>>;
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatesWithProofMessage {
+    pub state_root: Option<H256>,
+    pub access_path: AccessPath,
+}
+
+impl Message for StatesWithProofMessage {
+    type Result = Result<Vec<StateWithProof>>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ResolveMessage {
     pub address: MultiChainAddress,
@@ -232,3 +254,50 @@ pub struct DryRunTransactionResult {
     pub raw_output: RawTransactionOutput,
     pub vm_error_info: Option<VMErrorInfo>,
 }
+
+#[derive(Debug)]
+pub struct TraceTransactionMessage {
+    pub tx: VerifiedMoveOSTransaction,
+}
+
+impl Message for TraceTransactionMessage {
+    type Result = Result<TraceTransactionResult>;
+}
+
+/// Trace the sequenced transaction against the state before it, `pre_root` is the root after the previous transaction.
+#[derive(Debug)]
+pub struct TraceLedgerTransactionMessage {
+    pub ledger_tx: LedgerTransaction,
+    pub pre_root: ObjectMeta,
+}
+
+impl Message for TraceLedgerTransactionMessage {
+    type Result = Result<TraceTransactionResult>;
+}
+
+#[derive(Debug)]
+pub struct TraceTransactionResult {
+    pub raw_output: RawTransactionOutput,
+    pub vm_error_info: Option<VMErrorInfo>,
+    pub execution_gas_used: u64,
+    pub storage_gas_used: u64,
+    pub call_trace: CallTrace,
+    /// The Objects changed by the transaction, the child Objects are after the parent
+    pub object_changes: Vec<TracedObjectChange>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TracedObjectChange {
+    pub object_id: ObjectID,
+    /// None if the Object is created by the transaction
+    pub before: Option<TracedObjectState>,
+    /// None if the Object is removed by the transaction
+    pub after: Option<TracedObjectState>,
+}
+
+#[derive(Debug, Clone)]
+pub enum TracedObjectState {
+    Decoded(AnnotatedState),
+    /// The state can not be decoded, such as the type is published by the traced transaction
+    Raw(ObjectState),
+}
@@ -4,41 +4,56 @@
 use super::messages::{
     AnnotatedStatesMessage, ExecuteViewFunctionMessage, GetAnnotatedEventsByEventHandleMessage,
     GetAnnotatedEventsByEventIDsMessage, GetEventsByEventHandleMessage, GetStateChangeSetsMessage,
-    RefreshStateMessage, StatesMessage,
+    RefreshStateMessage, StatesMessage, StatesWithProofMessage, TraceLedgerTransactionMessage,
+    TraceTransactionMessage, TraceTransactionResult, TracedObjectChange, TracedObjectState,
 };
+use crate::actor::executor::{l1_tx_to_verified_tx, ResolverFunctionCaller};
 use crate::actor::messages::{
     GetEventsByEventIDsMessage, GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage,
     ListStatesMessage,
 };
+use crate::metrics::ReaderExecutorMetrics;
 use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor, LocalActorRef};
+use function_name::named;
+use move_core_types::effects::Op;
 use move_resource_viewer::MoveValueAnnotator;
 use moveos::moveos::MoveOS;
 use moveos::moveos::MoveOSConfig;
+use moveos_common::types::ClassifiedGasMeter;
 use moveos_eventbus::bus::EventData;
+use moveos_gas_profiling::profiler::{new_verified_gas_profiler, ProfileGasMeter};
 use moveos_store::transaction_store::TransactionStore;
 use moveos_store::MoveOSStore;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::function_return_value::AnnotatedFunctionReturnValue;
+use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::EventHandle;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event};
 use moveos_types::moveos_std::object::ObjectMeta;
-use moveos_types::state::{AnnotatedState, ObjectState, StateChangeSetExt};
+use moveos_types::state::{AnnotatedState, FieldKey, ObjectChange, ObjectState, StateChangeSetExt};
+use moveos_types::state_proof::StateWithProof;
 use moveos_types::state_resolver::RootObjectResolver;
-use moveos_types::state_resolver::{AnnotatedStateKV, AnnotatedStateReader, StateKV, StateReader};
-use moveos_types::transaction::TransactionExecutionInfo;
+use moveos_types::state_resolver::{
+    AnnotatedStateKV, AnnotatedStateReader, StateKV, StateReader, StatelessResolver,
+};
+use moveos_types::transaction::{TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use prometheus::Registry;
 use rooch_event::actor::{EventActor, EventActorSubscribeMessage};
 use rooch_event::event::GasUpgradeEvent;
 use rooch_genesis::FrameworksGasParameters;
 use rooch_store::RoochStore;
 use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
+use rooch_types::transaction::{LedgerTransaction, LedgerTxData};
+use std::sync::Arc;
 
 pub struct ReaderExecutorActor {
     root: ObjectMeta,
     moveos: MoveOS,
     moveos_store: MoveOSStore,
     rooch_store: RoochStore,
+    metrics: Arc<ReaderExecutorMetrics>,
     event_actor: Option<LocalActorRef<EventActor>>,
 }
 
@@ -47,6 +62,7 @@ impl ReaderExecutorActor {
         root: ObjectMeta,
         moveos_store: MoveOSStore,
         rooch_store: RoochStore,
+        registry: &Registry,
         event_actor: Option<LocalActorRef<EventActor>>,
     ) -> Result<Self> {
         let resolver = RootObjectResolver::new(root.clone(), &moveos_store);
@@ -64,6 +80,7 @@ impl ReaderExecutorActor {
             moveos,
             moveos_store,
             rooch_store,
+            metrics: Arc::new(ReaderExecutorMetrics::new(registry)),
             event_actor,
         })
     }
@@ -94,6 +111,82 @@ impl ReaderExecutorActor {
         self.root = root;
         self.moveos.flush_module_cache(is_upgrade)
     }
+
+    /// Execute the transaction at its `root` with the gas profiler, the output is not applied.
+    /// The changed Objects are decoded with the states before and after the execution.
+    #[named]
+    pub fn trace(&self, tx: VerifiedMoveOSTransaction) -> Result<TraceTransactionResult> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .executor_trace_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let pre_root = tx.root.clone();
+        let action = tx.action.clone();
+        let mut profiler = None;
+        let (raw_output, vm_error_info, _read_set) =
+            self.moveos.execute_with_gas_meter(tx, |gas_meter| {
+                let gas_profiler = new_verified_gas_profiler(&action, gas_meter);
+                profiler = Some(gas_profiler.clone());
+                gas_profiler
+            })?;
+        let mut profiler = profiler.expect("The gas profiler must be created before execution");
+        let gas_statement = profiler.gas_statement();
+        let call_trace = profiler.finish().exec_io.call_graph.to_call_trace();
+
+        // Calculate the Object metadata after the execution in memory, the nodes are not saved
+        let mut changeset = raw_output.changeset.clone();
+        self.moveos_store
+            .state_store
+            .change_set_to_nodes(&mut changeset)?;
+        let resolver = RootObjectResolver::new(pre_root.clone(), &self.moveos_store);
+        let annotator = MoveValueAnnotator::new(&resolver);
+        let mut object_changes = vec![];
+        for (field_key, change) in &changeset.changes {
+            collect_object_changes(
+                &resolver,
+                &annotator,
+                Some(pre_root.state_root()),
+                *field_key,
+                change,
+                &mut object_changes,
+            )?;
+        }
+
+        Ok(TraceTransactionResult {
+            raw_output,
+            vm_error_info,
+            execution_gas_used: u64::from(gas_statement.execution_gas_used),
+            storage_gas_used: u64::from(gas_statement.storage_gas_used),
+            call_trace,
+            object_changes,
+        })
+    }
+
+    /// Trace the sequenced transaction at `pre_root`, the state before it.
+    /// The transaction is validated at `pre_root`, so the sequence number and the session key are checked at that state.
+    /// The cached gas schedule and modules are used, so the gas used may be different from the recorded
+    /// if the gas schedule or the modules are upgraded after the transaction.
+    pub fn trace_ledger_tx(
+        &self,
+        ledger_tx: LedgerTransaction,
+        pre_root: ObjectMeta,
+    ) -> Result<TraceTransactionResult> {
+        let mut tx = match ledger_tx.data {
+            LedgerTxData::L1Block(block) => anyhow::bail!(
+                "Trace the L1 block {} requires the block body, it is not supported yet",
+                block.block_height
+            ),
+            LedgerTxData::L1Tx(l1_tx) => l1_tx_to_verified_tx(pre_root, l1_tx)?,
+            LedgerTxData::L2Tx(l2_tx) => {
+                let resolver = RootObjectResolver::new(pre_root, &self.moveos_store);
+                ResolverFunctionCaller::new(&self.moveos, &resolver).validate_l2_tx(l2_tx)?
+            }
+        };
+        tx.ctx.add(ledger_tx.sequence_info)?;
+        self.trace(tx)
+    }
 }
 
 #[async_trait]
@@ -114,9 +207,9 @@ impl Handler<ExecuteViewFunctionMessage> for ReaderExecutorActor {
         _ctx: &mut ActorContext,
     ) -> Result<AnnotatedFunctionResult, anyhow::Error> {
         let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
-        let function_result = self
-            .moveos()
-            .execute_view_function(self.root.clone(), msg.call);
+        let function_result =
+            self.moveos()
+                .execute_view_function(self.root.clone(), msg.call, msg.caller);
 
         Ok(AnnotatedFunctionResult {
             vm_status: function_result.vm_status,
@@ -156,6 +249,26 @@ impl Handler<StatesMessage> for ReaderExecutorActor {
     }
 }
 
+#[async_trait]
+impl Handler<StatesWithProofMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: StatesWithProofMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<StateWithProof>, anyhow::Error> {
+        let state_root = msg.state_root.unwrap_or_else(|| self.root.state_root());
+        let state_store = self.moveos_store.get_state_store();
+        msg.access_path
+            .into_state_query()
+            .into_fields_query()?
+            .into_iter()
+            .map(|(object_id, field_key)| {
+                state_store.get_field_with_proof(state_root, &object_id, field_key)
+            })
+            .collect()
+    }
+}
+
 #[async_trait]
 impl Handler<AnnotatedStatesMessage> for ReaderExecutorActor {
     async fn handle(
@@ -305,6 +418,28 @@ impl Handler<GetTxExecutionInfosByHashMessage> for ReaderExecutorActor {
     }
 }
 
+#[async_trait]
+impl Handler<TraceTransactionMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: TraceTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<TraceTransactionResult> {
+        self.trace(msg.tx)
+    }
+}
+
+#[async_trait]
+impl Handler<TraceLedgerTransactionMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: TraceLedgerTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<TraceTransactionResult> {
+        self.trace_ledger_tx(msg.ledger_tx, msg.pre_root)
+    }
+}
+
 #[async_trait]
 impl Handler<RefreshStateMessage> for ReaderExecutorActor {
     async fn handle(&mut self, msg: RefreshStateMessage, _ctx: &mut ActorContext) -> Result<()> {
@@ -347,3 +482,56 @@ impl Handler<GetStateChangeSetsMessage> for ReaderExecutorActor {
             .multi_get_state_change_set(tx_orders)
     }
 }
+
+/// Collect the changed Object and its changed descendant Objects with the states before and after the execution.
+/// `parent_state_root` is the state root of the parent Object before the execution, None if the parent is created.
+fn collect_object_changes(
+    resolver: &RootObjectResolver<MoveOSStore>,
+    annotator: &MoveValueAnnotator<RootObjectResolver<MoveOSStore>>,
+    parent_state_root: Option<H256>,
+    field_key: FieldKey,
+    change: &ObjectChange,
+    object_changes: &mut Vec<TracedObjectChange>,
+) -> Result<()> {
+    let before = match parent_state_root {
+        Some(state_root) => resolver.get_field_at(state_root, &field_key)?,
+        None => None,
+    };
+    let after = match &change.value {
+        Some(Op::New(value)) | Some(Op::Modify(value)) => {
+            Some(ObjectState::new(change.metadata.clone(), value.clone()))
+        }
+        Some(Op::Delete) => None,
+        // Only the fields of the Object are changed
+        None => before
+            .as_ref()
+            .map(|state| ObjectState::new(change.metadata.clone(), state.value.clone())),
+    };
+    let state_root = before.as_ref().map(|state| state.state_root());
+    object_changes.push(TracedObjectChange {
+        object_id: change.metadata.id.clone(),
+        before: before.map(|state| decode_object_state(annotator, state)),
+        after: after.map(|state| decode_object_state(annotator, state)),
+    });
+    for (field_key, field_change) in &change.fields {
+        collect_object_changes(
+            resolver,
+            annotator,
+            state_root,
+            *field_key,
+            field_change,
+            object_changes,
+        )?;
+    }
+    Ok(())
+}
+
+fn decode_object_state(
+    annotator: &MoveValueAnnotator<RootObjectResolver<MoveOSStore>>,
+    state: ObjectState,
+) -> TracedObjectState {
+    match state.clone().into_annotated_state(annotator) {
+        Ok(annotated_state) => TracedObjectState::Decoded(annotated_state),
+        Err(_) => TracedObjectState::Raw(state),
+    }
+}
@@ -2,14 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use metrics::metrics_util::LATENCY_SEC_BUCKETS;
-use prometheus::{register_histogram_vec_with_registry, HistogramVec, Registry};
+use prometheus::{
+    register_histogram_vec_with_registry, register_int_counter_vec_with_registry, HistogramVec,
+    IntCounterVec, Registry,
+};
 
 #[derive(Debug)]
 pub struct ExecutorMetrics {
     pub executor_execute_tx_latency_seconds: HistogramVec,
     pub executor_execute_tx_bytes: HistogramVec,
+    pub executor_execute_batch_latency_seconds: HistogramVec,
     pub executor_validate_tx_latency_seconds: HistogramVec,
     pub executor_validate_tx_bytes: HistogramVec,
+    pub executor_parallel_tx_count: IntCounterVec,
 }
 
 impl ExecutorMetrics {
@@ -33,6 +38,14 @@ impl ExecutorMetrics {
                 registry,
             )
             .unwrap(),
+            executor_execute_batch_latency_seconds: register_histogram_vec_with_registry!(
+                "executor_execute_batch_latency_seconds",
+                "Executor execute a batch of txs in parallel latency in seconds",
+                &["fn_name"],
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
             executor_validate_tx_latency_seconds: register_histogram_vec_with_registry!(
                 "executor_validate_tx_latency_seconds",
                 "Executor validate tx latency in seconds",
@@ -51,6 +64,33 @@ impl ExecutorMetrics {
                 registry,
             )
             .unwrap(),
+            executor_parallel_tx_count: register_int_counter_vec_with_registry!(
+                "executor_parallel_tx_count",
+                "Executor parallel execution tx count, committed from the speculative output or reexecuted",
+                &["result"],
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ReaderExecutorMetrics {
+    pub executor_trace_tx_latency_seconds: HistogramVec,
+}
+
+impl ReaderExecutorMetrics {
+    pub(crate) fn new(registry: &Registry) -> Self {
+        ReaderExecutorMetrics {
+            executor_trace_tx_latency_seconds: register_histogram_vec_with_registry!(
+                "executor_trace_tx_latency_seconds",
+                "Executor trace tx with the gas profiler latency in seconds",
+                &["fn_name"],
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
         }
     }
 }
@@ -5,14 +5,15 @@ use crate::actor::messages::{
     ConvertL2TransactionData, DryRunTransactionResult, GetAnnotatedEventsByEventIDsMessage,
     GetEventsByEventHandleMessage, GetEventsByEventIDsMessage, GetStateChangeSetsMessage,
     GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage, ListStatesMessage,
-    RefreshStateMessage, SaveStateChangeSetMessage, ValidateL1BlockMessage, ValidateL1TxMessage,
+    RefreshStateMessage, SaveStateChangeSetMessage, TraceLedgerTransactionMessage,
+    TraceTransactionMessage, TraceTransactionResult, ValidateL1BlockMessage, ValidateL1TxMessage,
 };
 use crate::actor::reader_executor::ReaderExecutorActor;
 use crate::actor::{
     executor::ExecutorActor,
     messages::{
         AnnotatedStatesMessage, ExecuteViewFunctionMessage, GetAnnotatedEventsByEventHandleMessage,
-        StatesMessage, ValidateL2TxMessage,
+        StatesMessage, StatesWithProofMessage, ValidateL2TxMessage,
     },
 };
 use anyhow::{anyhow, Result};
@@ -27,6 +28,7 @@ use moveos_types::moveos_std::event::{Event, EventID};
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::state::{FieldKey, StateChangeSetExt};
+use moveos_types::state_proof::StateWithProof;
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
 use moveos_types::transaction::FunctionCall;
 use moveos_types::transaction::TransactionExecutionInfo;
@@ -39,7 +41,7 @@ use moveos_types::{
 use rooch_types::bitcoin::network::BitcoinNetwork;
 use rooch_types::framework::chain_id::ChainID;
 use rooch_types::transaction::{
-    L1BlockWithBody, L1Transaction, RoochTransaction, RoochTransactionData,
+    L1BlockWithBody, L1Transaction, LedgerTransaction, RoochTransaction, RoochTransactionData,
 };
 use tokio::runtime::Handle;
 
@@ -96,6 +98,22 @@ impl ExecutorProxy {
         Ok((result.output, result.transaction_info))
     }
 
+    /// Execute the sequenced transactions in parallel, the results are in the order of the transactions,
+    /// and stop at the first failed transaction.
+    pub async fn execute_transactions(
+        &self,
+        txs: Vec<VerifiedMoveOSTransaction>,
+    ) -> Result<Vec<Result<(TransactionOutput, TransactionExecutionInfo)>>> {
+        let results = self
+            .actor
+            .send(crate::actor::messages::ExecuteTransactionsMessage { txs })
+            .await?;
+        Ok(results
+            .into_iter()
+            .map(|result| result.map(|result| (result.output, result.transaction_info)))
+            .collect())
+    }
+
     pub async fn dry_run_transaction(
         &self,
         tx: VerifiedMoveOSTransaction,
@@ -107,12 +125,42 @@ impl ExecutorProxy {
         Ok(result)
     }
 
+    pub async fn trace_transaction(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<TraceTransactionResult> {
+        self.reader_actor
+            .send(TraceTransactionMessage { tx })
+            .await?
+    }
+
+    pub async fn trace_ledger_transaction(
+        &self,
+        ledger_tx: LedgerTransaction,
+        pre_root: ObjectMeta,
+    ) -> Result<TraceTransactionResult> {
+        self.reader_actor
+            .send(TraceLedgerTransactionMessage {
+                ledger_tx,
+                pre_root,
+            })
+            .await?
+    }
+
     pub async fn execute_view_function(
         &self,
         call: FunctionCall,
+    ) -> Result<AnnotatedFunctionResult> {
+        self.execute_view_function_with_caller(call, None).await
+    }
+
+    pub async fn execute_view_function_with_caller(
+        &self,
+        call: FunctionCall,
+        caller: Option<AccountAddress>,
     ) -> Result<AnnotatedFunctionResult> {
         self.reader_actor
-            .send(ExecuteViewFunctionMessage { call })
+            .send(ExecuteViewFunctionMessage { call, caller })
             .await?
     }
 
@@ -129,6 +177,19 @@ impl ExecutorProxy {
             .await?
     }
 
+    pub async fn get_states_with_proof(
+        &self,
+        access_path: AccessPath,
+        state_root: Option<H256>,
+    ) -> Result<Vec<StateWithProof>> {
+        self.reader_actor
+            .send(StatesWithProofMessage {
+                state_root,
+                access_path,
+            })
+            .await?
+    }
+
     pub async fn get_annotated_states(
         &self,
         access_path: AccessPath,
@@ -107,6 +107,7 @@ impl RustBindingTest {
             root.clone(),
             rooch_db.moveos_store.clone(),
             rooch_db.rooch_store.clone(),
+            &registry_service.default_registry(),
             None,
         )?;
         Ok(Self {
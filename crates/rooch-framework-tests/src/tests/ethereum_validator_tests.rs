@@ -0,0 +1,87 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::vm_status::VMStatus;
+use moveos_types::transaction::{MoveAction, MoveOSTransaction};
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::auth_validator::{AuthValidatorCaller, BuiltinAuthValidator};
+use rooch_types::framework::empty::Empty;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use rooch_types::transaction::{Authenticator, RoochTransaction};
+
+#[tokio::test]
+async fn test_validate() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let root = binding_test.root().clone();
+
+    let auth_validator = BuiltinAuthValidator::Ethereum.auth_validator();
+    let validator_caller = AuthValidatorCaller::new(&binding_test, auth_validator);
+
+    let kp = RoochKeyPair::generate_secp256k1();
+    let sender = kp.public().rooch_address().unwrap();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+
+    for authenticator in [
+        Authenticator::ethereum(&kp, &tx_data).unwrap(),
+        Authenticator::ethereum_typed_data(&kp, &tx_data).unwrap(),
+    ] {
+        let tx = RoochTransaction::new(tx_data.clone(), authenticator);
+        let auth_info = tx.authenticator_info();
+        let move_tx: MoveOSTransaction = tx.into_moveos_transaction(root.clone());
+
+        let result = validator_caller
+            .validate(&move_tx.ctx, auth_info.authenticator.payload)
+            .unwrap();
+        assert_eq!(result.vm_status, VMStatus::Executed);
+    }
+}
+
+#[tokio::test]
+async fn test_validate_wrong_sender() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let root = binding_test.root().clone();
+
+    let auth_validator = BuiltinAuthValidator::Ethereum.auth_validator();
+    let validator_caller = AuthValidatorCaller::new(&binding_test, auth_validator);
+
+    let kp = RoochKeyPair::generate_secp256k1();
+    let other_kp = RoochKeyPair::generate_secp256k1();
+    let sender = other_kp.public().rooch_address().unwrap();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+
+    let tx = RoochTransaction::new(
+        tx_data.clone(),
+        Authenticator::ethereum(&kp, &tx_data).unwrap(),
+    );
+    let auth_info = tx.authenticator_info();
+    let move_tx: MoveOSTransaction = tx.into_moveos_transaction(root);
+
+    let result = validator_caller
+        .validate(&move_tx.ctx, auth_info.authenticator.payload)
+        .unwrap();
+    assert_ne!(result.vm_status, VMStatus::Executed);
+}
+
+#[tokio::test]
+async fn test_execute_with_ethereum_authenticator() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    // The new account is created by the first transaction signed by the Ethereum wallet
+    let kp = RoochKeyPair::generate_secp256k1();
+    let sender = kp.public().rooch_address().unwrap();
+    for (sequence_number, sign_typed_data) in [(0, false), (1, true)] {
+        let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+        let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+        let authenticator = if sign_typed_data {
+            Authenticator::ethereum_typed_data(&kp, &tx_data).unwrap()
+        } else {
+            Authenticator::ethereum(&kp, &tx_data).unwrap()
+        };
+        binding_test
+            .execute(RoochTransaction::new(tx_data, authenticator))
+            .unwrap();
+    }
+}
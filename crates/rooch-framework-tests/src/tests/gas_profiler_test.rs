@@ -0,0 +1,72 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_gas_profiling::profiler::{new_verified_gas_profiler, ProfileGasMeter};
+use moveos_types::state::MoveStructType;
+use moveos_types::transaction::VerifiedMoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::transaction::rooch::RoochTransactionData;
+
+#[tokio::test]
+async fn test_gas_profiler_same_as_plain_execution() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let recipient = AccountAddress::random();
+    // The second transfer aborts, the session is respawned after the failed execution
+    for (sequence_number, amount) in [(0, U256::from(1u64)), (1, U256::max_value())] {
+        let action =
+            TransferModule::create_transfer_coin_action(RGas::struct_tag(), recipient, amount);
+        let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+        let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+        let verified_tx = binding_test.executor.validate_l2_tx(tx).unwrap();
+
+        let moveos = binding_test.executor.moveos();
+        let (output, vm_error_info, read_set) =
+            moveos.execute_with_read_set(verified_tx.clone()).unwrap();
+        let mut profiler = None;
+        let (profiled_output, profiled_vm_error_info, profiled_read_set) = moveos
+            .execute_with_gas_meter(verified_tx.clone(), |gas_meter| {
+                let gas_profiler = new_verified_gas_profiler(&verified_tx.action, gas_meter);
+                profiler = Some(gas_profiler.clone());
+                gas_profiler
+            })
+            .unwrap();
+        let gas_log = profiler.unwrap().finish();
+
+        assert_eq!(profiled_output, output);
+        assert_eq!(profiled_read_set, read_set);
+        assert_eq!(
+            profiled_vm_error_info.map(|info| info.error_message),
+            vm_error_info.map(|info| info.error_message)
+        );
+        assert!(output.gas_used > 0);
+        assert_eq!(
+            output.status == KeptVMStatus::Executed,
+            sequence_number == 0,
+            "unexpected status {:?}",
+            output.status
+        );
+        // The root frame of the call graph is the called function
+        let VerifiedMoveAction::Function { call, .. } = &verified_tx.action else {
+            panic!("the transfer should be a function call");
+        };
+        assert_eq!(
+            gas_log.exec_io.call_graph.to_call_trace().function,
+            Some(call.function_id.clone())
+        );
+
+        binding_test
+            .execute_verified_tx_as_result(verified_tx)
+            .unwrap();
+    }
+}
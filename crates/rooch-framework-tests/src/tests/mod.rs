@@ -11,7 +11,11 @@ mod brc20_test;
 mod chain_id_test;
 mod empty_tests;
 mod ethereum_test;
+mod ethereum_validator_tests;
+mod gas_profiler_test;
 mod multisign_account_tests;
 mod ord_test;
+mod parallel_execution_test;
 mod session_validator_tests;
+mod trace_test;
 mod view_function_gas;
@@ -0,0 +1,159 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::state::MoveStructType;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::address::RoochAddress;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+
+/// The number of the txs committed with the `result` in the parallel execution
+fn parallel_tx_count(binding_test: &binding_test::RustBindingTest, result: &str) -> u64 {
+    binding_test
+        .registry_service
+        .default_registry()
+        .gather()
+        .iter()
+        .filter(|family| family.get_name() == "executor_parallel_tx_count")
+        .flat_map(|family| family.get_metric())
+        .filter(|metric| {
+            metric
+                .get_label()
+                .iter()
+                .any(|label| label.get_value() == result)
+        })
+        .map(|metric| metric.get_counter().get_value() as u64)
+        .sum()
+}
+
+fn empty_call_tx(
+    keystore: &InMemKeystore,
+    sender: RoochAddress,
+    sequence_number: u64,
+) -> RoochTransaction {
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+    keystore.sign_transaction(&sender, tx_data, None).unwrap()
+}
+
+#[tokio::test]
+async fn test_parallel_execution_same_as_sequential() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(4);
+    let recipient = AccountAddress::random();
+    // The empty calls are independent, the transfers conflict on the recipient,
+    // and all the txs deposit the gas fee to the same gas revenue stores
+    let txs = keystore
+        .addresses()
+        .into_iter()
+        .enumerate()
+        .map(|(i, sender)| {
+            let tx = if i % 2 == 0 {
+                empty_call_tx(&keystore, sender, 0)
+            } else {
+                let action = TransferModule::create_transfer_coin_action(
+                    RGas::struct_tag(),
+                    recipient,
+                    U256::from(1u64),
+                );
+                let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+                keystore.sign_transaction(&sender, tx_data, None).unwrap()
+            };
+            binding_test.executor.validate_l2_tx(tx).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let base_root = binding_test.root().clone();
+    let mut root = base_root.clone();
+    let mut sequential_infos = vec![];
+    for mut tx in txs.iter().cloned() {
+        tx.root = root.clone();
+        let info = binding_test.executor.execute(tx).unwrap().transaction_info;
+        root = info.root_metadata();
+        sequential_infos.push(info);
+    }
+
+    binding_test
+        .executor
+        .refresh_state(base_root, false)
+        .unwrap();
+    let tx_count = txs.len() as u64;
+    let parallel_infos = binding_test
+        .executor
+        .execute_batch(txs)
+        .into_iter()
+        .map(|result| result.unwrap().transaction_info)
+        .collect::<Vec<_>>();
+    assert_eq!(sequential_infos, parallel_infos);
+
+    // The first tx is committed speculatively, the following txs of the new accounts read the address mapping Object
+    // changed by it and are executed again
+    let committed = parallel_tx_count(&binding_test, "committed");
+    let reexecuted = parallel_tx_count(&binding_test, "reexecuted");
+    assert!(committed >= 1);
+    assert!(reexecuted >= 1);
+    assert_eq!(committed + reexecuted, tx_count);
+}
+
+#[tokio::test]
+async fn test_parallel_execution_independent_txs() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(4);
+    // Create the accounts before the batch, so the txs only change the Objects of their senders,
+    // the Timestamp and the transaction fee Objects
+    for sender in keystore.addresses() {
+        binding_test
+            .execute(empty_call_tx(&keystore, sender, 0))
+            .unwrap();
+    }
+    let txs = keystore
+        .addresses()
+        .into_iter()
+        .map(|sender| {
+            let tx = empty_call_tx(&keystore, sender, 1);
+            binding_test.executor.validate_l2_tx(tx).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let base_root = binding_test.root().clone();
+    let mut root = base_root.clone();
+    let mut sequential_infos = vec![];
+    for mut tx in txs.iter().cloned() {
+        tx.root = root.clone();
+        let info = binding_test.executor.execute(tx).unwrap().transaction_info;
+        root = info.root_metadata();
+        sequential_infos.push(info);
+    }
+
+    binding_test
+        .executor
+        .refresh_state(base_root, false)
+        .unwrap();
+    let tx_count = txs.len() as u64;
+    let parallel_infos = binding_test
+        .executor
+        .execute_batch(txs)
+        .into_iter()
+        .map(|result| result.unwrap().transaction_info)
+        .collect::<Vec<_>>();
+    assert!(parallel_infos
+        .iter()
+        .all(|info| info.status == KeptVMStatus::Executed));
+    assert_eq!(sequential_infos, parallel_infos);
+
+    // The writes of the Timestamp and the transaction fee Objects are not conflicts, all the txs are committed speculatively
+    assert_eq!(parallel_tx_count(&binding_test, "committed"), tx_count);
+    assert_eq!(parallel_tx_count(&binding_test, "reexecuted"), 0);
+}
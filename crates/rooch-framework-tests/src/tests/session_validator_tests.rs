@@ -2,20 +2,29 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::binding_test;
+use fastcrypto::secp256r1::Secp256r1KeyPair;
+use fastcrypto::traits::{KeyPair, ToFromBytes};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::ident_str;
 use move_core_types::language_storage::ModuleId;
+use move_core_types::u256::U256;
 use move_core_types::value::MoveValue;
 use move_core_types::vm_status::{AbortLocation, KeptVMStatus, VMStatus};
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_std::string::MoveString;
 use moveos_types::move_types::FunctionId;
+use moveos_types::state::MoveStructType;
 use moveos_types::{module_binding::ModuleBinding, transaction::MoveAction};
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::crypto::PublicKey;
+use rooch_types::framework::gas_coin::RGas;
 use rooch_types::framework::session_key::SessionKeyModule;
 use rooch_types::framework::session_validator::SessionValidatorModule;
 use rooch_types::framework::timestamp::TimestampModule;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::test_utils::sign_webauthn_payload;
+use rooch_types::transaction::{authenticator::Authenticator, rooch::RoochTransaction};
 use rooch_types::{addresses::ROOCH_FRAMEWORK_ADDRESS, framework::empty::Empty};
 use rooch_types::{framework::session_key::SessionScope, transaction::rooch::RoochTransactionData};
 use std::str::FromStr;
@@ -184,3 +193,170 @@ async fn test_session_key_rooch() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_session_key_webauthn() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let sequence_number = 0;
+
+    // Register the passkey as a session key
+    let passkey = Secp256r1KeyPair::generate(&mut rand::thread_rng());
+    let passkey_auth_key = PublicKey::from_secp256r1_bytes(passkey.public().as_bytes())
+        .unwrap()
+        .authentication_key();
+    let session_scope = SessionScope::new(ROOCH_FRAMEWORK_ADDRESS, "*", "*").unwrap();
+    let action = SessionKeyModule::create_session_key_action(
+        MoveString::from_str("test").unwrap(),
+        MoveString::from_str("https://test.rooch.network").unwrap(),
+        passkey_auth_key.as_ref().to_vec(),
+        session_scope,
+        100,
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    // Send transaction signed by the passkey
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number + 1, action);
+    let payload = sign_webauthn_payload(&passkey, &tx_data.tx_hash());
+    let tx = RoochTransaction::new(tx_data, Authenticator::webauthn(payload));
+    binding_test.execute(tx).unwrap();
+
+    // The assertion of another transaction should be rejected
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number + 2, action);
+    let other_tx_data = RoochTransactionData::new_for_test(
+        sender,
+        sequence_number + 3,
+        MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]),
+    );
+    let payload = sign_webauthn_payload(&passkey, &other_tx_data.tx_hash());
+    let tx = RoochTransaction::new(tx_data, Authenticator::webauthn(payload));
+    let error = binding_test.execute_as_result(tx).unwrap_err();
+    match error.downcast_ref() {
+        Some(VMStatus::MoveAbort(_, code)) => {
+            // ErrorValidateInvalidAuthenticator = 1010
+            assert_eq!(*code, 1010, "expect ErrorValidateInvalidAuthenticator");
+        }
+        _ => {
+            panic!("Expect move abort")
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_session_key_limits() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let mut keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let session_auth_key = keystore.generate_session_key(&sender, None).unwrap();
+
+    // Create the session key with the tx count and coin limits in one transaction
+    let action = SessionKeyModule::create_session_key_with_limits_action(
+        MoveString::from_str("test").unwrap(),
+        MoveString::from_str("https://test.rooch.network").unwrap(),
+        session_auth_key.as_ref().to_vec(),
+        vec![SessionScope::new(ROOCH_FRAMEWORK_ADDRESS, "*", "*").unwrap()],
+        100,
+        U256::zero(),
+        3,
+        0,
+        vec![(RGas::type_tag(), U256::from(100u64))],
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let session_key = binding_test
+        .as_module_binding::<SessionKeyModule>()
+        .get_session_key(sender.into(), &session_auth_key)
+        .unwrap()
+        .expect("Session key not found");
+    keystore.binding_session_key(sender, session_key).unwrap();
+    let coin_limit = |binding_test: &binding_test::RustBindingTest| {
+        let limits = binding_test
+            .as_module_binding::<SessionKeyModule>()
+            .get_session_key_limits(sender.into(), &session_auth_key)
+            .unwrap()
+            .expect("Session key limits not found");
+        let coin_limit = limits
+            .coin_limits
+            .borrow(&MoveString::from(RGas::type_tag().to_canonical_string()))
+            .cloned()
+            .expect("RGas limit not found");
+        (limits.tx_count, coin_limit)
+    };
+    let (tx_count, limit) = coin_limit(&binding_test);
+    assert_eq!(tx_count, 0);
+    assert_eq!(limit.max_amount, U256::from(100u64));
+
+    // The transferred coin is recorded by the session key
+    let transfer_action = |amount: u64| {
+        TransferModule::create_transfer_coin_action(
+            RGas::struct_tag(),
+            AccountAddress::random(),
+            U256::from(amount),
+        )
+    };
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, transfer_action(60));
+    let tx = keystore
+        .sign_transaction_via_session_key(&sender, tx_data, &session_auth_key, None)
+        .unwrap();
+    binding_test.execute(tx).unwrap();
+    let (tx_count, limit) = coin_limit(&binding_test);
+    assert_eq!(tx_count, 1);
+    assert_eq!(limit.spent_amount, U256::from(60u64));
+
+    // The transfer exceeds the coin limit, it is aborted by `record_coin_spent`
+    let tx_data = RoochTransactionData::new_for_test(sender, 2, transfer_action(50));
+    let tx = keystore
+        .sign_transaction_via_session_key(&sender, tx_data, &session_auth_key, None)
+        .unwrap();
+    let execute_result = binding_test.execute_as_result(tx).unwrap();
+    match execute_result.transaction_info.status {
+        KeptVMStatus::MoveAbort(AbortLocation::Module(module_id), code) => {
+            assert_eq!(module_id, SessionKeyModule::module_id());
+            // ErrorSessionKeyCoinLimitExceeded = 6
+            assert_eq!(code, 6, "expect ErrorSessionKeyCoinLimitExceeded");
+        }
+        status => panic!("Expect move abort, got {:?}", status),
+    }
+    let (tx_count, limit) = coin_limit(&binding_test);
+    assert_eq!(tx_count, 2);
+    assert_eq!(limit.spent_amount, U256::from(60u64));
+
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 3, action.clone());
+    let tx = keystore
+        .sign_transaction_via_session_key(&sender, tx_data, &session_auth_key, None)
+        .unwrap();
+    binding_test.execute(tx).unwrap();
+
+    // The max tx count is reached, the transaction is rejected when validate
+    let tx_data = RoochTransactionData::new_for_test(sender, 4, action.clone());
+    let tx = keystore
+        .sign_transaction_via_session_key(&sender, tx_data, &session_auth_key, None)
+        .unwrap();
+    let error = binding_test.execute_as_result(tx).unwrap_err();
+    match error.downcast_ref() {
+        Some(VMStatus::MoveAbort(_, code)) => {
+            // ErrorValidateSessionKeyLimitExceeded = 1014
+            assert_eq!(*code, 1014, "expect ErrorValidateSessionKeyLimitExceeded");
+        }
+        _ => {
+            panic!("Expect move abort")
+        }
+    }
+
+    // The limits only apply to the session key
+    let tx_data = RoochTransactionData::new_for_test(sender, 4, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+}
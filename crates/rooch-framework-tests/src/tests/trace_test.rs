@@ -0,0 +1,97 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::moveos_std::account::Account;
+use moveos_types::state::MoveStructType;
+use moveos_types::transaction::VerifiedMoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use rooch_types::transaction::{LedgerTransaction, TransactionSequenceInfo};
+
+#[tokio::test]
+async fn test_trace_transaction() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let recipient = AccountAddress::random();
+    let action =
+        TransferModule::create_transfer_coin_action(RGas::struct_tag(), recipient, U256::one());
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let verified_tx = binding_test.executor.validate_l2_tx(tx.clone()).unwrap();
+    let pre_root = binding_test.root().clone();
+
+    let trace_result = binding_test
+        .reader_executor
+        .trace(verified_tx.clone())
+        .unwrap();
+    let (output, _vm_error_info, _read_set) = binding_test
+        .executor
+        .moveos()
+        .execute_with_read_set(verified_tx.clone())
+        .unwrap();
+    assert_eq!(trace_result.raw_output, output);
+    let VerifiedMoveAction::Function { call, .. } = &verified_tx.action else {
+        panic!("the transfer should be a function call");
+    };
+    assert_eq!(
+        trace_result.call_trace.function,
+        Some(call.function_id.clone())
+    );
+
+    let object_changes = &trace_result.object_changes;
+    // The sender account is changed, the coin store of the recipient is created
+    let sender_account = object_changes
+        .iter()
+        .find(|change| change.object_id == Account::account_object_id(sender.into()))
+        .expect("the sender account should be changed");
+    assert!(sender_account.after.is_some());
+    let recipient_coin_store_id =
+        AccountCoinStoreModule::account_coin_store_id(recipient, RGas::struct_tag());
+    let recipient_coin_store = object_changes
+        .iter()
+        .find(|change| change.object_id == recipient_coin_store_id)
+        .expect("the coin store of the recipient should be created");
+    assert!(recipient_coin_store.before.is_none() && recipient_coin_store.after.is_some());
+    // The changed child Object is collected after its parent
+    for (index, change) in object_changes.iter().enumerate() {
+        if let Some(parent_index) = change.object_id.parent().and_then(|parent| {
+            object_changes
+                .iter()
+                .position(|change| change.object_id == parent)
+        }) {
+            assert!(parent_index < index);
+        }
+    }
+
+    binding_test.execute(tx.clone()).unwrap();
+
+    // Trace the sequenced transaction at the state before it, the sequence number is checked at that state
+    let ledger_tx = LedgerTransaction::new_l2_tx(tx, TransactionSequenceInfo::random());
+    let trace_result = binding_test
+        .reader_executor
+        .trace_ledger_tx(ledger_tx.clone(), pre_root)
+        .unwrap();
+    assert_eq!(trace_result.raw_output.status, KeptVMStatus::Executed);
+    assert!(trace_result
+        .object_changes
+        .iter()
+        .any(|change| change.object_id == recipient_coin_store_id && change.before.is_none()));
+
+    // The transaction is stale at the latest state
+    let latest_root = binding_test.root().clone();
+    assert!(binding_test
+        .reader_executor
+        .trace_ledger_tx(ledger_tx, latest_root)
+        .is_err());
+}
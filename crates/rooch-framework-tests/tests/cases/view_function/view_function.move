@@ -0,0 +1,48 @@
+//# init --addresses creator=0x42
+
+//# publish
+module creator::counter {
+    use moveos_std::object::{Self, Object};
+
+    struct Counter has key {
+        value: u64,
+    }
+
+    #[view]
+    public fun value(counter: &Object<Counter>): u64 {
+        object::borrow(counter).value
+    }
+}
+
+//# publish
+module creator::test {
+
+    #[view]
+    public fun value_with_signer(_s: &signer): u64 {
+        0
+    }
+}
+
+//# publish
+module creator::test {
+
+    #[view]
+    public fun value_with_mut_ref(v: &mut u64): u64 {
+        *v
+    }
+}
+
+//# publish
+module creator::test {
+    use moveos_std::event;
+
+    struct ValueEvent has copy, drop {
+        value: u64,
+    }
+
+    #[view]
+    public fun value_with_event(value: u64): u64 {
+        event::emit(ValueEvent { value });
+        value
+    }
+}
@@ -29,6 +29,7 @@ use rooch_db::RoochDB;
 use rooch_framework::natives::gas_parameter::gas_member::{
     FromOnChainGasSchedule, InitialGasSchedule, ToOnChainGasSchedule,
 };
+use rooch_framework::natives::rooch_framework::crypto::{ecdsa_r1, schnorr};
 use rooch_framework::ROOCH_FRAMEWORK_ADDRESS;
 use rooch_indexer::store::traits::IndexerStoreTrait;
 use rooch_store::state_store::StateStore;
@@ -175,8 +176,32 @@ impl FrameworksGasParameters {
         v2_gas_parameter
     }
 
+    pub fn v4() -> Self {
+        let mut v3_gas_parameter = FrameworksGasParameters::v3();
+
+        v3_gas_parameter.rooch_framework_gas_params.ecdsa_r1.verify =
+            ecdsa_r1::FromBytesGasParametersOptional {
+                base: Some(1000.into()),
+                per_byte: Some(30.into()),
+            };
+        v3_gas_parameter.rooch_framework_gas_params.schnorr.verify =
+            schnorr::FromBytesGasParametersOptional {
+                base: Some(1000.into()),
+                per_byte: Some(30.into()),
+            };
+        v3_gas_parameter
+            .rooch_framework_gas_params
+            .schnorr
+            .tagged_hash = schnorr::FromBytesGasParametersOptional {
+            base: Some(100.into()),
+            per_byte: Some(10.into()),
+        };
+
+        v3_gas_parameter
+    }
+
     pub fn latest() -> Self {
-        FrameworksGasParameters::v3()
+        FrameworksGasParameters::v4()
     }
 
     pub fn to_gas_schedule_config(&self, chain_id: ChainID) -> GasScheduleConfig {
@@ -732,6 +757,39 @@ mod tests {
         genesis_init_test_case(network, genesis);
     }
 
+    #[test]
+    fn test_load_gas_schedule_without_new_entries() {
+        // The on-chain gas schedule of the existing chain has no entries of the new natives
+        let genesis = load_genesis_from_binary(BuiltinChainID::Test)
+            .unwrap()
+            .unwrap();
+        let gas_parameter =
+            FrameworksGasParameters::load_from_gas_config(&genesis.initial_gas_config).unwrap();
+        let ecdsa_r1_verify = gas_parameter.rooch_framework_gas_params.ecdsa_r1.verify;
+        assert!(ecdsa_r1_verify.base.is_none() && ecdsa_r1_verify.per_byte.is_none());
+        let schnorr = gas_parameter.rooch_framework_gas_params.schnorr;
+        assert!(schnorr.verify.base.is_none() && schnorr.tagged_hash.base.is_none());
+
+        // The new entries are added by the gas schedule upgrade
+        let latest_gas_config = FrameworksGasParameters::latest()
+            .to_gas_schedule_config(BuiltinChainID::Test.chain_id());
+        let latest_keys = latest_gas_config
+            .entries
+            .iter()
+            .map(|entry| entry.key.to_string())
+            .collect::<Vec<_>>();
+        for key in [
+            "ecdsa_r1.verify.base",
+            "ecdsa_r1.verify.per_byte",
+            "schnorr.verify.base",
+            "schnorr.verify.per_byte",
+            "schnorr.tagged_hash.base",
+            "schnorr.tagged_hash.per_byte",
+        ] {
+            assert!(latest_keys.contains(&key.to_owned()));
+        }
+    }
+
     #[test]
     fn test_genesis_load_from_binary() {
         assert!(load_genesis_from_binary(BuiltinChainID::Test)
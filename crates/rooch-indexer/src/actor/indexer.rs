@@ -10,9 +10,10 @@ use crate::store::traits::IndexerStoreTrait;
 use crate::IndexerStore;
 use anyhow::Result;
 use async_trait::async_trait;
-use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use coerce::actor::{context::ActorContext, message::Handler, Actor, LocalActorRef};
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::transaction::MoveAction;
+use rooch_event::actor::{EventActor, TransactionIndexedMessage};
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::state::{
     handle_object_change, handle_revert_object_change, IndexerObjectStateChangeSet,
@@ -23,13 +24,19 @@ use rooch_types::indexer::transaction::IndexerTransaction;
 pub struct IndexerActor {
     root: ObjectMeta,
     indexer_store: IndexerStore,
+    event_actor: Option<LocalActorRef<EventActor>>,
 }
 
 impl IndexerActor {
-    pub fn new(root: ObjectMeta, indexer_store: IndexerStore) -> Result<Self> {
+    pub fn new(
+        root: ObjectMeta,
+        indexer_store: IndexerStore,
+        event_actor: Option<LocalActorRef<EventActor>>,
+    ) -> Result<Self> {
         Ok(Self {
             root,
             indexer_store,
+            event_actor,
         })
     }
 }
@@ -90,6 +97,11 @@ impl Handler<UpdateIndexerMessage> for IndexerActor {
         self.indexer_store
             .apply_object_states(indexer_object_state_change_set)?;
 
+        // Notify the subscribers after the transaction is fully indexed
+        if let Some(event_actor) = &self.event_actor {
+            let _ = event_actor.notify(TransactionIndexedMessage { tx_order });
+        }
+
         Ok(())
     }
 }
@@ -40,6 +40,23 @@ pub enum IndexerError {
     #[error(transparent)]
     SQLiteError(#[from] diesel::result::Error),
 
+    #[error("Indexer failed to build PostgreSQL connection pool with error: `{0}`")]
+    PgConnectionPoolInitError(String),
+
+    #[error(
+        "Indexer failed to get a pool connection from PostgreSQL connection pool with error: `{0}`"
+    )]
+    PgPoolConnectionError(String),
+
+    #[error("Indexer failed to read PostgreSQL with error: `{0}`")]
+    PgReadError(String),
+
+    #[error("Indexer async read PostgreSQL error: `{0}`")]
+    PgAsyncReadError(String),
+
+    #[error("Indexer failed to write PostgreSQL with error: `{0}`")]
+    PgWriteError(String),
+
     #[error("Indexer failed to serialize/deserialize with error: `{0}`")]
     SerdeError(String),
 
@@ -9,15 +9,20 @@ use crate::models::transactions::StoredTransaction;
 use crate::schema::{events, transactions};
 use crate::utils::escape_sql_string;
 use crate::{
-    IndexerResult, IndexerStoreMeta, IndexerTableName, SqliteConnectionConfig,
-    SqliteConnectionPoolConfig, SqlitePoolConnection, DEFAULT_BUSY_TIMEOUT,
+    get_pg_pool_connection, new_pg_connection_pool, IndexerResult, IndexerStoreMeta,
+    IndexerTableName, PgConnectionPool, PgConnectionPoolConfig, PgPoolConnection,
+    SqliteConnectionConfig, SqliteConnectionPoolConfig, SqlitePoolConnection, DEFAULT_BUSY_TIMEOUT,
     INDEXER_EVENTS_TABLE_NAME, INDEXER_OBJECT_STATES_TABLE_NAME,
     INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME, INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
     INDEXER_TRANSACTIONS_TABLE_NAME,
 };
 use anyhow::{anyhow, Result};
+use diesel::pg::{Pg, PgConnection};
+use diesel::query_builder::SqlQuery;
+use diesel::sqlite::Sqlite;
 use diesel::{
-    r2d2::ConnectionManager, Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection,
+    r2d2::ConnectionManager, Connection, ExpressionMethods, QueryDsl, QueryableByName, RunQueryDsl,
+    SqliteConnection,
 };
 use function_name::named;
 use move_core_types::language_storage::StructTag;
@@ -131,9 +136,63 @@ impl InnerIndexerReader {
     }
 }
 
+#[derive(Clone)]
+pub struct InnerPgIndexerReader {
+    pub(crate) pool: PgConnectionPool,
+}
+
+impl InnerPgIndexerReader {
+    pub fn new_with_config(db_url: &str, config: PgConnectionPoolConfig) -> Result<Self> {
+        let pool = new_pg_connection_pool(db_url, config)?;
+        Ok(Self { pool })
+    }
+
+    pub fn get_connection(&self) -> Result<PgPoolConnection, IndexerError> {
+        get_pg_pool_connection(&self.pool)
+    }
+
+    pub fn run_query_with_timeout<T, E, F>(&self, query: F) -> Result<T, IndexerError>
+    where
+        F: FnOnce(&mut PgConnection) -> Result<T, E> + Send + 'static,
+        E: From<diesel::result::Error> + std::error::Error + Send,
+        T: Send + 'static,
+    {
+        // default query time out in second
+        let timeout_duration = Duration::from_secs(DEFAULT_QUERY_TIMEOUT);
+        let mut connection = self.get_connection()?;
+
+        tokio::task::block_in_place(|| {
+            Handle::current().block_on(async move {
+                timeout(
+                    timeout_duration,
+                    tokio::task::spawn_blocking(move || {
+                        connection
+                            .deref_mut()
+                            .build_transaction()
+                            .read_only()
+                            .run(query)
+                            .map_err(|e| IndexerError::PgReadError(e.to_string()))
+                    }),
+                )
+                .await
+                .map_err(|e| IndexerError::PgAsyncReadError(e.to_string()))??
+            })
+        })
+    }
+}
+
+/// The backend which the indexer tables are read from
+#[derive(Clone)]
+pub enum IndexerReaderBackend {
+    /// Each table is read from its own SQLite file
+    Sqlite(HashMap<String, InnerIndexerReader>),
+    /// All the tables are read from one PostgreSQL db
+    Postgres(InnerPgIndexerReader),
+}
+
 #[derive(Clone)]
 pub struct IndexerReader {
-    pub(crate) inner_indexer_reader_mapping: HashMap<String, InnerIndexerReader>,
+    pub(crate) backend: IndexerReaderBackend,
     metrics: Arc<IndexerReaderMetrics>,
 }
 
@@ -143,6 +202,27 @@ impl IndexerReader {
         Self::new_with_config(db_path, config, registry)
     }
 
+    pub fn new_postgres(db_url: &str, registry: &Registry) -> Result<Self> {
+        let config = PgConnectionPoolConfig::pool_config(true);
+        let pg_reader = InnerPgIndexerReader::new_with_config(db_url, config)?;
+        Ok(IndexerReader {
+            backend: IndexerReaderBackend::Postgres(pg_reader),
+            metrics: Arc::new(IndexerReaderMetrics::new(registry)),
+        })
+    }
+
+    /// Read the PostgreSQL indexer if the db url is set, otherwise the SQLite indexer under the db path.
+    pub fn new_with_db_url(
+        db_path: PathBuf,
+        db_url: Option<&str>,
+        registry: &Registry,
+    ) -> Result<Self> {
+        match db_url {
+            Some(db_url) => Self::new_postgres(db_url, registry),
+            None => Self::new(db_path, registry),
+        }
+    }
+
     pub fn new_with_config(
         db_path: PathBuf,
         config: SqliteConnectionPoolConfig,
@@ -164,17 +244,39 @@ impl IndexerReader {
         }
 
         Ok(IndexerReader {
-            inner_indexer_reader_mapping,
+            backend: IndexerReaderBackend::Sqlite(inner_indexer_reader_mapping),
             metrics: Arc::new(IndexerReaderMetrics::new(registry)),
         })
     }
 
     pub fn get_inner_indexer_reader(&self, table_name: &str) -> Result<InnerIndexerReader> {
-        Ok(self
-            .inner_indexer_reader_mapping
-            .get(table_name)
-            .ok_or(anyhow::anyhow!("Inner indexer reader not exist"))?
-            .clone())
+        match &self.backend {
+            IndexerReaderBackend::Sqlite(inner_indexer_reader_mapping) => {
+                Ok(inner_indexer_reader_mapping
+                    .get(table_name)
+                    .ok_or(anyhow::anyhow!("Inner indexer reader not exist"))?
+                    .clone())
+            }
+            IndexerReaderBackend::Postgres(_) => Err(anyhow::anyhow!(
+                "Inner indexer reader not exist in PostgreSQL indexer"
+            )),
+        }
+    }
+
+    /// Load the rows of the raw SQL query from the table, the query must be valid for both SQLite and PostgreSQL.
+    fn load_with_timeout<T>(&self, table_name: &str, query: String) -> IndexerResult<Vec<T>>
+    where
+        T: QueryableByName<Sqlite> + QueryableByName<Pg> + Send + 'static,
+    {
+        let query: SqlQuery = diesel::sql_query(query);
+        match &self.backend {
+            IndexerReaderBackend::Postgres(pg_reader) => {
+                pg_reader.run_query_with_timeout(move |conn| query.load::<T>(conn))
+            }
+            IndexerReaderBackend::Sqlite(_) => self
+                .get_inner_indexer_reader(table_name)?
+                .run_query_with_timeout(move |conn| query.load::<T>(conn)),
+        }
     }
 
     #[named]
@@ -195,14 +297,16 @@ impl IndexerReader {
         let tx_order = if let Some(cursor) = cursor {
             cursor as i64
         } else if descending_order {
-            let max_tx_order: i64 = self
-                .get_inner_indexer_reader(INDEXER_TRANSACTIONS_TABLE_NAME)?
-                .run_query_with_timeout(|conn| {
-                    transactions::dsl::transactions
-                        .select(transactions::tx_order)
-                        .order_by(transactions::tx_order.desc())
-                        .first::<i64>(conn)
-                })?;
+            let max_tx_order_query = transactions::dsl::transactions
+                .select(transactions::tx_order)
+                .order_by(transactions::tx_order.desc());
+            let max_tx_order: i64 = match &self.backend {
+                IndexerReaderBackend::Postgres(pg_reader) => pg_reader
+                    .run_query_with_timeout(move |conn| max_tx_order_query.first::<i64>(conn))?,
+                IndexerReaderBackend::Sqlite(_) => self
+                    .get_inner_indexer_reader(INDEXER_TRANSACTIONS_TABLE_NAME)?
+                    .run_query_with_timeout(move |conn| max_tx_order_query.first::<i64>(conn))?,
+            };
             max_tx_order + 1
         } else {
             -1
@@ -210,12 +314,12 @@ impl IndexerReader {
 
         let main_where_clause = match filter {
             TransactionFilter::Sender(sender) => {
-                format!("{TX_SENDER_STR} = \"{}\"", sender.to_hex_literal())
+                format!("{TX_SENDER_STR} = '{}'", sender.to_hex_literal())
             }
             TransactionFilter::TxHashes(tx_hashes) => {
                 let in_tx_hash_str: String = tx_hashes
                     .iter()
-                    .map(|tx_hash| format!("\"{:?}\"", tx_hash))
+                    .map(|tx_hash| format!("'{:?}'", tx_hash))
                     .collect::<Vec<String>>()
                     .join(",");
                 format!("{TX_HASH_STR} in ({})", in_tx_hash_str)
@@ -262,11 +366,8 @@ impl IndexerReader {
         );
 
         tracing::debug!("Query transactions: {}", query);
-        let stored_transactions = self
-            .get_inner_indexer_reader(INDEXER_TRANSACTIONS_TABLE_NAME)?
-            .run_query_with_timeout(|conn| {
-                diesel::sql_query(query).load::<StoredTransaction>(conn)
-            })?;
+        let stored_transactions: Vec<StoredTransaction> =
+            self.load_with_timeout(INDEXER_TRANSACTIONS_TABLE_NAME, query)?;
 
         let result = stored_transactions
             .into_iter()
@@ -302,14 +403,21 @@ impl IndexerReader {
             } = cursor;
             (tx_order as i64, event_index as i64)
         } else if descending_order {
-            let (max_tx_order, event_index): (i64, i64) = self
-                .get_inner_indexer_reader(INDEXER_EVENTS_TABLE_NAME)?
-                .run_query_with_timeout(|conn| {
-                    events::dsl::events
-                        .select((events::tx_order, events::event_index))
-                        .order_by((events::tx_order.desc(), events::event_index.desc()))
-                        .first::<(i64, i64)>(conn)
-                })?;
+            let last_event_query = events::dsl::events
+                .select((events::tx_order, events::event_index))
+                .order_by((events::tx_order.desc(), events::event_index.desc()));
+            let (max_tx_order, event_index): (i64, i64) = match &self.backend {
+                IndexerReaderBackend::Postgres(pg_reader) => {
+                    pg_reader.run_query_with_timeout(move |conn| {
+                        last_event_query.first::<(i64, i64)>(conn)
+                    })?
+                }
+                IndexerReaderBackend::Sqlite(_) => self
+                    .get_inner_indexer_reader(INDEXER_EVENTS_TABLE_NAME)?
+                    .run_query_with_timeout(move |conn| {
+                        last_event_query.first::<(i64, i64)>(conn)
+                    })?,
+            };
             (max_tx_order, event_index + 1)
         } else {
             (-1, 0)
@@ -318,20 +426,20 @@ impl IndexerReader {
         let main_where_clause = match filter {
             EventFilter::EventTypeWithSender { event_type, sender } => {
                 format!(
-                    "{TX_SENDER_STR} = \"{}\" AND {EVENT_TYPE_STR} = \"{}\"",
+                    "{TX_SENDER_STR} = '{}' AND {EVENT_TYPE_STR} = '{}'",
                     sender.to_hex_literal(),
                     event_type
                 )
             }
             EventFilter::EventType(event_type) => {
-                format!("{EVENT_TYPE_STR} = \"{}\"", event_type)
+                format!("{EVENT_TYPE_STR} = '{}'", event_type)
             }
             EventFilter::Sender(sender) => {
-                format!("{TX_SENDER_STR} = \"{}\"", sender.to_hex_literal())
+                format!("{TX_SENDER_STR} = '{}'", sender.to_hex_literal())
             }
             EventFilter::TxHash(tx_hash) => {
                 let tx_hash_str = format!("{:?}", tx_hash);
-                format!("{TX_HASH_STR} = \"{}\"", tx_hash_str)
+                format!("{TX_HASH_STR} = '{}'", tx_hash_str)
             }
             EventFilter::TimeRange {
                 start_time,
@@ -381,9 +489,8 @@ impl IndexerReader {
         );
 
         tracing::debug!("Query events: {}", query);
-        let stored_events = self
-            .get_inner_indexer_reader(INDEXER_EVENTS_TABLE_NAME)?
-            .run_query_with_timeout(|conn| diesel::sql_query(query).load::<StoredEvent>(conn))?;
+        let stored_events: Vec<StoredEvent> =
+            self.load_with_timeout(INDEXER_EVENTS_TABLE_NAME, query)?;
 
         let result = stored_events
             .into_iter()
@@ -443,14 +550,14 @@ impl IndexerReader {
                             object_type_query(&object_type)
                         };
                         format!(
-                            "{STATE_OWNER_STR} = \"{}\" AND {}",
+                            "{STATE_OWNER_STR} = '{}' AND {}",
                             owner.to_hex_literal(),
                             object_query
                         )
                     }
                     _ => {
                         // Ignore object_type param for utxo and inscription query
-                        format!("{STATE_OWNER_STR} = \"{}\"", owner.to_hex_literal(),)
+                        format!("{STATE_OWNER_STR} = '{}'", owner.to_hex_literal(),)
                     }
                 }
             }
@@ -463,12 +570,12 @@ impl IndexerReader {
             }
 
             ObjectStateFilter::Owner(owner) => {
-                format!("{STATE_OWNER_STR} = \"{}\"", owner.to_hex_literal())
+                format!("{STATE_OWNER_STR} = '{}'", owner.to_hex_literal())
             }
             ObjectStateFilter::ObjectId(object_ids) => {
                 let object_ids_str = object_ids
                     .into_iter()
-                    .map(|obj_id| format!("\"{}\"", obj_id))
+                    .map(|obj_id| format!("'{}'", obj_id))
                     .collect::<Vec<_>>()
                     .join(",");
                 format!("{OBJECT_ID_STR} IN ({object_ids_str})")
@@ -505,11 +612,8 @@ impl IndexerReader {
         );
 
         tracing::debug!("Query object states: {}", query);
-        let stored_object_state_infos = self
-            .get_inner_indexer_reader(table_name)?
-            .run_query_with_timeout(|conn| {
-                diesel::sql_query(query).load::<StoredObjectStateInfo>(conn)
-            })?;
+        let stored_object_state_infos: Vec<StoredObjectStateInfo> =
+            self.load_with_timeout(table_name, query)?;
 
         tracing::debug!("Query object states time elapsed: {:?}", start.elapsed());
         Ok(stored_object_state_infos)
@@ -564,9 +668,7 @@ impl IndexerReader {
         );
 
         tracing::debug!("query last indexer state id: {}", query);
-        let stored_state_ids = self
-            .get_inner_indexer_reader(table_name)?
-            .run_query_with_timeout(|conn| diesel::sql_query(query).load::<StoredStateID>(conn))?;
+        let stored_state_ids: Vec<StoredStateID> = self.load_with_timeout(table_name, query)?;
 
         let last_state_id = if stored_state_ids.is_empty() {
             None
@@ -585,7 +687,7 @@ impl IndexerReader {
         state_type: ObjectStateType,
     ) -> IndexerResult<Option<u64>> {
         let table_name = get_table_name_by_state_type(state_type);
-        let where_clause = format!("{TX_ORDER_STR} = \"{}\"", tx_order as i64);
+        let where_clause = format!("{TX_ORDER_STR} = {}", tx_order as i64);
         let order_clause = format!("{TX_ORDER_STR} DESC, {STATE_INDEX_STR} DESC");
         let query = format!(
             "
@@ -598,9 +700,7 @@ impl IndexerReader {
         );
 
         tracing::debug!("query last state index by tx order: {}", query);
-        let stored_state_ids = self
-            .get_inner_indexer_reader(table_name)?
-            .run_query_with_timeout(|conn| diesel::sql_query(query).load::<StoredStateID>(conn))?;
+        let stored_state_ids: Vec<StoredStateID> = self.load_with_timeout(table_name, query)?;
         let last_state_index = if stored_state_ids.is_empty() {
             None
         } else {
@@ -624,11 +724,11 @@ fn object_type_query(object_type: &StructTag) -> String {
         let (first_bound, second_bound, upper_bound) =
             optimize_object_type_like_query(object_type_str.as_str());
         format!(
-            "({STATE_OBJECT_TYPE_STR} = \"{}\" OR ({STATE_OBJECT_TYPE_STR} >= \"{}\" AND {STATE_OBJECT_TYPE_STR} < \"{}\" AND {STATE_OBJECT_TYPE_STR} < \"{}\"))",
+            "({STATE_OBJECT_TYPE_STR} = '{}' OR ({STATE_OBJECT_TYPE_STR} >= '{}' AND {STATE_OBJECT_TYPE_STR} < '{}' AND {STATE_OBJECT_TYPE_STR} < '{}'))",
             object_type_str, first_bound, second_bound, upper_bound
         )
     } else {
-        format!("{STATE_OBJECT_TYPE_STR} = \"{}\"", object_type_str)
+        format!("{STATE_OBJECT_TYPE_STR} = '{}'", object_type_str)
     }
 }
 
@@ -639,11 +739,11 @@ fn not_object_type_query(object_type: &StructTag) -> String {
         let (first_bound, second_bound, upper_bound) =
             optimize_object_type_like_query(object_type_str.as_str());
         format!(
-            "({STATE_OBJECT_TYPE_STR} != \"{}\" AND ({STATE_OBJECT_TYPE_STR} < \"{}\" OR {STATE_OBJECT_TYPE_STR} >= \"{}\" OR {STATE_OBJECT_TYPE_STR} >= \"{}\"))",
+            "({STATE_OBJECT_TYPE_STR} != '{}' AND ({STATE_OBJECT_TYPE_STR} < '{}' OR {STATE_OBJECT_TYPE_STR} >= '{}' OR {STATE_OBJECT_TYPE_STR} >= '{}'))",
             object_type_str, first_bound, second_bound, upper_bound
         )
     } else {
-        format!("{STATE_OBJECT_TYPE_STR} != \"{}\"", object_type_str)
+        format!("{STATE_OBJECT_TYPE_STR} != '{}'", object_type_str)
     }
 }
 
@@ -2,11 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::store::metrics::IndexerDBMetrics;
+use crate::store::pg_store::PgIndexerStore;
 use crate::store::sqlite_store::SqliteIndexerStore;
 use crate::store::traits::IndexerStoreTrait;
-use crate::utils::create_all_tables_if_not_exists;
+use crate::utils::{create_all_pg_tables_if_not_exists, create_all_tables_if_not_exists};
 use anyhow::Result;
 use diesel::connection::SimpleConnection;
+use diesel::pg::PgConnection;
 use diesel::r2d2::ConnectionManager;
 use diesel::sqlite::SqliteConnection;
 use diesel::ConnectionError::BadConnection;
@@ -71,10 +73,21 @@ impl IndexerStoreMeta {
 
 pub type SqliteConnectionPool = diesel::r2d2::Pool<ConnectionManager<SqliteConnection>>;
 pub type SqlitePoolConnection = diesel::r2d2::PooledConnection<ConnectionManager<SqliteConnection>>;
+pub type PgConnectionPool = diesel::r2d2::Pool<ConnectionManager<PgConnection>>;
+pub type PgPoolConnection = diesel::r2d2::PooledConnection<ConnectionManager<PgConnection>>;
+
+/// The backend storing the indexer tables
+#[derive(Clone)]
+pub enum IndexerStoreBackend {
+    /// Each table is stored in its own SQLite file
+    Sqlite(HashMap<String, SqliteIndexerStore>),
+    /// All the tables are stored in one PostgreSQL db
+    Postgres(PgIndexerStore),
+}
 
 #[derive(Clone)]
 pub struct IndexerStore {
-    pub sqlite_store_mapping: HashMap<String, SqliteIndexerStore>,
+    pub backend: IndexerStoreBackend,
 }
 
 impl IndexerStore {
@@ -101,24 +114,71 @@ impl IndexerStore {
         }
 
         let store = Self {
-            sqlite_store_mapping,
+            backend: IndexerStoreBackend::Sqlite(sqlite_store_mapping),
+        };
+        store.create_all_tables_if_not_exists()?;
+        Ok(store)
+    }
+
+    pub fn new_postgres(db_url: &str, registry: &Registry) -> Result<Self> {
+        let db_metrics = IndexerDBMetrics::get_or_init(registry).clone();
+        let pg_cp = new_pg_connection_pool(db_url, PgConnectionPoolConfig::default())?;
+        let store = Self {
+            backend: IndexerStoreBackend::Postgres(PgIndexerStore::new(pg_cp, db_metrics)),
         };
         store.create_all_tables_if_not_exists()?;
         Ok(store)
     }
 
+    /// Open the PostgreSQL indexer if the db url is set, otherwise the SQLite indexer under the db path.
+    pub fn new_with_db_url(
+        db_path: PathBuf,
+        db_url: Option<&str>,
+        registry: &Registry,
+    ) -> Result<Self> {
+        match db_url {
+            Some(db_url) => Self::new_postgres(db_url, registry),
+            None => Self::new(db_path, registry),
+        }
+    }
+
     pub fn get_sqlite_store(&self, table_name: &str) -> Result<SqliteIndexerStore> {
-        Ok(self
-            .sqlite_store_mapping
-            .get(table_name)
-            .ok_or(anyhow::anyhow!("Sqlite store not exist"))?
-            .clone())
+        match &self.backend {
+            IndexerStoreBackend::Sqlite(sqlite_store_mapping) => Ok(sqlite_store_mapping
+                .get(table_name)
+                .ok_or(anyhow::anyhow!("Sqlite store not exist"))?
+                .clone()),
+            IndexerStoreBackend::Postgres(_) => Err(anyhow::anyhow!(
+                "Sqlite store not exist in PostgreSQL indexer"
+            )),
+        }
+    }
+
+    // the store of the table: the SQLite store of the table, or the PostgreSQL store of all the tables
+    fn get_table_store(&self, table_name: &str) -> Result<&dyn IndexerStoreTrait, IndexerError> {
+        match &self.backend {
+            IndexerStoreBackend::Sqlite(sqlite_store_mapping) => sqlite_store_mapping
+                .get(table_name)
+                .map(|store| store as &dyn IndexerStoreTrait)
+                .ok_or_else(|| {
+                    IndexerError::GenericError(format!("Sqlite store {} not exist", table_name))
+                }),
+            IndexerStoreBackend::Postgres(pg_store) => Ok(pg_store),
+        }
     }
 
     fn create_all_tables_if_not_exists(&self) -> Result<()> {
-        for (k, v) in &self.sqlite_store_mapping {
-            let mut connection = get_sqlite_pool_connection(&v.connection_pool)?;
-            create_all_tables_if_not_exists(&mut connection, k.clone())?;
+        match &self.backend {
+            IndexerStoreBackend::Sqlite(sqlite_store_mapping) => {
+                for (k, v) in sqlite_store_mapping {
+                    let mut connection = get_sqlite_pool_connection(&v.connection_pool)?;
+                    create_all_tables_if_not_exists(&mut connection, k.clone())?;
+                }
+            }
+            IndexerStoreBackend::Postgres(pg_store) => {
+                let mut connection = get_pg_pool_connection(&pg_store.connection_pool)?;
+                create_all_pg_tables_if_not_exists(&mut connection)?;
+            }
         }
         Ok(())
     }
@@ -139,11 +199,33 @@ pub fn new_sqlite_connection_pool(db_url: &str) -> Result<SqliteConnectionPool,
     new_sqlite_connection_pool_impl(db_url, None)
 }
 
+pub fn new_pg_connection_pool(
+    db_url: &str,
+    pool_config: PgConnectionPoolConfig,
+) -> Result<PgConnectionPool, IndexerError> {
+    let manager = ConnectionManager::<PgConnection>::new(db_url);
+
+    diesel::r2d2::Pool::builder()
+        .max_size(pool_config.pool_size)
+        .connection_timeout(pool_config.connection_timeout)
+        .build(manager)
+        .map_err(|e| {
+            IndexerError::PgConnectionPoolInitError(format!(
+                "Failed to initialize connection pool with error: {:?}. Please check whether the configured pool size (currently {}) exceeds the max_connections of the PostgreSQL server.",
+                e, pool_config.pool_size
+            ))
+        })
+}
+
 impl IndexerStoreTrait for IndexerStore {
     fn apply_object_states(
         &self,
         object_state_change_set: IndexerObjectStateChangeSet,
     ) -> Result<(), IndexerError> {
+        if let IndexerStoreBackend::Postgres(pg_store) = &self.backend {
+            // all the state tables are updated in one PostgreSQL transaction
+            return pg_store.apply_object_states(object_state_change_set);
+        }
         self.update_object_states_by_state_type(
             object_state_change_set.object_states,
             INDEXER_OBJECT_STATES_TABLE_NAME,
@@ -165,12 +247,12 @@ impl IndexerStoreTrait for IndexerStore {
         &self,
         states: Vec<IndexerObjectState>,
     ) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_OBJECT_STATES_TABLE_NAME)?
+        self.get_table_store(INDEXER_OBJECT_STATES_TABLE_NAME)?
             .persist_or_update_object_states(states)
     }
 
     fn delete_object_states(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_OBJECT_STATES_TABLE_NAME)?
+        self.get_table_store(INDEXER_OBJECT_STATES_TABLE_NAME)?
             .delete_object_states(state_pks)
     }
 
@@ -178,12 +260,12 @@ impl IndexerStoreTrait for IndexerStore {
         &self,
         states: Vec<IndexerObjectState>,
     ) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME)?
+        self.get_table_store(INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME)?
             .persist_or_update_object_state_utxos(states)
     }
 
     fn delete_object_state_utxos(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME)?
+        self.get_table_store(INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME)?
             .delete_object_state_utxos(state_pks)
     }
 
@@ -191,12 +273,12 @@ impl IndexerStoreTrait for IndexerStore {
         &self,
         states: Vec<IndexerObjectState>,
     ) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME)?
+        self.get_table_store(INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME)?
             .persist_or_update_object_state_inscriptions(states)
     }
 
     fn delete_object_state_inscriptions(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME)?
+        self.get_table_store(INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME)?
             .delete_object_state_inscriptions(state_pks)
     }
 
@@ -204,22 +286,22 @@ impl IndexerStoreTrait for IndexerStore {
         &self,
         transactions: Vec<IndexerTransaction>,
     ) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_TRANSACTIONS_TABLE_NAME)?
+        self.get_table_store(INDEXER_TRANSACTIONS_TABLE_NAME)?
             .persist_transactions(transactions)
     }
 
     fn persist_events(&self, events: Vec<IndexerEvent>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_EVENTS_TABLE_NAME)?
+        self.get_table_store(INDEXER_EVENTS_TABLE_NAME)?
             .persist_events(events)
     }
 
     fn delete_transactions(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_TRANSACTIONS_TABLE_NAME)?
+        self.get_table_store(INDEXER_TRANSACTIONS_TABLE_NAME)?
             .delete_transactions(tx_orders)
     }
 
     fn delete_events(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
-        self.get_sqlite_store(INDEXER_EVENTS_TABLE_NAME)?
+        self.get_table_store(INDEXER_EVENTS_TABLE_NAME)?
             .delete_events(tx_orders)
     }
 }
@@ -333,6 +415,54 @@ impl Default for SqliteConnectionPoolConfig {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct PgConnectionPoolConfig {
+    pool_size: u32,
+    connection_timeout: Duration,
+}
+
+impl PgConnectionPoolConfig {
+    // the pool size should not exceed the max_connections (100 by default) of the PostgreSQL server
+    const DEFAULT_POOL_SIZE: u32 = 16;
+    const DEFAULT_READER_POOL_SIZE: u32 = 64;
+    const DEFAULT_CONNECTION_TIMEOUT: u64 = 30; // second
+
+    pub fn set_pool_size(&mut self, size: u32) {
+        self.pool_size = size;
+    }
+
+    pub fn set_connection_timeout(&mut self, timeout: Duration) {
+        self.connection_timeout = timeout;
+    }
+
+    pub fn pool_config(read_only: bool) -> Self {
+        let default_pool_size = if read_only {
+            Self::DEFAULT_READER_POOL_SIZE
+        } else {
+            Self::DEFAULT_POOL_SIZE
+        };
+        let db_pool_size = std::env::var("PG_POOL_SIZE")
+            .ok()
+            .and_then(|s| s.parse::<u32>().ok())
+            .unwrap_or(default_pool_size);
+        let conn_timeout_secs = std::env::var("PG_CONNECTION_TIMEOUT")
+            .ok()
+            .and_then(|s| s.parse::<u64>().ok())
+            .unwrap_or(Self::DEFAULT_CONNECTION_TIMEOUT);
+
+        Self {
+            pool_size: db_pool_size,
+            connection_timeout: Duration::from_secs(conn_timeout_secs),
+        }
+    }
+}
+
+impl Default for PgConnectionPoolConfig {
+    fn default() -> Self {
+        Self::pool_config(false)
+    }
+}
+
 #[derive(Debug, Clone)]
 struct SqliteConnectionConfig {
     // SQLite does not support the statement_timeout parameter
@@ -391,6 +521,15 @@ impl diesel::r2d2::CustomizeConnection<SqliteConnection, diesel::r2d2::Error>
     }
 }
 
+pub fn get_pg_pool_connection(pool: &PgConnectionPool) -> Result<PgPoolConnection, IndexerError> {
+    pool.get().map_err(|e| {
+        IndexerError::PgPoolConnectionError(format!(
+            "Failed to get connection from PostgreSQL connection pool with error: {:?}",
+            e
+        ))
+    })
+}
+
 pub fn get_sqlite_pool_connection(
     pool: &SqliteConnectionPool,
 ) -> Result<SqlitePoolConnection, IndexerError> {
@@ -0,0 +1,404 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::{Context, IndexerError};
+use crate::models::events::StoredEvent;
+use crate::models::inscriptions::StoredInscription;
+use crate::models::states::StoredObjectState;
+use crate::models::transactions::StoredTransaction;
+use crate::models::utxos::StoredUTXO;
+use crate::schema::{events, inscriptions, object_states, transactions, utxos};
+use crate::store::metrics::IndexerDBMetrics;
+use crate::store::traits::IndexerStoreTrait;
+use crate::{get_pg_pool_connection, PgConnectionPool};
+use diesel::upsert::excluded;
+use diesel::{Connection, ExpressionMethods, QueryDsl, RunQueryDsl};
+use function_name::named;
+use rooch_types::indexer::event::IndexerEvent;
+use rooch_types::indexer::state::{
+    IndexerObjectState, IndexerObjectStateChangeSet, IndexerObjectStateChanges,
+};
+use rooch_types::indexer::transaction::IndexerTransaction;
+use std::sync::Arc;
+
+// PostgreSQL limits the bind parameters of a statement to 65535,
+// the widest table has 9 columns, so 5000 rows per statement is safe.
+const PG_INSERT_CHUNK_SIZE: usize = 5000;
+
+/// PgIndexerStore stores all the indexer tables in one PostgreSQL db.
+#[derive(Clone)]
+pub struct PgIndexerStore {
+    pub(crate) connection_pool: PgConnectionPool,
+    db_metrics: Arc<IndexerDBMetrics>,
+}
+
+impl PgIndexerStore {
+    pub fn new(connection_pool: PgConnectionPool, db_metrics: Arc<IndexerDBMetrics>) -> Self {
+        Self {
+            connection_pool,
+            db_metrics,
+        }
+    }
+
+    fn apply_object_state_changes<P, D>(
+        &self,
+        mut object_state_change: IndexerObjectStateChanges,
+        persist_or_update: P,
+        delete: D,
+    ) -> Result<(), IndexerError>
+    where
+        P: FnOnce(&Self, Vec<IndexerObjectState>) -> Result<(), IndexerError>,
+        D: FnOnce(&Self, Vec<String>) -> Result<(), IndexerError>,
+    {
+        let mut object_states_new_and_update = object_state_change.new_object_states;
+        object_states_new_and_update.append(&mut object_state_change.update_object_states);
+        persist_or_update(self, object_states_new_and_update)?;
+        delete(self, object_state_change.remove_object_states)
+    }
+}
+
+impl IndexerStoreTrait for PgIndexerStore {
+    fn apply_object_states(
+        &self,
+        object_state_change_set: IndexerObjectStateChangeSet,
+    ) -> Result<(), IndexerError> {
+        self.apply_object_state_changes(
+            object_state_change_set.object_states,
+            Self::persist_or_update_object_states,
+            Self::delete_object_states,
+        )?;
+        self.apply_object_state_changes(
+            object_state_change_set.object_state_utxos,
+            Self::persist_or_update_object_state_utxos,
+            Self::delete_object_state_utxos,
+        )?;
+        self.apply_object_state_changes(
+            object_state_change_set.object_state_inscriptions,
+            Self::persist_or_update_object_state_inscriptions,
+            Self::delete_object_state_inscriptions,
+        )
+    }
+
+    #[named]
+    fn persist_or_update_object_states(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+        let states = states
+            .into_iter()
+            .map(StoredObjectState::from)
+            .collect::<Vec<_>>();
+
+        connection
+            .transaction(|conn| {
+                for chunk in states.chunks(PG_INSERT_CHUNK_SIZE) {
+                    diesel::insert_into(object_states::table)
+                        .values(chunk)
+                        .on_conflict(object_states::id)
+                        .do_update()
+                        .set((
+                            object_states::owner.eq(excluded(object_states::owner)),
+                            object_states::tx_order.eq(excluded(object_states::tx_order)),
+                            object_states::state_index.eq(excluded(object_states::state_index)),
+                            object_states::updated_at.eq(excluded(object_states::updated_at)),
+                        ))
+                        .execute(conn)?;
+                }
+                Ok::<(), diesel::result::Error>(())
+            })
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to write or update object states to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn delete_object_states(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if state_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(object_states::table.filter(object_states::id.eq_any(state_pks.as_slice())))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to delete object states to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn persist_or_update_object_state_utxos(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+        let states = states.into_iter().map(StoredUTXO::from).collect::<Vec<_>>();
+
+        connection
+            .transaction(|conn| {
+                for chunk in states.chunks(PG_INSERT_CHUNK_SIZE) {
+                    diesel::insert_into(utxos::table)
+                        .values(chunk)
+                        .on_conflict(utxos::id)
+                        .do_update()
+                        .set((
+                            utxos::owner.eq(excluded(utxos::owner)),
+                            utxos::tx_order.eq(excluded(utxos::tx_order)),
+                            utxos::state_index.eq(excluded(utxos::state_index)),
+                            utxos::updated_at.eq(excluded(utxos::updated_at)),
+                        ))
+                        .execute(conn)?;
+                }
+                Ok::<(), diesel::result::Error>(())
+            })
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to write or update object state utxos to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn delete_object_state_utxos(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if state_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(utxos::table.filter(utxos::id.eq_any(state_pks.as_slice())))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to delete object state utxos to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn persist_or_update_object_state_inscriptions(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+        let states = states
+            .into_iter()
+            .map(StoredInscription::from)
+            .collect::<Vec<_>>();
+
+        connection
+            .transaction(|conn| {
+                for chunk in states.chunks(PG_INSERT_CHUNK_SIZE) {
+                    diesel::insert_into(inscriptions::table)
+                        .values(chunk)
+                        .on_conflict(inscriptions::id)
+                        .do_update()
+                        .set((
+                            inscriptions::owner.eq(excluded(inscriptions::owner)),
+                            inscriptions::tx_order.eq(excluded(inscriptions::tx_order)),
+                            inscriptions::state_index.eq(excluded(inscriptions::state_index)),
+                            inscriptions::updated_at.eq(excluded(inscriptions::updated_at)),
+                        ))
+                        .execute(conn)?;
+                }
+                Ok::<(), diesel::result::Error>(())
+            })
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to write or update object state inscriptions to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn delete_object_state_inscriptions(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if state_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(inscriptions::table.filter(inscriptions::id.eq_any(state_pks.as_slice())))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to delete object state inscriptions to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn persist_transactions(
+        &self,
+        transactions: Vec<IndexerTransaction>,
+    ) -> Result<(), IndexerError> {
+        if transactions.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+        // The values are bound as parameters, no need to escape them
+        let transactions = transactions
+            .into_iter()
+            .map(StoredTransaction::from)
+            .collect::<Vec<_>>();
+
+        connection
+            .transaction(|conn| {
+                for chunk in transactions.chunks(PG_INSERT_CHUNK_SIZE) {
+                    diesel::insert_into(transactions::table)
+                        .values(chunk)
+                        .execute(conn)?;
+                }
+                Ok::<(), diesel::result::Error>(())
+            })
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to write transactions to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn persist_events(&self, events: Vec<IndexerEvent>) -> Result<(), IndexerError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+        let events = events
+            .into_iter()
+            .map(StoredEvent::from)
+            .collect::<Vec<_>>();
+
+        connection
+            .transaction(|conn| {
+                for chunk in events.chunks(PG_INSERT_CHUNK_SIZE) {
+                    diesel::insert_into(events::table)
+                        .values(chunk)
+                        .execute(conn)?;
+                }
+                Ok::<(), diesel::result::Error>(())
+            })
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to write events to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn delete_transactions(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
+        if tx_orders.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        let tx_orders: Vec<_> = tx_orders.into_iter().map(|v| v as i64).collect();
+        diesel::delete(
+            transactions::table.filter(transactions::tx_order.eq_any(tx_orders.as_slice())),
+        )
+        .execute(&mut connection)
+        .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+        .context("Failed to delete transactions to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    fn delete_events(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
+        if tx_orders.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        let tx_orders: Vec<_> = tx_orders.into_iter().map(|v| v as i64).collect();
+        diesel::delete(events::table.filter(events::tx_order.eq_any(tx_orders.as_slice())))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to delete events to PostgreSQL")?;
+
+        Ok(())
+    }
+}
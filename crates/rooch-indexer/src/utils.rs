@@ -1,13 +1,14 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::SqlitePoolConnection;
+use crate::{PgPoolConnection, SqlitePoolConnection};
 use anyhow::anyhow;
 use diesel::{RunQueryDsl, SqliteConnection};
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 use tracing::{debug, info};
 
 const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+const PG_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations_pg");
 
 /// creates all the tables by applying all migrations.
 pub fn create_all_tables_if_not_exists(
@@ -100,7 +101,30 @@ pub fn drop_all_tables(conn: &mut SqliteConnection) -> Result<(), diesel::result
     Ok(())
 }
 
+/// creates all the tables in the PostgreSQL db by applying all migrations.
+pub fn create_all_pg_tables_if_not_exists(
+    conn: &mut PgPoolConnection,
+) -> Result<(), anyhow::Error> {
+    debug!("Indexer creates all tables in the PostgreSQL db ...");
+    conn.run_pending_migrations(PG_MIGRATIONS)
+        .map_err(|e| anyhow!("Failed to run migrations {e}"))?;
+    debug!("Indexer creates all tables in the PostgreSQL db complete.");
+    Ok(())
+}
+
+/// Resets the PostgreSQL db by reverting all migrations and reapplying them.
+/// All data in the indexer tables will be lost.
+pub fn reset_pg_db(conn: &mut PgPoolConnection) -> Result<(), anyhow::Error> {
+    info!("Resetting PostgreSQL db ...");
+    conn.revert_all_migrations(PG_MIGRATIONS)
+        .map_err(|e| anyhow!("Error reverting all migrations {e}"))?;
+    conn.run_pending_migrations(PG_MIGRATIONS)
+        .map_err(|e| anyhow!("Failed to run migrations {e}"))?;
+    info!("Reset PostgreSQL db complete.");
+    Ok(())
+}
+
 pub fn escape_sql_string(value: String) -> String {
-    // In SQLite, replace single quotes with two single quotes
+    // In SQLite and PostgreSQL, replace single quotes with two single quotes
     value.replace(['\''], "''")
 }
@@ -3,6 +3,7 @@
 
 use super::types::LocalAccount;
 use crate::key_derive::{generate_derivation_path, generate_new_key_pair};
+use rooch_types::framework::ethereum_validator::EthereumSignType;
 use rooch_types::framework::session_key::SessionKey;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::{
@@ -145,6 +146,15 @@ pub trait AccountKeystore {
         password: Option<String>,
     ) -> Result<RoochTransaction, anyhow::Error>;
 
+    /// Sign the transaction as the Ethereum wallet, via EIP-191 `personal_sign` or EIP-712 typed data
+    fn sign_transaction_ethereum(
+        &self,
+        address: &RoochAddress,
+        msg: RoochTransactionData,
+        sign_type: EthereumSignType,
+        password: Option<String>,
+    ) -> Result<RoochTransaction, anyhow::Error>;
+
     fn sign_secure<T>(
         &self,
         address: &RoochAddress,
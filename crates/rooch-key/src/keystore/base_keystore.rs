@@ -4,6 +4,7 @@
 use super::types::{LocalAccount, LocalSessionKey};
 use crate::keystore::account_keystore::AccountKeystore;
 use anyhow::{ensure, Ok};
+use rooch_types::framework::ethereum_validator::EthereumSignType;
 use rooch_types::framework::session_key::SessionKey;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::to_bech32::ToBech32;
@@ -75,11 +76,13 @@ impl AccountKeystore for BaseKeyStore {
             let keypair: RoochKeyPair = encryption.decrypt_with_type(password.clone())?;
             let public_key = keypair.public();
             let bitcoin_address = public_key.bitcoin_address()?;
+            let ethereum_address = public_key.ethereum_address()?;
             let nostr_bech32_public_key = public_key.xonly_public_key()?.to_bech32()?;
             let has_session_key = self.session_keys.contains_key(address);
             let local_account = LocalAccount {
                 address: *address,
                 bitcoin_address,
+                ethereum_address,
                 nostr_bech32_public_key,
                 public_key,
                 has_session_key,
@@ -146,6 +149,25 @@ impl AccountKeystore for BaseKeyStore {
         Ok(RoochTransaction::new(msg, auth))
     }
 
+    fn sign_transaction_ethereum(
+        &self,
+        address: &RoochAddress,
+        msg: RoochTransactionData,
+        sign_type: EthereumSignType,
+        password: Option<String>,
+    ) -> Result<RoochTransaction, anyhow::Error> {
+        let kp = self.get_key_pair(address, password).ok().ok_or_else(|| {
+            RoochError::SignMessageError(format!("Cannot find key for address: [{address}]"))
+        })?;
+        let auth = match sign_type {
+            EthereumSignType::PersonalSign => authenticator::Authenticator::ethereum(&kp, &msg)?,
+            EthereumSignType::TypedData => {
+                authenticator::Authenticator::ethereum_typed_data(&kp, &msg)?
+            }
+        };
+        Ok(RoochTransaction::new(msg, auth))
+    }
+
     fn add_address_encryption_data_to_keys(
         &mut self,
         address: RoochAddress,
@@ -5,6 +5,7 @@ use super::types::LocalAccount;
 use crate::keystore::account_keystore::AccountKeystore;
 use crate::keystore::base_keystore::BaseKeyStore;
 use anyhow::anyhow;
+use rooch_types::framework::ethereum_validator::EthereumSignType;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::{
     address::RoochAddress,
@@ -92,6 +93,17 @@ impl AccountKeystore for FileBasedKeystore {
         self.keystore.sign_transaction(address, msg, password)
     }
 
+    fn sign_transaction_ethereum(
+        &self,
+        address: &RoochAddress,
+        msg: RoochTransactionData,
+        sign_type: EthereumSignType,
+        password: Option<String>,
+    ) -> Result<RoochTransaction, anyhow::Error> {
+        self.keystore
+            .sign_transaction_ethereum(address, msg, sign_type, password)
+    }
+
     fn sign_secure<T>(
         &self,
         address: &RoochAddress,
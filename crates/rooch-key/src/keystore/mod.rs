@@ -5,6 +5,7 @@ use crate::keystore::account_keystore::AccountKeystore;
 use crate::keystore::file_keystore::FileBasedKeystore;
 use enum_dispatch::enum_dispatch;
 use memory_keystore::InMemKeystore;
+use rooch_types::framework::ethereum_validator::EthereumSignType;
 use rooch_types::key_struct::{GeneratedKeyPair, MnemonicData, MnemonicResult};
 use rooch_types::{
     address::RoochAddress,
@@ -176,6 +177,23 @@ impl AccountKeystore for Keystore {
         }
     }
 
+    fn sign_transaction_ethereum(
+        &self,
+        address: &RoochAddress,
+        msg: RoochTransactionData,
+        sign_type: EthereumSignType,
+        password: Option<String>,
+    ) -> Result<RoochTransaction, anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => {
+                file_keystore.sign_transaction_ethereum(address, msg, sign_type, password)
+            }
+            Keystore::InMem(inmem_keystore) => {
+                inmem_keystore.sign_transaction_ethereum(address, msg, sign_type, password)
+            }
+        }
+    }
+
     fn sign_secure<T>(
         &self,
         address: &RoochAddress,
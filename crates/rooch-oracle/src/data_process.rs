@@ -8,6 +8,7 @@ use rooch_rpc_api::jsonrpc_types::KeptVMStatusView;
 use rooch_rpc_client::wallet_context::WalletContext;
 use rooch_types::address::RoochAddress;
 use rooch_types::framework::oracle::OracleModule;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 use std::time::Duration;
 use tokio::sync::mpsc;
@@ -19,10 +20,11 @@ use tracing::{debug, error, info, warn};
 
 use crate::datasource::OracleDecimalData;
 
-pub fn subscribe_websocket(
+/// Subscribe the websocket, the text messages are deserialized as `T`
+pub fn subscribe_websocket<T: DeserializeOwned + Send + 'static>(
     url: String,
     subscribe_msg: Option<Value>,
-) -> impl Stream<Item = Result<Value>> {
+) -> impl Stream<Item = Result<T>> {
     let (tx, rx) = mpsc::channel(1);
 
     tokio::spawn(async move {
@@ -83,19 +85,20 @@ pub fn subscribe_websocket(
     ReceiverStream::new(rx)
 }
 
-pub fn subscribe_http(url: String, interval: u64) -> impl Stream<Item = Result<Value>> {
+/// Poll the url every `interval` seconds, the responses are deserialized as `T`
+pub fn subscribe_http<T: DeserializeOwned + Send + 'static>(
+    url: String,
+    interval: u64,
+) -> impl Stream<Item = Result<T>> {
     let (tx, rx) = mpsc::channel(1);
 
     tokio::spawn(async move {
         loop {
             match reqwest::get(&url).await {
                 Ok(response) => {
-                    if let Err(e) = tx.try_send(
-                        response
-                            .json::<Value>()
-                            .await
-                            .map_err(|e| anyhow::anyhow!(e)),
-                    ) {
+                    if let Err(e) =
+                        tx.try_send(response.json::<T>().await.map_err(|e| anyhow::anyhow!(e)))
+                    {
                         match e {
                             mpsc::error::TrySendError::Closed(_) => {
                                 warn!("Subscribe {} channel closed", url);
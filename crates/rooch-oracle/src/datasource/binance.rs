@@ -19,19 +19,20 @@ impl DataSource for BinanceSource {
     const IDENTIFIER: &'static str = "binance";
     fn subscribe(
         self,
-        ticker: Ticker,
-    ) -> Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>> {
-        let bticker = ticker_mapping(ticker);
+        ticker: &Ticker,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>>> {
+        let bticker = ticker_mapping(ticker)?;
         let url = URL_TEMPLATE.replace("{ticker}", bticker);
         let stream = data_process::subscribe_websocket(url, None);
         let stream = stream.map(|result| result.and_then(parse_data));
-        Box::pin(stream)
+        Ok(Box::pin(stream))
     }
 }
 
-fn ticker_mapping(ticker: Ticker) -> &'static str {
-    match ticker {
-        Ticker::BTCUSD => "btcusdt",
+fn ticker_mapping(ticker: &Ticker) -> Result<&'static str> {
+    match ticker.as_str() {
+        Ticker::BTCUSD => Ok("btcusdt"),
+        _ => Err(anyhow!("Ticker {} is not supported by binance", ticker)),
     }
 }
 
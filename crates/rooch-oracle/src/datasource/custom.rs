@@ -0,0 +1,365 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{OracleDecimalData, Ticker};
+use crate::data_process;
+use anyhow::{anyhow, bail, ensure, Result};
+use futures_util::Stream;
+use move_core_types::u256::U256;
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::pin::Pin;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio_stream::StreamExt;
+use tracing::debug;
+
+fn default_interval() -> u64 {
+    5
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampUnit {
+    Second,
+    #[default]
+    Millisecond,
+}
+
+/// The config of a ticker of the custom data source
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomTickerConfig {
+    /// The http(s) url is polled every `interval` seconds, the ws(s) url is subscribed by websocket
+    pub url: String,
+    /// The message sent after the websocket is connected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribe_message: Option<Value>,
+    /// The polling interval in seconds of the http url
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+    /// The JSON path of the price in the response, such as `$.data[0].last`, the price can be a number or a string.
+    /// The price is parsed from the raw text of the response, so the number keeps its precision
+    pub price_path: String,
+    /// The JSON path of the timestamp in the response, if not set, the local time of receiving is used
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp_path: Option<String>,
+    #[serde(default)]
+    pub timestamp_unit: TimestampUnit,
+    /// The decimal of the reported value, the price is scaled by 10^decimal
+    pub decimal: u8,
+}
+
+/// The config of the custom data source, loaded from a TOML or JSON file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomSourceConfig {
+    /// The identifier of the data source, it is reported with the data
+    pub name: String,
+    pub tickers: BTreeMap<Ticker, CustomTickerConfig>,
+}
+
+/// CustomSource subscribes the price from any HTTP or WebSocket JSON API defined by the config
+#[derive(Debug, Clone)]
+pub struct CustomSource {
+    config: CustomSourceConfig,
+}
+
+impl CustomSource {
+    pub fn new(config: CustomSourceConfig) -> Self {
+        Self { config }
+    }
+
+    /// Load the config from the file, the format is decided by the file extension, `.json` for JSON, otherwise TOML
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read file: {}, err: {}", path.display(), e))?;
+        let config: CustomSourceConfig = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => serde_json::from_str(&content)?,
+            _ => toml::from_str(&content)?,
+        };
+        ensure!(
+            !config.name.is_empty(),
+            "The name of the custom data source is empty"
+        );
+        Ok(Self::new(config))
+    }
+
+    pub fn identifier(&self) -> &str {
+        &self.config.name
+    }
+
+    pub fn subscribe(
+        self,
+        ticker: &Ticker,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>>> {
+        let config =
+            self.config.tickers.get(ticker).cloned().ok_or_else(|| {
+                anyhow!("Ticker {} is not supported by {}", ticker, self.config.name)
+            })?;
+        // Keep the raw text of the response, the price number is not parsed as f64
+        let stream: Pin<Box<dyn Stream<Item = Result<Box<RawValue>>> + Send>> =
+            if config.url.starts_with("ws://") || config.url.starts_with("wss://") {
+                Box::pin(data_process::subscribe_websocket(
+                    config.url.clone(),
+                    config.subscribe_message.clone(),
+                ))
+            } else if config.url.starts_with("http://") || config.url.starts_with("https://") {
+                Box::pin(data_process::subscribe_http(
+                    config.url.clone(),
+                    config.interval,
+                ))
+            } else {
+                bail!("Unsupported url: {}", config.url);
+            };
+        // Skip the messages without the price, such as the subscription response and the heartbeat
+        let stream = stream.filter_map(move |result| match result {
+            Ok(response) => match json_path(&response, &config.price_path) {
+                Some(_) => Some(parse_data(&config, &response)),
+                None => {
+                    debug!("Price not found in response: {}, skip", response);
+                    None
+                }
+            },
+            Err(e) => Some(Err(e)),
+        });
+        Ok(Box::pin(stream))
+    }
+}
+
+fn parse_data(config: &CustomTickerConfig, response: &RawValue) -> Result<OracleDecimalData> {
+    let price = json_path(response, &config.price_path)
+        .ok_or_else(|| anyhow!("price field not found in response: {}", response))?;
+    let price = match serde_json::from_str::<Value>(price.get())? {
+        Value::String(price) => price,
+        // The raw text of the number, `Number::to_string` loses the precision of f64
+        Value::Number(_) => price.get().to_string(),
+        _ => bail!("Invalid price {} in response: {}", price, response),
+    };
+    let timestamp = match &config.timestamp_path {
+        Some(timestamp_path) => {
+            let timestamp = json_path(response, timestamp_path)
+                .ok_or_else(|| anyhow!("timestamp field not found in response: {}", response))?;
+            let timestamp = match serde_json::from_str::<Value>(timestamp.get())? {
+                Value::String(timestamp) => timestamp.parse::<u64>()?,
+                Value::Number(timestamp) => timestamp
+                    .as_u64()
+                    .ok_or_else(|| anyhow!("Invalid timestamp {}", timestamp))?,
+                _ => bail!("Invalid timestamp {} in response: {}", timestamp, response),
+            };
+            match config.timestamp_unit {
+                TimestampUnit::Second => timestamp
+                    .checked_mul(1000)
+                    .ok_or_else(|| anyhow!("Invalid timestamp {} in seconds", timestamp))?,
+                TimestampUnit::Millisecond => timestamp,
+            }
+        }
+        None => SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64,
+    };
+    Ok(OracleDecimalData {
+        value: parse_decimal(&price, config.decimal)?,
+        decimal: config.decimal,
+        timestamp,
+    })
+}
+
+/// The max digits of the scaled integer, the max value of U256 has 78 digits
+const MAX_DECIMAL_DIGITS: i64 = 78;
+
+/// Parse the decimal string to the integer scaled by 10^decimal, the extra fraction digits are truncated.
+/// The exponent notation, such as `1.5e-3`, is supported.
+fn parse_decimal(s: &str, decimal: u8) -> Result<U256> {
+    let s = s.trim();
+    let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (
+            mantissa,
+            exponent
+                .parse::<i64>()
+                .map_err(|_| anyhow!("Invalid decimal price: {}", s))?,
+        ),
+        None => (s, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    ensure!(
+        !integer.is_empty()
+            && integer.chars().all(|c| c.is_ascii_digit())
+            && fraction.chars().all(|c| c.is_ascii_digit()),
+        "Invalid decimal price: {}",
+        s
+    );
+    // The number of the digits before the decimal point after scaling
+    let integer_digits = (integer.len() as i64)
+        .checked_add(exponent)
+        .and_then(|digits| digits.checked_add(decimal as i64))
+        .ok_or_else(|| anyhow!("Invalid decimal price: {}", s))?;
+    ensure!(
+        integer_digits <= MAX_DECIMAL_DIGITS,
+        "The decimal price {} is too large",
+        s
+    );
+    if integer_digits <= 0 {
+        return Ok(U256::zero());
+    }
+    let digits = integer
+        .chars()
+        .chain(fraction.chars())
+        .chain(std::iter::repeat('0'))
+        .take(integer_digits as usize)
+        .collect::<String>();
+    Ok(digits.parse::<U256>()?)
+}
+
+/// Get the field of the JSON object
+fn get_key<'a>(value: &'a RawValue, key: &str) -> Option<&'a RawValue> {
+    serde_json::from_str::<BTreeMap<String, &'a RawValue>>(value.get())
+        .ok()?
+        .remove(key)
+}
+
+/// Get the element of the JSON array
+fn get_index<'a>(value: &'a RawValue, index: usize) -> Option<&'a RawValue> {
+    serde_json::from_str::<Vec<&'a RawValue>>(value.get())
+        .ok()?
+        .into_iter()
+        .nth(index)
+}
+
+/// Select the raw value by a JSON path, support the subset `$.key`, `$.key[0]` and `$['key']`.
+/// The path starts with `/` is treated as a JSON pointer.
+pub fn json_path<'a>(value: &'a RawValue, path: &str) -> Option<&'a RawValue> {
+    if let Some(pointer) = path.strip_prefix('/') {
+        let mut current = value;
+        for token in pointer.split('/') {
+            let token = token.replace("~1", "/").replace("~0", "~");
+            current = match get_key(current, &token) {
+                Some(value) => value,
+                None => get_index(current, token.parse::<usize>().ok()?)?,
+            };
+        }
+        return Some(current);
+    }
+    let mut rest = path.strip_prefix('$').unwrap_or(path);
+    let mut current = value;
+    while !rest.is_empty() {
+        if let Some(after_dot) = rest.strip_prefix('.') {
+            let end = after_dot.find(['.', '[']).unwrap_or(after_dot.len());
+            current = get_key(current, &after_dot[..end])?;
+            rest = &after_dot[end..];
+        } else if let Some(after_bracket) = rest.strip_prefix('[') {
+            let end = after_bracket.find(']')?;
+            let segment = &after_bracket[..end];
+            current = match segment
+                .strip_prefix('\'')
+                .and_then(|segment| segment.strip_suffix('\''))
+            {
+                Some(key) => get_key(current, key)?,
+                None => get_index(current, segment.parse::<usize>().ok()?)?,
+            };
+            rest = &after_bracket[end + 1..];
+        } else {
+            // The path without `$`, such as `data[0].last`
+            let end = rest.find(['.', '[']).unwrap_or(rest.len());
+            current = get_key(current, &rest[..end])?;
+            rest = &rest[end..];
+        }
+    }
+    Some(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn raw(json: &str) -> Box<RawValue> {
+        RawValue::from_string(json.to_string()).unwrap()
+    }
+
+    fn select<'a>(value: &'a RawValue, path: &str) -> Option<&'a str> {
+        json_path(value, path).map(RawValue::get)
+    }
+
+    #[test]
+    fn test_json_path() {
+        let value = raw(r#"{"data": [{"last": "65000.5", "ts": 1700000000}], "a.b": 1, "c/d": 2}"#);
+        assert_eq!(select(&value, "$.data[0].last"), Some(r#""65000.5""#));
+        assert_eq!(select(&value, "data[0].ts"), Some("1700000000"));
+        assert_eq!(select(&value, "$['a.b']"), Some("1"));
+        assert_eq!(select(&value, "/data/0/last"), Some(r#""65000.5""#));
+        assert_eq!(select(&value, "/c~1d"), Some("2"));
+        assert_eq!(select(&value, "$.data[1].last"), None);
+        assert_eq!(select(&value, "$"), Some(value.get()));
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(
+            parse_decimal("65000.5", 8).unwrap(),
+            U256::from(6500050000000u64)
+        );
+        assert_eq!(parse_decimal("1.123456789", 2).unwrap(), U256::from(112u64));
+        assert_eq!(parse_decimal("42", 0).unwrap(), U256::from(42u64));
+        assert_eq!(parse_decimal("1e5", 2).unwrap(), U256::from(10000000u64));
+        assert_eq!(parse_decimal("1.5E-3", 6).unwrap(), U256::from(1500u64));
+        assert_eq!(
+            parse_decimal("3.0000000000000004e-5", 8).unwrap(),
+            U256::from(3000u64)
+        );
+        assert_eq!(parse_decimal("1e-9", 8).unwrap(), U256::zero());
+        assert!(parse_decimal("-1", 2).is_err());
+        assert!(parse_decimal("1e", 2).is_err());
+        assert!(parse_decimal("1e100", 2).is_err());
+    }
+
+    #[test]
+    fn test_parse_config_and_data() {
+        let config: CustomSourceConfig = toml::from_str(
+            r#"
+            name = "internal"
+
+            [tickers.ETHUSD]
+            url = "https://prices.example.com/eth"
+            price_path = "$.result.price"
+            timestamp_path = "$.result.time"
+            timestamp_unit = "second"
+            decimal = 6
+            "#,
+        )
+        .unwrap();
+        let ticker = Ticker::from_str("ETHUSD").unwrap();
+        let ticker_config = config.tickers.get(&ticker).unwrap();
+        assert_eq!(ticker_config.interval, 5);
+        let data = parse_data(
+            ticker_config,
+            &raw(r#"{"result": {"price": 3000.25, "time": "1700000000"}}"#),
+        )
+        .unwrap();
+        assert_eq!(
+            data,
+            OracleDecimalData {
+                value: U256::from(3000250000u64),
+                decimal: 6,
+                timestamp: 1700000000000,
+            }
+        );
+        // The price has more digits than f64 can represent
+        let data = parse_data(
+            ticker_config,
+            &raw(r#"{"result": {"price": 12345678901234567.123456789, "time": 1700000000}}"#),
+        )
+        .unwrap();
+        assert_eq!(
+            data.value,
+            U256::from_str("12345678901234567123456").unwrap()
+        );
+        // The timestamp in seconds overflows in milliseconds
+        assert!(parse_data(
+            ticker_config,
+            &raw(r#"{"result": {"price": "1", "time": 18446744073709551615}}"#),
+        )
+        .is_err());
+        let source = CustomSource::new(config);
+        assert!(source.subscribe(&Ticker::default()).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use async_trait::async_trait;
 use futures_util::Stream;
 use move_core_types::u256::U256;
@@ -12,29 +12,56 @@ use std::{
     str::FromStr,
 };
 
-#[derive(Debug, Clone, Copy, Default, clap::ValueEnum, Serialize)]
-#[serde(rename_all = "UPPERCASE")]
-pub enum Ticker {
-    #[default]
-    BTCUSD,
+/// The ticker of the price, such as BTCUSD.
+/// The builtin data sources only support the known tickers, the custom data sources can define any ticker.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Ticker(String);
+
+impl Ticker {
+    pub const BTCUSD: &'static str = "BTCUSD";
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for Ticker {
+    fn default() -> Self {
+        Ticker(Self::BTCUSD.to_string())
+    }
 }
 
 impl FromStr for Ticker {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        match s {
-            "BTCUSD" => Ok(Ticker::BTCUSD),
-            _ => Err(anyhow::anyhow!("Invalid ticker")),
-        }
+        ensure!(
+            !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+            "Invalid ticker: {}",
+            s
+        );
+        Ok(Ticker(s.to_uppercase()))
+    }
+}
+
+impl TryFrom<String> for Ticker {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        Ticker::from_str(&s)
+    }
+}
+
+impl From<Ticker> for String {
+    fn from(ticker: Ticker) -> Self {
+        ticker.0
     }
 }
 
 impl Display for Ticker {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Ticker::BTCUSD => write!(f, "BTCUSD"),
-        }
+        write!(f, "{}", self.0)
     }
 }
 
@@ -48,10 +75,11 @@ pub struct OracleDecimalData {
 #[async_trait]
 pub trait DataSource {
     const IDENTIFIER: &'static str;
+    /// Subscribe the price of the ticker, returns error if the ticker is not supported
     fn subscribe(
         self,
-        ticker: Ticker,
-    ) -> Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>>;
+        ticker: &Ticker,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>>>;
 
     fn identifier(&self) -> &'static str {
         Self::IDENTIFIER
@@ -59,6 +87,7 @@ pub trait DataSource {
 }
 
 pub mod binance;
+pub mod custom;
 pub mod okx;
 pub mod pyth;
 
@@ -67,25 +96,28 @@ pub enum DataSourceType {
     OKX(okx::OKXSource),
     Binance(binance::BinanceSource),
     Pyth(pyth::PythSource),
+    Custom(custom::CustomSource),
 }
 
 impl DataSourceType {
-    pub fn identifier(&self) -> &'static str {
+    pub fn identifier(&self) -> &str {
         match self {
             DataSourceType::OKX(ds) => ds.identifier(),
             DataSourceType::Binance(ds) => ds.identifier(),
             DataSourceType::Pyth(ds) => ds.identifier(),
+            DataSourceType::Custom(ds) => ds.identifier(),
         }
     }
 
     pub fn subscribe(
         self,
-        ticker: Ticker,
-    ) -> Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>> {
+        ticker: &Ticker,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>>> {
         match self {
             DataSourceType::OKX(ds) => ds.subscribe(ticker),
             DataSourceType::Binance(ds) => ds.subscribe(ticker),
             DataSourceType::Pyth(ds) => ds.subscribe(ticker),
+            DataSourceType::Custom(ds) => ds.subscribe(ticker),
         }
     }
 }
@@ -119,7 +151,7 @@ mod tests {
 
     async fn test_datasource(ds: impl DataSource) {
         let identifier = ds.identifier();
-        let mut stream = ds.subscribe(Ticker::BTCUSD);
+        let mut stream = ds.subscribe(&Ticker::default()).unwrap();
         //consume 10 items
         let mut ok_count = 0;
         for _ in 0..10 {
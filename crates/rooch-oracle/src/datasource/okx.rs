@@ -19,9 +19,9 @@ impl DataSource for OKXSource {
     const IDENTIFIER: &'static str = "okx";
     fn subscribe(
         self,
-        ticker: Ticker,
-    ) -> Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>> {
-        let inst_id = ticker_mapping(ticker);
+        ticker: &Ticker,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>>> {
+        let inst_id = ticker_mapping(ticker)?;
         let subscribe_msg = json!({
             "op": "subscribe",
             "args": [{
@@ -33,13 +33,14 @@ impl DataSource for OKXSource {
         let stream = data_process::subscribe_websocket(url, Some(subscribe_msg));
         //skip the first message
         let stream = stream.skip(1).map(|result| result.and_then(parse_data));
-        Box::pin(stream)
+        Ok(Box::pin(stream))
     }
 }
 
-fn ticker_mapping(ticker: Ticker) -> &'static str {
-    match ticker {
-        Ticker::BTCUSD => "BTC-USDT",
+fn ticker_mapping(ticker: &Ticker) -> Result<&'static str> {
+    match ticker.as_str() {
+        Ticker::BTCUSD => Ok("BTC-USDT"),
+        _ => Err(anyhow!("Ticker {} is not supported by okx", ticker)),
     }
 }
 
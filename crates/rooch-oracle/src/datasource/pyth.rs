@@ -20,20 +20,21 @@ impl DataSource for PythSource {
     const IDENTIFIER: &'static str = "pyth";
     fn subscribe(
         self,
-        ticker: Ticker,
-    ) -> Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>> {
-        let url = URL_TEMPLATE.replace("{ticker}", ticker_mapping(ticker));
+        ticker: &Ticker,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>>> {
+        let url = URL_TEMPLATE.replace("{ticker}", ticker_mapping(ticker)?);
 
         let stream = data_process::subscribe_http_stream(url);
         let stream = stream.map(|result| result.and_then(parse_data));
-        Box::pin(stream)
+        Ok(Box::pin(stream))
     }
 }
 
 //https://www.pyth.network/developers/price-feed-ids
-fn ticker_mapping(ticker: Ticker) -> &'static str {
-    match ticker {
-        Ticker::BTCUSD => "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43",
+fn ticker_mapping(ticker: &Ticker) -> Result<&'static str> {
+    match ticker.as_str() {
+        Ticker::BTCUSD => Ok("0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43"),
+        _ => Err(anyhow!("Ticker {} is not supported by pyth", ticker)),
     }
 }
 
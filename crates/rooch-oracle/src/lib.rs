@@ -4,4 +4,5 @@
 pub mod aggregator_stream;
 pub mod data_process;
 pub mod datasource;
+pub mod multi_source;
 pub mod reporter;
@@ -0,0 +1,354 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::aggregator_stream::AggregateStrategy;
+use crate::datasource::{DataSourceType, OracleDecimalData, Ticker};
+use anyhow::Result;
+use futures::stream::{select_all, SelectAll, Stream, StreamExt};
+use move_core_types::u256::U256;
+use std::collections::BTreeMap;
+use std::pin::Pin;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time::{interval, Interval, MissedTickBehavior};
+use tracing::{debug, warn};
+
+const BPS_BASE: u64 = 10000;
+
+type SourceStream = Pin<Box<dyn Stream<Item = (String, Result<OracleDecimalData>)> + Send>>;
+
+/// The policy to filter the quotes of the data sources and trigger the report
+#[derive(Debug, Clone)]
+pub struct ReportPolicy {
+    /// The interval to aggregate the quotes and check the triggers
+    pub check_interval: Duration,
+    /// Report when the aggregated price moves more than the threshold from the last reported price, in basis points
+    pub deviation_threshold_bps: u64,
+    /// Report when the time since the last report exceeds the heartbeat interval, even if the price does not move
+    pub heartbeat_interval: Duration,
+    /// The quote older than the max staleness is dropped
+    pub max_staleness: Duration,
+    /// The quote which deviates from the median of all quotes more than the threshold is dropped as an outlier, in basis points
+    pub outlier_threshold_bps: u64,
+    /// The min number of valid quotes to aggregate
+    pub min_sources: usize,
+}
+
+impl Default for ReportPolicy {
+    fn default() -> Self {
+        Self {
+            check_interval: Duration::from_secs(10),
+            deviation_threshold_bps: 50,
+            heartbeat_interval: Duration::from_secs(3600),
+            max_staleness: Duration::from_secs(60),
+            outlier_threshold_bps: 500,
+            min_sources: 1,
+        }
+    }
+}
+
+impl ReportPolicy {
+    /// Filter the stale and outlier quotes, the values are normalized to the max decimal of the quotes
+    pub fn filter_quotes(
+        &self,
+        quotes: Vec<(String, OracleDecimalData)>,
+        now_ms: u64,
+    ) -> Vec<(String, OracleDecimalData)> {
+        let max_staleness_ms = self.max_staleness.as_millis() as u64;
+        let fresh = quotes
+            .into_iter()
+            .filter(|(source, quote)| {
+                let stale = now_ms.saturating_sub(quote.timestamp) > max_staleness_ms;
+                if stale {
+                    debug!("Drop stale quote from {}: {:?}", source, quote);
+                }
+                !stale
+            })
+            .collect::<Vec<_>>();
+        let Some(decimal) = fresh.iter().map(|(_, quote)| quote.decimal).max() else {
+            return vec![];
+        };
+        let fresh = fresh
+            .into_iter()
+            .map(|(source, quote)| (source, normalize(quote, decimal)))
+            .collect::<Vec<_>>();
+
+        let median = AggregateStrategy::Median
+            .aggregate(fresh.iter().map(|(_, quote)| quote.clone()).collect())
+            .value;
+        fresh
+            .into_iter()
+            .filter(|(source, quote)| {
+                let diff = if quote.value > median {
+                    quote.value - median
+                } else {
+                    median - quote.value
+                };
+                let outlier =
+                    diff * U256::from(BPS_BASE) > median * U256::from(self.outlier_threshold_bps);
+                if outlier {
+                    warn!(
+                        "Drop outlier quote from {}: {}, median: {}",
+                        source, quote.value, median
+                    );
+                }
+                !outlier
+            })
+            .collect()
+    }
+
+    /// Whether the aggregated data should be reported, compared to the last report and its report time
+    pub fn should_report(
+        &self,
+        last_report: Option<&(OracleDecimalData, u64)>,
+        data: &OracleDecimalData,
+        now_ms: u64,
+    ) -> bool {
+        let Some((last_data, last_report_ms)) = last_report else {
+            return true;
+        };
+        if now_ms.saturating_sub(*last_report_ms) >= self.heartbeat_interval.as_millis() as u64 {
+            return true;
+        }
+        if last_data.decimal != data.decimal || last_data.value == U256::zero() {
+            return true;
+        }
+        let diff = if data.value > last_data.value {
+            data.value - last_data.value
+        } else {
+            last_data.value - data.value
+        };
+        diff * U256::from(BPS_BASE) >= last_data.value * U256::from(self.deviation_threshold_bps)
+    }
+}
+
+/// MultiSourceStream fans in the quotes of several data sources of a ticker,
+/// keeps the latest quote of each source, and aggregates the valid quotes when a report is triggered.
+pub struct MultiSourceStream {
+    inner: SelectAll<SourceStream>,
+    strategy: AggregateStrategy,
+    policy: ReportPolicy,
+    latest: BTreeMap<String, OracleDecimalData>,
+    last_report: Option<(OracleDecimalData, u64)>,
+    check_interval: Interval,
+}
+
+impl MultiSourceStream {
+    pub fn new(
+        datasources: Vec<DataSourceType>,
+        ticker: &Ticker,
+        strategy: AggregateStrategy,
+        policy: ReportPolicy,
+    ) -> Result<Self> {
+        let mut streams = vec![];
+        for datasource in datasources {
+            let identifier = datasource.identifier().to_string();
+            let stream: SourceStream = Box::pin(
+                datasource
+                    .subscribe(ticker)?
+                    .map(move |result| (identifier.clone(), result)),
+            );
+            streams.push(stream);
+        }
+        Ok(Self::from_streams(streams, strategy, policy))
+    }
+
+    pub fn from_streams(
+        streams: impl IntoIterator<Item = SourceStream>,
+        strategy: AggregateStrategy,
+        policy: ReportPolicy,
+    ) -> Self {
+        let mut check_interval = interval(policy.check_interval);
+        check_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Self {
+            inner: select_all(streams),
+            strategy,
+            policy,
+            latest: BTreeMap::new(),
+            last_report: None,
+            check_interval,
+        }
+    }
+
+    /// Wait for the next data to report, returns None if all the data sources are closed.
+    /// The report is not recorded until `reported` is called, so the failed report is retried on the next check.
+    pub async fn next_report(&mut self) -> Option<OracleDecimalData> {
+        loop {
+            tokio::select! {
+                item = self.inner.next() => match item {
+                    Some((source, Ok(quote))) => {
+                        self.latest.insert(source, quote);
+                    }
+                    Some((source, Err(e))) => {
+                        warn!("Error in data source {}: {}", source, e);
+                    }
+                    None => return None,
+                },
+                _ = self.check_interval.tick() => {
+                    let now_ms = now_millis();
+                    if let Some(data) = self.aggregate(now_ms) {
+                        if self.policy.should_report(self.last_report.as_ref(), &data, now_ms) {
+                            return Some(data);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn reported(&mut self, data: OracleDecimalData) {
+        self.last_report = Some((data, now_millis()));
+    }
+
+    fn aggregate(&self, now_ms: u64) -> Option<OracleDecimalData> {
+        let quotes = self
+            .latest
+            .iter()
+            .map(|(source, quote)| (source.clone(), quote.clone()))
+            .collect();
+        let mut valid_quotes = self.policy.filter_quotes(quotes, now_ms);
+        if valid_quotes.len() < self.policy.min_sources.max(1) {
+            debug!(
+                "Not enough valid quotes, expect at least {}, got {}",
+                self.policy.min_sources,
+                valid_quotes.len()
+            );
+            return None;
+        }
+        // The aggregated data takes the timestamp of the last quote
+        valid_quotes.sort_by_key(|(_, quote)| quote.timestamp);
+        Some(
+            self.strategy
+                .aggregate(valid_quotes.into_iter().map(|(_, quote)| quote).collect()),
+        )
+    }
+}
+
+fn normalize(quote: OracleDecimalData, decimal: u8) -> OracleDecimalData {
+    OracleDecimalData {
+        value: (quote.decimal..decimal).fold(quote.value, |value, _| value * U256::from(10u64)),
+        decimal,
+        timestamp: quote.timestamp,
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time should be after the unix epoch")
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockSource {
+        identifier: String,
+        values: Vec<u64>,
+    }
+
+    impl MockSource {
+        fn into_stream(self) -> SourceStream {
+            let identifier = self.identifier;
+            let quotes = self.values.into_iter().map(move |value| {
+                (
+                    identifier.clone(),
+                    Ok(OracleDecimalData {
+                        value: U256::from(value),
+                        decimal: 8,
+                        timestamp: now_millis(),
+                    }),
+                )
+            });
+            // Keep the source open after all the quotes are sent
+            Box::pin(futures::stream::iter(quotes).chain(futures::stream::pending()))
+        }
+    }
+
+    fn quote(value: u64, decimal: u8, timestamp: u64) -> OracleDecimalData {
+        OracleDecimalData {
+            value: U256::from(value),
+            decimal,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn test_filter_quotes() {
+        let policy = ReportPolicy {
+            max_staleness: Duration::from_secs(60),
+            outlier_threshold_bps: 100,
+            ..Default::default()
+        };
+        let now_ms = 1_000_000;
+        let quotes = vec![
+            ("a".to_string(), quote(10000, 2, now_ms)),
+            ("b".to_string(), quote(100500, 3, now_ms - 1000)),
+            ("c".to_string(), quote(10050, 2, now_ms)),
+            // outlier
+            ("d".to_string(), quote(12000, 2, now_ms)),
+            // stale
+            ("e".to_string(), quote(10000, 2, now_ms - 61_000)),
+        ];
+        let valid = policy.filter_quotes(quotes, now_ms);
+        let sources = valid
+            .iter()
+            .map(|(source, _)| source.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(sources, vec!["a", "b", "c"]);
+        assert!(valid.iter().all(|(_, quote)| quote.decimal == 3));
+        assert_eq!(valid[0].1.value, U256::from(100000u64));
+    }
+
+    #[test]
+    fn test_should_report() {
+        let policy = ReportPolicy {
+            deviation_threshold_bps: 50,
+            heartbeat_interval: Duration::from_secs(60),
+            ..Default::default()
+        };
+        let last_report = (quote(10000, 2, 0), 0);
+        assert!(policy.should_report(None, &quote(10000, 2, 0), 0));
+        assert!(!policy.should_report(Some(&last_report), &quote(10049, 2, 0), 1000));
+        assert!(policy.should_report(Some(&last_report), &quote(10050, 2, 0), 1000));
+        assert!(policy.should_report(Some(&last_report), &quote(9950, 2, 0), 1000));
+        assert!(policy.should_report(Some(&last_report), &quote(10000, 2, 0), 60_000));
+    }
+
+    #[tokio::test]
+    async fn test_multi_source_stream() {
+        let policy = ReportPolicy {
+            check_interval: Duration::from_millis(10),
+            outlier_threshold_bps: 500,
+            min_sources: 2,
+            ..Default::default()
+        };
+        let sources = vec![
+            MockSource {
+                identifier: "mock1".to_string(),
+                values: vec![10000, 20000],
+            },
+            MockSource {
+                identifier: "mock2".to_string(),
+                values: vec![20200],
+            },
+            MockSource {
+                identifier: "mock3".to_string(),
+                values: vec![50000],
+            },
+        ];
+        let mut stream = MultiSourceStream::from_streams(
+            sources.into_iter().map(MockSource::into_stream),
+            AggregateStrategy::Average,
+            policy,
+        );
+        let data = stream.next_report().await.unwrap();
+        // The latest quote of mock1 is 20000, the quote of mock3 is an outlier
+        assert_eq!(data.value, U256::from(20100u64));
+        stream.reported(data);
+
+        // The price does not move, no report until the heartbeat
+        let next = tokio::time::timeout(Duration::from_millis(100), stream.next_report()).await;
+        assert!(next.is_err());
+    }
+}
@@ -2,12 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    aggregator_stream::{AggregateStrategy, AggregatorStream},
+    aggregator_stream::AggregateStrategy,
     data_process,
     datasource::{DataSourceType, Ticker},
+    multi_source::{MultiSourceStream, ReportPolicy},
 };
-use anyhow::Result;
-use futures::stream::StreamExt;
+use anyhow::{ensure, Result};
 use moveos_types::moveos_std::object::ObjectID;
 use rooch_rpc_client::wallet_context::WalletContext;
 use rooch_types::address::RoochAddress;
@@ -18,10 +18,10 @@ pub struct Reporter {
     pub sender: RoochAddress,
     pub oracle_id: ObjectID,
     pub oracle_admin_id: ObjectID,
-    pub report_interval: u64,
     pub ticker: Ticker,
     pub aggregate_strategy: AggregateStrategy,
-    pub datasource: DataSourceType,
+    pub datasources: Vec<DataSourceType>,
+    pub report_policy: ReportPolicy,
     pub stop_on_error: bool,
     pub stop_after_report_times: Option<u64>,
 }
@@ -32,10 +32,10 @@ impl Reporter {
         sender: RoochAddress,
         oracle_id: ObjectID,
         oracle_admin_id: ObjectID,
-        report_interval: u64,
         ticker: Ticker,
-        datasource: DataSourceType,
+        datasources: Vec<DataSourceType>,
         aggregate_strategy: AggregateStrategy,
+        report_policy: ReportPolicy,
         stop_on_error: bool,
         stop_after_report_times: Option<u64>,
     ) -> Self {
@@ -44,10 +44,10 @@ impl Reporter {
             sender,
             oracle_id,
             oracle_admin_id,
-            report_interval,
             ticker,
-            datasource,
+            datasources,
             aggregate_strategy,
+            report_policy,
             stop_on_error,
             stop_after_report_times,
         }
@@ -59,44 +59,53 @@ impl Reporter {
             oracle_id,
             oracle_admin_id,
             wallet_context,
-            report_interval,
             ticker,
-            datasource,
+            datasources,
             aggregate_strategy,
+            report_policy,
             stop_on_error,
             stop_after_report_times,
         } = self;
-        let identifier = datasource.identifier().to_owned();
-        let stream = datasource.subscribe(ticker);
-        let mut aggregator = AggregatorStream::new(stream, aggregate_strategy);
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(report_interval));
+        ensure!(
+            !datasources.is_empty(),
+            "At least one data source is required"
+        );
+        let identifier = datasources
+            .iter()
+            .map(|datasource| datasource.identifier())
+            .collect::<Vec<_>>()
+            .join(",");
+        let mut stream =
+            MultiSourceStream::new(datasources, &ticker, aggregate_strategy, report_policy)?;
         let mut report_times = 0u64;
-        loop {
-            let _ = interval.tick().await;
-            if let Some(data) = aggregator.next().await {
-                let result = data_process::execute_submit_data_tx(
-                    &wallet_context,
-                    sender,
-                    oracle_id.clone(),
-                    ticker.to_string(),
-                    data,
-                    identifier.clone(),
-                    oracle_admin_id.clone(),
-                )
-                .await;
-                if let Err(e) = result {
+        while let Some(data) = stream.next_report().await {
+            let result = data_process::execute_submit_data_tx(
+                &wallet_context,
+                sender,
+                oracle_id.clone(),
+                ticker.to_string(),
+                data.clone(),
+                identifier.clone(),
+                oracle_admin_id.clone(),
+            )
+            .await;
+            match result {
+                Ok(()) => {
+                    stream.reported(data);
+                    report_times += 1;
+                    if let Some(stop_after_report_times) = stop_after_report_times {
+                        if report_times >= stop_after_report_times {
+                            break;
+                        }
+                    }
+                }
+                Err(e) => {
                     if stop_on_error {
                         return Err(e);
                     } else {
                         error!("Failed to submit data: {}", e);
                     }
                 }
-                report_times += 1;
-                if let Some(stop_after_report_times) = stop_after_report_times {
-                    if report_times >= stop_after_report_times {
-                        break;
-                    }
-                }
             }
         }
         Ok(format!("Reported {} times", report_times))
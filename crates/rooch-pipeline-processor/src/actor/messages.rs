@@ -5,7 +5,10 @@ use anyhow::Result;
 use coerce::actor::message::Message;
 use rooch_types::{
     service_status::ServiceStatus,
-    transaction::{ExecuteTransactionResponse, L1BlockWithBody, L1Transaction, RoochTransaction},
+    transaction::{
+        ExecuteTransactionResponse, L1BlockWithBody, L1Transaction, LedgerTransaction,
+        RoochTransaction,
+    },
 };
 
 #[derive(Clone)]
@@ -17,6 +20,16 @@ impl Message for ExecuteL2TxMessage {
     type Result = Result<ExecuteTransactionResponse>;
 }
 
+/// Execute the L2 txs in order, the results are in the order of the txs
+#[derive(Clone)]
+pub struct ExecuteL2TxsMessage {
+    pub txs: Vec<RoochTransaction>,
+}
+
+impl Message for ExecuteL2TxsMessage {
+    type Result = Vec<Result<ExecuteTransactionResponse>>;
+}
+
 #[derive(Clone)]
 pub struct ExecuteL1BlockMessage {
     pub tx: L1BlockWithBody,
@@ -35,6 +48,16 @@ impl Message for ExecuteL1TxMessage {
     type Result = Result<ExecuteTransactionResponse>;
 }
 
+/// Execute the transactions which have already been sequenced by the sequencer in order, e.g. synced from DA
+#[derive(Clone)]
+pub struct ExecuteSequencedTxsMessage {
+    pub txs: Vec<LedgerTransaction>,
+}
+
+impl Message for ExecuteSequencedTxsMessage {
+    type Result = Result<()>;
+}
+
 #[derive(Clone)]
 pub struct GetServiceStatusMessage {}
 
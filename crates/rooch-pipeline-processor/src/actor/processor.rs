@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::messages::{
-    ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage, GetServiceStatusMessage,
+    ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage, ExecuteL2TxsMessage,
+    ExecuteSequencedTxsMessage, GetServiceStatusMessage,
 };
 use crate::metrics::PipelineProcessorMetrics;
 use anyhow::{Error, Result};
@@ -12,8 +13,11 @@ use bitcoin_client::proxy::BitcoinClientProxy;
 use coerce::actor::{context::ActorContext, message::Handler, Actor, LocalActorRef};
 use function_name::named;
 use moveos::moveos::VMPanicError;
+use moveos_types::h256::H256;
 use moveos_types::state::StateChangeSetExt;
-use moveos_types::transaction::VerifiedMoveOSTransaction;
+use moveos_types::transaction::{
+    TransactionExecutionInfo, TransactionOutput, VerifiedMoveOSTransaction,
+};
 use prometheus::Registry;
 use rooch_da::actor::messages::AppendTransactionMessage;
 use rooch_da::proxy::DAServerProxy;
@@ -26,13 +30,17 @@ use rooch_types::bitcoin::types::Block as BitcoinBlock;
 use rooch_types::{
     service_status::ServiceStatus,
     transaction::{
-        ExecuteTransactionResponse, L1BlockWithBody, L1Transaction, LedgerTransaction,
+        ExecuteTransactionResponse, L1Block, L1BlockWithBody, L1Transaction, LedgerTransaction,
         LedgerTxData, RoochTransaction,
     },
 };
+use std::collections::{HashSet, VecDeque};
 use std::sync::Arc;
 use tracing::{debug, info};
 
+/// The max number of applied txs loaded for the parallel execution on startup at once
+const LOAD_APPLIED_TX_BATCH_SIZE: usize = 1024;
+
 /// PipelineProcessor aggregates the executor, sequencer, proposer, and indexer to process transactions.
 pub struct PipelineProcessorActor {
     pub(crate) executor: ExecutorProxy,
@@ -44,6 +52,8 @@ pub struct PipelineProcessorActor {
     event_actor: Option<LocalActorRef<EventActor>>,
     rooch_db: RoochDB,
     bitcoin_client_proxy: Option<BitcoinClientProxy>,
+    /// The max number of L2 txs executed in parallel, disabled if less than 2
+    parallel_execution_window: usize,
 }
 
 impl PipelineProcessorActor {
@@ -57,6 +67,7 @@ impl PipelineProcessorActor {
         event_actor: Option<LocalActorRef<EventActor>>,
         rooch_db: RoochDB,
         bitcoin_client_proxy: Option<BitcoinClientProxy>,
+        parallel_execution_window: usize,
     ) -> Self {
         Self {
             executor,
@@ -68,15 +79,14 @@ impl PipelineProcessorActor {
             event_actor,
             rooch_db,
             bitcoin_client_proxy,
+            parallel_execution_window,
         }
     }
 
-    pub async fn process_sequenced_tx_on_startup(&mut self) -> Result<()> {
+    // get the hashes of sequenced but not executed txs, in order
+    async fn get_unexecuted_tx_hashes(&self) -> Result<Vec<H256>> {
         let last_order = self.sequencer.get_sequencer_order().await.unwrap_or(0);
-        debug!("process_sequenced_tx_on_startup last_order: {}", last_order);
-        if last_order == 0 {
-            return Ok(());
-        }
+        debug!("get_unexecuted_tx_hashes last_order: {}", last_order);
         let mut txs = Vec::new();
         for order in (1..=last_order).rev() {
             let tx_hash = self
@@ -99,6 +109,13 @@ impl PipelineProcessorActor {
                 break;
             }
         }
+        // reverse the txs to keep the order
+        txs.reverse();
+        Ok(txs)
+    }
+
+    pub async fn process_sequenced_tx_on_startup(&mut self) -> Result<()> {
+        let txs = self.get_unexecuted_tx_hashes().await?;
         if txs.is_empty() {
             return Ok(());
         }
@@ -107,8 +124,7 @@ impl PipelineProcessorActor {
             txs
         );
 
-        for tx_hash in txs.into_iter().rev() {
-            // reverse the txs to keep the order
+        for tx_hash in txs {
             let ledger_tx = self
                 .sequencer
                 .get_transaction_by_hash(tx_hash)
@@ -118,25 +134,8 @@ impl PipelineProcessorActor {
             match &ledger_tx.data {
                 LedgerTxData::L1Block(block) => {
                     debug!("process_sequenced_tx_on_startup l1_block_tx: {:?}", block);
-                    match &self.bitcoin_client_proxy {
-                        Some(bitcoin_client_proxy) => {
-                            let block_hash_vec = block.block_hash.clone();
-                            let block_hash =
-                                bitcoin::block::BlockHash::from_slice(&block_hash_vec)?;
-                            let btc_block = bitcoin_client_proxy.get_block(block_hash).await?;
-                            let block_body = BitcoinBlock::from(btc_block);
-                            self.execute_l1_block(L1BlockWithBody::new(
-                                block.clone(),
-                                block_body.encode(),
-                            ))
-                            .await?;
-                        }
-                        None => {
-                            return Err(anyhow::anyhow!(
-                                "The bitcoin client proxy should be initialized before processing the sequenced l1_block_tx(block: {:?}) on startup", block
-                            ));
-                        }
-                    }
+                    let l1_block = self.get_l1_block_with_body(block).await?;
+                    self.execute_l1_block(l1_block).await?;
                 }
                 LedgerTxData::L1Tx(l1_tx) => {
                     debug!("process_sequenced_tx_on_startup l1_tx: {:?}", l1_tx);
@@ -163,6 +162,224 @@ impl PipelineProcessorActor {
         Ok(())
     }
 
+    async fn get_l1_block_with_body(&self, block: &L1Block) -> Result<L1BlockWithBody> {
+        match &self.bitcoin_client_proxy {
+            Some(bitcoin_client_proxy) => {
+                let block_hash = bitcoin::block::BlockHash::from_slice(&block.block_hash)?;
+                let btc_block = bitcoin_client_proxy.get_block(block_hash).await?;
+                let block_body = BitcoinBlock::from(btc_block);
+                Ok(L1BlockWithBody::new(block.clone(), block_body.encode()))
+            }
+            None => Err(anyhow::anyhow!(
+                "The bitcoin client proxy should be initialized before processing the sequenced l1_block_tx(block: {:?})", block
+            )),
+        }
+    }
+
+    /// Execute the applied but not executed txs on startup when syncing from DA,
+    /// they have been applied to the sequencer, so they are executed only.
+    pub async fn process_applied_tx_on_startup(&mut self) -> Result<()> {
+        let txs = self.get_unexecuted_tx_hashes().await?;
+        if txs.is_empty() {
+            return Ok(());
+        }
+        info!(
+            "Process applied but not executed transactions on startup, txs: {:?}",
+            txs
+        );
+        if self.parallel_execution_window > 1 {
+            for tx_hashes in txs.chunks(LOAD_APPLIED_TX_BATCH_SIZE) {
+                let mut ledger_txs = VecDeque::with_capacity(tx_hashes.len());
+                for tx_hash in tx_hashes {
+                    ledger_txs.push_back(self.get_ledger_tx(*tx_hash).await?);
+                }
+                self.execute_applied_txs_in_parallel(ledger_txs).await?;
+            }
+            return Ok(());
+        }
+
+        for tx_hash in txs {
+            let ledger_tx = self.get_ledger_tx(tx_hash).await?;
+            let moveos_tx = self.validate_ledger_tx(&ledger_tx).await?;
+            self.execute_applied_tx(ledger_tx, moveos_tx).await?;
+        }
+        Ok(())
+    }
+
+    async fn get_ledger_tx(&self, tx_hash: H256) -> Result<LedgerTransaction> {
+        self.sequencer
+            .get_transaction_by_hash(tx_hash)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("The tx with hash {} should exists", tx_hash))
+    }
+
+    /// Execute the applied txs in windows, the L2 txs in a window are executed in parallel by the executor,
+    /// and the L1 txs are executed one by one.
+    /// The txs in a window are validated against the state before the window,
+    /// so a window only contains the txs of different senders, the validation of a L2 tx only depends on the state of its sender.
+    /// A window ends before the tx which fails to validate, the tx is validated again in the next window.
+    #[named]
+    async fn execute_applied_txs_in_parallel(
+        &mut self,
+        mut pending: VecDeque<LedgerTransaction>,
+    ) -> Result<()> {
+        let fn_name = function_name!();
+        while let Some(first_tx) = pending.front() {
+            if !matches!(first_tx.data, LedgerTxData::L2Tx(_)) {
+                let ledger_tx = pending.pop_front().expect("pending tx should exist");
+                let moveos_tx = self.validate_ledger_tx(&ledger_tx).await?;
+                self.execute_applied_tx(ledger_tx, moveos_tx).await?;
+                continue;
+            }
+
+            let mut window = Vec::new();
+            let mut senders = HashSet::new();
+            while let Some(ledger_tx) = pending.front() {
+                if window.len() >= self.parallel_execution_window {
+                    break;
+                }
+                let LedgerTxData::L2Tx(l2_tx) = &ledger_tx.data else {
+                    break;
+                };
+                if !senders.insert(l2_tx.sender()) {
+                    break;
+                }
+                match self.validate_ledger_tx(ledger_tx).await {
+                    Ok(mut moveos_tx) => {
+                        // Add sequence info to tx context, let the Move contract can get the sequence info
+                        moveos_tx.ctx.add(ledger_tx.sequence_info.clone())?;
+                        let ledger_tx = pending.pop_front().expect("pending tx should exist");
+                        window.push((ledger_tx, moveos_tx));
+                    }
+                    // The first tx is validated against the latest state, same as the sequential execution
+                    Err(err) if window.is_empty() => return Err(err),
+                    Err(_) => break,
+                }
+            }
+
+            let (ledger_txs, moveos_txs): (Vec<_>, Vec<_>) = window.into_iter().unzip();
+            let results = self
+                .executor
+                .execute_transactions(moveos_txs.clone())
+                .await?;
+            let executed = results.len();
+            for ((ledger_tx, moveos_tx), result) in
+                ledger_txs.iter().cloned().zip(moveos_txs).zip(results)
+            {
+                let tx_order = ledger_tx.sequence_info.tx_order;
+                match result {
+                    Ok((output, execution_info)) => {
+                        self.handle_executed_tx(
+                            ledger_tx,
+                            moveos_tx,
+                            output,
+                            execution_info,
+                            false,
+                            fn_name,
+                        )
+                        .await?;
+                    }
+                    Err(err) => self.handle_applied_tx_error(tx_order, true, err)?,
+                }
+            }
+            // The executor stops at the failed tx, the following txs are executed in the next window
+            for ledger_tx in ledger_txs.into_iter().skip(executed).rev() {
+                pending.push_front(ledger_tx);
+            }
+        }
+        Ok(())
+    }
+
+    async fn validate_ledger_tx(
+        &self,
+        ledger_tx: &LedgerTransaction,
+    ) -> Result<VerifiedMoveOSTransaction> {
+        match &ledger_tx.data {
+            LedgerTxData::L1Block(block) => {
+                let l1_block = self.get_l1_block_with_body(block).await?;
+                self.executor.validate_l1_block(l1_block).await
+            }
+            LedgerTxData::L1Tx(l1_tx) => self.executor.validate_l1_tx(l1_tx.clone()).await,
+            LedgerTxData::L2Tx(l2_tx) => self.executor.validate_l2_tx(l2_tx.clone()).await,
+        }
+    }
+
+    /// Execute the tx which has been sequenced by the sequencer (e.g. synced from DA),
+    /// the tx is applied to the local sequencer as is, and is not appended to DA again.
+    #[named]
+    pub async fn execute_sequenced_tx(&mut self, ledger_tx: LedgerTransaction) -> Result<()> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .pipeline_processor_execution_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let moveos_tx = self.validate_ledger_tx(&ledger_tx).await?;
+        self.sequencer
+            .apply_sequenced_transaction(ledger_tx.clone())
+            .await?;
+        self.execute_applied_tx(ledger_tx, moveos_tx).await
+    }
+
+    /// Execute the txs which have been sequenced by the sequencer in order, see `execute_sequenced_tx`.
+    /// The txs are applied to the local sequencer first, then the L2 txs are executed in parallel if enabled.
+    #[named]
+    pub async fn execute_sequenced_txs(
+        &mut self,
+        ledger_txs: Vec<LedgerTransaction>,
+    ) -> Result<()> {
+        if self.parallel_execution_window < 2 {
+            for ledger_tx in ledger_txs {
+                self.execute_sequenced_tx(ledger_tx).await?;
+            }
+            return Ok(());
+        }
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .pipeline_processor_execution_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        for ledger_tx in &ledger_txs {
+            self.sequencer
+                .apply_sequenced_transaction(ledger_tx.clone())
+                .await?;
+        }
+        self.execute_applied_txs_in_parallel(ledger_txs.into())
+            .await
+    }
+
+    async fn execute_applied_tx(
+        &mut self,
+        ledger_tx: LedgerTransaction,
+        moveos_tx: VerifiedMoveOSTransaction,
+    ) -> Result<()> {
+        let tx_order = ledger_tx.sequence_info.tx_order;
+        let is_l2_tx = matches!(ledger_tx.data, LedgerTxData::L2Tx(_));
+        match self.execute_tx(ledger_tx, moveos_tx, false).await {
+            Ok(_v) => Ok(()),
+            Err(err) => self.handle_applied_tx_error(tx_order, is_l2_tx, err),
+        }
+    }
+
+    fn handle_applied_tx_error(&self, tx_order: u64, is_l2_tx: bool, err: Error) -> Result<()> {
+        // same as the sequencer, L2 tx failed without VM panic is ignored
+        if is_l2_tx && !is_vm_panic_error(&err) {
+            tracing::warn!(
+                "Execute sequenced L2 Tx failed, tx_order: {}, error: {:?}",
+                tx_order,
+                err
+            );
+            return Ok(());
+        }
+        tracing::error!(
+            "Execute sequenced tx failed, tx_order: {}, error: {:?}",
+            tx_order,
+            err
+        );
+        Err(err)
+    }
+
     #[named]
     pub async fn execute_l1_block(
         &mut self,
@@ -182,7 +399,7 @@ impl PipelineProcessorActor {
             .await?;
         let tx_order = ledger_tx.sequence_info.tx_order;
         let size = moveos_tx.ctx.tx_size;
-        let result = match self.execute_tx(ledger_tx, moveos_tx).await {
+        let result = match self.execute_tx(ledger_tx, moveos_tx, true).await {
             Ok(v) => v,
             Err(err) => {
                 if is_vm_panic_error(&err) {
@@ -226,7 +443,7 @@ impl PipelineProcessorActor {
             .await?;
         let size = moveos_tx.ctx.tx_size;
         let tx_order = ledger_tx.sequence_info.tx_order;
-        let result = match self.execute_tx(ledger_tx, moveos_tx).await {
+        let result = match self.execute_tx(ledger_tx, moveos_tx, true).await {
             Ok(v) => v,
             Err(err) => {
                 if is_vm_panic_error(&err) {
@@ -264,33 +481,20 @@ impl PipelineProcessorActor {
     #[named]
     pub async fn execute_l2_tx(
         &mut self,
-        mut tx: RoochTransaction,
+        tx: RoochTransaction,
     ) -> Result<ExecuteTransactionResponse> {
-        debug!("pipeline execute_l2_tx: {:?}", tx.tx_hash());
         let fn_name = function_name!();
         let _timer = self
             .metrics
             .pipeline_processor_execution_tx_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
-        let moveos_tx = self.executor.validate_l2_tx(tx.clone()).await?;
-        let ledger_tx = self
-            .sequencer
-            .sequence_transaction(LedgerTxData::L2Tx(tx.clone()))
-            .await?;
+        let (ledger_tx, moveos_tx) = self.sequence_l2_tx(tx.clone()).await?;
         let size = moveos_tx.ctx.tx_size;
-        let result = match self.execute_tx(ledger_tx, moveos_tx).await {
+        let result = match self.execute_tx(ledger_tx, moveos_tx, true).await {
             Ok(v) => v,
             Err(err) => {
-                if is_vm_panic_error(&err) {
-                    let l2_tx_bcs_bytes = bcs::to_bytes(&tx)?;
-                    tracing::error!(
-                        "Execute L2 Tx failed while VM panic occurred and revert tx. error: {:?} tx info {}",
-                        err, hex::encode(l2_tx_bcs_bytes)
-                    );
-                    let tx_hash = tx.tx_hash();
-                    self.rooch_db.revert_tx(tx_hash)?;
-                }
+                self.handle_l2_tx_error(tx, &err, true).await?;
                 return Err(err);
             }
         };
@@ -307,11 +511,187 @@ impl PipelineProcessorActor {
         Ok(result)
     }
 
+    /// Execute the L2 txs in order, the results are in the order of the txs.
+    /// The txs are validated and sequenced in windows of different senders, and the txs in a window are executed in parallel.
+    /// The tx which fails to validate is not sequenced, same as `execute_l2_tx`.
+    #[named]
+    pub async fn execute_l2_txs(
+        &mut self,
+        txs: Vec<RoochTransaction>,
+    ) -> Vec<Result<ExecuteTransactionResponse>> {
+        if self.parallel_execution_window < 2 {
+            let mut results = Vec::with_capacity(txs.len());
+            for tx in txs {
+                results.push(self.execute_l2_tx(tx).await);
+            }
+            return results;
+        }
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .pipeline_processor_execution_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut results = Vec::with_capacity(txs.len());
+        results.resize_with(txs.len(), || None);
+        let mut txs = txs.into_iter().enumerate().peekable();
+        while txs.peek().is_some() {
+            let mut window = VecDeque::new();
+            let mut senders = HashSet::new();
+            while window.len() < self.parallel_execution_window {
+                let Some((_, tx)) = txs.peek() else {
+                    break;
+                };
+                if !senders.insert(tx.sender()) {
+                    break;
+                }
+                let (index, tx) = txs.next().expect("peeked tx should exist");
+                match self.sequence_l2_tx(tx).await {
+                    Ok((ledger_tx, mut moveos_tx)) => {
+                        // Add sequence info to tx context, let the Move contract can get the sequence info
+                        match moveos_tx.ctx.add(ledger_tx.sequence_info.clone()) {
+                            Ok(_) => window.push_back((index, ledger_tx, moveos_tx)),
+                            Err(err) => results[index] = Some(Err(err)),
+                        }
+                    }
+                    Err(err) => results[index] = Some(Err(err)),
+                }
+            }
+            let stopped = self
+                .execute_sequenced_l2_txs(window, &mut results, fn_name)
+                .await;
+            // The following txs are not sequenced after the service is set to Maintenance mode, same as the L1 tx
+            if stopped {
+                for (index, mut tx) in txs.by_ref() {
+                    results[index] = Some(Err(anyhow::anyhow!(
+                        "The service is in Maintenance mode, the tx {} is not executed",
+                        tx.tx_hash()
+                    )));
+                }
+            }
+        }
+        results
+            .into_iter()
+            .map(|result| result.expect("every tx should have a result"))
+            .collect()
+    }
+
+    /// Execute the sequenced L2 txs in parallel, the executor stops at the failed tx,
+    /// and the following txs have been sequenced, so they are executed again in parallel.
+    /// If the failed tx can not be reverted, the service is set to Maintenance mode and the following txs are not executed,
+    /// their results are errors. Return true if the execution is stopped by the Maintenance mode.
+    async fn execute_sequenced_l2_txs(
+        &mut self,
+        mut window: VecDeque<(usize, LedgerTransaction, VerifiedMoveOSTransaction)>,
+        results: &mut [Option<Result<ExecuteTransactionResponse>>],
+        fn_name: &str,
+    ) -> bool {
+        while !window.is_empty() {
+            let moveos_txs = window
+                .iter()
+                .map(|(_, _, moveos_tx)| moveos_tx.clone())
+                .collect();
+            let executed = match self.executor.execute_transactions(moveos_txs).await {
+                Ok(executed) => executed,
+                Err(err) => vec![Err(err)],
+            };
+            for result in executed {
+                let (index, ledger_tx, moveos_tx) =
+                    window.pop_front().expect("executed tx should exist");
+                let result = match result {
+                    Ok((output, execution_info)) => {
+                        self.metrics
+                            .pipeline_processor_l2_tx_gas_used
+                            .inc_by(output.gas_used);
+                        self.handle_executed_tx(
+                            ledger_tx,
+                            moveos_tx,
+                            output,
+                            execution_info,
+                            true,
+                            fn_name,
+                        )
+                        .await
+                    }
+                    Err(err) => {
+                        let was_maintenance = self.service_status.is_maintenance();
+                        if let LedgerTxData::L2Tx(tx) = ledger_tx.data {
+                            // Only the last sequenced tx can be reverted
+                            if let Err(e) =
+                                self.handle_l2_tx_error(tx, &err, window.is_empty()).await
+                            {
+                                tracing::error!("Handle L2 tx error failed: {:?}", e);
+                            }
+                        }
+                        if !was_maintenance && self.service_status.is_maintenance() {
+                            results[index] = Some(Err(err));
+                            for (index, ledger_tx, _) in window.drain(..) {
+                                results[index] = Some(Err(anyhow::anyhow!(
+                                    "The service is in Maintenance mode, the tx with order {} is sequenced but not executed",
+                                    ledger_tx.sequence_info.tx_order
+                                )));
+                            }
+                            return true;
+                        }
+                        Err(err)
+                    }
+                };
+                results[index] = Some(result);
+            }
+        }
+        false
+    }
+
+    /// Validate and sequence the L2 tx
+    async fn sequence_l2_tx(
+        &mut self,
+        mut tx: RoochTransaction,
+    ) -> Result<(LedgerTransaction, VerifiedMoveOSTransaction)> {
+        debug!("pipeline sequence_l2_tx: {:?}", tx.tx_hash());
+        let moveos_tx = self.executor.validate_l2_tx(tx.clone()).await?;
+        let ledger_tx = self
+            .sequencer
+            .sequence_transaction(LedgerTxData::L2Tx(tx))
+            .await?;
+        Ok((ledger_tx, moveos_tx))
+    }
+
+    /// Revert the sequenced L2 tx which failed with VM panic.
+    /// If the following txs have been sequenced, the tx can not be reverted,
+    /// then set service to Maintenance mode, same as the L1 tx.
+    async fn handle_l2_tx_error(
+        &mut self,
+        mut tx: RoochTransaction,
+        err: &Error,
+        can_revert: bool,
+    ) -> Result<()> {
+        if !is_vm_panic_error(err) {
+            return Ok(());
+        }
+        let l2_tx_bcs_bytes = bcs::to_bytes(&tx)?;
+        if can_revert {
+            tracing::error!(
+                "Execute L2 Tx failed while VM panic occurred and revert tx. error: {:?} tx info {}",
+                err, hex::encode(l2_tx_bcs_bytes)
+            );
+            self.rooch_db.revert_tx(tx.tx_hash())?;
+        } else {
+            tracing::error!(
+                "Execute L2 Tx failed while VM panic occurred, the following txs have been sequenced, \
+                set service to Maintenance mode. error: {:?} tx info {}",
+                err, hex::encode(l2_tx_bcs_bytes)
+            );
+            self.update_service_status(ServiceStatus::Maintenance).await;
+        }
+        Ok(())
+    }
+
     #[named]
     pub async fn execute_tx(
         &mut self,
         tx: LedgerTransaction,
         mut moveos_tx: VerifiedMoveOSTransaction,
+        append_to_da: bool,
     ) -> Result<ExecuteTransactionResponse> {
         let fn_name = function_name!();
         let _timer = self
@@ -323,14 +703,30 @@ impl PipelineProcessorActor {
         moveos_tx.ctx.add(tx.sequence_info.clone())?;
 
         // Then execute
-        let size = moveos_tx.ctx.tx_size;
         let (output, execution_info) = self.executor.execute_transaction(moveos_tx.clone()).await?;
-        self.da_server
-            .append_tx(AppendTransactionMessage {
-                tx_order: tx.sequence_info.tx_order,
-                tx_timestamp: tx.sequence_info.tx_timestamp,
-            })
-            .await?;
+        self.handle_executed_tx(tx, moveos_tx, output, execution_info, append_to_da, fn_name)
+            .await
+    }
+
+    /// Append the executed tx to DA, sync the state to the reader executor and update the indexer.
+    async fn handle_executed_tx(
+        &mut self,
+        tx: LedgerTransaction,
+        moveos_tx: VerifiedMoveOSTransaction,
+        output: TransactionOutput,
+        execution_info: TransactionExecutionInfo,
+        append_to_da: bool,
+        fn_name: &str,
+    ) -> Result<ExecuteTransactionResponse> {
+        let size = moveos_tx.ctx.tx_size;
+        if append_to_da {
+            self.da_server
+                .append_tx(AppendTransactionMessage {
+                    tx_order: tx.sequence_info.tx_order,
+                    tx_timestamp: tx.sequence_info.tx_timestamp,
+                })
+                .await?;
+        }
         let root = execution_info.root_metadata();
         // Sync latest state root from writer executor to reader executor
         self.executor
@@ -393,6 +789,17 @@ impl Handler<ExecuteL2TxMessage> for PipelineProcessorActor {
     }
 }
 
+#[async_trait]
+impl Handler<ExecuteL2TxsMessage> for PipelineProcessorActor {
+    async fn handle(
+        &mut self,
+        msg: ExecuteL2TxsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Vec<Result<ExecuteTransactionResponse>> {
+        self.execute_l2_txs(msg.txs).await
+    }
+}
+
 #[async_trait]
 impl Handler<ExecuteL1BlockMessage> for PipelineProcessorActor {
     async fn handle(
@@ -415,6 +822,17 @@ impl Handler<ExecuteL1TxMessage> for PipelineProcessorActor {
     }
 }
 
+#[async_trait]
+impl Handler<ExecuteSequencedTxsMessage> for PipelineProcessorActor {
+    async fn handle(
+        &mut self,
+        msg: ExecuteSequencedTxsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        self.execute_sequenced_txs(msg.txs).await
+    }
+}
+
 #[async_trait]
 impl Handler<GetServiceStatusMessage> for PipelineProcessorActor {
     async fn handle(
@@ -0,0 +1,176 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::metrics::DASyncerMetrics;
+use crate::proxy::PipelineProcessorProxy;
+use anyhow::{anyhow, Result};
+use prometheus::Registry;
+use rooch_da::actor::messages::AppendSyncedBlockMessage;
+use rooch_da::backend::DABackends;
+use rooch_da::proxy::DAServerProxy;
+use rooch_sequencer::proxy::SequencerProxy;
+use rooch_types::address::BitcoinAddress;
+use rooch_types::da::batch::DABatch;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+
+/// DASyncer makes a full node follow the sequencer by syncing batches from DA backends:
+/// 1. fetch the next batch (block) from DA backends, and check it's signed by the sequencer.
+/// 2. apply & execute transactions in the batch in order, tx accumulator must be the same as the one recorded in each tx.
+/// 3. append the block to local DA meta, so the next block number to sync could be recovered after restart.
+///
+/// DASyncer stops when local state diverges from the sequencer or any transaction fails to apply,
+/// because it's unsafe to continue.
+pub struct DASyncer {
+    backends: DABackends,
+    sequencer_account: BitcoinAddress,
+    processor: PipelineProcessorProxy,
+    sequencer: SequencerProxy,
+    da_server: DAServerProxy,
+    sync_interval: u64,
+    metrics: Arc<DASyncerMetrics>,
+}
+
+impl DASyncer {
+    pub fn new(
+        backends: DABackends,
+        sequencer_account: BitcoinAddress,
+        processor: PipelineProcessorProxy,
+        sequencer: SequencerProxy,
+        da_server: DAServerProxy,
+        sync_interval: u64,
+        registry: &Registry,
+    ) -> Result<Self> {
+        if backends.backends.is_empty() {
+            return Err(anyhow!("no DA backend configured for syncing"));
+        }
+        Ok(Self {
+            backends,
+            sequencer_account,
+            processor,
+            sequencer,
+            da_server,
+            sync_interval,
+            metrics: Arc::new(DASyncerMetrics::new(registry)),
+        })
+    }
+
+    pub fn run(self, mut shutdown_rx: broadcast::Receiver<()>) {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(self.sync_interval));
+            loop {
+                tokio::select! {
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("DA syncer received shutdown signal, exiting...");
+                        break;
+                    }
+                    _ = ticker.tick() => {
+                        if let Err(e) = self.sync().await {
+                            tracing::error!("DA syncer stopped: {:?}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Sync batches until no new batch is found.
+    /// Err is returned only when it's unsafe to continue, errors from DA backends will be retried in the next round.
+    pub async fn sync(&self) -> Result<()> {
+        loop {
+            let next_block_number = self
+                .da_server
+                .get_status()
+                .await?
+                .last_block_number
+                .map_or(0, |n| n + 1);
+            let batch = match self.backends.get_batch(next_block_number, None).await {
+                Ok(Some(batch)) => batch,
+                Ok(None) => {
+                    tracing::debug!(
+                        "DA syncer: block {} is not available yet",
+                        next_block_number
+                    );
+                    return Ok(());
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "DA syncer: fetch block {} failed, will retry: {:?}",
+                        next_block_number,
+                        e
+                    );
+                    return Ok(());
+                }
+            };
+            self.verify_signer(&batch)?;
+            self.apply_batch(batch).await?;
+        }
+    }
+
+    fn verify_signer(&self, batch: &DABatch) -> Result<()> {
+        let signer = batch.get_signer()?.bitcoin_address()?;
+        if signer != self.sequencer_account {
+            return Err(anyhow!(
+                "block {} is signed by {}, not the sequencer {}",
+                batch.meta.block_range.block_number,
+                signer,
+                self.sequencer_account
+            ));
+        }
+        Ok(())
+    }
+
+    async fn apply_batch(&self, batch: DABatch) -> Result<()> {
+        let block_range = batch.meta.block_range.clone();
+        let block_number = block_range.block_number;
+        let batch_hash = batch.get_hash();
+
+        // txs may have been applied before restarting, skip them
+        let last_order = self.sequencer.get_sequencer_order().await?;
+        let txs = batch
+            .get_tx_list()
+            .into_iter()
+            .filter(|tx| tx.sequence_info.tx_order > last_order)
+            .collect::<Vec<_>>();
+        let last_tx_timestamp = txs.last().map(|tx| tx.sequence_info.tx_timestamp);
+        if !txs.is_empty() {
+            self.processor
+                .execute_sequenced_txs(txs)
+                .await
+                .map_err(|e| anyhow!("apply txs in block {} failed: {:?}", block_number, e))?;
+        }
+
+        self.da_server
+            .append_synced_block(AppendSyncedBlockMessage {
+                block_range: block_range.clone(),
+                batch_hash,
+            })
+            .await?;
+
+        self.metrics
+            .da_syncer_last_block_number
+            .set(block_number as i64);
+        self.metrics
+            .da_syncer_last_tx_order
+            .set(block_range.tx_order_end as i64);
+        let lag_seconds = match last_tx_timestamp {
+            Some(tx_timestamp) => {
+                let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+                let lag_seconds = now.saturating_sub(tx_timestamp) / 1000;
+                self.metrics.da_syncer_lag_seconds.set(lag_seconds as i64);
+                Some(lag_seconds)
+            }
+            None => None,
+        };
+        tracing::info!(
+            "DA syncer: block {} synced, tx_order: [{}, {}], lag: {:?}s",
+            block_number,
+            block_range.tx_order_start,
+            block_range.tx_order_end,
+            lag_seconds
+        );
+        Ok(())
+    }
+}
@@ -2,5 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod actor;
+pub mod da_syncer;
 pub mod metrics;
 pub mod proxy;
+pub mod tx_queue;
@@ -3,8 +3,8 @@
 
 use metrics::metrics_util::LATENCY_SEC_BUCKETS;
 use prometheus::{
-    register_histogram_vec_with_registry, register_int_counter_with_registry, HistogramVec,
-    IntCounter, Registry,
+    register_histogram_vec_with_registry, register_int_counter_with_registry,
+    register_int_gauge_with_registry, HistogramVec, IntCounter, IntGauge, Registry,
 };
 
 #[derive(Debug)]
@@ -58,3 +58,35 @@ impl PipelineProcessorMetrics {
         }
     }
 }
+
+#[derive(Debug)]
+pub struct DASyncerMetrics {
+    pub da_syncer_last_block_number: IntGauge,
+    pub da_syncer_last_tx_order: IntGauge,
+    pub da_syncer_lag_seconds: IntGauge,
+}
+
+impl DASyncerMetrics {
+    pub(crate) fn new(registry: &Registry) -> Self {
+        DASyncerMetrics {
+            da_syncer_last_block_number: register_int_gauge_with_registry!(
+                "da_syncer_last_block_number",
+                "DA syncer last synced block number",
+                registry,
+            )
+            .unwrap(),
+            da_syncer_last_tx_order: register_int_gauge_with_registry!(
+                "da_syncer_last_tx_order",
+                "DA syncer last synced tx order",
+                registry,
+            )
+            .unwrap(),
+            da_syncer_lag_seconds: register_int_gauge_with_registry!(
+                "da_syncer_lag_seconds",
+                "DA syncer lag in seconds, between now and the timestamp of the last synced tx",
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+}
@@ -3,7 +3,8 @@
 
 use crate::actor::{
     messages::{
-        ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage, GetServiceStatusMessage,
+        ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage, ExecuteL2TxsMessage,
+        ExecuteSequencedTxsMessage, GetServiceStatusMessage,
     },
     processor::PipelineProcessorActor,
 };
@@ -13,6 +14,7 @@ use rooch_types::{
     service_status::ServiceStatus,
     transaction::{
         rooch::RoochTransaction, ExecuteTransactionResponse, L1BlockWithBody, L1Transaction,
+        LedgerTransaction,
     },
 };
 
@@ -30,6 +32,14 @@ impl PipelineProcessorProxy {
         self.actor.send(ExecuteL2TxMessage { tx }).await?
     }
 
+    /// Execute the L2 txs in order, the results are in the order of the txs
+    pub async fn execute_l2_txs(
+        &self,
+        txs: Vec<RoochTransaction>,
+    ) -> Result<Vec<Result<ExecuteTransactionResponse>>> {
+        Ok(self.actor.send(ExecuteL2TxsMessage { txs }).await?)
+    }
+
     pub async fn execute_l1_block(
         &self,
         tx: L1BlockWithBody,
@@ -41,6 +51,10 @@ impl PipelineProcessorProxy {
         self.actor.send(ExecuteL1TxMessage { tx }).await?
     }
 
+    pub async fn execute_sequenced_txs(&self, txs: Vec<LedgerTransaction>) -> Result<()> {
+        self.actor.send(ExecuteSequencedTxsMessage { txs }).await?
+    }
+
     pub async fn get_service_status(&self) -> Result<ServiceStatus> {
         self.actor.send(GetServiceStatusMessage {}).await?
     }
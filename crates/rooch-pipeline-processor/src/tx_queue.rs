@@ -0,0 +1,392 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::proxy::PipelineProcessorProxy;
+use anyhow::{anyhow, bail, ensure, Result};
+use move_core_types::u256::U256;
+use moveos_types::access_path::AccessPath;
+use moveos_types::h256::H256;
+use moveos_types::state::MoveStructType;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::{RoochSignature, Signature, SignatureScheme, ToFromBytes};
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
+use rooch_types::framework::auth_payload::{AuthPayload, MultisignAuthPayload};
+use rooch_types::framework::auth_validator::BuiltinAuthValidator;
+use rooch_types::framework::coin_store::CoinStoreInfo;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::webauthn::WebauthnAuthPayload;
+use rooch_types::transaction::RoochTransaction;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::Arc;
+use tokio::sync::{broadcast, mpsc, Mutex};
+
+pub const DEFAULT_TX_QUEUE_CAPACITY: usize = 10000;
+pub const DEFAULT_TX_QUEUE_MAX_PER_SENDER: usize = 64;
+/// The max number of transactions dispatched to the processor at once
+const TX_QUEUE_WORKER_BATCH_SIZE: usize = 64;
+/// The max number of failed tx statuses kept in memory
+const MAX_FAILED_TX_STATUSES: usize = 100000;
+
+/// The status of a transaction in the TxQueue.
+/// The transaction is removed from the TxQueue after it is sequenced and executed,
+/// then its status should be read from the store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxQueueStatus {
+    Pending,
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+struct PendingTx {
+    tx_hash: H256,
+    max_gas_amount: u64,
+}
+
+#[derive(Default)]
+struct TxQueueState {
+    /// The pending transactions of each sender, sequence_number -> PendingTx
+    senders: HashMap<RoochAddress, BTreeMap<u64, PendingTx>>,
+    pending: HashMap<H256, (RoochAddress, u64)>,
+    failed: HashMap<H256, String>,
+    failed_order: VecDeque<H256>,
+}
+
+impl TxQueueState {
+    fn add_failed(&mut self, tx_hash: H256, error: String) {
+        if self.failed.insert(tx_hash, error).is_none() {
+            self.failed_order.push_back(tx_hash);
+        }
+        while self.failed_order.len() > MAX_FAILED_TX_STATUSES {
+            if let Some(tx_hash) = self.failed_order.pop_front() {
+                self.failed.remove(&tx_hash);
+            }
+        }
+    }
+
+    fn remove_pending(&mut self, sender: RoochAddress, sequence_number: u64, tx_hash: H256) {
+        self.pending.remove(&tx_hash);
+        if let Some(txs) = self.senders.get_mut(&sender) {
+            txs.remove(&sequence_number);
+            if txs.is_empty() {
+                self.senders.remove(&sender);
+            }
+        }
+    }
+
+    /// Fail the transaction and the following pending transactions of the same sender,
+    /// the following transactions can not pass the sequence number check without it.
+    fn fail_pending(
+        &mut self,
+        sender: RoochAddress,
+        sequence_number: u64,
+        tx_hash: H256,
+        error: String,
+    ) {
+        self.remove_pending(sender, sequence_number, tx_hash);
+        let following = self
+            .senders
+            .get_mut(&sender)
+            .map(|txs| txs.split_off(&sequence_number))
+            .unwrap_or_default();
+        if self.senders.get(&sender).is_some_and(|txs| txs.is_empty()) {
+            self.senders.remove(&sender);
+        }
+        for pending_tx in following.into_values() {
+            self.pending.remove(&pending_tx.tx_hash);
+            self.add_failed(
+                pending_tx.tx_hash,
+                format!("The previous transaction {} failed", tx_hash),
+            );
+        }
+        self.add_failed(tx_hash, error);
+    }
+}
+
+/// TxQueue is an in-memory transaction queue in front of the PipelineProcessor.
+/// The transactions are validated before entering the queue, and processed by the TxQueueWorker in order,
+/// so the submitter does not need to wait for the execution.
+///
+/// A sender can queue multiple transactions with consecutive sequence numbers,
+/// only the first one can be fully validated against the current state,
+/// the following ones are checked for the sequence number, signature and gas balance,
+/// and fully validated again before being sequenced.
+#[derive(Clone)]
+pub struct TxQueue {
+    executor: ExecutorProxy,
+    sender: mpsc::Sender<RoochTransaction>,
+    state: Arc<Mutex<TxQueueState>>,
+    max_per_sender: usize,
+}
+
+/// TxQueueWorker dispatches the queued transactions to the PipelineProcessor in batches,
+/// the processor executes the transactions of a batch in parallel, and sequences them in the queued order.
+pub struct TxQueueWorker {
+    receiver: mpsc::Receiver<RoochTransaction>,
+    state: Arc<Mutex<TxQueueState>>,
+}
+
+impl TxQueueWorker {
+    pub async fn run(
+        mut self,
+        processor: PipelineProcessorProxy,
+        mut shutdown_rx: broadcast::Receiver<()>,
+    ) {
+        loop {
+            let tx = tokio::select! {
+                _ = shutdown_rx.recv() => {
+                    tracing::info!("TxQueue received shutdown signal, exiting...");
+                    break;
+                }
+                tx = self.receiver.recv() => tx,
+            };
+            let Some(tx) = tx else {
+                break;
+            };
+            let mut txs = vec![tx];
+            while txs.len() < TX_QUEUE_WORKER_BATCH_SIZE {
+                match self.receiver.try_recv() {
+                    Ok(tx) => txs.push(tx),
+                    Err(_) => break,
+                }
+            }
+            self.process_txs(&processor, txs).await;
+        }
+    }
+
+    async fn process_txs(&self, processor: &PipelineProcessorProxy, txs: Vec<RoochTransaction>) {
+        // The transaction is dropped if the previous transaction of the same sender failed
+        let txs = {
+            let state = self.state.lock().await;
+            txs.into_iter()
+                .filter_map(|mut tx| {
+                    let tx_hash = tx.tx_hash();
+                    state
+                        .pending
+                        .contains_key(&tx_hash)
+                        .then_some((tx_hash, tx))
+                })
+                .collect::<Vec<_>>()
+        };
+        let (tx_keys, txs): (Vec<_>, Vec<_>) = txs
+            .into_iter()
+            .map(|(tx_hash, tx)| ((tx_hash, tx.sender(), tx.sequence_number()), tx))
+            .unzip();
+        let results = match processor.execute_l2_txs(txs).await {
+            Ok(results) => results
+                .into_iter()
+                .map(|result| result.map(|_| ()).map_err(|e| e.to_string()))
+                .collect(),
+            Err(e) => vec![Err(e.to_string()); tx_keys.len()],
+        };
+
+        let mut state = self.state.lock().await;
+        for ((tx_hash, sender, sequence_number), result) in tx_keys.into_iter().zip(results) {
+            // The transaction is already failed by the previous transaction of the same sender
+            if !state.pending.contains_key(&tx_hash) {
+                continue;
+            }
+            match result {
+                Ok(_) => state.remove_pending(sender, sequence_number, tx_hash),
+                Err(error) => {
+                    tracing::warn!("TxQueue process tx {} failed: {}", tx_hash, error);
+                    state.fail_pending(sender, sequence_number, tx_hash, error)
+                }
+            }
+        }
+    }
+}
+
+impl TxQueue {
+    pub fn new(
+        executor: ExecutorProxy,
+        capacity: usize,
+        max_per_sender: usize,
+    ) -> (Self, TxQueueWorker) {
+        let (sender, receiver) = mpsc::channel::<RoochTransaction>(capacity.max(1));
+        let state = Arc::new(Mutex::new(TxQueueState::default()));
+        let tx_queue = Self {
+            executor,
+            sender,
+            state: state.clone(),
+            max_per_sender: max_per_sender.max(1),
+        };
+        (tx_queue, TxQueueWorker { receiver, state })
+    }
+
+    pub fn start(
+        processor: PipelineProcessorProxy,
+        executor: ExecutorProxy,
+        capacity: usize,
+        max_per_sender: usize,
+        shutdown_rx: broadcast::Receiver<()>,
+    ) -> Self {
+        let (tx_queue, worker) = Self::new(executor, capacity, max_per_sender);
+        tokio::spawn(worker.run(processor, shutdown_rx));
+        tx_queue
+    }
+
+    /// Validate and queue the transaction, returns the tx hash without waiting for the execution.
+    /// The queue is not locked during the validation, the pending transactions of the sender are checked again before queueing.
+    pub async fn submit(&self, mut tx: RoochTransaction) -> Result<H256> {
+        let tx_hash = tx.tx_hash();
+        let sender = tx.sender();
+        let sequence_number = tx.sequence_number();
+        let max_gas_amount = tx.max_gas_amount();
+
+        let pending_gas = {
+            let state = self.state.lock().await;
+            if state.pending.contains_key(&tx_hash) {
+                return Ok(tx_hash);
+            }
+            self.check_pending(&state, sender, sequence_number)?
+        };
+        match pending_gas {
+            Some(pending_gas) => {
+                Self::verify_signature(&tx)?;
+                self.check_gas_balance(sender, pending_gas + U256::from(max_gas_amount))
+                    .await?;
+            }
+            None => {
+                self.executor.validate_l2_tx(tx.clone()).await?;
+            }
+        }
+
+        let mut state = self.state.lock().await;
+        if state.pending.contains_key(&tx_hash) {
+            return Ok(tx_hash);
+        }
+        self.check_pending(&state, sender, sequence_number)?;
+        self.sender
+            .try_send(tx)
+            .map_err(|e| anyhow!("Queue transaction {} failed: {}", tx_hash, e))?;
+        state.senders.entry(sender).or_default().insert(
+            sequence_number,
+            PendingTx {
+                tx_hash,
+                max_gas_amount,
+            },
+        );
+        state.pending.insert(tx_hash, (sender, sequence_number));
+        state.failed.remove(&tx_hash);
+        Ok(tx_hash)
+    }
+
+    /// Check the sequence number against the pending transactions of the sender,
+    /// returns the gas required by them, or None if the sender has no pending transactions.
+    fn check_pending(
+        &self,
+        state: &TxQueueState,
+        sender: RoochAddress,
+        sequence_number: u64,
+    ) -> Result<Option<U256>> {
+        let Some(pending_txs) = state.senders.get(&sender) else {
+            return Ok(None);
+        };
+        ensure!(
+            pending_txs.len() < self.max_per_sender,
+            "Too many pending transactions of sender {}, the limit is {}",
+            sender,
+            self.max_per_sender
+        );
+        let last_sequence_number = pending_txs
+            .last_key_value()
+            .map(|(sequence_number, _)| *sequence_number)
+            .unwrap_or_default();
+        ensure!(
+            sequence_number == last_sequence_number + 1,
+            "Invalid sequence number {} of sender {}, expect {}",
+            sequence_number,
+            sender,
+            last_sequence_number + 1
+        );
+        Ok(Some(
+            pending_txs
+                .values()
+                .map(|pending_tx| U256::from(pending_tx.max_gas_amount))
+                .fold(U256::zero(), |acc, gas| acc + gas),
+        ))
+    }
+
+    pub async fn get_status(&self, tx_hash: H256) -> Option<TxQueueStatus> {
+        let state = self.state.lock().await;
+        if state.pending.contains_key(&tx_hash) {
+            Some(TxQueueStatus::Pending)
+        } else {
+            state
+                .failed
+                .get(&tx_hash)
+                .map(|error| TxQueueStatus::Failed(error.clone()))
+        }
+    }
+
+    /// Verify the signature of the builtin authenticators, the authorization is checked by the auth validator on execution.
+    /// The transaction of the other auth validators is rejected, its signature can not be verified without executing the validator.
+    fn verify_signature(tx: &RoochTransaction) -> Result<()> {
+        let authenticator = &tx.authenticator;
+        let auth_validator = u8::try_from(authenticator.auth_validator_id)
+            .ok()
+            .and_then(|flag| BuiltinAuthValidator::from_flag_byte(flag).ok());
+        match auth_validator {
+            Some(BuiltinAuthValidator::Session) => {
+                if authenticator.payload.first() == Some(&SignatureScheme::Secp256r1.flag()) {
+                    let payload =
+                        WebauthnAuthPayload::from_session_payload(&authenticator.payload)?;
+                    payload.verify(&tx.data.tx_hash())?;
+                } else {
+                    let signature = Signature::from_bytes(&authenticator.payload)?;
+                    signature.verify(tx.data.tx_hash().as_bytes())?;
+                }
+            }
+            Some(BuiltinAuthValidator::Bitcoin) => {
+                let payload = bcs::from_bytes::<AuthPayload>(&authenticator.payload)?;
+                payload.verify(&tx.data)?;
+            }
+            Some(BuiltinAuthValidator::BitcoinMultisign) => {
+                let payload = bcs::from_bytes::<MultisignAuthPayload>(&authenticator.payload)?;
+                payload.verify(&tx.data)?;
+            }
+            Some(BuiltinAuthValidator::Ethereum) => {
+                let payload = bcs::from_bytes::<AuthPayload>(&authenticator.payload)?;
+                payload.verify_ethereum(&tx.data)?;
+            }
+            _ => bail!(
+                "The signature of auth validator {} can not be verified before execution, \
+                the transaction can only be submitted when the sender has no pending transactions",
+                authenticator.auth_validator_id
+            ),
+        }
+        Ok(())
+    }
+
+    async fn check_gas_balance(&self, sender: RoochAddress, required_gas: U256) -> Result<()> {
+        // The gas balance is not checked in local or dev chain, keep the same as the transaction validator
+        let chain_id = self.executor.chain_id().await?;
+        if chain_id
+            .to_builtin()
+            .is_some_and(|chain_id| chain_id.is_local() || chain_id.is_dev())
+        {
+            return Ok(());
+        }
+        let coin_store_id =
+            AccountCoinStoreModule::account_coin_store_id(sender.into(), RGas::struct_tag());
+        let balance = self
+            .executor
+            .get_states(AccessPath::object(coin_store_id), None)
+            .await?
+            .pop()
+            .flatten()
+            .map(CoinStoreInfo::try_from)
+            .transpose()?
+            .map(|coin_store| coin_store.balance())
+            .unwrap_or_default();
+        ensure!(
+            balance >= required_gas,
+            "Insufficient gas balance of sender {}, balance: {}, required by the pending transactions: {}",
+            sender,
+            balance,
+            required_gas
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+// Every test binary uses a different part of the common module
+#![allow(dead_code)]
+
+use anyhow::Result;
+use coerce::actor::{system::ActorSystem, IntoActor};
+use prometheus::Registry;
+use rooch_config::da_config::{derive_namespace_from_genesis, DAConfig};
+use rooch_config::RoochOpt;
+use rooch_da::actor::server::DAServerActor;
+use rooch_da::proxy::DAServerProxy;
+use rooch_db::RoochDB;
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_executor::actor::reader_executor::ReaderExecutorActor;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_genesis::RoochGenesis;
+use rooch_indexer::actor::indexer::IndexerActor;
+use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
+use rooch_indexer::proxy::IndexerProxy;
+use rooch_pipeline_processor::actor::processor::PipelineProcessorActor;
+use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
+use rooch_sequencer::actor::sequencer::SequencerActor;
+use rooch_sequencer::proxy::SequencerProxy;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::rooch_network::{BuiltinChainID, RoochNetwork};
+use rooch_types::service_status::ServiceStatus;
+use tokio::sync::broadcast;
+
+/// A node with the actors wired as the RPC server does, on a temp store of the local chain.
+/// Nodes built with the same sequencer key share the same genesis.
+pub struct TestNode {
+    // keep the opt to ensure the temp dir is not deleted before the test ends
+    _opt: RoochOpt,
+    pub network: RoochNetwork,
+    pub genesis_namespace: String,
+    pub registry: Registry,
+    pub rooch_db: RoochDB,
+    pub executor: ExecutorProxy,
+    pub sequencer: SequencerProxy,
+    pub da_server: DAServerProxy,
+    pub processor: PipelineProcessorProxy,
+    shutdown_tx: broadcast::Sender<()>,
+}
+
+impl TestNode {
+    pub async fn new(
+        sequencer_key: &RoochKeyPair,
+        service_status: ServiceStatus,
+        parallel_execution_window: usize,
+    ) -> Result<Self> {
+        let mut network: RoochNetwork = BuiltinChainID::Local.into();
+        network.mock_genesis_account(sequencer_key)?;
+
+        let opt = RoochOpt::new_with_temp_store()?;
+        // every node has its own registry to avoid registering the same metrics twice
+        let registry = Registry::new();
+        let rooch_db = RoochDB::init(opt.store_config(), &registry)?;
+        let genesis = RoochGenesis::load_or_init(network.clone(), &rooch_db)?;
+        let root = rooch_db
+            .latest_root()?
+            .ok_or_else(|| anyhow::anyhow!("No root object should exist after genesis init."))?;
+        let genesis_namespace = derive_namespace_from_genesis(genesis.genesis_hash());
+
+        let actor_system = ActorSystem::global_system();
+        let (shutdown_tx, _) = broadcast::channel(16);
+
+        let executor = ExecutorActor::new(
+            root.clone(),
+            rooch_db.moveos_store.clone(),
+            rooch_db.rooch_store.clone(),
+            &registry,
+            None,
+        )?
+        .into_actor(None, &actor_system)
+        .await?;
+        let reader_executor = ReaderExecutorActor::new(
+            root.clone(),
+            rooch_db.moveos_store.clone(),
+            rooch_db.rooch_store.clone(),
+            &registry,
+            None,
+        )?
+        .into_actor(None, &actor_system)
+        .await?;
+        let executor = ExecutorProxy::new(executor.into(), reader_executor.into());
+
+        let sequencer = SequencerActor::new(
+            sequencer_key.copy(),
+            rooch_db.rooch_store.clone(),
+            service_status,
+            &registry,
+            None,
+        )?
+        .into_actor(None, &actor_system)
+        .await?;
+        let sequencer = SequencerProxy::new(sequencer.into());
+
+        // no DA backend, blocks are made but not submitted
+        let da_server = DAServerActor::new(
+            DAConfig::default(),
+            sequencer_key.copy(),
+            rooch_db.rooch_store.clone(),
+            genesis_namespace.clone(),
+            shutdown_tx.subscribe(),
+        )
+        .await?
+        .into_actor(None, &actor_system)
+        .await?;
+        let da_server = DAServerProxy::new(da_server.into());
+
+        let indexer = IndexerActor::new(root, rooch_db.indexer_store.clone(), None)?
+            .into_actor(None, &actor_system)
+            .await?;
+        let indexer_reader = IndexerReaderActor::new(rooch_db.indexer_reader.clone())?
+            .into_actor(None, &actor_system)
+            .await?;
+        let indexer = IndexerProxy::new(indexer.into(), indexer_reader.into());
+
+        let processor = PipelineProcessorActor::new(
+            executor.clone(),
+            sequencer.clone(),
+            da_server.clone(),
+            indexer,
+            service_status,
+            &registry,
+            None,
+            rooch_db.clone(),
+            None,
+            parallel_execution_window,
+        )
+        .into_actor(None, &actor_system)
+        .await?;
+        let processor = PipelineProcessorProxy::new(processor.into());
+
+        Ok(Self {
+            _opt: opt,
+            network,
+            genesis_namespace,
+            registry,
+            rooch_db,
+            executor,
+            sequencer,
+            da_server,
+            processor,
+            shutdown_tx,
+        })
+    }
+
+    pub fn shutdown_rx(&self) -> broadcast::Receiver<()> {
+        self.shutdown_tx.subscribe()
+    }
+}
+
+impl Drop for TestNode {
+    fn drop(&mut self) {
+        let _ = self.shutdown_tx.send(());
+    }
+}
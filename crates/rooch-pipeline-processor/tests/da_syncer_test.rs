@@ -0,0 +1,142 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use anyhow::Result;
+use common::TestNode;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use moveos_types::state::MoveStructType;
+use rooch_config::da_config::{
+    DABackendConfig, DABackendConfigType, DABackendOpenDAConfig, OpenDAScheme,
+};
+use rooch_da::backend::DABackends;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_pipeline_processor::da_syncer::DASyncer;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::da::batch::DABatch;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::service_status::ServiceStatus;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn fs_backend_config(root: &std::path::Path) -> DABackendConfig {
+    DABackendConfig {
+        submit_strategy: None,
+        backends: vec![DABackendConfigType::OpenDa(DABackendOpenDAConfig {
+            scheme: OpenDAScheme::Fs,
+            config: HashMap::from([("root".to_string(), root.display().to_string())]),
+            namespace: None,
+            max_segment_size: None,
+            max_retries: None,
+            chunk_version: None,
+            compression_level: None,
+        })],
+    }
+}
+
+#[tokio::test]
+async fn test_da_syncer() -> Result<()> {
+    let sequencer_key = RoochKeyPair::generate_secp256k1();
+    let source = TestNode::new(&sequencer_key, ServiceStatus::Active, 0).await?;
+
+    // execute txs on the sequencer node
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let recipient = AccountAddress::random();
+    let mut tx_hashes = vec![];
+    for sequence_number in 0..3 {
+        for sender in keystore.addresses() {
+            let action = TransferModule::create_transfer_coin_action(
+                RGas::struct_tag(),
+                recipient,
+                U256::from(1u64),
+            );
+            let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+            let tx = keystore.sign_transaction(&sender, tx_data, None)?;
+            let response = source.processor.execute_l2_tx(tx).await?;
+            tx_hashes.push(response.execution_info.tx_hash);
+        }
+    }
+    let ledger_txs = source
+        .sequencer
+        .get_transactions_by_hash(tx_hashes.clone())
+        .await?
+        .into_iter()
+        .map(|tx| tx.expect("sequenced tx should exist"))
+        .collect::<Vec<_>>();
+
+    // submit the txs to DA as blocks of 2 txs
+    let da_dir = tempfile::tempdir()?;
+    let source_backends = DABackends::initialize(
+        Some(fs_backend_config(da_dir.path())),
+        source.genesis_namespace.clone(),
+    )
+    .await?;
+    let mut last_block_number = 0;
+    for (block_number, txs) in ledger_txs.chunks(2).enumerate() {
+        let batch = Arc::new(DABatch::new(
+            block_number as u128,
+            txs.first().unwrap().sequence_info.tx_order,
+            txs.last().unwrap().sequence_info.tx_order,
+            &txs.to_vec(),
+            sequencer_key.copy(),
+        ));
+        for backend in &source_backends.backends {
+            backend.submit_batch(batch.clone()).await?;
+        }
+        last_block_number = block_number as u128;
+    }
+
+    // sync the blocks into a fresh store
+    let node = TestNode::new(&sequencer_key, ServiceStatus::ReadOnlyMode, 0).await?;
+    let da_syncer = DASyncer::new(
+        DABackends::initialize(
+            Some(fs_backend_config(da_dir.path())),
+            node.genesis_namespace.clone(),
+        )
+        .await?,
+        node.network.genesis_config.sequencer_account.clone(),
+        node.processor.clone(),
+        node.sequencer.clone(),
+        node.da_server.clone(),
+        1,
+        &node.registry,
+    )?;
+    da_syncer.sync().await?;
+
+    assert_eq!(
+        node.sequencer.get_sequencer_order().await?,
+        source.sequencer.get_sequencer_order().await?
+    );
+    let da_status = node.da_server.get_status().await?;
+    assert_eq!(da_status.last_block_number, Some(last_block_number));
+    assert_eq!(
+        da_status.last_tx_order,
+        Some(ledger_txs.last().unwrap().sequence_info.tx_order)
+    );
+    assert_eq!(
+        node.executor
+            .get_transaction_execution_infos_by_hash(tx_hashes.clone())
+            .await?,
+        source
+            .executor
+            .get_transaction_execution_infos_by_hash(tx_hashes)
+            .await?
+    );
+    let root = node.executor.get_root().await?;
+    let source_root = source.executor.get_root().await?;
+    assert_eq!(root.metadata.state_root, source_root.metadata.state_root);
+    assert_eq!(root.metadata.size, source_root.metadata.size);
+
+    // nothing more to sync
+    da_syncer.sync().await?;
+    assert_eq!(
+        node.da_server.get_status().await?.last_block_number,
+        Some(last_block_number)
+    );
+    Ok(())
+}
@@ -0,0 +1,192 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use anyhow::Result;
+use common::TestNode;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use moveos_types::h256::H256;
+use moveos_types::state::MoveStructType;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_pipeline_processor::tx_queue::{TxQueue, TxQueueStatus};
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::ethereum_validator::EthereumSignType;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::service_status::ServiceStatus;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use std::time::Duration;
+
+fn transfer_tx(
+    keystore: &InMemKeystore,
+    sender: RoochAddress,
+    sequence_number: u64,
+) -> Result<RoochTransaction> {
+    let action = TransferModule::create_transfer_coin_action(
+        RGas::struct_tag(),
+        AccountAddress::random(),
+        U256::from(1u64),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+    Ok(keystore.sign_transaction(&sender, tx_data, None)?)
+}
+
+async fn wait_processed(tx_queue: &TxQueue, tx_hashes: &[H256]) -> Result<()> {
+    for _ in 0..100 {
+        let mut pending = false;
+        for tx_hash in tx_hashes {
+            match tx_queue.get_status(*tx_hash).await {
+                Some(TxQueueStatus::Pending) => pending = true,
+                Some(TxQueueStatus::Failed(error)) => {
+                    anyhow::bail!("tx {} failed: {}", tx_hash, error)
+                }
+                None => {}
+            }
+        }
+        if !pending {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    anyhow::bail!("the queued txs are not processed in time")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tx_queue_order() -> Result<()> {
+    let sequencer_key = RoochKeyPair::generate_secp256k1();
+    let node = TestNode::new(&sequencer_key, ServiceStatus::Active, 0).await?;
+    let (tx_queue, worker) = TxQueue::new(node.executor.clone(), 100, 10);
+
+    // queue the txs of two senders before the worker runs
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let mut tx_hashes = vec![];
+    for sequence_number in 0..3 {
+        for sender in keystore.addresses() {
+            let tx = transfer_tx(&keystore, sender, sequence_number)?;
+            let tx_hash = tx_queue.submit(tx.clone()).await?;
+            // submitting the same tx again is a no-op
+            assert_eq!(tx_queue.submit(tx).await?, tx_hash);
+            assert_eq!(
+                tx_queue.get_status(tx_hash).await,
+                Some(TxQueueStatus::Pending)
+            );
+            tx_hashes.push(tx_hash);
+        }
+    }
+
+    tokio::spawn(worker.run(node.processor.clone(), node.shutdown_rx()));
+    wait_processed(&tx_queue, &tx_hashes).await?;
+
+    // the txs are sequenced in the queued order
+    let tx_orders = node
+        .sequencer
+        .get_transactions_by_hash(tx_hashes.clone())
+        .await?
+        .into_iter()
+        .map(|tx| {
+            tx.expect("queued tx should be sequenced")
+                .sequence_info
+                .tx_order
+        })
+        .collect::<Vec<_>>();
+    assert!(tx_orders.windows(2).all(|orders| orders[0] < orders[1]));
+    assert!(node
+        .executor
+        .get_transaction_execution_infos_by_hash(tx_hashes)
+        .await?
+        .iter()
+        .all(Option::is_some));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tx_queue_reject() -> Result<()> {
+    let sequencer_key = RoochKeyPair::generate_secp256k1();
+    let node = TestNode::new(&sequencer_key, ServiceStatus::Active, 0).await?;
+    // the worker is not running, the queued txs stay pending
+    let (tx_queue, _worker) = TxQueue::new(node.executor.clone(), 3, 2);
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let senders = keystore.addresses();
+
+    // the first tx is fully validated
+    let mut bad_tx = transfer_tx(&keystore, senders[0], 0)?;
+    bad_tx.authenticator.payload[1] ^= 0xff;
+    assert!(tx_queue.submit(bad_tx).await.is_err());
+    tx_queue
+        .submit(transfer_tx(&keystore, senders[0], 0)?)
+        .await?;
+
+    // the following tx with a nonce gap
+    let error = tx_queue
+        .submit(transfer_tx(&keystore, senders[0], 2)?)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Invalid sequence number"));
+
+    // the following tx with a bad signature
+    let mut bad_tx = transfer_tx(&keystore, senders[0], 1)?;
+    bad_tx.authenticator.payload[1] ^= 0xff;
+    assert!(tx_queue.submit(bad_tx).await.is_err());
+
+    // too many pending txs of the sender
+    tx_queue
+        .submit(transfer_tx(&keystore, senders[0], 1)?)
+        .await?;
+    let error = tx_queue
+        .submit(transfer_tx(&keystore, senders[0], 2)?)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Too many pending transactions"));
+
+    // the queue is full
+    tx_queue
+        .submit(transfer_tx(&keystore, senders[1], 0)?)
+        .await?;
+    let mut tx = transfer_tx(&keystore, senders[1], 1)?;
+    let error = tx_queue.submit(tx.clone()).await.unwrap_err();
+    assert!(error.to_string().contains("Queue transaction"));
+    assert_eq!(tx_queue.get_status(tx.tx_hash()).await, None);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tx_queue_ethereum() -> Result<()> {
+    let sequencer_key = RoochKeyPair::generate_secp256k1();
+    let node = TestNode::new(&sequencer_key, ServiceStatus::Active, 0).await?;
+    let (tx_queue, worker) = TxQueue::new(node.executor.clone(), 100, 10);
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+
+    // the signature of the Ethereum wallet is verified before execution, so the following txs are queued
+    let mut tx_hashes = vec![];
+    for (sequence_number, sign_type) in [
+        (0, EthereumSignType::PersonalSign),
+        (1, EthereumSignType::TypedData),
+    ] {
+        let action = TransferModule::create_transfer_coin_action(
+            RGas::struct_tag(),
+            AccountAddress::random(),
+            U256::from(1u64),
+        );
+        let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+        let tx = keystore.sign_transaction_ethereum(&sender, tx_data, sign_type, None)?;
+        let mut bad_tx = tx.clone();
+        bad_tx.authenticator.payload[1] ^= 0xff;
+        assert!(tx_queue.submit(bad_tx).await.is_err());
+        tx_hashes.push(tx_queue.submit(tx).await?);
+    }
+
+    tokio::spawn(worker.run(node.processor.clone(), node.shutdown_rx()));
+    wait_processed(&tx_queue, &tx_hashes).await?;
+    assert!(node
+        .executor
+        .get_transaction_execution_infos_by_hash(tx_hashes)
+        .await?
+        .iter()
+        .all(Option::is_some));
+    Ok(())
+}
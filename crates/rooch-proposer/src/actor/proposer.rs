@@ -3,6 +3,7 @@
 
 use super::messages::ProposeBlock;
 use crate::metrics::ProposerMetrics;
+use crate::scc::submitter::BlockSubmitter;
 use crate::scc::StateCommitmentChain;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
@@ -11,6 +12,7 @@ use prometheus::Registry;
 use rooch_config::proposer_config::ProposerConfig;
 use rooch_store::proposer_store::ProposerStore;
 use rooch_store::RoochStore;
+use rooch_types::block::Block;
 use rooch_types::crypto::RoochKeyPair;
 use std::sync::Arc;
 
@@ -19,6 +21,7 @@ const PROPOSE_BLOCK_FN_NAME: &str = "propose_block";
 pub struct ProposerActor {
     proposer_key: RoochKeyPair,
     scc: StateCommitmentChain,
+    submitter: Option<BlockSubmitter>,
     metrics: Arc<ProposerMetrics>,
 }
 
@@ -29,6 +32,7 @@ impl ProposerActor {
         rooch_store: RoochStore,
         registry: &Registry,
         config: ProposerConfig,
+        submitter: Option<BlockSubmitter>,
     ) -> anyhow::Result<Self> {
         let init_offset = config.init_offset;
         let last_proposed = rooch_store.get_last_proposed()?;
@@ -43,14 +47,31 @@ impl ProposerActor {
             }
         };
 
-        let scc = StateCommitmentChain::new(rooch_store, moveos_store)?;
+        let scc = StateCommitmentChain::new(
+            rooch_store,
+            moveos_store,
+            proposer_key.public().rooch_address()?,
+        )?;
 
         Ok(Self {
             proposer_key,
             scc,
+            submitter,
             metrics: Arc::new(ProposerMetrics::new(registry)),
         })
     }
+
+    // submit the block to the on-chain SCC if the submitter is set,
+    // the block is marked as proposed only after the submission is confirmed.
+    async fn submit_block(&mut self, block: &Block) -> anyhow::Result<()> {
+        if let Some(submitter) = &self.submitter {
+            submitter.submit(&self.proposer_key, block).await?;
+            self.metrics
+                .proposer_submitted_block_number
+                .set(block.block_number as i64);
+        }
+        self.scc.set_last_proposed(block)
+    }
 }
 
 impl Actor for ProposerActor {}
@@ -69,25 +90,26 @@ impl Handler<ProposeBlock> for ProposerActor {
             Ok(block) => {
                 match block {
                     Some(block) => {
-                        // TODO submit to the on-chain SCC contract use the proposer key
-                        let _proposer_key = &self.proposer_key;
-                        let ret = self.scc.set_last_proposed(block.block_number);
+                        let ret = self.submit_block(&block).await;
                         match ret {
                             Ok(_) => {
                                 tracing::info!(
                                     "[ProposeBlock] done. block_number: {}",
                                     block.block_number,
                                 );
+                                self.metrics
+                                    .proposer_propose_block_batch_size
+                                    .set(block.batch_size as i64);
                             }
                             Err(e) => {
-                                tracing::error!("[ProposeBlock] set last proposed error: {:?}", e);
+                                // the same block will be proposed again in the next round
+                                tracing::error!(
+                                    "[ProposeBlock] block_number: {} error: {:?}",
+                                    block.block_number,
+                                    e
+                                );
                             }
                         }
-
-                        // TODO make new metric for matching real data submit to the chain
-                        self.metrics
-                            .proposer_propose_block_batch_size
-                            .set(block.batch_size as i64);
                     }
                     None => {
                         tracing::debug!("[ProposeBlock] no transaction to propose block");
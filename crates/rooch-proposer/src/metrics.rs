@@ -11,6 +11,7 @@ use prometheus::{
 pub struct ProposerMetrics {
     pub proposer_propose_block_latency_seconds: HistogramVec,
     pub proposer_propose_block_batch_size: IntGauge,
+    pub proposer_submitted_block_number: IntGauge,
 }
 
 impl ProposerMetrics {
@@ -30,6 +31,12 @@ impl ProposerMetrics {
                 registry,
             )
             .unwrap(),
+            proposer_submitted_block_number: register_int_gauge_with_registry!(
+                "proposer_submitted_block_number",
+                "The last block number submitted to the on-chain SCC",
+                registry,
+            )
+            .unwrap(),
         }
     }
 }
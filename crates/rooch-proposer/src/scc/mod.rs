@@ -7,44 +7,88 @@ use moveos_types::h256::H256;
 use rooch_store::da_store::DAMetaStore;
 use rooch_store::proposer_store::ProposerStore;
 use rooch_store::RoochStore;
+use rooch_types::address::RoochAddress;
 use rooch_types::block::Block;
-use rooch_types::da::batch::BlockSubmitState;
-use rooch_types::transaction::LedgerTransaction;
+use rooch_types::da::batch::{BlockRange, BlockSubmitState};
+use rooch_types::framework::state_commitment_chain::StateCommitmentChainModule;
+use rooch_types::transaction::{LedgerTransaction, LedgerTxData};
+
+pub mod submitter;
 
 /// State Commitment Chain(SCC) is a chain of transaction state root
 /// This SCC is a mirror of the on-chain SCC
+///
+/// The blocks which only contain the proposer's own `propose_block` transactions are skipped,
+/// otherwise every submission would make a new block to submit.
+/// The next proposed block is linked to the last proposed one by the tx accumulator root, over the skipped blocks.
 pub struct StateCommitmentChain {
     last_proposed_block_number: Option<u128>,
+    // tx accumulator root of the last proposed block which is not skipped
     last_proposed_block_accumulator_root: H256,
     rooch_store: RoochStore,
     moveos_store: MoveOSStore,
+    proposer: RoochAddress,
 }
 
 impl StateCommitmentChain {
     /// Create a new SCC
-    pub fn new(rooch_store: RoochStore, moveos_store: MoveOSStore) -> anyhow::Result<Self> {
+    pub fn new(
+        rooch_store: RoochStore,
+        moveos_store: MoveOSStore,
+        proposer: RoochAddress,
+    ) -> anyhow::Result<Self> {
         Self::repair_last_proposed(rooch_store.clone())?;
 
         let last_proposed_block_number = rooch_store.get_last_proposed()?;
 
-        let last_proposed_block_accumulator_root: H256 = match last_proposed_block_number {
-            Some(last_proposed) => {
-                let last_proposed_block_state = rooch_store.get_block_state(last_proposed)?;
-                let ledger_tx = get_ledger_tx(
-                    rooch_store.clone(),
-                    last_proposed_block_state.block_range.tx_order_end,
-                )?;
-                ledger_tx.sequence_info.tx_accumulator_root
-            }
-            None => H256::zero(),
-        };
-
-        Ok(Self {
+        let mut scc = Self {
             last_proposed_block_number,
-            last_proposed_block_accumulator_root,
+            last_proposed_block_accumulator_root: H256::zero(),
             rooch_store,
             moveos_store,
-        })
+            proposer,
+        };
+        if let Some(last_proposed) = last_proposed_block_number {
+            scc.last_proposed_block_accumulator_root =
+                scc.get_unskipped_accumulator_root(last_proposed)?;
+        }
+        Ok(scc)
+    }
+
+    // get the tx accumulator root of the last block which is not skipped, at or before the given block
+    fn get_unskipped_accumulator_root(&self, block_number: u128) -> anyhow::Result<H256> {
+        let mut block_number = block_number;
+        loop {
+            let block_range = self.rooch_store.get_block_state(block_number)?.block_range;
+            // the blocks before the init offset may not exist
+            let is_first_block = block_number == 0
+                || self
+                    .rooch_store
+                    .try_get_block_state(block_number - 1)?
+                    .is_none();
+            if is_first_block || !self.is_self_submission_block(&block_range)? {
+                return self.get_accumulator_root(block_range.tx_order_end);
+            }
+            block_number -= 1;
+        }
+    }
+
+    // whether all transactions in the block are the `propose_block` transactions sent by the proposer
+    fn is_self_submission_block(&self, block_range: &BlockRange) -> anyhow::Result<bool> {
+        for tx_order in block_range.tx_order_start..=block_range.tx_order_end {
+            let ledger_tx = get_ledger_tx(self.rooch_store.clone(), tx_order)?;
+            let is_self_submission = match &ledger_tx.data {
+                LedgerTxData::L2Tx(tx) => {
+                    tx.sender() == self.proposer
+                        && StateCommitmentChainModule::is_propose_block_action(tx.action())
+                }
+                _ => false,
+            };
+            if !is_self_submission {
+                return Ok(false);
+            }
+        }
+        Ok(true)
     }
 
     // last_proposed may beyond the DA submitted caused by manual rollback/revert
@@ -124,7 +168,6 @@ impl StateCommitmentChain {
         Ok((tx_accumulator_root, tx_state_root))
     }
 
-    #[allow(dead_code)]
     fn get_accumulator_root(&self, tx_order: u64) -> anyhow::Result<H256> {
         let ledger_tx = get_ledger_tx(self.rooch_store.clone(), tx_order)?;
         Ok(ledger_tx.sequence_info.tx_accumulator_root)
@@ -148,10 +191,9 @@ impl StateCommitmentChain {
         self.get_accumulator_root(prev_tx_order_end)
     }
 
-    fn append_new_block(
-        &mut self,
-        block_da_submit_state: BlockSubmitState,
-    ) -> anyhow::Result<Block> {
+    // make the next block on top of the last proposed block,
+    // the in-memory state is not changed until the block is confirmed by `set_last_proposed`
+    fn make_next_block(&self, block_da_submit_state: BlockSubmitState) -> anyhow::Result<Block> {
         let block_number = block_da_submit_state.block_range.block_number;
         let tx_order_end = block_da_submit_state.block_range.tx_order_end;
         let batch_size = tx_order_end - block_da_submit_state.block_range.tx_order_start + 1;
@@ -165,38 +207,52 @@ impl StateCommitmentChain {
             tx_accumulator_root,
             tx_state_root,
         );
-        self.last_proposed_block_number = Some(block_number);
-        self.last_proposed_block_accumulator_root = tx_accumulator_root;
         Ok(block)
     }
 
-    pub fn set_last_proposed(&self, block_number: u128) -> anyhow::Result<()> {
-        self.rooch_store.set_last_proposed(block_number)
+    /// Mark the block as proposed, it must be the block returned by `propose_block`
+    pub fn set_last_proposed(&mut self, block: &Block) -> anyhow::Result<()> {
+        self.rooch_store.set_last_proposed(block.block_number)?;
+        self.last_proposed_block_number = Some(block.block_number);
+        self.last_proposed_block_accumulator_root = block.tx_accumulator_root;
+        Ok(())
     }
 
-    /// Trigger the proposer to propose a new block
+    // mark the block as proposed without changing the accumulator root the next block is linked to
+    fn skip_block(&mut self, block_number: u128) -> anyhow::Result<()> {
+        self.rooch_store.set_last_proposed(block_number)?;
+        self.last_proposed_block_number = Some(block_number);
+        Ok(())
+    }
+
+    /// Trigger the proposer to propose a new block.
+    /// The returned block is not proposed until `set_last_proposed` is called,
+    /// so the same block will be returned again if it's not confirmed.
+    /// The blocks before it which only contain the proposer's own `propose_block` transactions are skipped.
     pub async fn propose_block(&mut self) -> anyhow::Result<Option<Block>> {
-        let last_proposed = self.rooch_store.get_last_proposed()?;
-        let next_propose_block_number = match last_proposed {
-            Some(last_proposed) => last_proposed + 1,
-            None => 0,
-        };
-        let next_block_da_state_opt = self
-            .rooch_store
-            .try_get_block_state(next_propose_block_number)?; // DB error
-        match next_block_da_state_opt {
-            Some(next_block_da_state) => {
-                if !next_block_da_state.done {
-                    Ok(None)
-                } else {
-                    let block = self.append_new_block(next_block_da_state)?;
-                    Ok(Some(block))
-                }
-            }
-            None => {
-                // init state, no block state
-                Ok(None)
+        loop {
+            let next_propose_block_number = match self.last_proposed_block_number {
+                Some(last_proposed) => last_proposed + 1,
+                None => 0,
+            };
+            let next_block_da_state = match self
+                .rooch_store
+                .try_get_block_state(next_propose_block_number)? // DB error
+            {
+                Some(next_block_da_state) if next_block_da_state.done => next_block_da_state,
+                // init state (no block state) or the block is not submitted to DA yet
+                _ => return Ok(None),
+            };
+            if self.is_self_submission_block(&next_block_da_state.block_range)? {
+                tracing::debug!(
+                    "[ProposeBlock] skip block: {}, it only contains the proposer's submissions",
+                    next_propose_block_number
+                );
+                self.skip_block(next_propose_block_number)?;
+                continue;
             }
+            let block = self.make_next_block(next_block_da_state)?;
+            return Ok(Some(block));
         }
     }
 }
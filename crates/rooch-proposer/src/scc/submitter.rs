@@ -0,0 +1,99 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::anyhow;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
+use rooch_types::block::Block;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::state_commitment_chain::StateCommitmentChainModule;
+use rooch_types::transaction::RoochTransactionData;
+use std::time::Duration;
+
+const MAX_SUBMIT_RETRY: u32 = 3;
+const SUBMIT_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// BlockSubmitter submits proposed blocks to the on-chain SCC contract
+#[derive(Clone)]
+pub struct BlockSubmitter {
+    executor: ExecutorProxy,
+    processor: PipelineProcessorProxy,
+}
+
+impl BlockSubmitter {
+    pub fn new(executor: ExecutorProxy, processor: PipelineProcessorProxy) -> Self {
+        Self {
+            executor,
+            processor,
+        }
+    }
+
+    /// Submit the block signed by the proposer key, returns Ok only if the block is in the on-chain SCC.
+    pub async fn submit(&self, proposer_key: &RoochKeyPair, block: &Block) -> anyhow::Result<()> {
+        // the block may have been submitted before restarting, but not marked as proposed locally
+        if self.is_submitted(block)? {
+            tracing::info!(
+                "[BlockSubmitter] block {} is already in the on-chain SCC",
+                block.block_number
+            );
+            return Ok(());
+        }
+
+        let mut retry = 0;
+        loop {
+            match self.try_submit(proposer_key, block).await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    retry += 1;
+                    if retry >= MAX_SUBMIT_RETRY {
+                        return Err(e.context(format!(
+                            "submit block {} failed after {} retries",
+                            block.block_number, retry
+                        )));
+                    }
+                    tracing::warn!(
+                        "[BlockSubmitter] submit block {} failed, retry: {}, error: {:?}",
+                        block.block_number,
+                        retry,
+                        e
+                    );
+                    tokio::time::sleep(SUBMIT_RETRY_INTERVAL * retry).await;
+                }
+            }
+        }
+    }
+
+    fn is_submitted(&self, block: &Block) -> anyhow::Result<bool> {
+        let scc_module = self
+            .executor
+            .as_module_binding::<StateCommitmentChainModule>();
+        let last_block_number = scc_module.last_block_number()?;
+        Ok(matches!(last_block_number, Some(last) if last >= block.block_number))
+    }
+
+    async fn try_submit(&self, proposer_key: &RoochKeyPair, block: &Block) -> anyhow::Result<()> {
+        let sender = proposer_key.public().rooch_address()?;
+        let sequence_number = self.executor.get_sequence_number(sender.into()).await?;
+        let chain_id = self.executor.chain_id().await?.id();
+        let tx = RoochTransactionData::new(
+            sender,
+            sequence_number,
+            chain_id,
+            GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT,
+            StateCommitmentChainModule::propose_block_action(block),
+        )
+        .sign(proposer_key);
+        let response = self.processor.execute_l2_tx(tx).await?;
+        match response.execution_info.status {
+            KeptVMStatus::Executed => Ok(()),
+            status => Err(anyhow!(
+                "propose block {} transaction failed, status: {:?}",
+                block.block_number,
+                status
+            )),
+        }
+    }
+}
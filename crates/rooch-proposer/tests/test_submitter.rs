@@ -0,0 +1,254 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use coerce::actor::{system::ActorSystem, IntoActor, LocalActorRef};
+use moveos_types::h256::H256;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::transaction::MoveAction;
+use prometheus::Registry;
+use rooch_config::da_config::{derive_namespace_from_genesis, DAConfig};
+use rooch_config::proposer_config::ProposerConfig;
+use rooch_config::RoochOpt;
+use rooch_da::actor::server::DAServerActor;
+use rooch_da::proxy::DAServerProxy;
+use rooch_db::RoochDB;
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_executor::actor::reader_executor::ReaderExecutorActor;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_genesis::RoochGenesis;
+use rooch_indexer::actor::indexer::IndexerActor;
+use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
+use rooch_indexer::proxy::IndexerProxy;
+use rooch_pipeline_processor::actor::processor::PipelineProcessorActor;
+use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
+use rooch_proposer::actor::messages::ProposeBlock;
+use rooch_proposer::actor::proposer::ProposerActor;
+use rooch_proposer::scc::submitter::BlockSubmitter;
+use rooch_sequencer::actor::sequencer::SequencerActor;
+use rooch_sequencer::proxy::SequencerProxy;
+use rooch_store::da_store::DAMetaStore;
+use rooch_store::proposer_store::ProposerStore;
+use rooch_store::RoochStore;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::state_commitment_chain::StateCommitmentChainModule;
+use rooch_types::rooch_network::{BuiltinChainID, RoochNetwork};
+use rooch_types::service_status::ServiceStatus;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use tokio::sync::broadcast;
+
+struct TestNode {
+    // keep the opt to ensure the temp dir is not deleted before the test ends
+    _opt: RoochOpt,
+    _shutdown_tx: broadcast::Sender<()>,
+    registry: Registry,
+    rooch_db: RoochDB,
+    executor: ExecutorProxy,
+    sequencer: SequencerProxy,
+    processor: PipelineProcessorProxy,
+}
+
+impl TestNode {
+    async fn new(sequencer_key: &RoochKeyPair) -> Result<Self> {
+        let mut network: RoochNetwork = BuiltinChainID::Local.into();
+        network.mock_genesis_account(sequencer_key)?;
+
+        let opt = RoochOpt::new_with_temp_store()?;
+        let registry = Registry::new();
+        let rooch_db = RoochDB::init(opt.store_config(), &registry)?;
+        let genesis = RoochGenesis::load_or_init(network, &rooch_db)?;
+        let root = rooch_db
+            .latest_root()?
+            .expect("root should exist after genesis");
+
+        let actor_system = ActorSystem::global_system();
+        let (shutdown_tx, _) = broadcast::channel(16);
+
+        let executor = ExecutorActor::new(
+            root.clone(),
+            rooch_db.moveos_store.clone(),
+            rooch_db.rooch_store.clone(),
+            &registry,
+            None,
+        )?
+        .into_actor(None, &actor_system)
+        .await?;
+        let reader_executor = ReaderExecutorActor::new(
+            root.clone(),
+            rooch_db.moveos_store.clone(),
+            rooch_db.rooch_store.clone(),
+            &registry,
+            None,
+        )?
+        .into_actor(None, &actor_system)
+        .await?;
+        let executor = ExecutorProxy::new(executor.into(), reader_executor.into());
+        let sequencer = SequencerActor::new(
+            sequencer_key.copy(),
+            rooch_db.rooch_store.clone(),
+            ServiceStatus::Active,
+            &registry,
+            None,
+        )?
+        .into_actor(None, &actor_system)
+        .await?;
+        let sequencer = SequencerProxy::new(sequencer.into());
+        let da_server = DAServerActor::new(
+            DAConfig::default(),
+            sequencer_key.copy(),
+            rooch_db.rooch_store.clone(),
+            derive_namespace_from_genesis(genesis.genesis_hash()),
+            shutdown_tx.subscribe(),
+        )
+        .await?
+        .into_actor(None, &actor_system)
+        .await?;
+        let indexer = IndexerActor::new(root, rooch_db.indexer_store.clone(), None)?
+            .into_actor(None, &actor_system)
+            .await?;
+        let indexer_reader = IndexerReaderActor::new(rooch_db.indexer_reader.clone())?
+            .into_actor(None, &actor_system)
+            .await?;
+        let processor = PipelineProcessorActor::new(
+            executor.clone(),
+            sequencer.clone(),
+            DAServerProxy::new(da_server.into()),
+            IndexerProxy::new(indexer.into(), indexer_reader.into()),
+            ServiceStatus::Active,
+            &registry,
+            None,
+            rooch_db.clone(),
+            None,
+            0,
+        )
+        .into_actor(None, &actor_system)
+        .await?;
+
+        Ok(Self {
+            _opt: opt,
+            _shutdown_tx: shutdown_tx,
+            registry,
+            rooch_db,
+            executor,
+            sequencer,
+            processor: PipelineProcessorProxy::new(processor.into()),
+        })
+    }
+
+    fn rooch_store(&self) -> &RoochStore {
+        &self.rooch_db.rooch_store
+    }
+
+    async fn execute_user_tx(&self, user_key: &RoochKeyPair) -> Result<()> {
+        let sender = user_key.public().rooch_address()?;
+        let sequence_number = self.executor.get_sequence_number(sender.into()).await?;
+        let tx = RoochTransactionData::new_for_test(
+            sender,
+            sequence_number,
+            MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]),
+        )
+        .sign(user_key);
+        self.processor.execute_l2_tx(tx).await?;
+        Ok(())
+    }
+
+    // make a DA block of all txs after the last block, and mark it submitted to DA
+    async fn make_block(&self) -> Result<u128> {
+        let rooch_store = self.rooch_store();
+        let tx_order_start = match rooch_store.get_last_block_number()? {
+            Some(last_block_number) => {
+                rooch_store
+                    .get_block_state(last_block_number)?
+                    .block_range
+                    .tx_order_end
+                    + 1
+            }
+            None => 0,
+        };
+        let tx_order_end = self.sequencer.get_sequencer_order().await?;
+        let block_number = rooch_store.append_submitting_block(tx_order_start, tx_order_end)?;
+        rooch_store.set_submitting_block_done(
+            block_number,
+            tx_order_start,
+            tx_order_end,
+            H256::random(),
+        )?;
+        Ok(block_number)
+    }
+
+    fn last_scc_block_number(&self) -> Result<Option<u128>> {
+        self.executor
+            .as_module_binding::<StateCommitmentChainModule>()
+            .last_block_number()
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_submit_blocks_without_self_submission_loop() -> Result<()> {
+    let sequencer_key = RoochKeyPair::generate_secp256k1();
+    let node = TestNode::new(&sequencer_key).await?;
+    let user_key = RoochKeyPair::generate_secp256k1();
+    // the sequencer is the proposer by default
+    let proposer_address = sequencer_key.public().rooch_address()?;
+
+    let proposer: LocalActorRef<ProposerActor> = ProposerActor::new(
+        sequencer_key.copy(),
+        node.rooch_db.moveos_store.clone(),
+        node.rooch_store().clone(),
+        &node.registry,
+        ProposerConfig::default(),
+        Some(BlockSubmitter::new(
+            node.executor.clone(),
+            node.processor.clone(),
+        )),
+    )?
+    .into_actor(None, &ActorSystem::global_system())
+    .await?;
+
+    // N blocks with user txs, each block (except the first one) also contains the submission of the previous block
+    let n = 3;
+    for i in 0..n {
+        node.execute_user_tx(&user_key).await?;
+        let block_number = node.make_block().await?;
+        assert_eq!(block_number, i);
+        proposer.send(ProposeBlock {}).await?;
+        assert_eq!(node.last_scc_block_number()?, Some(block_number));
+    }
+    assert_eq!(
+        node.executor
+            .get_sequence_number(proposer_address.into())
+            .await?,
+        n as u64
+    );
+
+    // the block only containing the last submission is skipped, no more submission
+    let skipped_block_number = node.make_block().await?;
+    for _ in 0..3 {
+        proposer.send(ProposeBlock {}).await?;
+    }
+    assert_eq!(
+        node.executor
+            .get_sequence_number(proposer_address.into())
+            .await?,
+        n as u64
+    );
+    assert_eq!(node.last_scc_block_number()?, Some(n - 1));
+    assert_eq!(
+        node.rooch_store().get_last_proposed()?,
+        Some(skipped_block_number)
+    );
+
+    // the next block is linked to the last submitted block over the skipped one
+    node.execute_user_tx(&user_key).await?;
+    let block_number = node.make_block().await?;
+    proposer.send(ProposeBlock {}).await?;
+    assert_eq!(node.last_scc_block_number()?, Some(block_number));
+    assert_eq!(
+        node.executor
+            .get_sequence_number(proposer_address.into())
+            .await?,
+        n as u64 + 1
+    );
+    Ok(())
+}
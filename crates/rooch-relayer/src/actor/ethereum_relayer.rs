@@ -6,78 +6,213 @@ use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use ethers::prelude::*;
+use moveos_types::module_binding::MoveFunctionCaller;
 use rooch_config::EthereumRelayerConfig;
+use rooch_executor::proxy::ExecutorProxy;
 use rooch_types::{
-    framework::ethereum::BlockHeader,
+    framework::ethereum::{BlockHeader, EthereumModule},
     multichain_id::RoochMultiChainID,
     transaction::{L1Block, L1BlockWithBody, L1Transaction},
 };
-use std::collections::HashSet;
-use tracing::info;
+use std::collections::VecDeque;
+use tracing::{debug, error, info, warn};
 
 pub struct EthereumRelayer {
     rpc_client: Provider<Http>,
-    processed_blocks: HashSet<H256>,
+    move_caller: ExecutorProxy,
+    confirmation_depth: u64,
+    buffer: VecDeque<BlockResult>,
+    batch_size: usize,
+    // the transactions of the last popped block
+    ready_txs: Vec<L1Transaction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BlockResult {
+    pub block_hash: H256,
+    pub header: BlockHeader,
+    pub tx_hashes: Vec<H256>,
 }
 
 impl EthereumRelayer {
-    pub fn new(config: EthereumRelayerConfig) -> Result<Self> {
+    pub fn new(config: EthereumRelayerConfig, executor: ExecutorProxy) -> Result<Self> {
         let rpc_client = Provider::<Http>::try_from(config.eth_rpc_url)?;
         Ok(Self {
             rpc_client,
-            //TODO load processed block from Move state
-            processed_blocks: HashSet::new(),
+            move_caller: executor,
+            confirmation_depth: config.eth_confirmation_depth,
+            buffer: VecDeque::new(),
+            batch_size: 5,
+            ready_txs: vec![],
         })
     }
 
-    async fn relay_ethereum(&mut self) -> Result<Option<L1BlockWithBody>> {
+    async fn get_block(&self, block_number: u64) -> Result<BlockResult> {
         let block = self
             .rpc_client
-            .get_block(BlockId::Number(BlockNumber::Latest))
-            .await?;
-        match block {
-            Some(block) => {
-                let block_hash = block
-                    .hash
-                    .ok_or_else(|| anyhow::format_err!("The block is a pending block"))?;
-                if self.processed_blocks.contains(&block_hash) {
-                    info!("The block {} has already been processed", block_hash);
-                    return Ok(None);
+            .get_block(BlockId::Number(BlockNumber::Number(block_number.into())))
+            .await?
+            .ok_or_else(|| anyhow::format_err!("The block {} is not found", block_number))?;
+        let block_hash = block
+            .hash
+            .ok_or_else(|| anyhow::format_err!("The block {} is a pending block", block_number))?;
+        let header = BlockHeader::try_from(&block)?;
+        Ok(BlockResult {
+            block_hash,
+            header,
+            tx_hashes: block.transactions,
+        })
+    }
+
+    /// Find the block to start sync from, the start block is included.
+    /// Returns the start block number and the expected parent hash of the start block.
+    async fn find_start_block(&self, confirmed_block_number: u64) -> Result<(u64, Option<H256>)> {
+        let ethereum_module = self.move_caller.as_module_binding::<EthereumModule>();
+        let mut block_number = match ethereum_module.get_latest_block_number()? {
+            Some(block_number) => block_number,
+            // if no block has been relayed, we start from the latest confirmed block
+            None => return Ok((confirmed_block_number, None)),
+        };
+
+        // if the latest block in rooch is not in the main chain, we need to find the common ancestor
+        loop {
+            let block_hash_in_rooch = match ethereum_module.get_block_hash(block_number)? {
+                Some(block_hash) => H256::from_slice(&block_hash),
+                // walked back to the first relayed block, relay the main chain block at this height
+                None => return Ok((block_number + 1, None)),
+            };
+            let block = self.get_block(block_number).await?;
+            if block.block_hash == block_hash_in_rooch {
+                return Ok((block_number + 1, Some(block_hash_in_rooch)));
+            }
+            warn!(
+                "EthereumRelayer detect reorg, block {} in rooch: {}, in ethereum: {}",
+                block_number, block_hash_in_rooch, block.block_hash
+            );
+            if block_number == 0 {
+                return Ok((0, None));
+            }
+            block_number -= 1;
+        }
+    }
+
+    async fn sync_block(&mut self) -> Result<()> {
+        if self.buffer.len() > self.batch_size {
+            return Ok(());
+        }
+
+        let latest_block_number = self.rpc_client.get_block_number().await?.as_u64();
+        let confirmed_block_number = match latest_block_number.checked_sub(self.confirmation_depth)
+        {
+            Some(confirmed_block_number) => confirmed_block_number,
+            None => return Ok(()),
+        };
+
+        let (mut next_block_number, mut parent_hash) = match self.buffer.back() {
+            Some(last_block) => (last_block.header.number + 1, Some(last_block.block_hash)),
+            None => self.find_start_block(confirmed_block_number).await?,
+        };
+
+        let mut batch_count = 0;
+        while next_block_number <= confirmed_block_number && batch_count <= self.batch_size {
+            let block = self.get_block(next_block_number).await?;
+            if let Some(parent_hash) = parent_hash {
+                if block.header.parent_hash != parent_hash.as_bytes() {
+                    // the buffered blocks are reorged, drop them and find the common ancestor in the next sync
+                    warn!(
+                        "EthereumRelayer detect reorg at block {}, expect parent: {}, actual parent: {}",
+                        next_block_number,
+                        parent_hash,
+                        hex::encode(&block.header.parent_hash)
+                    );
+                    self.buffer.clear();
+                    return Ok(());
                 }
-                let block_header = BlockHeader::try_from(&block)?;
+            }
+
+            info!(
+                "EthereumRelayer buffer block, number: {}, hash: {}",
+                next_block_number, block.block_hash
+            );
+            parent_hash = Some(block.block_hash);
+            next_block_number += 1;
+            self.buffer.push_back(block);
+            batch_count += 1;
+        }
+        Ok(())
+    }
+
+    fn pop_buffer(&mut self) -> Result<Option<L1BlockWithBody>> {
+        match self.buffer.pop_front() {
+            Some(block_result) => {
                 info!(
-                    "EthereumRelayer process block, hash: {}, number: {}, timestamp: {}",
-                    block_hash, block_header.number, block_header.timestamp
+                    "EthereumRelayer process block, hash: {}, number: {}, tx_size: {}, timestamp: {}",
+                    block_result.block_hash,
+                    block_result.header.number,
+                    block_result.tx_hashes.len(),
+                    block_result.header.timestamp
                 );
-                let l1_block = L1BlockWithBody {
+                debug!("BlockResult: {:?}", block_result);
+                self.ready_txs = Self::l1_txs(&block_result);
+                Ok(Some(L1BlockWithBody {
                     block: L1Block {
                         chain_id: RoochMultiChainID::Ether.multichain_id(),
-                        block_height: block_header.number,
-                        block_hash: block_hash.as_bytes().to_vec(),
+                        block_height: block_result.header.number,
+                        block_hash: block_result.block_hash.as_bytes().to_vec(),
                     },
-                    block_body: block_header.encode(),
-                };
-                self.processed_blocks.insert(block_hash);
-                Ok(Some(l1_block))
-            }
-            None => {
-                info!("The RPC returned no block");
-                Ok(None)
+                    block_body: block_result.header.encode(),
+                }))
             }
+            None => Ok(None),
+        }
+    }
+
+    fn l1_txs(block_result: &BlockResult) -> Vec<L1Transaction> {
+        block_result
+            .tx_hashes
+            .iter()
+            .map(|tx_hash| {
+                L1Transaction::new(
+                    RoochMultiChainID::Ether.multichain_id(),
+                    block_result.block_hash.as_bytes().to_vec(),
+                    tx_hash.as_bytes().to_vec(),
+                )
+            })
+            .collect()
+    }
+
+    /// The transactions of the latest relayed block may not be relayed before restarting,
+    /// relay them again, the Move side ignores the repeated transactions.
+    async fn load_ready_txs(&mut self) -> Result<()> {
+        let ethereum_module = self.move_caller.as_module_binding::<EthereumModule>();
+        let latest_block_number = match ethereum_module.get_latest_block_number()? {
+            Some(latest_block_number) => latest_block_number,
+            None => return Ok(()),
+        };
+        let block_hash_in_rooch = ethereum_module.get_block_hash(latest_block_number)?;
+        let block = self.get_block(latest_block_number).await?;
+        if block_hash_in_rooch.as_deref() == Some(block.block_hash.as_bytes()) {
+            self.ready_txs = Self::l1_txs(&block);
         }
-        //TODO clean up processed block
+        Ok(())
     }
 }
 
 #[async_trait]
 impl Actor for EthereumRelayer {
-    async fn started(&mut self, _ctx: &mut ActorContext) {}
+    async fn started(&mut self, _ctx: &mut ActorContext) {
+        if let Err(e) = self.load_ready_txs().await {
+            error!("EthereumRelayer load ready txs error: {:?}", e);
+        }
+    }
 }
 
 #[async_trait]
 impl Handler<SyncTick> for EthereumRelayer {
     async fn handle(&mut self, _message: SyncTick, _ctx: &mut ActorContext) {
-        //TODO support buffer block
+        if let Err(e) = self.sync_block().await {
+            error!("EthereumRelayer sync block error: {:?}", e);
+        }
     }
 }
 
@@ -88,7 +223,7 @@ impl Handler<GetReadyL1BlockMessage> for EthereumRelayer {
         _message: GetReadyL1BlockMessage,
         _ctx: &mut ActorContext,
     ) -> Result<Option<L1BlockWithBody>> {
-        self.relay_ethereum().await
+        self.pop_buffer()
     }
 }
 
@@ -99,7 +234,6 @@ impl Handler<GetReadyL1TxsMessage> for EthereumRelayer {
         _message: GetReadyL1TxsMessage,
         _ctx: &mut ActorContext,
     ) -> Result<Vec<L1Transaction>> {
-        //TODO
-        Ok(vec![])
+        Ok(std::mem::take(&mut self.ready_txs))
     }
 }
@@ -70,7 +70,7 @@ impl RelayerActor {
 
     async fn init_relayer(&mut self, ctx: &mut ActorContext) -> Result<()> {
         if let Some(ethereum_config) = &self.ethereum_config {
-            let eth_relayer = EthereumRelayer::new(ethereum_config.clone())?;
+            let eth_relayer = EthereumRelayer::new(ethereum_config.clone(), self.executor.clone())?;
             let eth_relayer_actor_ref = ctx.spawn("eth_relayer".into(), eth_relayer).await?;
             self.relayers
                 .push(RelayerProxy::ethereum(eth_relayer_actor_ref.into()));
@@ -145,11 +145,11 @@ impl RelayerActor {
     //We migrate this function from Relayer to here
     //Becase the relayer actor will blocked when sync block
     //TODO refactor the relayer, put the sync task in a separate actor
-    fn get_ready_l1_txs(&self, relayer: &RelayerProxy) -> Result<Vec<L1Transaction>> {
+    async fn get_ready_l1_txs(&self, relayer: &RelayerProxy) -> Result<Vec<L1Transaction>> {
         if relayer.is_bitcoin() {
             self.get_ready_l1_txs_bitcoin()
         } else {
-            Ok(vec![])
+            relayer.get_ready_l1_txs().await
         }
     }
 
@@ -218,7 +218,7 @@ impl RelayerActor {
                 }
 
                 // Execute all ready l1 txs
-                match self.get_ready_l1_txs(&relayer) {
+                match self.get_ready_l1_txs(&relayer).await {
                     Ok(txs) => {
                         for tx in txs {
                             if let Err(err) = self.handle_l1_tx(tx).await {
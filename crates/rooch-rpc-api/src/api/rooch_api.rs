@@ -3,19 +3,23 @@
 
 use crate::jsonrpc_types::account_view::BalanceInfoView;
 use crate::jsonrpc_types::address::UnitedAddressView;
-use crate::jsonrpc_types::event_view::{EventFilterView, IndexerEventIDView};
+use crate::jsonrpc_types::event_view::{EventFilterView, IndexerEventIDView, IndexerEventView};
 use crate::jsonrpc_types::repair_view::{RepairIndexerParamsView, RepairIndexerTypeView};
-use crate::jsonrpc_types::transaction_view::{TransactionFilterView, TransactionWithInfoView};
+use crate::jsonrpc_types::transaction_view::{
+    TransactionFilterView, TransactionStatusView, TransactionWithInfoView,
+};
 use crate::jsonrpc_types::{
     AccessPathView, AnnotatedFunctionResultView, BalanceInfoPageView, BytesView, EventOptions,
     EventPageView, ExecuteTransactionResponseView, FieldKeyView, FunctionCallView, H256View,
-    IndexerEventPageView, IndexerObjectStatePageView, IndexerStateIDView, ModuleABIView,
-    ObjectIDVecView, ObjectIDView, ObjectStateFilterView, ObjectStateView, QueryOptions,
-    RoochAddressView, StateChangeSetPageView, StateOptions, StatePageView, StrView, StructTagView,
-    SyncStateFilterView, TransactionWithInfoPageView, TxOptions,
+    IndexerEventPageView, IndexerObjectChangeView, IndexerObjectStatePageView, IndexerStateIDView,
+    ModuleABIView, ObjectIDVecView, ObjectIDView, ObjectStateFilterView, ObjectStateView,
+    QueryOptions, RoochAddressView, StateChangeSetPageView, StateOptions, StatePageView,
+    StateWithProofView, StrView, StructTagView, SyncStateFilterView, TraceTransactionResponseView,
+    TransactionWithInfoPageView, TxOptions,
 };
 use crate::jsonrpc_types::{DryRunTransactionResponseView, Status};
 use crate::RpcResult;
+use jsonrpsee::core::SubscriptionResult;
 use jsonrpsee::proc_macros::rpc;
 use moveos_types::{access_path::AccessPath, state::FieldKey};
 use rooch_open_rpc_macros::open_rpc;
@@ -44,12 +48,27 @@ pub trait RoochAPI {
     #[method(name = "dryRunRawTransaction")]
     async fn dry_run(&self, tx_bcs_hex: BytesView) -> RpcResult<DryRunTransactionResponseView>;
 
+    /// Trace the executed transaction against the state before it, returns the call tree with the gas used
+    /// and the changed Objects before and after the execution. Returns None if the transaction is not found.
+    #[method(name = "traceTransaction")]
+    async fn trace_transaction(
+        &self,
+        tx_hash: H256View,
+    ) -> RpcResult<Option<TraceTransactionResponseView>>;
+
+    /// Trace the transaction data in bcs hex format against the latest state, the output is not applied.
+    #[method(name = "traceDryRun")]
+    async fn trace_dry_run(&self, tx_bcs_hex: BytesView)
+        -> RpcResult<TraceTransactionResponseView>;
+
     /// Execute a read-only function call
     /// The function do not change the state of Application
+    /// The caller is the sender of the TxContext, default is 0x0
     #[method(name = "executeViewFunction")]
     async fn execute_view_function(
         &self,
         function_call: FunctionCallView,
+        caller: Option<UnitedAddressView>,
     ) -> RpcResult<AnnotatedFunctionResultView>;
 
     /// Get the states by access_path
@@ -94,6 +113,31 @@ pub trait RoochAPI {
         self.get_states(access_path_view, state_option).await
     }
 
+    /// Get the states with the proofs by access_path.
+    /// The proofs are from the root Object down to the states, against the state_root,
+    /// the latest state root is used if the state_root is not given.
+    #[method(name = "getStatesWithProof")]
+    async fn get_states_with_proof(
+        &self,
+        access_path: AccessPathView,
+        state_root: Option<H256View>,
+    ) -> RpcResult<Vec<StateWithProofView>>;
+
+    /// Get Object Fields with the proofs via ObjectID and field keys.
+    #[method(name = "getFieldStatesWithProof")]
+    async fn get_field_states_with_proof(
+        &self,
+        object_id: ObjectIDView,
+        field_key: Vec<FieldKeyView>,
+        state_root: Option<H256View>,
+    ) -> RpcResult<Vec<StateWithProofView>> {
+        let key_states = field_key.into_iter().map(FieldKey::from).collect();
+        let access_path_view =
+            AccessPathView::from(AccessPath::fields(object_id.into(), key_states));
+        self.get_states_with_proof(access_path_view, state_root)
+            .await
+    }
+
     /// List Object Fields via ObjectID.
     #[method(name = "listFieldStates")]
     async fn list_field_states(
@@ -126,6 +170,14 @@ pub trait RoochAPI {
         tx_hashes: Vec<H256View>,
     ) -> RpcResult<Vec<Option<TransactionWithInfoView>>>;
 
+    /// Get the status of the transaction submitted by `sendRawTransaction`,
+    /// returns None if the transaction is unknown
+    #[method(name = "getTransactionStatus")]
+    async fn get_transaction_status(
+        &self,
+        tx_hash: H256View,
+    ) -> RpcResult<Option<TransactionStatusView>>;
+
     #[method(name = "getTransactionsByOrder")]
     async fn get_transactions_by_order(
         &self,
@@ -214,4 +266,43 @@ pub trait RoochAPI {
     /// Get the chain and service status
     #[method(name = "status")]
     async fn status(&self) -> RpcResult<Status>;
+
+    /// Subscribe to the new transactions matching the transaction filter
+    #[subscription(
+        name = "subscribeTransactions",
+        unsubscribe = "unsubscribeTransactions",
+        item = TransactionWithInfoView
+    )]
+    async fn subscribe_transactions(
+        &self,
+        filter: TransactionFilterView,
+        // resume after the exclusive cursor if `Some`, otherwise start from the latest transaction
+        cursor: Option<StrView<u64>>,
+    ) -> SubscriptionResult;
+
+    /// Subscribe to the new events matching the event filter
+    #[subscription(
+        name = "subscribeEvents",
+        unsubscribe = "unsubscribeEvents",
+        item = IndexerEventView
+    )]
+    async fn subscribe_events(
+        &self,
+        filter: EventFilterView,
+        // resume after the exclusive cursor if `Some`, otherwise start from the latest event
+        cursor: Option<IndexerEventIDView>,
+    ) -> SubscriptionResult;
+
+    /// Subscribe to the object changes matching the state filter
+    #[subscription(
+        name = "subscribeObjectChanges",
+        unsubscribe = "unsubscribeObjectChanges",
+        item = IndexerObjectChangeView
+    )]
+    async fn subscribe_object_changes(
+        &self,
+        filter: ObjectStateFilterView,
+        // resume after the exclusive tx_order cursor if `Some`, otherwise start from the latest transaction
+        cursor: Option<StrView<u64>>,
+    ) -> SubscriptionResult;
 }
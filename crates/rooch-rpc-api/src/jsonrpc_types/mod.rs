@@ -12,6 +12,7 @@ mod rooch_types;
 mod rpc_options;
 mod state_view;
 mod status;
+mod trace_view;
 
 #[cfg(test)]
 mod tests;
@@ -37,4 +38,5 @@ pub use rpc_options::*;
 pub use state_view::*;
 pub use status::*;
 pub use str_view::*;
+pub use trace_view::*;
 pub use transaction_argument_view::*;
@@ -14,6 +14,7 @@ use move_binary_format::{
     },
 };
 use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+use moveos_verifier::metadata::get_metadata_from_compiled_module;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Debug};
@@ -220,6 +221,8 @@ pub struct MoveFunctionView {
     pub name: IdentifierView,
     /// Whether the function can be called as an entry function directly in a transaction
     pub is_entry: bool,
+    /// Whether the function is a #[view] function, which is safe to call via `executeViewFunction`
+    pub is_view: bool,
     /// Generic type params associated with the Move function
     pub type_params: Vec<MoveFunctionTypeParamView>,
     /// Parameters associated with the move function
@@ -230,12 +233,13 @@ pub struct MoveFunctionView {
 }
 
 impl MoveFunctionView {
-    fn new(m: &CompiledModule, def: &FunctionDefinition) -> Self {
+    fn new(m: &CompiledModule, def: &FunctionDefinition, is_view: bool) -> Self {
         let fhandle = m.function_handle_at(def.function);
         let name = m.identifier_at(fhandle.name).to_owned();
         Self {
             name: name.into(),
             is_entry: def.is_entry,
+            is_view,
             type_params: fhandle
                 .type_parameters
                 .iter()
@@ -366,6 +370,7 @@ impl ModuleABIView {
 impl From<CompiledModule> for ModuleABIView {
     fn from(m: CompiledModule) -> Self {
         let (address, name) = <(AccountAddress, Identifier)>::from(m.self_id());
+        let metadata = get_metadata_from_compiled_module(&m).unwrap_or_default();
         Self {
             address: address.into(),
             name: name.into(),
@@ -389,7 +394,11 @@ impl From<CompiledModule> for ModuleABIView {
                             Visibility::Private | Visibility::Friend => false,
                         }
                 })
-                .map(|def| MoveFunctionView::new(&m, def))
+                .map(|def| {
+                    let fhandle = m.function_handle_at(def.function);
+                    let is_view = metadata.is_view_function(m.identifier_at(fhandle.name).as_str());
+                    MoveFunctionView::new(&m, def, is_view)
+                })
                 .collect(),
             structs: m
                 .struct_defs
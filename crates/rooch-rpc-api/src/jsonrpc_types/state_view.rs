@@ -10,6 +10,7 @@ use anyhow::Result;
 use move_core_types::effects::Op;
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::state::{FieldKey, ObjectChange};
+use moveos_types::state_proof::{SparseMerkleProof, StateProof, StateWithProof};
 use moveos_types::state_resolver::StateKV;
 use moveos_types::{
     moveos_std::object::{human_readable_flag, ObjectID},
@@ -390,6 +391,32 @@ impl HumanReadableDisplay for IndexerObjectStateView {
     }
 }
 
+/// Object change view. Used as the item of `subscribeObjectChanges`.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct IndexerObjectChangeView {
+    /// The metadata after the change, or the metadata before the object is deleted.
+    #[serde(flatten)]
+    pub metadata: ObjectMetaView,
+    /// The value change of the Object, None if only the metadata is changed.
+    pub value: Option<OpView>,
+    #[serde(flatten)]
+    pub indexer_id: IndexerStateIDView,
+}
+
+impl IndexerObjectChangeView {
+    pub fn new(
+        metadata: ObjectMeta,
+        value: Option<Op<Vec<u8>>>,
+        indexer_id: IndexerStateID,
+    ) -> Self {
+        IndexerObjectChangeView {
+            metadata: metadata.into(),
+            value: value.map(Into::into),
+            indexer_id: indexer_id.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ObjectStateFilterView {
@@ -496,6 +523,105 @@ impl From<AnnotatedState> for ObjectStateView {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SparseMerkleLeafView {
+    pub key_hash: H256View,
+    pub value_hash: H256View,
+}
+
+/// The proof of a key in a Sparse Merkle Tree, see `SparseMerkleProof` for details.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SparseMerkleProofView {
+    pub leaf: Option<SparseMerkleLeafView>,
+    pub siblings: Vec<H256View>,
+}
+
+impl From<SparseMerkleProof> for SparseMerkleProofView {
+    fn from(proof: SparseMerkleProof) -> Self {
+        SparseMerkleProofView {
+            leaf: proof
+                .leaf
+                .map(|(key_hash, value_hash)| SparseMerkleLeafView {
+                    key_hash: key_hash.into(),
+                    value_hash: value_hash.into(),
+                }),
+            siblings: proof.siblings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<SparseMerkleProofView> for SparseMerkleProof {
+    fn from(proof: SparseMerkleProofView) -> Self {
+        SparseMerkleProof::new(
+            proof.leaf.map(|leaf| (leaf.key_hash.0, leaf.value_hash.0)),
+            proof
+                .siblings
+                .into_iter()
+                .map(|sibling| sibling.0)
+                .collect(),
+        )
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ObjectStateWithProofView {
+    pub state: ObjectStateView,
+    pub proof: SparseMerkleProofView,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct StateProofView {
+    /// The ancestor Objects from the child of the root Object down to the parent Object of the state.
+    pub ancestors: Vec<ObjectStateWithProofView>,
+    /// The proof of the state in the state tree of its parent Object,
+    /// or the non-inclusion proof of the first missing ancestor Object.
+    pub proof: SparseMerkleProofView,
+}
+
+/// State with proof view. Used as return type of `getStatesWithProof`.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct StateWithProofView {
+    pub state: Option<ObjectStateView>,
+    pub proof: StateProofView,
+}
+
+impl From<StateWithProof> for StateWithProofView {
+    fn from(state_with_proof: StateWithProof) -> Self {
+        let StateWithProof { state, proof } = state_with_proof;
+        StateWithProofView {
+            state: state.map(Into::into),
+            proof: StateProofView {
+                ancestors: proof
+                    .ancestors
+                    .into_iter()
+                    .map(|(state, proof)| ObjectStateWithProofView {
+                        state: state.into(),
+                        proof: proof.into(),
+                    })
+                    .collect(),
+                proof: proof.proof.into(),
+            },
+        }
+    }
+}
+
+impl From<StateWithProofView> for StateWithProof {
+    fn from(state_with_proof: StateWithProofView) -> Self {
+        let StateWithProofView { state, proof } = state_with_proof;
+        StateWithProof::new(
+            state.map(Into::into),
+            StateProof {
+                ancestors: proof
+                    .ancestors
+                    .into_iter()
+                    .map(|ancestor| (ancestor.state.into(), ancestor.proof.into()))
+                    .collect(),
+                proof: proof.proof.into(),
+            },
+        )
+    }
+}
+
 impl HumanReadableDisplay for ObjectStateView {
     fn to_human_readable_string(&self, verbose: bool, indent: usize) -> String {
         self.metadata.to_human_readable_string(verbose, indent)
@@ -0,0 +1,58 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::event_view::TransactionEventView;
+use crate::jsonrpc_types::{
+    FunctionIdView, KeptVMStatusView, ObjectIDView, ObjectStateView, StrView, TypeTagView,
+};
+use moveos_types::transaction::{CallTrace, VMErrorInfo};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A call in the call tree of the traced transaction
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CallTraceView {
+    /// The called function, None for the script
+    pub function: Option<FunctionIdView>,
+    pub ty_args: Vec<TypeTagView>,
+    pub is_native: bool,
+    /// The gas used by the call, including the callees
+    pub gas_used: StrView<u64>,
+    pub calls: Vec<CallTraceView>,
+}
+
+impl From<CallTrace> for CallTraceView {
+    fn from(call: CallTrace) -> Self {
+        Self {
+            function: call.function.map(Into::into),
+            ty_args: call.ty_args.into_iter().map(Into::into).collect(),
+            is_native: call.is_native,
+            gas_used: call.gas_used.into(),
+            calls: call.calls.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The Object changed by the traced transaction, the states are decoded if the type can be resolved
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ObjectChangeTraceView {
+    pub object_id: ObjectIDView,
+    /// None if the Object is created by the transaction
+    pub before: Option<ObjectStateView>,
+    /// None if the Object is removed by the transaction
+    pub after: Option<ObjectStateView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TraceTransactionResponseView {
+    pub status: KeptVMStatusView,
+    pub gas_used: StrView<u64>,
+    pub execution_gas_used: StrView<u64>,
+    /// The gas charged for the transaction size and the state changes
+    pub storage_gas_used: StrView<u64>,
+    pub vm_error_info: Option<VMErrorInfo>,
+    pub call_trace: CallTraceView,
+    pub events: Vec<TransactionEventView>,
+    /// The changed Objects, the child Objects are after the parent
+    pub object_changes: Vec<ObjectChangeTraceView>,
+}
@@ -3,8 +3,8 @@
 
 use super::{BytesView, StrView};
 use crate::jsonrpc_types::{
-    H256View, TransactionExecutionInfoView, TransactionSequenceInfoView, TransactionView,
-    UnitedAddressView,
+    H256View, KeptVMStatusView, TransactionExecutionInfoView, TransactionSequenceInfoView,
+    TransactionView, UnitedAddressView,
 };
 use bitcoin::hashes::Hash;
 use rooch_types::indexer::transaction::TransactionFilter;
@@ -182,3 +182,20 @@ impl From<TransactionFilterView> for TransactionFilter {
         }
     }
 }
+
+/// The status of a transaction submitted by `sendRawTransaction`
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TransactionStatusView {
+    /// The transaction is waiting in the queue
+    Pending,
+    /// The transaction is sequenced but not executed yet
+    Sequenced { tx_order: StrView<u64> },
+    /// The transaction is executed, the `status` is the VM status of the execution
+    Executed {
+        tx_order: StrView<u64>,
+        status: KeptVMStatusView,
+    },
+    /// The transaction is dropped from the queue because it failed to be validated or sequenced
+    Failed { error: String },
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{Client, ClientBuilder};
-use anyhow::anyhow;
+use anyhow::{anyhow, ensure};
 use rooch_config::config::Config;
 use rooch_config::server_config::ServerConfig;
 use rooch_types::address::RoochAddress;
@@ -14,6 +14,7 @@ use std::fmt::{Display, Formatter, Write};
 use std::path::PathBuf;
 
 pub const DEFAULT_EXPIRATION_SECS: u64 = 30;
+pub const DEFAULT_GAS_ESTIMATE_MULTIPLIER: f64 = 1.5;
 pub const ROOCH_DEV_NET_URL: &str = "https://dev-seed.rooch.network";
 pub const ROOCH_TEST_NET_URL: &str = "https://test-seed.rooch.network";
 pub const ROOCH_MAIN_NET_URL: &str = "https://main-seed.rooch.network";
@@ -24,6 +25,10 @@ pub struct ClientConfig {
     pub active_address: Option<RoochAddress>,
     pub envs: Vec<Env>,
     pub active_env: Option<String>,
+    /// The safety multiplier applied to the gas used by dry run when estimating the max gas amount,
+    /// default is 1.5
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_estimate_multiplier: Option<f64>,
 }
 
 impl ClientConfig {
@@ -33,9 +38,23 @@ impl ClientConfig {
             active_address: None,
             envs: vec![],
             active_env: None,
+            gas_estimate_multiplier: None,
         }
     }
 
+    /// The gas estimate multiplier in the config or the default, it must be a finite number not less than 1
+    pub fn gas_estimate_multiplier(&self) -> Result<f64, anyhow::Error> {
+        let multiplier = self
+            .gas_estimate_multiplier
+            .unwrap_or(DEFAULT_GAS_ESTIMATE_MULTIPLIER);
+        ensure!(
+            multiplier.is_finite() && multiplier >= 1.0,
+            "Invalid gas_estimate_multiplier {} in the client config, it must be a finite number not less than 1",
+            multiplier
+        );
+        Ok(multiplier)
+    }
+
     pub fn get_env(&self, alias: &Option<String>) -> Option<&Env> {
         if let Some(alias) = alias {
             self.envs.iter().find(|env| &env.alias == alias)
@@ -67,6 +86,14 @@ impl ClientConfig {
     }
 }
 
+/// Estimate the max gas amount from the gas used by dry run, the gas used is multiplied by the `multiplier`,
+/// and capped by the max gas amount of the dry run, which is enough for the dry run to succeed.
+pub fn estimate_max_gas_amount(gas_used: u64, multiplier: f64, max_gas_amount_cap: u64) -> u64 {
+    // The float to int `as` conversion saturates at u64::MAX
+    let max_gas_amount = ((gas_used as f64) * multiplier).ceil() as u64;
+    max_gas_amount.min(max_gas_amount_cap).max(1)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Env {
     pub alias: String,
@@ -171,3 +198,40 @@ impl Display for ClientConfig {
         write!(f, "{}", writer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gas_estimate_multiplier() {
+        let mut config = ClientConfig::new(PathBuf::new());
+        assert_eq!(
+            config.gas_estimate_multiplier().unwrap(),
+            DEFAULT_GAS_ESTIMATE_MULTIPLIER
+        );
+        config.gas_estimate_multiplier = Some(1.0);
+        assert_eq!(config.gas_estimate_multiplier().unwrap(), 1.0);
+        config.gas_estimate_multiplier = Some(2.5);
+        assert_eq!(config.gas_estimate_multiplier().unwrap(), 2.5);
+        for invalid in [0.5, 0.0, -1.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            config.gas_estimate_multiplier = Some(invalid);
+            assert!(config.gas_estimate_multiplier().is_err());
+        }
+    }
+
+    #[test]
+    fn test_estimate_max_gas_amount() {
+        let cap = 1_000_000;
+        assert_eq!(estimate_max_gas_amount(1000, 1.5, cap), 1500);
+        assert_eq!(estimate_max_gas_amount(1000, 1.0, cap), 1000);
+        // rounded up
+        assert_eq!(estimate_max_gas_amount(3, 1.5, cap), 5);
+        // at least 1
+        assert_eq!(estimate_max_gas_amount(0, 1.5, cap), 1);
+        // capped by the max gas amount of the dry run
+        assert_eq!(estimate_max_gas_amount(800_000, 1.5, cap), cap);
+        assert_eq!(estimate_max_gas_amount(cap, 1.5, cap), cap);
+        assert_eq!(estimate_max_gas_amount(u64::MAX, 1e10, cap), cap);
+    }
+}
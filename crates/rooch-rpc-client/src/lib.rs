@@ -27,6 +27,7 @@ use tokio::runtime::Handle;
 
 pub mod client_config;
 pub mod rooch_client;
+pub mod state_proof;
 pub mod wallet_context;
 
 pub struct ClientBuilder {
@@ -184,7 +185,25 @@ impl ModuleResolver for ClientResolver {
     }
 
     fn get_module(&self, id: &ModuleId) -> std::result::Result<Option<Vec<u8>>, Error> {
-        (&self.client).get_module(id)
+        // Load the module at the root, the module may be upgraded after the root
+        tokio::task::block_in_place(|| {
+            Handle::current().block_on(async {
+                let mut states = self
+                    .client
+                    .rooch
+                    .get_states(AccessPath::module(id), Some(self.root.state_root()))
+                    .await?;
+                states
+                    .pop()
+                    .flatten()
+                    .map(|state_view| {
+                        let state = ObjectState::from(state_view);
+                        let module = state.value_as_df::<MoveString, MoveModule>()?;
+                        Ok(module.value.byte_codes)
+                    })
+                    .transpose()
+            })
+        })
     }
 }
 
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::state_proof::StateProofVerifier;
 use anyhow::{Ok, Result};
 use bitcoincore_rpc::RawTx;
 use jsonrpsee::http_client::HttpClient;
@@ -15,7 +16,9 @@ use rooch_rpc_api::api::btc_api::BtcAPIClient;
 use rooch_rpc_api::api::rooch_api::RoochAPIClient;
 use rooch_rpc_api::jsonrpc_types::btc::ord::{InscriptionFilterView, InscriptionObjectView};
 use rooch_rpc_api::jsonrpc_types::btc::utxo::{UTXOFilterView, UTXOObjectView};
-use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
+use rooch_rpc_api::jsonrpc_types::transaction_view::{
+    TransactionFilterView, TransactionStatusView,
+};
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView, transaction_view::TransactionWithInfoView, InscriptionPageView,
     Status, UTXOPageView,
@@ -23,9 +26,12 @@ use rooch_rpc_api::jsonrpc_types::{
 use rooch_rpc_api::jsonrpc_types::{
     AccessPathView, AnnotatedFunctionResultView, BalanceInfoPageView, BytesView, EventOptions,
     EventPageView, FieldKeyView, ObjectIDVecView, ObjectIDView, RoochAddressView, StateOptions,
-    StatePageView, StructTagView,
+    StatePageView, StructTagView, TraceTransactionResponseView,
+};
+use rooch_rpc_api::jsonrpc_types::{
+    DryRunTransactionResponseView, ExecuteTransactionResponseView, ObjectStateView,
+    StateWithProofView,
 };
-use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, ObjectStateView};
 use rooch_rpc_api::jsonrpc_types::{
     IndexerObjectStatePageView, ObjectStateFilterView, QueryOptions,
 };
@@ -34,7 +40,10 @@ use rooch_types::address::BitcoinAddress;
 use rooch_types::bitcoin::multisign_account::MultisignAccountInfo;
 use rooch_types::framework::address_mapping::RoochToBitcoinAddressMapping;
 use rooch_types::indexer::state::IndexerStateID;
-use rooch_types::{address::RoochAddress, transaction::rooch::RoochTransaction};
+use rooch_types::{
+    address::RoochAddress,
+    transaction::rooch::{RoochTransaction, RoochTransactionData},
+};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -67,12 +76,64 @@ impl RoochRpcClient {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    pub async fn dry_run_tx(
+        &self,
+        tx_data: RoochTransactionData,
+    ) -> Result<DryRunTransactionResponseView> {
+        let tx_payload = bcs::to_bytes(&tx_data)?;
+        Ok(self.http.dry_run(tx_payload.into()).await?)
+    }
+
+    pub async fn trace_dry_run_tx(
+        &self,
+        tx_data: RoochTransactionData,
+    ) -> Result<TraceTransactionResponseView> {
+        let tx_payload = bcs::to_bytes(&tx_data)?;
+        Ok(self.http.trace_dry_run(tx_payload.into()).await?)
+    }
+
+    pub async fn trace_transaction(
+        &self,
+        tx_hash: H256,
+    ) -> Result<Option<TraceTransactionResponseView>> {
+        Ok(self.http.trace_transaction(tx_hash.into()).await?)
+    }
+
+    /// Queue the transaction without waiting for the execution, returns the tx hash.
+    pub async fn send_tx(&self, tx: RoochTransaction) -> Result<H256> {
+        let tx_payload = bcs::to_bytes(&tx)?;
+        self.http
+            .send_raw_transaction(tx_payload.into())
+            .await
+            .map(Into::into)
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    pub async fn get_transaction_status(
+        &self,
+        tx_hash: H256,
+    ) -> Result<Option<TransactionStatusView>> {
+        Ok(self.http.get_transaction_status(tx_hash.into()).await?)
+    }
+
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
+    ) -> Result<AnnotatedFunctionResultView> {
+        self.execute_view_function_with_caller(function_call, None)
+            .await
+    }
+
+    pub async fn execute_view_function_with_caller(
+        &self,
+        function_call: FunctionCall,
+        caller: Option<AccountAddress>,
     ) -> Result<AnnotatedFunctionResultView> {
         self.http
-            .execute_view_function(function_call.into())
+            .execute_view_function(
+                function_call.into(),
+                caller.map(|caller| RoochAddress::from(caller).into()),
+            )
             .await
             .map_err(|e| anyhow::anyhow!(e))
     }
@@ -118,6 +179,29 @@ impl RoochRpcClient {
             .await?)
     }
 
+    pub async fn get_states_with_proof(
+        &self,
+        access_path: AccessPath,
+        state_root: Option<H256>,
+    ) -> Result<Vec<StateWithProofView>> {
+        Ok(self
+            .http
+            .get_states_with_proof(access_path.into(), state_root.map(Into::into))
+            .await?)
+    }
+
+    /// Get the states by access_path, and verify them against the trusted state root.
+    pub async fn get_verified_states(
+        &self,
+        access_path: AccessPath,
+        trusted_state_root: H256,
+    ) -> Result<Vec<Option<ObjectState>>> {
+        let states = self
+            .get_states_with_proof(access_path.clone(), Some(trusted_state_root))
+            .await?;
+        StateProofVerifier::new(trusted_state_root).verify_states(access_path, states)
+    }
+
     pub async fn get_transactions_by_order(
         &self,
         cursor: Option<u64>,
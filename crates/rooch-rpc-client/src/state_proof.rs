@@ -0,0 +1,57 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, Result};
+use moveos_types::access_path::AccessPath;
+use moveos_types::h256::H256;
+use moveos_types::state::ObjectState;
+use moveos_types::state_proof::StateWithProof;
+use rooch_rpc_api::jsonrpc_types::StateWithProofView;
+
+/// StateProofVerifier verifies the states returned by the RPC node against a trusted state root,
+/// so the client does not need to trust the RPC node.
+#[derive(Debug, Clone, Copy)]
+pub struct StateProofVerifier {
+    trusted_state_root: H256,
+}
+
+impl StateProofVerifier {
+    pub fn new(trusted_state_root: H256) -> Self {
+        Self { trusted_state_root }
+    }
+
+    pub fn trusted_state_root(&self) -> H256 {
+        self.trusted_state_root
+    }
+
+    /// Verify the states with proofs of the `access_path`, returns the verified states.
+    pub fn verify_states(
+        &self,
+        access_path: AccessPath,
+        states: Vec<StateWithProofView>,
+    ) -> Result<Vec<Option<ObjectState>>> {
+        let query = access_path.into_state_query().into_fields_query()?;
+        ensure!(
+            query.len() == states.len(),
+            "The number of states mismatch, expect {}, got {}",
+            query.len(),
+            states.len()
+        );
+        query
+            .into_iter()
+            .zip(states)
+            .map(|((object_id, field_key), state_view)| {
+                let state_with_proof = StateWithProof::from(state_view);
+                state_with_proof
+                    .verify(self.trusted_state_root, &object_id, field_key)
+                    .map_err(|e| {
+                        e.context(format!(
+                            "Verify state proof failed, object_id: {}, field_key: {}",
+                            object_id, field_key
+                        ))
+                    })?;
+                Ok(state_with_proof.state)
+            })
+            .collect()
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::client_config::{ClientConfig, DEFAULT_EXPIRATION_SECS};
+use crate::client_config::{estimate_max_gas_amount, ClientConfig, DEFAULT_EXPIRATION_SECS};
 use crate::Client;
 use anyhow::{anyhow, Result};
 use bitcoin::key::Secp256k1;
@@ -9,7 +9,9 @@ use bitcoin::psbt::{GetKey, KeyRequest};
 use bitcoin::secp256k1::Signing;
 use bitcoin::PrivateKey;
 use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
 use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
+use moveos_types::state::MoveStructType;
 use moveos_types::transaction::MoveAction;
 use rooch_config::config::{Config, PersistedConfig};
 use rooch_config::{rooch_config_dir, ROOCH_CLIENT_CONFIG};
@@ -23,6 +25,8 @@ use rooch_types::authentication_key::AuthenticationKey;
 use rooch_types::bitcoin::network::Network;
 use rooch_types::crypto::RoochKeyPair;
 use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::framework::ethereum_validator::EthereumSignType;
+use rooch_types::framework::gas_coin::RGas;
 use rooch_types::rooch_network::{BuiltinChainID, RoochNetwork};
 use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
 use rooch_types::{addresses, crypto};
@@ -214,15 +218,99 @@ impl WalletContext {
                 .map_err(RoochError::from)?,
         );
         tracing::debug!("use sequence_number: {}", sequence_number);
-        //TODO max gas amount from cli option or dry run estimate
+        let max_gas_amount = match max_gas_amount {
+            Some(max_gas_amount) => max_gas_amount,
+            None => {
+                self.estimate_max_gas_amount(
+                    &client,
+                    sender,
+                    sequence_number,
+                    chain_id,
+                    action.clone(),
+                )
+                .await?
+            }
+        };
+        self.check_gas_balance(&client, sender, chain_id, max_gas_amount)
+            .await?;
+        let tx_data =
+            RoochTransactionData::new(sender, sequence_number, chain_id, max_gas_amount, action);
+        Ok(tx_data)
+    }
+
+    /// Estimate the max gas amount of the action via dry run, the gas used is multiplied by the gas estimate multiplier,
+    /// and capped by the default max gas amount. Returns the error if the dry run fails.
+    async fn estimate_max_gas_amount(
+        &self,
+        client: &Client,
+        sender: RoochAddress,
+        sequence_number: u64,
+        chain_id: u64,
+        action: MoveAction,
+    ) -> RoochResult<u64> {
+        let multiplier = self.client_config.gas_estimate_multiplier()?;
         let tx_data = RoochTransactionData::new(
             sender,
             sequence_number,
             chain_id,
-            max_gas_amount.unwrap_or(GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT),
+            GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT,
             action,
         );
-        Ok(tx_data)
+        let dry_run_result = client
+            .rooch
+            .dry_run_tx(tx_data)
+            .await
+            .map_err(RoochError::from)?;
+        if dry_run_result.raw_output.status != KeptVMStatusView::Executed {
+            return Err(RoochError::TransactionError(format!(
+                "Estimate gas via dry run failed, status: {:?}, error: {}",
+                dry_run_result.raw_output.status, dry_run_result.vm_error_info.error_message
+            )));
+        }
+        let gas_used = dry_run_result.raw_output.gas_used.0;
+        let max_gas_amount = estimate_max_gas_amount(
+            gas_used,
+            multiplier,
+            GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT,
+        );
+        tracing::debug!(
+            "estimate gas used: {}, max_gas_amount: {}",
+            gas_used,
+            max_gas_amount
+        );
+        Ok(max_gas_amount)
+    }
+
+    /// Check the sender's gas coin balance can pay the max gas amount before submitting the transaction.
+    /// The gas balance is not checked in local or dev chain, keep the same as the transaction validator.
+    async fn check_gas_balance(
+        &self,
+        client: &Client,
+        sender: RoochAddress,
+        chain_id: u64,
+        max_gas_amount: u64,
+    ) -> RoochResult<()> {
+        if BuiltinChainID::try_from(chain_id)
+            .is_ok_and(|chain_id| chain_id.is_local() || chain_id.is_dev())
+        {
+            return Ok(());
+        }
+        let balance = client
+            .rooch
+            .get_balance(sender.into(), RGas::struct_tag().into())
+            .await
+            .map_err(RoochError::from)?
+            .balance
+            .0;
+        // The gas factor is 1, the gas fee is the same as the gas amount
+        let required = U256::from(max_gas_amount);
+        if balance < required {
+            return Err(RoochError::TransactionError(format!(
+                "Insufficient gas balance of {}, balance: {}, required: {}. Please get RGas from the faucet or set a lower `--max-gas-amount`",
+                sender, balance, required
+            )));
+        }
+        Ok(())
     }
 
     pub fn generate_session_key(&mut self, address: &RoochAddress) -> Result<AuthenticationKey> {
@@ -256,6 +344,21 @@ impl WalletContext {
         Ok(tx)
     }
 
+    pub fn sign_transaction_ethereum(
+        &self,
+        signer: RoochAddress,
+        tx_data: RoochTransactionData,
+        sign_type: EthereumSignType,
+    ) -> RoochResult<RoochTransaction> {
+        let tx = self.keystore.sign_transaction_ethereum(
+            &signer,
+            tx_data,
+            sign_type,
+            self.password.clone(),
+        )?;
+        Ok(tx)
+    }
+
     pub async fn execute(
         &self,
         tx: RoochTransaction,
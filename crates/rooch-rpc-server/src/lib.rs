@@ -18,14 +18,17 @@ use coerce::actor::{system::ActorSystem, IntoActor};
 use jsonrpsee::RpcModule;
 use moveos_eventbus::bus::EventBus;
 use raw_store::errors::RawStoreError;
-use rooch_config::da_config::derive_namespace_from_genesis;
+use rooch_config::da_config::{derive_namespace_from_genesis, DEFAULT_DA_SYNC_INTERVAL};
 use rooch_config::server_config::ServerConfig;
 use rooch_config::settings::PROPOSER_CHECK_INTERVAL;
 use rooch_config::{RoochOpt, ServerOpt};
 use rooch_da::actor::server::DAServerActor;
+use rooch_da::backend::DABackends;
 use rooch_da::proxy::DAServerProxy;
+use rooch_db::pruner::StatePruner;
 use rooch_db::RoochDB;
 use rooch_event::actor::EventActor;
+use rooch_event::event::TransactionIndexedEvent;
 use rooch_executor::actor::executor::ExecutorActor;
 use rooch_executor::actor::reader_executor::ReaderExecutorActor;
 use rooch_executor::proxy::ExecutorProxy;
@@ -34,9 +37,14 @@ use rooch_indexer::actor::indexer::IndexerActor;
 use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
 use rooch_indexer::proxy::IndexerProxy;
 use rooch_pipeline_processor::actor::processor::PipelineProcessorActor;
+use rooch_pipeline_processor::da_syncer::DASyncer;
 use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
+use rooch_pipeline_processor::tx_queue::{
+    TxQueue, DEFAULT_TX_QUEUE_CAPACITY, DEFAULT_TX_QUEUE_MAX_PER_SENDER,
+};
 use rooch_proposer::actor::messages::ProposeBlock;
 use rooch_proposer::actor::proposer::ProposerActor;
+use rooch_proposer::scc::submitter::BlockSubmitter;
 use rooch_relayer::actor::messages::RelayTick;
 use rooch_relayer::actor::relayer::RelayerActor;
 use rooch_rpc_api::api::RoochRpcModule;
@@ -47,6 +55,7 @@ use rooch_store::da_store::DAMetaStore;
 use rooch_types::address::RoochAddress;
 use rooch_types::error::{GenesisError, RoochError};
 use rooch_types::rooch_network::BuiltinChainID;
+use rooch_types::service_status::ServiceStatus;
 use rooch_types::service_type::ServiceType;
 use serde_json::json;
 use std::fmt::Debug;
@@ -57,6 +66,7 @@ use std::{env, panic, process};
 use tokio::signal;
 use tokio::sync::broadcast;
 use tokio::sync::broadcast::Sender;
+use tokio::sync::watch;
 use tower_governor::key_extractor::SmartIpKeyExtractor;
 use tower_governor::{governor::GovernorConfigBuilder, GovernorLayer};
 use tower_http::cors::{AllowOrigin, CorsLayer};
@@ -222,7 +232,14 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     let sequencer_account = sequencer_keypair.public().rooch_address()?;
     let sequencer_bitcoin_address = sequencer_keypair.public().bitcoin_address()?;
 
-    let service_status = opt.service_status;
+    let sync_from_da = opt.sync_from_da;
+    // Full node syncing from DA only applies transactions sequenced by the sequencer
+    let service_status = if sync_from_da {
+        info!("Sync from DA, the service is in read-only mode");
+        ServiceStatus::ReadOnlyMode
+    } else {
+        opt.service_status
+    };
 
     let mut network = opt.network();
     if network.chain_id == BuiltinChainID::Local.chain_id() {
@@ -231,7 +248,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         let rooch_dao_address = rooch_dao_bitcoin_address.to_rooch_address();
         println!("Rooch DAO address: {:?}", rooch_dao_address);
         println!("Rooch DAO Bitcoin address: {}", rooch_dao_bitcoin_address);
-    } else {
+    } else if !sync_from_da {
         ensure!(
             network.genesis_config.sequencer_account == sequencer_bitcoin_address,
             "Sequencer({:?}) in genesis config is not equal to sequencer({:?}) in cli config",
@@ -273,6 +290,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         root.clone(),
         moveos_store.clone(),
         rooch_store.clone(),
+        &prometheus_registry,
         Some(event_actor_ref.clone()),
     )?;
 
@@ -301,10 +319,18 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     // Init DA
     let genesis_hash = genesis.genesis_hash();
     let genesis_namespace = derive_namespace_from_genesis(genesis_hash);
-    let last_tx_order = sequencer_proxy.get_sequencer_order().await?;
-    let (da_issues, da_fixed) = rooch_store.try_repair_da_meta(last_tx_order, false)?;
-    info!("DA meta issues: {:?}, fixed: {:?}", da_issues, da_fixed);
-    let da_config = opt.da_config().clone();
+    let mut da_config = opt.da_config().clone();
+    // DA meta is synced from DA backends when syncing from DA, blocks must not be re-made or submitted
+    let sync_da_backends = if sync_from_da {
+        let da_backend = da_config.da_backend.take();
+        Some(DABackends::initialize(da_backend, genesis_namespace.clone()).await?)
+    } else {
+        let last_tx_order = sequencer_proxy.get_sequencer_order().await?;
+        let (da_issues, da_fixed) = rooch_store.try_repair_da_meta(last_tx_order, false)?;
+        info!("DA meta issues: {:?}, fixed: {:?}", da_issues, da_fixed);
+        None
+    };
+    let da_sync_interval = da_config.sync_interval.unwrap_or(DEFAULT_DA_SYNC_INTERVAL);
     let da_proxy = DAServerProxy::new(
         DAServerActor::new(
             da_config,
@@ -319,31 +345,8 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         .into(),
     );
 
-    // Init proposer
-    let proposer_keypair = server_opt.proposer_keypair.unwrap();
-    let proposer_account: RoochAddress = proposer_keypair.public().rooch_address()?;
-    info!("RPC Server proposer address: {:?}", proposer_account);
-    let proposer = ProposerActor::new(
-        proposer_keypair,
-        moveos_store,
-        rooch_store,
-        &prometheus_registry,
-        opt.proposer.clone(),
-    )?
-    .into_actor(Some("Proposer"), &actor_system)
-    .await?;
-    let block_propose_duration_in_seconds: u64 =
-        opt.proposer.interval.unwrap_or(PROPOSER_CHECK_INTERVAL);
-    let mut timers = vec![];
-    let proposer_timer = Timer::start(
-        proposer,
-        Duration::from_secs(block_propose_duration_in_seconds),
-        ProposeBlock {},
-    );
-    timers.push(proposer_timer);
-
     // Init indexer
-    let indexer_executor = IndexerActor::new(root, indexer_store)?
+    let indexer_executor = IndexerActor::new(root, indexer_store, Some(event_actor_ref.clone()))?
         .into_actor(Some("Indexer"), &actor_system)
         .await?;
     let indexer_reader_executor = IndexerReaderActor::new(indexer_reader)?
@@ -359,16 +362,18 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
             btc_rpc_password: config.btc_rpc_password.clone(),
             local_block_store_dir: Some(config.btc_reorg_aware_block_store_dir.clone()), // this client will be used for startup processing, may need reorg blocks
         });
-    let bitcoin_client_proxy = if service_status.is_active() && bitcoin_client_config.is_some() {
-        let bitcoin_client = bitcoin_client_config.unwrap().build()?;
-        let bitcoin_client_actor_ref = bitcoin_client
-            .into_actor(Some("bitcoin_client_for_rpc_service"), &actor_system)
-            .await?;
-        let bitcoin_client_proxy = BitcoinClientProxy::new(bitcoin_client_actor_ref.into());
-        Some(bitcoin_client_proxy)
-    } else {
-        None
-    };
+    // Full node syncing from DA needs bitcoin client to fetch L1 block body
+    let bitcoin_client_proxy =
+        if (service_status.is_active() || sync_from_da) && bitcoin_client_config.is_some() {
+            let bitcoin_client = bitcoin_client_config.unwrap().build()?;
+            let bitcoin_client_actor_ref = bitcoin_client
+                .into_actor(Some("bitcoin_client_for_rpc_service"), &actor_system)
+                .await?;
+            let bitcoin_client_proxy = BitcoinClientProxy::new(bitcoin_client_actor_ref.into());
+            Some(bitcoin_client_proxy)
+        } else {
+            None
+        };
 
     let mut processor = PipelineProcessorActor::new(
         executor_proxy.clone(),
@@ -380,17 +385,118 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         Some(event_actor_ref.clone()),
         rooch_db,
         bitcoin_client_proxy.clone(),
+        opt.parallel_execution_window.unwrap_or_default(),
     );
 
     // Only process sequenced tx on startup when service is active
     if service_status.is_active() {
         processor.process_sequenced_tx_on_startup().await?;
+    } else if sync_from_da {
+        processor.process_applied_tx_on_startup().await?;
     }
+    // The subscriptions are notified with the tx_order of the latest indexed transaction
+    let last_tx_order = sequencer_proxy.get_sequencer_order().await?;
+    let (indexed_tx_order_sender, indexed_tx_order) = watch::channel(last_tx_order);
+    event_bus.callback_subscribe::<TransactionIndexedEvent, _>(
+        "RpcSubscription",
+        move |event| {
+            if let Ok(event) = event.downcast::<TransactionIndexedEvent>() {
+                indexed_tx_order_sender.send_if_modified(|tx_order| {
+                    let modified = event.tx_order > *tx_order;
+                    if modified {
+                        *tx_order = event.tx_order;
+                    }
+                    modified
+                });
+            }
+        },
+    )?;
+
     let processor_actor = processor
         .into_actor(Some("PipelineProcessor"), &actor_system)
         .await?;
     let processor_proxy = PipelineProcessorProxy::new(processor_actor.into());
 
+    if let Some(da_backends) = sync_da_backends {
+        let da_syncer = DASyncer::new(
+            da_backends,
+            network.genesis_config.sequencer_account.clone(),
+            processor_proxy.clone(),
+            sequencer_proxy.clone(),
+            da_proxy.clone(),
+            da_sync_interval,
+            &prometheus_registry,
+        )?;
+        da_syncer.run(shutdown_tx.subscribe());
+        info!("DA syncer started");
+    }
+
+    // Init state pruner
+    if opt.pruner.enable {
+        let pruner = StatePruner::new(
+            moveos_store.clone(),
+            rooch_store.clone(),
+            opt.pruner.keep_recent_roots(),
+            opt.pruner.pinned_roots.clone(),
+            opt.pruner.batch_size(),
+        );
+        let pruner_interval = Duration::from_secs(opt.pruner.interval());
+        let mut pruner_rx = shutdown_tx.subscribe();
+        tokio::spawn(async move {
+            let mut tick = tokio::time::interval(pruner_interval);
+            loop {
+                tokio::select! {
+                    _ = pruner_rx.recv() => {
+                        info!("State pruner received cancel signal, stopping.");
+                        break;
+                    }
+                    _ = tick.tick() => {
+                        let pruner = pruner.clone();
+                        match tokio::task::spawn_blocking(move || pruner.prune_round()).await {
+                            Ok(Ok(result)) => info!("State pruner round finished: {:?}", result),
+                            Ok(Err(e)) => error!("State pruner round error: {:?}", e),
+                            Err(e) => error!("State pruner task error: {:?}", e),
+                        }
+                    }
+                }
+            }
+        });
+        info!("State pruner started");
+    }
+
+    // Init proposer
+    let proposer_keypair = server_opt.proposer_keypair.unwrap();
+    let proposer_account: RoochAddress = proposer_keypair.public().rooch_address()?;
+    info!("RPC Server proposer address: {:?}", proposer_account);
+    // Blocks are submitted to the on-chain SCC only by the active sequencer node
+    let block_submitter = if opt.proposer.submit_to_chain && service_status.is_active() {
+        Some(BlockSubmitter::new(
+            executor_proxy.clone(),
+            processor_proxy.clone(),
+        ))
+    } else {
+        None
+    };
+    let proposer = ProposerActor::new(
+        proposer_keypair,
+        moveos_store,
+        rooch_store,
+        &prometheus_registry,
+        opt.proposer.clone(),
+        block_submitter,
+    )?
+    .into_actor(Some("Proposer"), &actor_system)
+    .await?;
+    let block_propose_duration_in_seconds: u64 =
+        opt.proposer.interval.unwrap_or(PROPOSER_CHECK_INTERVAL);
+    let mut timers = vec![];
+    let proposer_timer = Timer::start(
+        proposer,
+        Duration::from_secs(block_propose_duration_in_seconds),
+        ProposeBlock {},
+    );
+    timers.push(proposer_timer);
+
     let ethereum_relayer_config = opt.ethereum_relayer_config();
 
     if service_status.is_active()
@@ -415,6 +521,15 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         timers.push(relayer_timer);
     }
 
+    let tx_queue = TxQueue::start(
+        processor_proxy.clone(),
+        executor_proxy.clone(),
+        opt.tx_queue_capacity.unwrap_or(DEFAULT_TX_QUEUE_CAPACITY),
+        opt.tx_queue_max_per_sender
+            .unwrap_or(DEFAULT_TX_QUEUE_MAX_PER_SENDER),
+        shutdown_tx.subscribe(),
+    );
+
     let rpc_service = RpcService::new(
         network.chain_id.id,
         network.genesis_config.bitcoin_network,
@@ -424,6 +539,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         processor_proxy,
         bitcoin_client_proxy,
         da_proxy,
+        tx_queue,
     );
     let aggregate_service = AggregateService::new(rpc_service.clone());
 
@@ -510,6 +626,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     rpc_module_builder.register_module(RoochServer::new(
         rpc_service.clone(),
         aggregate_service.clone(),
+        indexed_tx_order,
     ))?;
     rpc_module_builder.register_module(BtcServer::new(rpc_service.clone()).await?)?;
     rpc_module_builder
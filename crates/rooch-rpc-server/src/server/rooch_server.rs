@@ -4,7 +4,9 @@
 use crate::service::aggregate_service::AggregateService;
 use crate::service::rpc_service::RpcService;
 use anyhow::Result;
-use jsonrpsee::{core::async_trait, RpcModule};
+use jsonrpsee::core::{async_trait, SubscriptionResult};
+use jsonrpsee::{PendingSubscriptionSink, RpcModule, SubscriptionMessage, SubscriptionSink};
+use move_core_types::effects::Op;
 use move_core_types::{
     account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
 };
@@ -12,24 +14,28 @@ use moveos_types::{
     access_path::AccessPath,
     h256::H256,
     move_std::string::MoveString,
+    moveos_std::object::is_dynamic_field_type,
     moveos_std::{move_module::MoveModule, object::ObjectID},
-    state::{AnnotatedState, FieldKey},
+    state::{AnnotatedState, FieldKey, ObjectChange},
 };
+use rooch_executor::actor::messages::{TraceTransactionResult, TracedObjectState};
+use rooch_pipeline_processor::tx_queue::TxQueueStatus;
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView,
     event_view::{EventFilterView, EventView, IndexerEventIDView, IndexerEventView},
-    transaction_view::{TransactionFilterView, TransactionWithInfoView},
+    transaction_view::{TransactionFilterView, TransactionStatusView, TransactionWithInfoView},
     AccessPathView, BalanceInfoPageView, DryRunTransactionResponseView, EventOptions,
     EventPageView, ExecuteTransactionResponseView, FunctionCallView, H256View,
-    IndexerEventPageView, IndexerObjectStatePageView, IndexerStateIDView, ModuleABIView,
-    ObjectIDVecView, ObjectStateFilterView, ObjectStateView, QueryOptions,
-    RawTransactionOutputView, RoochAddressView, StateChangeSetPageView,
-    StateChangeSetWithTxOrderView, StateKVView, StateOptions, StatePageView, StrView,
-    StructTagView, SyncStateFilterView, TransactionWithInfoPageView, TxOptions, UnitedAddressView,
+    IndexerEventPageView, IndexerObjectChangeView, IndexerObjectStatePageView, IndexerStateIDView,
+    KeptVMStatusView, ModuleABIView, ObjectIDVecView, ObjectStateFilterView, ObjectStateView,
+    QueryOptions, RawTransactionOutputView, RoochAddressView, StateChangeSetPageView,
+    StateChangeSetWithTxOrderView, StateKVView, StateOptions, StatePageView, StateWithProofView,
+    StrView, StructTagView, SyncStateFilterView, TraceTransactionResponseView,
+    TransactionWithInfoPageView, TxOptions, UnitedAddressView,
 };
 use rooch_rpc_api::jsonrpc_types::{
     repair_view::{RepairIndexerParamsView, RepairIndexerTypeView},
-    Status,
+    ObjectChangeTraceView, Status,
 };
 use rooch_rpc_api::{
     api::rooch_api::RoochAPIServer,
@@ -40,22 +46,176 @@ use rooch_rpc_api::{
     jsonrpc_types::BytesView,
     RpcError, RpcResult,
 };
-use rooch_types::indexer::state::{IndexerStateID, ObjectStateType};
+use rooch_types::indexer::event::{EventFilter, IndexerEventID};
+use rooch_types::indexer::state::{
+    IndexerObjectState, IndexerObjectStatesIndexGenerator, IndexerStateID, ObjectStateFilter,
+    ObjectStateType,
+};
+use rooch_types::indexer::transaction::TransactionFilter;
+use rooch_types::indexer::Filter;
+use rooch_types::state::{StateChangeSetWithTxOrder, SyncStateFilter};
 use rooch_types::transaction::{RoochTransaction, RoochTransactionData, TransactionWithInfo};
 use std::cmp::min;
 use std::str::FromStr;
+use tokio::sync::watch;
 use tracing::{debug, info};
 
+/// The max number of transactions fetched in one round of a subscription
+const SUBSCRIPTION_BATCH_SIZE: usize = 100;
+
 pub struct RoochServer {
     rpc_service: RpcService,
     aggregate_service: AggregateService,
+    // The tx_order of the latest indexed transaction, the subscriptions are waked up when it changes
+    indexed_tx_order: watch::Receiver<u64>,
 }
 
 impl RoochServer {
-    pub fn new(rpc_service: RpcService, aggregate_service: AggregateService) -> Self {
+    pub fn new(
+        rpc_service: RpcService,
+        aggregate_service: AggregateService,
+        indexed_tx_order: watch::Receiver<u64>,
+    ) -> Self {
         Self {
             rpc_service,
             aggregate_service,
+            indexed_tx_order,
+        }
+    }
+
+    /// Wait for the next indexed transaction, returns false if the subscription is closed.
+    async fn wait_for_indexed(
+        indexed_tx_order: &mut watch::Receiver<u64>,
+        sink: &SubscriptionSink,
+    ) -> bool {
+        tokio::select! {
+            _ = sink.closed() => false,
+            changed = indexed_tx_order.changed() => changed.is_ok(),
+        }
+    }
+
+    // Each subscription fetches the items after its own cursor from the indexer,
+    // and `sink.send` waits if the client can not keep up, so a slow client only falls behind.
+    async fn run_transactions_subscription(
+        &self,
+        sink: SubscriptionSink,
+        filter: TransactionFilter,
+        mut cursor: u64,
+    ) -> Result<()> {
+        let mut indexed_tx_order = self.indexed_tx_order.clone();
+        loop {
+            let _ = indexed_tx_order.borrow_and_update();
+            loop {
+                let txs = self
+                    .rpc_service
+                    .query_transactions(
+                        filter.clone(),
+                        Some(cursor),
+                        SUBSCRIPTION_BATCH_SIZE,
+                        false,
+                    )
+                    .await?;
+                let Some(last_tx) = txs.last() else {
+                    break;
+                };
+                cursor = last_tx.tx_order;
+                let fetched = txs.len();
+                let data = self
+                    .aggregate_service
+                    .build_transaction_with_infos(txs)
+                    .await?;
+                for tx in self.transactions_to_view(data).await? {
+                    if sink
+                        .send(SubscriptionMessage::from_json(&tx)?)
+                        .await
+                        .is_err()
+                    {
+                        return Ok(());
+                    }
+                }
+                if fetched < SUBSCRIPTION_BATCH_SIZE {
+                    break;
+                }
+            }
+            if !Self::wait_for_indexed(&mut indexed_tx_order, &sink).await {
+                return Ok(());
+            }
+        }
+    }
+
+    async fn run_events_subscription(
+        &self,
+        sink: SubscriptionSink,
+        filter: EventFilter,
+        mut cursor: Option<IndexerEventID>,
+    ) -> Result<()> {
+        let mut indexed_tx_order = self.indexed_tx_order.clone();
+        loop {
+            let _ = indexed_tx_order.borrow_and_update();
+            loop {
+                let events = self
+                    .rpc_service
+                    .query_events(filter.clone(), cursor, SUBSCRIPTION_BATCH_SIZE, false)
+                    .await?;
+                let Some(last_event) = events.last() else {
+                    break;
+                };
+                cursor = Some(last_event.indexer_event_id);
+                let fetched = events.len();
+                for event in events {
+                    let event = IndexerEventView::from(event);
+                    if sink
+                        .send(SubscriptionMessage::from_json(&event)?)
+                        .await
+                        .is_err()
+                    {
+                        return Ok(());
+                    }
+                }
+                if fetched < SUBSCRIPTION_BATCH_SIZE {
+                    break;
+                }
+            }
+            if !Self::wait_for_indexed(&mut indexed_tx_order, &sink).await {
+                return Ok(());
+            }
+        }
+    }
+
+    // The indexer only keeps the latest object states, so the object changes are read from the
+    // state change set of each transaction.
+    async fn run_object_changes_subscription(
+        &self,
+        sink: SubscriptionSink,
+        filter: ObjectStateFilter,
+        mut cursor: u64,
+    ) -> Result<()> {
+        let mut indexed_tx_order = self.indexed_tx_order.clone();
+        loop {
+            let latest_tx_order = *indexed_tx_order.borrow_and_update();
+            while cursor < latest_tx_order {
+                let end = min(cursor + SUBSCRIPTION_BATCH_SIZE as u64, latest_tx_order);
+                let tx_orders = (cursor + 1..=end).collect::<Vec<_>>();
+                let state_change_sets = self
+                    .rpc_service
+                    .sync_states(tx_orders, SyncStateFilter::All)
+                    .await?;
+                for state_change_set in state_change_sets {
+                    for object_change in collect_object_changes(&filter, state_change_set) {
+                        if sink
+                            .send(SubscriptionMessage::from_json(&object_change)?)
+                            .await
+                            .is_err()
+                        {
+                            return Ok(());
+                        }
+                    }
+                }
+                cursor = end;
+            }
+            if !Self::wait_for_indexed(&mut indexed_tx_order, &sink).await {
+                return Ok(());
+            }
         }
     }
 
@@ -109,8 +269,7 @@ impl RoochAPIServer for RoochServer {
             tx.tx_hash()
         );
 
-        let hash = tx.tx_hash();
-        self.rpc_service.queue_tx(tx).await?;
+        let hash = self.rpc_service.queue_tx(tx).await?;
         Ok(hash.into())
     }
 
@@ -182,13 +341,32 @@ impl RoochAPIServer for RoochServer {
         Ok(tx_response)
     }
 
+    async fn trace_transaction(
+        &self,
+        tx_hash: H256View,
+    ) -> RpcResult<Option<TraceTransactionResponseView>> {
+        Ok(self
+            .rpc_service
+            .trace_tx(tx_hash.into())
+            .await?
+            .map(trace_result_to_view))
+    }
+
+    async fn trace_dry_run(&self, payload: BytesView) -> RpcResult<TraceTransactionResponseView> {
+        let tx = bcs::from_bytes::<RoochTransactionData>(&payload.0)?;
+        let result = self.rpc_service.trace_dry_run_tx(tx).await?;
+        Ok(trace_result_to_view(result))
+    }
+
     async fn execute_view_function(
         &self,
         function_call: FunctionCallView,
+        caller: Option<UnitedAddressView>,
     ) -> RpcResult<AnnotatedFunctionResultView> {
+        let caller = caller.map(Into::into);
         Ok(self
             .rpc_service
-            .execute_view_function(function_call.into())
+            .execute_view_function(function_call.into(), caller)
             .await?
             .into())
     }
@@ -248,6 +426,21 @@ impl RoochAPIServer for RoochServer {
         Ok(state_views)
     }
 
+    async fn get_states_with_proof(
+        &self,
+        access_path: AccessPathView,
+        state_root: Option<H256View>,
+    ) -> RpcResult<Vec<StateWithProofView>> {
+        access_path.0.validate_max_object_ids()?;
+        Ok(self
+            .rpc_service
+            .get_states_with_proof(access_path.into(), state_root.map(|h256_view| h256_view.0))
+            .await?
+            .into_iter()
+            .map(StateWithProofView::from)
+            .collect())
+    }
+
     async fn list_states(
         &self,
         access_path: AccessPathView,
@@ -494,6 +687,40 @@ impl RoochAPIServer for RoochServer {
         Ok(data)
     }
 
+    async fn get_transaction_status(
+        &self,
+        tx_hash: H256View,
+    ) -> RpcResult<Option<TransactionStatusView>> {
+        let tx_hash: H256 = tx_hash.into();
+        let tx_with_info = self
+            .aggregate_service
+            .get_transaction_with_info(vec![tx_hash])
+            .await?
+            .pop()
+            .flatten();
+        if let Some(tx_with_info) = tx_with_info {
+            let tx_order = tx_with_info.transaction.sequence_info.tx_order.into();
+            let status = match tx_with_info.execution_info {
+                Some(execution_info) => TransactionStatusView::Executed {
+                    tx_order,
+                    status: KeptVMStatusView::from(execution_info.status),
+                },
+                None => TransactionStatusView::Sequenced { tx_order },
+            };
+            return Ok(Some(status));
+        }
+
+        let status =
+            self.rpc_service
+                .get_tx_queue_status(tx_hash)
+                .await
+                .map(|status| match status {
+                    TxQueueStatus::Pending => TransactionStatusView::Pending,
+                    TxQueueStatus::Failed(error) => TransactionStatusView::Failed { error },
+                });
+        Ok(status)
+    }
+
     async fn get_transactions_by_order(
         &self,
         cursor: Option<StrView<u64>>,
@@ -858,6 +1085,73 @@ impl RoochAPIServer for RoochServer {
         let status = self.rpc_service.status().await?;
         Ok(status)
     }
+
+    async fn subscribe_transactions(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: TransactionFilterView,
+        cursor: Option<StrView<u64>>,
+    ) -> SubscriptionResult {
+        let cursor = cursor
+            .map(|v| v.0)
+            .unwrap_or_else(|| *self.indexed_tx_order.borrow());
+        let sink = pending.accept().await?;
+        self.run_transactions_subscription(sink, filter.into(), cursor)
+            .await?;
+        Ok(())
+    }
+
+    async fn subscribe_events(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: EventFilterView,
+        cursor: Option<IndexerEventIDView>,
+    ) -> SubscriptionResult {
+        let filter: EventFilter = filter.into();
+        let cursor = match cursor {
+            Some(cursor) => Some(cursor.into()),
+            // start after the latest event matching the filter
+            None => match self
+                .rpc_service
+                .query_events(filter.clone(), None, 1, true)
+                .await
+            {
+                Ok(mut events) => events.pop().map(|event| event.indexer_event_id),
+                Err(e) => {
+                    pending.reject(RpcError::from(e)).await;
+                    return Ok(());
+                }
+            },
+        };
+        let sink = pending.accept().await?;
+        self.run_events_subscription(sink, filter, cursor).await?;
+        Ok(())
+    }
+
+    async fn subscribe_object_changes(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: ObjectStateFilterView,
+        cursor: Option<StrView<u64>>,
+    ) -> SubscriptionResult {
+        let filter = match ObjectStateFilterView::try_into_object_state_filter(
+            filter,
+            QueryOptions::default(),
+        ) {
+            Ok(filter) => filter,
+            Err(e) => {
+                pending.reject(RpcError::from(e)).await;
+                return Ok(());
+            }
+        };
+        let cursor = cursor
+            .map(|v| v.0)
+            .unwrap_or_else(|| *self.indexed_tx_order.borrow());
+        let sink = pending.accept().await?;
+        self.run_object_changes_subscription(sink, filter, cursor)
+            .await?;
+        Ok(())
+    }
 }
 
 impl RoochRpcModule for RoochServer {
@@ -865,3 +1159,88 @@ impl RoochRpcModule for RoochServer {
         self.into_rpc()
     }
 }
+
+/// Collect the object changes matching the filter in the state change set,
+/// the state index is generated in the same way as the indexer.
+fn collect_object_changes(
+    filter: &ObjectStateFilter,
+    state_change_set: StateChangeSetWithTxOrder,
+) -> Vec<IndexerObjectChangeView> {
+    let mut state_index_generator = IndexerObjectStatesIndexGenerator::default();
+    let mut object_changes = vec![];
+    for (_field_key, object_change) in state_change_set.state_change_set.changes {
+        collect_object_change(
+            filter,
+            &mut state_index_generator,
+            state_change_set.tx_order,
+            object_change,
+            &mut object_changes,
+        );
+    }
+    object_changes
+}
+
+fn collect_object_change(
+    filter: &ObjectStateFilter,
+    state_index_generator: &mut IndexerObjectStatesIndexGenerator,
+    tx_order: u64,
+    object_change: ObjectChange,
+    object_changes: &mut Vec<IndexerObjectChangeView>,
+) {
+    let ObjectChange {
+        metadata,
+        value,
+        fields,
+    } = object_change;
+    // Do not index dynamic field object
+    if is_dynamic_field_type(&metadata.object_type) {
+        return;
+    }
+    let state_index = state_index_generator.get(&metadata.object_type);
+    state_index_generator.incr(&metadata.object_type);
+
+    let state = IndexerObjectState::new(metadata, tx_order, state_index);
+    if filter.matches(&state) {
+        let indexer_id = state.indexer_state_id();
+        object_changes.push(IndexerObjectChangeView::new(
+            state.metadata,
+            value,
+            indexer_id,
+        ));
+    }
+    for (_key, change) in fields {
+        collect_object_change(
+            filter,
+            state_index_generator,
+            tx_order,
+            change,
+            object_changes,
+        );
+    }
+}
+
+fn trace_result_to_view(result: TraceTransactionResult) -> TraceTransactionResponseView {
+    let state_view = |state: TracedObjectState| match state {
+        TracedObjectState::Decoded(state) => ObjectStateView::from(state),
+        TracedObjectState::Raw(state) => ObjectStateView::from(state),
+    };
+    let raw_output = result.raw_output;
+    TraceTransactionResponseView {
+        status: raw_output.status.into(),
+        gas_used: raw_output.gas_used.into(),
+        execution_gas_used: result.execution_gas_used.into(),
+        storage_gas_used: result.storage_gas_used.into(),
+        vm_error_info: result.vm_error_info,
+        call_trace: result.call_trace.into(),
+        events: raw_output.events.into_iter().map(Into::into).collect(),
+        object_changes: result
+            .object_changes
+            .into_iter()
+            .map(|change| ObjectChangeTraceView {
+                object_id: change.object_id.into(),
+                before: change.before.map(state_view),
+                after: change.after.map(state_view),
+            })
+            .collect(),
+    }
+}
@@ -15,13 +15,15 @@ use moveos_types::moveos_std::display::{get_object_display_id, RawDisplay};
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
 use moveos_types::moveos_std::object::{ObjectID, MAX_OBJECT_IDS_PER_QUERY};
 use moveos_types::state::{AnnotatedState, FieldKey, ObjectState, StateChangeSet};
+use moveos_types::state_proof::StateWithProof;
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
 use moveos_types::transaction::{FunctionCall, TransactionExecutionInfo};
 use rooch_da::proxy::DAServerProxy;
-use rooch_executor::actor::messages::DryRunTransactionResult;
+use rooch_executor::actor::messages::{DryRunTransactionResult, TraceTransactionResult};
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_indexer::proxy::IndexerProxy;
 use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
+use rooch_pipeline_processor::tx_queue::{TxQueue, TxQueueStatus};
 use rooch_rpc_api::jsonrpc_types::{
     BitcoinStatus, DisplayFieldsView, IndexerObjectStateView, ObjectMetaView, RoochStatus, Status,
 };
@@ -58,6 +60,7 @@ pub struct RpcService {
     pub(crate) pipeline_processor: PipelineProcessorProxy,
     pub(crate) bitcoin_client: Option<BitcoinClientProxy>,
     pub(crate) da_server: DAServerProxy,
+    pub(crate) tx_queue: TxQueue,
 }
 
 impl RpcService {
@@ -70,6 +73,7 @@ impl RpcService {
         pipeline_processor: PipelineProcessorProxy,
         bitcoin_client: Option<BitcoinClientProxy>,
         da_server: DAServerProxy,
+        tx_queue: TxQueue,
     ) -> Self {
         Self {
             chain_id,
@@ -80,6 +84,7 @@ impl RpcService {
             pipeline_processor,
             bitcoin_client,
             da_server,
+            tx_queue,
         }
     }
 }
@@ -93,10 +98,13 @@ impl RpcService {
         self.bitcoin_network
     }
 
-    pub async fn queue_tx(&self, tx: RoochTransaction) -> Result<()> {
-        //TODO implement queue tx and do not wait to execute
-        let _ = self.execute_tx(tx).await?;
-        Ok(())
+    /// Queue the transaction and return the tx hash, the transaction is executed in the background.
+    pub async fn queue_tx(&self, tx: RoochTransaction) -> Result<H256> {
+        self.tx_queue.submit(tx).await
+    }
+
+    pub async fn get_tx_queue_status(&self, tx_hash: H256) -> Option<TxQueueStatus> {
+        self.tx_queue.get_status(tx_hash).await
     }
 
     pub async fn execute_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponse> {
@@ -108,16 +116,58 @@ impl RpcService {
         self.executor.dry_run_transaction(verified_tx).await
     }
 
+    pub async fn trace_dry_run_tx(
+        &self,
+        tx: RoochTransactionData,
+    ) -> Result<TraceTransactionResult> {
+        let verified_tx = self.executor.convert_to_verified_tx(tx).await?;
+        self.executor.trace_transaction(verified_tx).await
+    }
+
+    /// Trace the executed transaction against the state after the previous transaction,
+    /// returns None if the transaction is not found.
+    pub async fn trace_tx(&self, tx_hash: H256) -> Result<Option<TraceTransactionResult>> {
+        let Some(ledger_tx) = self.get_transaction_by_hash(tx_hash).await? else {
+            return Ok(None);
+        };
+        let tx_order = ledger_tx.sequence_info.tx_order;
+        if tx_order == 0 {
+            return Err(format_err!("The genesis transaction can not be traced"));
+        }
+        let prev_tx_hash = self
+            .get_tx_hashes(vec![tx_order - 1])
+            .await?
+            .pop()
+            .flatten()
+            .ok_or_else(|| format_err!("tx hash not found for tx order {}", tx_order - 1))?;
+        let pre_root = self
+            .get_transaction_execution_infos_by_hash(vec![prev_tx_hash])
+            .await?
+            .pop()
+            .flatten()
+            .ok_or_else(|| format_err!("execution info not found for tx {:?}", prev_tx_hash))?
+            .root_metadata();
+        let result = self
+            .executor
+            .trace_ledger_transaction(ledger_tx, pre_root)
+            .await?;
+        Ok(Some(result))
+    }
+
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
+        caller: Option<AccountAddress>,
     ) -> Result<AnnotatedFunctionResult> {
         let module_id = function_call.function_id.module_id.clone();
         if !self.exists_module(module_id.clone()).await? {
             return Err(anyhow::anyhow!("Module does not exist: {}", module_id));
         }
 
-        let resp = self.executor.execute_view_function(function_call).await?;
+        let resp = self
+            .executor
+            .execute_view_function_with_caller(function_call, caller)
+            .await?;
         Ok(resp)
     }
 
@@ -129,6 +179,16 @@ impl RpcService {
         self.executor.get_states(access_path, state_root).await
     }
 
+    pub async fn get_states_with_proof(
+        &self,
+        access_path: AccessPath,
+        state_root: Option<H256>,
+    ) -> Result<Vec<StateWithProof>> {
+        self.executor
+            .get_states_with_proof(access_path, state_root)
+            .await
+    }
+
     pub async fn exists_module(&self, module_id: ModuleId) -> Result<bool> {
         let mut resp = self
             .get_states(AccessPath::module(&module_id), None)
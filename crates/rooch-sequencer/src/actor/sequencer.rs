@@ -6,8 +6,9 @@ use std::sync::Arc;
 use std::time::SystemTime;
 
 use crate::messages::{
-    GetSequencerInfoMessage, GetSequencerOrderMessage, GetTransactionByHashMessage,
-    GetTransactionsByHashMessage, GetTxHashsMessage, TransactionSequenceMessage,
+    ApplySequencedTransactionMessage, GetSequencerInfoMessage, GetSequencerOrderMessage,
+    GetTransactionByHashMessage, GetTransactionsByHashMessage, GetTxHashsMessage,
+    TransactionSequenceMessage,
 };
 use crate::metrics::SequencerMetrics;
 use accumulator::{Accumulator, MerkleAccumulator};
@@ -168,6 +169,54 @@ impl SequencerActor {
 
         Ok(tx)
     }
+
+    /// Apply a transaction sequenced by the remote sequencer (e.g. synced from DA) to local store.
+    /// The tx must be the next one of local, and the tx accumulator root after appending it
+    /// must be the same as the one recorded in the tx, otherwise local has diverged from the sequencer.
+    pub fn apply_sequenced(&mut self, mut tx: LedgerTransaction) -> Result<()> {
+        let tx_order = tx.sequence_info.tx_order;
+        let exp_tx_order = self.last_sequencer_info.last_order + 1;
+        if tx_order != exp_tx_order {
+            return Err(anyhow::anyhow!(
+                "tx order mismatched, expect: {}, actual: {}",
+                exp_tx_order,
+                tx_order
+            ));
+        }
+
+        let tx_hash = tx.tx_hash();
+        let tx_accumulator_root = self.tx_accumulator.append(vec![tx_hash].as_slice())?;
+        let tx_accumulator_info = self.tx_accumulator.get_info();
+        if tx_accumulator_info != tx.sequence_info.tx_accumulator_info() {
+            // drop the unsaved nodes, keep accumulator same as the last saved one
+            self.tx_accumulator = self
+                .tx_accumulator
+                .fork(Some(self.last_sequencer_info.last_accumulator_info.clone()));
+            return Err(anyhow::anyhow!(
+                "tx accumulator diverged at tx_order: {}, tx_hash: {}, expect root: {:?}, actual root: {:?}",
+                tx_order,
+                tx_hash,
+                tx.sequence_info.tx_accumulator_root,
+                tx_accumulator_root
+            ));
+        }
+        let tx_accumulator_unsaved_nodes = self.tx_accumulator.pop_unsaved_nodes();
+
+        let sequencer_info = SequencerInfo::new(tx_order, tx_accumulator_info);
+        self.rooch_store.save_sequenced_tx(
+            tx_hash,
+            tx,
+            sequencer_info.clone(),
+            tx_accumulator_unsaved_nodes,
+        )?;
+        info!(
+            "sequencer applied tx_hash: {} tx_order: {:?}",
+            tx_hash, tx_order
+        );
+        self.last_sequencer_info = sequencer_info;
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -191,6 +240,17 @@ impl Handler<TransactionSequenceMessage> for SequencerActor {
     }
 }
 
+#[async_trait]
+impl Handler<ApplySequencedTransactionMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: ApplySequencedTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        self.apply_sequenced(msg.tx)
+    }
+}
+
 #[async_trait]
 impl Handler<GetTransactionByHashMessage> for SequencerActor {
     async fn handle(
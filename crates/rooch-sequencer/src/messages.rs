@@ -20,6 +20,16 @@ impl Message for TransactionSequenceMessage {
     type Result = Result<LedgerTransaction>;
 }
 
+/// Apply a transaction which has already been sequenced by the sequencer, e.g. synced from DA
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApplySequencedTransactionMessage {
+    pub tx: LedgerTransaction,
+}
+
+impl Message for ApplySequencedTransactionMessage {
+    type Result = Result<()>;
+}
+
 /// Get Transaction By Hash Message
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetTransactionByHashMessage {
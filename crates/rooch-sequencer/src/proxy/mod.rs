@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::messages::{
-    GetSequencerInfoMessage, GetSequencerOrderMessage, GetTransactionByHashMessage,
-    GetTransactionsByHashMessage, GetTxHashsMessage,
+    ApplySequencedTransactionMessage, GetSequencerInfoMessage, GetSequencerOrderMessage,
+    GetTransactionByHashMessage, GetTransactionsByHashMessage, GetTxHashsMessage,
 };
 use crate::{actor::sequencer::SequencerActor, messages::TransactionSequenceMessage};
 use anyhow::Result;
@@ -26,6 +26,12 @@ impl SequencerProxy {
         self.actor.send(TransactionSequenceMessage { tx }).await?
     }
 
+    pub async fn apply_sequenced_transaction(&self, tx: LedgerTransaction) -> Result<()> {
+        self.actor
+            .send(ApplySequencedTransactionMessage { tx })
+            .await?
+    }
+
     pub async fn get_transaction_by_hash(&self, hash: H256) -> Result<Option<LedgerTransaction>> {
         self.actor
             .send(GetTransactionByHashMessage { hash })
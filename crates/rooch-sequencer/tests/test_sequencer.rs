@@ -137,3 +137,50 @@ async fn test_sequencer_concurrent() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_sequencer_apply_sequenced() -> Result<()> {
+    let registry_service = RegistryService::default();
+    let opt = RoochOpt::new_with_temp_store()?;
+    let rooch_db = init_rooch_db(&opt, &registry_service.default_registry())?;
+    let mut sequencer = SequencerActor::new(
+        RoochKeyPair::generate_secp256k1(),
+        rooch_db.rooch_store,
+        ServiceStatus::Active,
+        &registry_service.default_registry(),
+        None,
+    )?;
+    let mut ledger_txs = vec![];
+    for _ in 0..5 {
+        let tx_data = LedgerTxData::L2Tx(RoochTransaction::mock());
+        ledger_txs.push(sequencer.sequence(tx_data)?);
+    }
+
+    // full node applies txs sequenced by the sequencer
+    let new_registry = prometheus::Registry::new();
+    let full_node_opt = RoochOpt::new_with_temp_store()?;
+    let full_node_db = init_rooch_db(&full_node_opt, &new_registry)?;
+    let mut full_node = SequencerActor::new(
+        RoochKeyPair::generate_secp256k1(),
+        full_node_db.rooch_store,
+        ServiceStatus::ReadOnlyMode,
+        &new_registry,
+        None,
+    )?;
+
+    // tx order must be the next one
+    assert!(full_node.apply_sequenced(ledger_txs[1].clone()).is_err());
+    full_node.apply_sequenced(ledger_txs[0].clone())?;
+
+    // tx accumulator diverged
+    let mut diverged_tx = ledger_txs[1].clone();
+    diverged_tx.sequence_info.tx_accumulator_root = ledger_txs[0].sequence_info.tx_accumulator_root;
+    assert!(full_node.apply_sequenced(diverged_tx).is_err());
+    assert_eq!(full_node.last_order(), ledger_txs[0].sequence_info.tx_order);
+
+    for ledger_tx in ledger_txs.iter().skip(1) {
+        full_node.apply_sequenced(ledger_tx.clone())?;
+    }
+    assert_eq!(full_node.last_order(), sequencer.last_order());
+    Ok(())
+}
@@ -15,6 +15,9 @@ pub const MAX_TXS_PER_BLOCK_IN_FIX: usize = 8192; // avoid OOM when fix submitti
 
 // [0,background_submit_block_cursor] are submitted blocks verified by background submitter
 pub const BACKGROUND_SUBMIT_BLOCK_CURSOR_KEY: &str = "background_submit_block_cursor";
+// [0,backend_submit_block_cursor] are submitted blocks to the backend, each backend has its own cursor:
+// key is the prefix + backend identifier
+pub const BACKEND_SUBMIT_BLOCK_CURSOR_KEY_PREFIX: &str = "backend_submit_block_cursor:";
 // for fast access to last block number, must be updated with submitting block state updates atomically
 pub const LAST_BLOCK_NUMBER_KEY: &str = "last_block_number";
 
@@ -74,11 +77,26 @@ pub trait DAMetaStore {
     fn set_background_submit_block_cursor(&self, block_cursor: u128) -> anyhow::Result<()>;
     fn get_background_submit_block_cursor(&self) -> anyhow::Result<Option<u128>>;
 
+    fn set_backend_submit_block_cursor(
+        &self,
+        backend_identifier: &str,
+        block_cursor: u128,
+    ) -> anyhow::Result<()>;
+    fn get_backend_submit_block_cursor(
+        &self,
+        backend_identifier: &str,
+    ) -> anyhow::Result<Option<u128>>;
+    fn remove_backend_submit_block_cursor(&self, backend_identifier: &str) -> anyhow::Result<()>;
+
     fn get_last_block_number(&self) -> anyhow::Result<Option<u128>>;
     // get block state by block_number, must exist for the block_number, otherwise return error
     fn get_block_state(&self, block_number: u128) -> anyhow::Result<BlockSubmitState>;
     // get block state by block_number, return None if not exist
     fn try_get_block_state(&self, block_number: u128) -> anyhow::Result<Option<BlockSubmitState>>;
+
+    // restore the last block of a state snapshot into an empty DA meta, the blocks before it don't exist.
+    // block state, LAST_BLOCK_NUMBER & background_submit_block_cursor(if the block is done) are updated atomically
+    fn restore_last_block(&self, block_state: BlockSubmitState) -> anyhow::Result<()>;
 }
 
 #[derive(Clone)]
@@ -563,6 +581,28 @@ impl DAMetaStore for DAMetaDBStore {
             .kv_get(BACKGROUND_SUBMIT_BLOCK_CURSOR_KEY.to_string())
     }
 
+    fn set_backend_submit_block_cursor(
+        &self,
+        backend_identifier: &str,
+        cursor: u128,
+    ) -> anyhow::Result<()> {
+        self.block_cursor_store
+            .kv_put(backend_submit_block_cursor_key(backend_identifier), cursor)
+    }
+
+    fn get_backend_submit_block_cursor(
+        &self,
+        backend_identifier: &str,
+    ) -> anyhow::Result<Option<u128>> {
+        self.block_cursor_store
+            .kv_get(backend_submit_block_cursor_key(backend_identifier))
+    }
+
+    fn remove_backend_submit_block_cursor(&self, backend_identifier: &str) -> anyhow::Result<()> {
+        self.block_cursor_store
+            .remove(backend_submit_block_cursor_key(backend_identifier))
+    }
+
     fn get_last_block_number(&self) -> anyhow::Result<Option<u128>> {
         self.block_cursor_store
             .kv_get(LAST_BLOCK_NUMBER_KEY.to_string())
@@ -577,4 +617,47 @@ impl DAMetaStore for DAMetaDBStore {
     fn try_get_block_state(&self, block_number: u128) -> anyhow::Result<Option<BlockSubmitState>> {
         self.get_block_state_opt(block_number)
     }
+
+    fn restore_last_block(&self, block_state: BlockSubmitState) -> anyhow::Result<()> {
+        if let Some(last_block_number) = self.get_last_block_number()? {
+            return Err(anyhow::anyhow!(
+                "DA meta is not empty, last_block_number: {}",
+                last_block_number
+            ));
+        }
+        let block_number = block_state.block_range.block_number;
+        let mut cursor_rows = vec![(
+            to_bytes(LAST_BLOCK_NUMBER_KEY)?,
+            WriteOp::Value(to_bytes(&block_number)?),
+        )];
+        if block_state.done {
+            cursor_rows.push((
+                to_bytes(BACKGROUND_SUBMIT_BLOCK_CURSOR_KEY)?,
+                WriteOp::Value(to_bytes(&block_number)?),
+            ));
+        }
+        let cf_batches = vec![
+            WriteBatchCF::new_with_rows(
+                vec![(
+                    to_bytes(&block_number)?,
+                    WriteOp::Value(to_bytes(&block_state)?),
+                )],
+                DA_BLOCK_SUBMIT_STATE_COLUMN_FAMILY_NAME.to_string(),
+            ),
+            WriteBatchCF::new_with_rows(
+                cursor_rows,
+                DA_BLOCK_CURSOR_COLUMN_FAMILY_NAME.to_string(),
+            ),
+        ];
+        let inner_store = self.block_submit_state_store.get_store().store();
+        inner_store.write_cf_batch(cf_batches, true)?;
+        Ok(())
+    }
+}
+
+fn backend_submit_block_cursor_key(backend_identifier: &str) -> String {
+    format!(
+        "{}{}",
+        BACKEND_SUBMIT_BLOCK_CURSOR_KEY_PREFIX, backend_identifier
+    )
 }
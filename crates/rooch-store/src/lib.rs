@@ -5,6 +5,7 @@ use crate::accumulator_store::{AccumulatorStore, TransactionAccumulatorStore};
 use crate::da_store::{DAMetaDBStore, DAMetaStore};
 use crate::meta_store::{MetaDBStore, MetaStore, SEQUENCER_INFO_KEY};
 use crate::proposer_store::{ProposerDBStore, ProposerStore};
+use crate::pruner_store::{PrunerDBStore, PrunerStore};
 use crate::state_store::{StateDBStore, StateStore};
 use crate::transaction_store::{TransactionDBStore, TransactionStore};
 use accumulator::{AccumulatorNode, AccumulatorTreeStore};
@@ -36,6 +37,7 @@ pub mod state_store;
 pub mod transaction_store;
 
 pub mod proposer_store;
+pub mod pruner_store;
 #[cfg(test)]
 mod tests;
 
@@ -53,6 +55,10 @@ pub const DA_BLOCK_CURSOR_COLUMN_FAMILY_NAME: ColumnFamilyName = "da_last_block_
 
 pub const PROPOSER_LAST_BLOCK_COLUMN_FAMILY_NAME: ColumnFamilyName = "proposer_last_block";
 
+pub const PRUNER_LAST_PRUNED_ORDER_COLUMN_FAMILY_NAME: ColumnFamilyName =
+    "pruner_last_pruned_order";
+pub const PRUNER_PINNED_ROOTS_COLUMN_FAMILY_NAME: ColumnFamilyName = "pruner_pinned_roots";
+
 ///db store use cf_name vec to init
 /// Please note that adding a column family needs to be added in vec simultaneously, remember！！
 static VEC_COLUMN_FAMILY_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
@@ -65,6 +71,8 @@ static VEC_COLUMN_FAMILY_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
         DA_BLOCK_SUBMIT_STATE_COLUMN_FAMILY_NAME,
         DA_BLOCK_CURSOR_COLUMN_FAMILY_NAME,
         PROPOSER_LAST_BLOCK_COLUMN_FAMILY_NAME,
+        PRUNER_LAST_PRUNED_ORDER_COLUMN_FAMILY_NAME,
+        PRUNER_PINNED_ROOTS_COLUMN_FAMILY_NAME,
     ]
 });
 
@@ -86,6 +94,7 @@ pub struct RoochStore {
     pub state_store: StateDBStore,
     pub da_meta_store: DAMetaDBStore,
     pub proposer_store: ProposerDBStore,
+    pub pruner_store: PrunerDBStore,
 }
 
 impl RoochStore {
@@ -114,6 +123,7 @@ impl RoochStore {
             state_store: StateDBStore::new(instance.clone()),
             da_meta_store,
             proposer_store: ProposerDBStore::new(instance.clone()),
+            pruner_store: PrunerDBStore::new(instance.clone()),
         };
         Ok(store)
     }
@@ -150,6 +160,10 @@ impl RoochStore {
         &self.proposer_store
     }
 
+    pub fn get_pruner_store(&self) -> &PrunerDBStore {
+        &self.pruner_store
+    }
+
     /// atomic save updates made by Sequencer.sequence(tx) to the store
     pub fn save_sequenced_tx(
         &self,
@@ -382,6 +396,25 @@ impl DAMetaStore for RoochStore {
             .get_background_submit_block_cursor()
     }
 
+    fn set_backend_submit_block_cursor(
+        &self,
+        backend_identifier: &str,
+        cursor: u128,
+    ) -> Result<()> {
+        self.get_da_meta_store()
+            .set_backend_submit_block_cursor(backend_identifier, cursor)
+    }
+
+    fn get_backend_submit_block_cursor(&self, backend_identifier: &str) -> Result<Option<u128>> {
+        self.get_da_meta_store()
+            .get_backend_submit_block_cursor(backend_identifier)
+    }
+
+    fn remove_backend_submit_block_cursor(&self, backend_identifier: &str) -> Result<()> {
+        self.get_da_meta_store()
+            .remove_backend_submit_block_cursor(backend_identifier)
+    }
+
     fn get_last_block_number(&self) -> Result<Option<u128>> {
         self.get_da_meta_store().get_last_block_number()
     }
@@ -393,6 +426,10 @@ impl DAMetaStore for RoochStore {
     fn try_get_block_state(&self, block_number: u128) -> Result<Option<BlockSubmitState>> {
         self.get_da_meta_store().try_get_block_state(block_number)
     }
+
+    fn restore_last_block(&self, block_state: BlockSubmitState) -> Result<()> {
+        self.get_da_meta_store().restore_last_block(block_state)
+    }
 }
 
 impl ProposerStore for RoochStore {
@@ -408,3 +445,21 @@ impl ProposerStore for RoochStore {
         self.get_proposer_store().clear_last_proposed()
     }
 }
+
+impl PrunerStore for RoochStore {
+    fn get_last_pruned_order(&self) -> Result<Option<u64>> {
+        self.get_pruner_store().get_last_pruned_order()
+    }
+
+    fn set_last_pruned_order(&self, tx_order: u64) -> Result<()> {
+        self.get_pruner_store().set_last_pruned_order(tx_order)
+    }
+
+    fn get_pinned_roots(&self) -> Result<Vec<H256>> {
+        self.get_pruner_store().get_pinned_roots()
+    }
+
+    fn set_pinned_roots(&self, pinned_roots: Vec<H256>) -> Result<()> {
+        self.get_pruner_store().set_pinned_roots(pinned_roots)
+    }
+}
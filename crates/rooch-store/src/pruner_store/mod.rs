@@ -0,0 +1,70 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{PRUNER_LAST_PRUNED_ORDER_COLUMN_FAMILY_NAME, PRUNER_PINNED_ROOTS_COLUMN_FAMILY_NAME};
+use moveos_types::h256::H256;
+use raw_store::{derive_store, CodecKVStore};
+
+pub const PRUNER_LAST_PRUNED_ORDER_KEY: &str = "pruner_last_pruned_order";
+pub const PRUNER_PINNED_ROOTS_KEY: &str = "pruner_pinned_roots";
+
+derive_store!(
+    PrunerLastPrunedOrderStore,
+    String,
+    u64,
+    PRUNER_LAST_PRUNED_ORDER_COLUMN_FAMILY_NAME
+);
+
+derive_store!(
+    PrunerPinnedRootsStore,
+    String,
+    Vec<H256>,
+    PRUNER_PINNED_ROOTS_COLUMN_FAMILY_NAME
+);
+
+pub trait PrunerStore {
+    fn get_last_pruned_order(&self) -> anyhow::Result<Option<u64>>;
+    fn set_last_pruned_order(&self, tx_order: u64) -> anyhow::Result<()>;
+    /// The state roots whose nodes are pinned in the state store
+    fn get_pinned_roots(&self) -> anyhow::Result<Vec<H256>>;
+    fn set_pinned_roots(&self, pinned_roots: Vec<H256>) -> anyhow::Result<()>;
+}
+
+#[derive(Clone)]
+pub struct PrunerDBStore {
+    last_pruned_order_store: PrunerLastPrunedOrderStore,
+    pinned_roots_store: PrunerPinnedRootsStore,
+}
+
+impl PrunerDBStore {
+    pub fn new(instance: raw_store::StoreInstance) -> Self {
+        PrunerDBStore {
+            last_pruned_order_store: PrunerLastPrunedOrderStore::new(instance.clone()),
+            pinned_roots_store: PrunerPinnedRootsStore::new(instance),
+        }
+    }
+}
+
+impl PrunerStore for PrunerDBStore {
+    fn get_last_pruned_order(&self) -> anyhow::Result<Option<u64>> {
+        self.last_pruned_order_store
+            .kv_get(PRUNER_LAST_PRUNED_ORDER_KEY.to_string())
+    }
+
+    fn set_last_pruned_order(&self, tx_order: u64) -> anyhow::Result<()> {
+        self.last_pruned_order_store
+            .put_sync(PRUNER_LAST_PRUNED_ORDER_KEY.to_string(), tx_order)
+    }
+
+    fn get_pinned_roots(&self) -> anyhow::Result<Vec<H256>> {
+        Ok(self
+            .pinned_roots_store
+            .kv_get(PRUNER_PINNED_ROOTS_KEY.to_string())?
+            .unwrap_or_default())
+    }
+
+    fn set_pinned_roots(&self, pinned_roots: Vec<H256>) -> anyhow::Result<()> {
+        self.pinned_roots_store
+            .put_sync(PRUNER_PINNED_ROOTS_KEY.to_string(), pinned_roots)
+    }
+}
@@ -3,7 +3,8 @@
 
 use crate::da_store::{DAMetaDBStore, DAMetaStore, MAX_TXS_PER_BLOCK_IN_FIX};
 use crate::RoochStore;
-use rooch_types::da::batch::BlockRange;
+use moveos_types::h256::H256;
+use rooch_types::da::batch::{BlockRange, BlockSubmitState};
 
 #[tokio::test]
 async fn get_submitting_blocks() {
@@ -49,6 +50,88 @@ async fn get_submitting_blocks() {
     assert_eq!(submitting_blocks[0].tx_order_end, 7);
 }
 
+#[tokio::test]
+async fn backend_submit_block_cursor() {
+    let (rooch_store, _) = RoochStore::mock_rooch_store().unwrap();
+    let da_meta_store = rooch_store.get_da_meta_store();
+
+    assert_eq!(
+        da_meta_store
+            .get_backend_submit_block_cursor("openda-fs")
+            .unwrap(),
+        None
+    );
+
+    da_meta_store.set_background_submit_block_cursor(9).unwrap();
+    da_meta_store
+        .set_backend_submit_block_cursor("openda-fs", 10)
+        .unwrap();
+    da_meta_store
+        .set_backend_submit_block_cursor("openda-celestia", 3)
+        .unwrap();
+
+    // cursors are independent of each other
+    assert_eq!(
+        da_meta_store
+            .get_backend_submit_block_cursor("openda-fs")
+            .unwrap(),
+        Some(10)
+    );
+    assert_eq!(
+        da_meta_store
+            .get_backend_submit_block_cursor("openda-celestia")
+            .unwrap(),
+        Some(3)
+    );
+    assert_eq!(
+        da_meta_store.get_background_submit_block_cursor().unwrap(),
+        Some(9)
+    );
+
+    da_meta_store
+        .remove_backend_submit_block_cursor("openda-fs")
+        .unwrap();
+    assert_eq!(
+        da_meta_store
+            .get_backend_submit_block_cursor("openda-fs")
+            .unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn restore_last_block() {
+    let (rooch_store, _) = RoochStore::mock_rooch_store().unwrap();
+    let da_meta_store = rooch_store.get_da_meta_store();
+
+    let block_state = BlockSubmitState::new_done(5, 101, 120, H256::random());
+    da_meta_store
+        .restore_last_block(block_state.clone())
+        .unwrap();
+    assert_eq!(da_meta_store.get_last_block_number().unwrap(), Some(5));
+    assert_eq!(
+        da_meta_store.get_background_submit_block_cursor().unwrap(),
+        Some(5)
+    );
+    assert_eq!(da_meta_store.get_block_state(5).unwrap(), block_state);
+    assert!(da_meta_store
+        .get_submitting_blocks(5, None)
+        .unwrap()
+        .is_empty());
+
+    // the restored block is consistent with the last tx order, no block is appended by repair
+    assert_eq!(
+        da_meta_store.try_repair_da_meta(120, false).unwrap(),
+        (0, 0)
+    );
+    assert_eq!(da_meta_store.get_last_block_number().unwrap(), Some(5));
+    // the new blocks follow the restored block
+    assert_eq!(da_meta_store.append_submitting_block(121, 130).unwrap(), 6);
+
+    // only an empty DA meta can be restored
+    assert!(da_meta_store.restore_last_block(block_state).is_err());
+}
+
 #[tokio::test]
 async fn generate_remove_blocks() {
     let (rooch_store, _) = RoochStore::mock_rooch_store().unwrap();
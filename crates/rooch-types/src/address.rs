@@ -43,7 +43,7 @@ use rand::{seq::SliceRandom, thread_rng};
 use serde::ser::Error;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_with::serde_as;
-use sha3::{Digest, Sha3_256};
+use sha3::{Digest, Keccak256};
 use std::fmt;
 use std::ops::Deref;
 use std::str::FromStr;
@@ -489,8 +489,8 @@ impl From<Secp256k1PublicKey> for EthereumAddress {
         let uncompressed_public_key_bytes = value.pubkey.serialize_uncompressed();
         // Ignore the first byte and take the last 64-bytes of the uncompressed pubkey
         let uncompressed_64 = uncompressed_public_key_bytes[1..].to_vec();
-        // create a SHA3-256 object
-        let mut hasher = Sha3_256::new();
+        // Ethereum uses the original Keccak-256, not the standardized SHA3-256
+        let mut hasher = Keccak256::new();
         // write input message
         hasher.update(&uncompressed_64);
         // read hash digest
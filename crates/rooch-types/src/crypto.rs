@@ -5,7 +5,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    address::{BitcoinAddress, RoochAddress},
+    address::{BitcoinAddress, EthereumAddress, RoochAddress},
     authentication_key::AuthenticationKey,
     error::{RoochError, RoochResult},
     rooch_key::ROOCH_SECRET_KEY_HRP,
@@ -36,6 +36,7 @@ use fastcrypto::{
 use fastcrypto::{
     hash::{Blake2b256, HashFunction},
     secp256k1::{Secp256k1PublicKey, Secp256k1Signature, Secp256k1SignatureAsBytes},
+    secp256r1::{Secp256r1PublicKey, Secp256r1PublicKeyAsBytes},
 };
 use moveos_types::serde::Readable;
 use schemars::JsonSchema;
@@ -50,6 +51,8 @@ pub type DefaultHash = Blake2b256;
 pub enum SignatureScheme {
     Ed25519,
     Secp256k1,
+    /// For WebAuthn(passkey), the key is kept by the device, so there is no RoochKeyPair for it
+    Secp256r1,
 }
 
 impl SignatureScheme {
@@ -57,6 +60,7 @@ impl SignatureScheme {
         match self {
             SignatureScheme::Ed25519 => 0,
             SignatureScheme::Secp256k1 => 1,
+            SignatureScheme::Secp256r1 => 2,
         }
     }
 
@@ -64,6 +68,7 @@ impl SignatureScheme {
         match byte_int {
             0 => Ok(SignatureScheme::Ed25519),
             1 => Ok(SignatureScheme::Secp256k1),
+            2 => Ok(SignatureScheme::Secp256r1),
             _ => Err(RoochError::InvalidSignatureScheme),
         }
     }
@@ -237,6 +242,7 @@ impl EncodeDecodeBase64 for RoochKeyPair {
                         bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?,
                     )?))
                 }
+                SignatureScheme::Secp256r1 => Err(eyre!("Unsupported key pair scheme")),
             },
             _ => Err(eyre!("Invalid bytes")),
         }
@@ -269,6 +275,7 @@ impl<'de> Deserialize<'de> for RoochKeyPair {
 pub enum PublicKey {
     Ed25519(Ed25519PublicKeyAsBytes),
     Secp256k1(Secp256k1PublicKeyAsBytes),
+    Secp256r1(Secp256r1PublicKeyAsBytes),
 }
 
 impl AsRef<[u8]> for PublicKey {
@@ -276,6 +283,7 @@ impl AsRef<[u8]> for PublicKey {
         match self {
             PublicKey::Ed25519(pk) => &pk.0,
             PublicKey::Secp256k1(pk) => &pk.0,
+            PublicKey::Secp256r1(pk) => &pk.0,
         }
     }
 }
@@ -306,6 +314,12 @@ impl EncodeDecodeBase64 for PublicKey {
                     )?;
                     Ok(PublicKey::Secp256k1((&pk).into()))
                 }
+                SignatureScheme::Secp256r1 => {
+                    let pk: Secp256r1PublicKey = Secp256r1PublicKey::from_bytes(
+                        bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?,
+                    )?;
+                    Ok(PublicKey::Secp256r1((&pk).into()))
+                }
             },
             Err(e) => Err(eyre!("Invalid bytes :{}", e)),
         }
@@ -342,6 +356,7 @@ impl PublicKey {
         match self {
             PublicKey::Ed25519(_) => Ed25519RoochSignature::SCHEME,
             PublicKey::Secp256k1(_) => Secp256k1RoochSignature::SCHEME,
+            PublicKey::Secp256r1(_) => SignatureScheme::Secp256r1,
         }
     }
 
@@ -383,6 +398,15 @@ impl PublicKey {
         }
     }
 
+    pub fn ethereum_address(&self) -> Result<EthereumAddress, anyhow::Error> {
+        match self {
+            PublicKey::Secp256k1(pk) => Ok(EthereumAddress::from(Secp256k1PublicKey::from_bytes(
+                &pk.0,
+            )?)),
+            _ => bail!("Only secp256k1 public key can be converted to ethereum address"),
+        }
+    }
+
     pub fn to_hex(&self) -> String {
         hex::encode(self.as_ref())
     }
@@ -419,6 +443,14 @@ impl PublicKey {
                     )?;
                     Ok(PublicKey::Secp256k1((&pk).into()))
                 }
+                SignatureScheme::Secp256r1 => {
+                    let pk: Secp256r1PublicKey = Secp256r1PublicKey::from_bytes(
+                        bytes
+                            .get(1..)
+                            .ok_or_else(|| anyhow!("Invalid public key length"))?,
+                    )?;
+                    Ok(PublicKey::Secp256r1((&pk).into()))
+                }
             },
             Err(e) => Err(anyhow!("Invalid bytes :{}", e)),
         }
@@ -429,6 +461,12 @@ impl PublicKey {
         let pk = Secp256k1PublicKey::from_bytes(&bytes)?;
         Ok(PublicKey::Secp256k1((&pk).into()))
     }
+
+    /// Create the public key from the 33-bytes compressed Secp256r1 public key of the passkey
+    pub fn from_secp256r1_bytes(bytes: &[u8]) -> Result<Self, anyhow::Error> {
+        let pk = Secp256r1PublicKey::from_bytes(bytes)?;
+        Ok(PublicKey::Secp256r1((&pk).into()))
+    }
 }
 
 impl std::fmt::Display for PublicKey {
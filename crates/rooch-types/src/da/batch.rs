@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::crypto::{RoochKeyPair, RoochSignature, Signature};
+use crate::crypto::{PublicKey, RoochKeyPair, RoochSignature, Signature};
 use crate::transaction::LedgerTransaction;
 use fastcrypto::traits::ToFromBytes;
 use moveos_types::h256;
@@ -159,6 +159,26 @@ impl DABatch {
         Ok(())
     }
 
+    /// Get the public key which signed the batch meta.
+    pub fn get_signer(&self) -> anyhow::Result<PublicKey> {
+        let meta_signature = Signature::from_bytes(&self.meta_signature)?;
+        let mut public_key_bytes = vec![meta_signature.scheme().flag()];
+        public_key_bytes.extend_from_slice(meta_signature.public_key_bytes());
+        PublicKey::from_bytes(&public_key_bytes)
+    }
+
+    /// Verify the batch meta is signed by the given sequencer public key.
+    /// `verify` only checks the signature is valid, it doesn't care about who signed it.
+    pub fn verify_signer(&self, sequencer_public_key: &PublicKey) -> anyhow::Result<()> {
+        if &self.get_signer()? != sequencer_public_key {
+            return Err(anyhow::anyhow!(
+                "batch meta is not signed by sequencer: {}",
+                sequencer_public_key
+            ));
+        }
+        Ok(())
+    }
+
     pub fn verify_tx_order(&self) -> anyhow::Result<()> {
         let tx_list: Vec<LedgerTransaction> = self.get_tx_list();
         let mut last_order = self.meta.block_range.tx_order_start;
@@ -2,14 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::da::batch::DABatch;
-use crate::da::segment::{Segment, SegmentID, SegmentV0};
+use crate::da::segment::{Segment, SegmentID, SegmentV0, SegmentV1};
+use accumulator::inmemory::InMemoryAccumulator;
 use lz4::EncoderBuilder;
+use moveos_types::h256::H256;
 use serde::{Deserialize, Serialize};
 use std::io;
 
+// zstd level 0 means the default level of zstd
+pub const DEFAULT_CHUNK_V1_COMPRESSION_LEVEL: i32 = 0;
+// max batches in a ChunkV1, the chunk which a block belongs to is at most CHUNK_V1_MAX_BATCHES - 1 blocks before it
+pub const CHUNK_V1_MAX_BATCHES: usize = 32;
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 pub enum ChunkVersion {
     V0,
+    V1,
     Unknown(u8),
 }
 
@@ -17,6 +25,7 @@ impl From<u8> for ChunkVersion {
     fn from(num: u8) -> Self {
         match num {
             0 => ChunkVersion::V0,
+            1 => ChunkVersion::V1,
             // ...
             _ => Self::Unknown(num),
         }
@@ -27,6 +36,7 @@ impl From<ChunkVersion> for u8 {
     fn from(version: ChunkVersion) -> Self {
         match version {
             ChunkVersion::V0 => 0,
+            ChunkVersion::V1 => 1,
             ChunkVersion::Unknown(num) => num,
         }
     }
@@ -78,28 +88,12 @@ impl Chunk for ChunkV0 {
     }
 
     fn to_segments(&self, max_segment_size: usize) -> Vec<Box<dyn Segment>> {
-        let bytes = self.to_bytes();
-        let segments_data = bytes.chunks(max_segment_size);
-        let segments_count = segments_data.len();
-
-        let chunk_id = self.get_chunk_id();
-        segments_data
-            .enumerate()
-            .map(|(i, data)| {
-                Box::new(SegmentV0 {
-                    id: SegmentID {
-                        chunk_id,
-                        segment_number: i as u64,
-                    },
-                    is_last: i == segments_count - 1, // extra info overhead is much smaller than max_block_size - max_segment_size
-                    data_len: data.len() as u64,
-                    // *_checksum will be filled in to_bytes method of Segment
-                    data_checksum: 0,
-                    checksum: 0,
-                    data: data.to_vec(),
-                }) as Box<dyn Segment>
-            })
-            .collect::<Vec<_>>()
+        split_into_segments(
+            self.get_chunk_id(),
+            &self.to_bytes(),
+            max_segment_size,
+            |segment| Box::new(segment),
+        )
     }
 
     fn get_batches(&self) -> Vec<DABatch> {
@@ -112,17 +106,201 @@ impl Chunk for ChunkV0 {
     }
 }
 
+// ChunkV1:
+// 1. each chunk packs several consecutive batches (block number of the first batch is chunk_id),
+//    so small blocks won't make huge numbers of tiny segments
+// 2. chunk data compressed by zstd
+// 3. batch_root is the merkle root over the batch hashes
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ChunkV1 {
+    pub version: ChunkVersion,
+    pub batch_root: H256,
+    pub batches: Vec<DABatch>,
+    // zstd compression level, not a part of the chunk
+    #[serde(skip)]
+    compression_level: i32,
+}
+
+impl ChunkV1 {
+    /// Batches must be consecutive: both block numbers and tx orders are continuous,
+    /// and there are at most CHUNK_V1_MAX_BATCHES batches
+    pub fn new(batches: Vec<DABatch>) -> anyhow::Result<Self> {
+        check_consecutive_batches(&batches)?;
+        Ok(Self {
+            version: ChunkVersion::V1,
+            batch_root: calc_batch_root(&batches),
+            batches,
+            compression_level: DEFAULT_CHUNK_V1_COMPRESSION_LEVEL,
+        })
+    }
+
+    pub fn with_compression_level(mut self, compression_level: i32) -> Self {
+        self.compression_level = compression_level;
+        self
+    }
+
+    /// Check the zstd compression level, 0 means the default level
+    pub fn check_compression_level(compression_level: i32) -> anyhow::Result<()> {
+        let range = zstd::compression_level_range();
+        if compression_level != 0 && !range.contains(&compression_level) {
+            return Err(anyhow::anyhow!(
+                "chunk_v1: invalid compression level: {}, exp: 0 or in [{}, {}]",
+                compression_level,
+                range.start(),
+                range.end()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Verify batches are consecutive and match the batch_root
+    pub fn verify(&self) -> anyhow::Result<()> {
+        check_consecutive_batches(&self.batches)?;
+        let exp_batch_root = calc_batch_root(&self.batches);
+        if exp_batch_root != self.batch_root {
+            return Err(anyhow::anyhow!(
+                "chunk_v1: batch root mismatch, exp: {:?}, act: {:?}",
+                exp_batch_root,
+                self.batch_root
+            ));
+        }
+        Ok(())
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let bytes = bcs::to_bytes(self).unwrap();
+        zstd::encode_all(&bytes[..], self.compression_level).unwrap()
+    }
+
+    pub fn from_segments(segments: Vec<Box<dyn Segment>>) -> anyhow::Result<Self> {
+        let chunk_id = check_segments(&segments, ChunkVersion::V1)?;
+        let bytes = segments
+            .iter()
+            .flat_map(|segment| segment.get_data())
+            .collect::<Vec<_>>();
+
+        let decompressed_bytes = zstd::decode_all(&bytes[..])?;
+        let chunk: ChunkV1 = bcs::from_bytes(&decompressed_bytes)?;
+        chunk.verify()?;
+        check_chunk_id(chunk_id, &chunk)?;
+        Ok(chunk)
+    }
+}
+
+impl Chunk for ChunkV1 {
+    fn get_version(&self) -> ChunkVersion {
+        ChunkVersion::V1
+    }
+
+    fn to_segments(&self, max_segment_size: usize) -> Vec<Box<dyn Segment>> {
+        split_into_segments(
+            self.get_chunk_id(),
+            &self.to_bytes(),
+            max_segment_size,
+            |segment| Box::new(SegmentV1(segment)),
+        )
+    }
+
+    fn get_batches(&self) -> Vec<DABatch> {
+        self.batches.clone()
+    }
+
+    /// using block_number of the first batch as chunk_id
+    fn get_chunk_id(&self) -> u128 {
+        self.batches
+            .first()
+            .map(|batch| batch.meta.block_range.block_number)
+            .expect("chunk_v1 must have at least one batch")
+    }
+}
+
+fn calc_batch_root(batches: &[DABatch]) -> H256 {
+    let batch_hashes = batches
+        .iter()
+        .map(|batch| batch.get_hash())
+        .collect::<Vec<_>>();
+    InMemoryAccumulator::from_leaves(&batch_hashes).root_hash()
+}
+
+fn check_consecutive_batches(batches: &[DABatch]) -> anyhow::Result<()> {
+    if batches.is_empty() {
+        return Err(anyhow::anyhow!("chunk_v1: empty batches"));
+    }
+    if batches.len() > CHUNK_V1_MAX_BATCHES {
+        return Err(anyhow::anyhow!(
+            "chunk_v1: too many batches: {}, max: {}",
+            batches.len(),
+            CHUNK_V1_MAX_BATCHES
+        ));
+    }
+    for pair in batches.windows(2) {
+        let prev = &pair[0].meta.block_range;
+        let next = &pair[1].meta.block_range;
+        if next.block_number != prev.block_number + 1
+            || next.tx_order_start != prev.tx_order_end + 1
+        {
+            return Err(anyhow::anyhow!(
+                "chunk_v1: batches are not consecutive, prev: {:?}, next: {:?}",
+                prev,
+                next
+            ));
+        }
+    }
+    Ok(())
+}
+
+// split chunk bytes into segments, segment_v0 fields are shared by all segment versions
+fn split_into_segments(
+    chunk_id: u128,
+    bytes: &[u8],
+    max_segment_size: usize,
+    make_segment: impl Fn(SegmentV0) -> Box<dyn Segment>,
+) -> Vec<Box<dyn Segment>> {
+    let segments_data = bytes.chunks(max_segment_size);
+    let segments_count = segments_data.len();
+
+    segments_data
+        .enumerate()
+        .map(|(i, data)| {
+            make_segment(SegmentV0 {
+                id: SegmentID {
+                    chunk_id,
+                    segment_number: i as u64,
+                },
+                is_last: i == segments_count - 1, // extra info overhead is much smaller than max_block_size - max_segment_size
+                data_len: data.len() as u64,
+                // *_checksum will be filled in to_bytes method of Segment
+                data_checksum: 0,
+                checksum: 0,
+                data: data.to_vec(),
+            })
+        })
+        .collect::<Vec<_>>()
+}
+
 pub fn chunk_from_segments(segments: Vec<Box<dyn Segment>>) -> anyhow::Result<Box<dyn Chunk>> {
+    let version = segments
+        .first()
+        .map(|segment| segment.get_version())
+        .ok_or_else(|| anyhow::anyhow!("empty segments"))?;
+    match version {
+        ChunkVersion::V0 => Ok(Box::new(ChunkV0::from_segments(segments)?)),
+        ChunkVersion::V1 => Ok(Box::new(ChunkV1::from_segments(segments)?)),
+        // ...
+        ChunkVersion::Unknown(_) => Err(anyhow::anyhow!("unsupported segment version")),
+    }
+}
+
+// check segments make up a whole chunk of the version, return the chunk_id
+fn check_segments(segments: &[Box<dyn Segment>], version: ChunkVersion) -> anyhow::Result<u128> {
     if segments.is_empty() {
         return Err(anyhow::anyhow!("empty segments"));
     }
     // check all segments have the same version
-    let versions = segments
+    if segments
         .iter()
-        .map(|segment| segment.get_version())
-        .collect::<Vec<_>>();
-    let version = versions.first().unwrap();
-    if versions.iter().any(|seg_version| *seg_version != *version) {
+        .any(|segment| segment.get_version() != version)
+    {
         return Err(anyhow::anyhow!("inconsistent segment versions"));
     }
     // check last segment.is_last == true, others must be false
@@ -150,16 +328,25 @@ pub fn chunk_from_segments(segments: Vec<Box<dyn Segment>>) -> anyhow::Result<Bo
     }) {
         return Err(anyhow::anyhow!("inconsistent segment ids"));
     }
+    Ok(chunk_id)
+}
 
-    match version {
-        ChunkVersion::V0 => Ok(Box::new(ChunkV0::from_segments(segments)?)),
-        // ...
-        ChunkVersion::Unknown(_) => Err(anyhow::anyhow!("unsupported segment version")),
+// the chunk decoded from segments must have the chunk_id of the segments
+fn check_chunk_id(exp_chunk_id: u128, chunk: &dyn Chunk) -> anyhow::Result<()> {
+    let chunk_id = chunk.get_chunk_id();
+    if chunk_id != exp_chunk_id {
+        return Err(anyhow::anyhow!(
+            "chunk id mismatch, segments: {}, chunk: {}",
+            exp_chunk_id,
+            chunk_id
+        ));
     }
+    Ok(())
 }
 
 impl ChunkV0 {
     pub fn from_segments(segments: Vec<Box<dyn Segment>>) -> anyhow::Result<Self> {
+        let chunk_id = check_segments(&segments, ChunkVersion::V0)?;
         let bytes = segments
             .iter()
             .flat_map(|segment| segment.get_data())
@@ -168,6 +355,7 @@ impl ChunkV0 {
         let decoder = lz4::Decoder::new(&bytes[..])?;
         let mut decompressed_reader = io::BufReader::new(decoder);
         let chunk: ChunkV0 = bcs::from_reader(&mut decompressed_reader)?;
+        check_chunk_id(chunk_id, &chunk)?;
         Ok(chunk)
     }
 }
@@ -187,7 +375,7 @@ mod tests {
         let tx_list = (0..tx_cnt)
             .map(|_| random_ledger_transaction())
             .collect::<Vec<_>>();
-        let batch = DABatch::new(123, 56, 78, &tx_list, keypair);
+        let batch = DABatch::new(123, 56, 78, &tx_list, keypair.copy());
 
         let chunk = ChunkV0::from(batch.clone());
         let segments = chunk.to_segments(1023);
@@ -201,6 +389,146 @@ mod tests {
             bcs::from_bytes(&act_batch.tx_list_bytes).expect("decode tx_list should success");
         assert_eq!(tx_list, act_tx_list);
 
-        assert!(act_batch.verify(false).is_ok())
+        assert!(act_batch.verify(false).is_ok());
+        assert!(act_batch.verify_signer(&keypair.public()).is_ok());
+        let other_keypair = RoochKeyPair::generate_secp256k1();
+        assert!(act_batch.verify_signer(&other_keypair.public()).is_err());
+    }
+
+    #[test]
+    fn test_chunk_v1() {
+        let keypair = RoochKeyPair::generate_secp256k1();
+
+        let mut batches = Vec::new();
+        let mut tx_order_start = 1;
+        for block_number in 10..14 {
+            let tx_cnt = 32;
+            let tx_list = (0..tx_cnt)
+                .map(|_| random_ledger_transaction())
+                .collect::<Vec<_>>();
+            let tx_order_end = tx_order_start + tx_cnt - 1;
+            batches.push(DABatch::new(
+                block_number,
+                tx_order_start,
+                tx_order_end,
+                &tx_list,
+                keypair.copy(),
+            ));
+            tx_order_start = tx_order_end + 1;
+        }
+
+        let chunk = ChunkV1::new(batches.clone())
+            .unwrap()
+            .with_compression_level(19);
+        assert_eq!(chunk.get_chunk_id(), 10);
+        let segments = chunk.to_segments(1023);
+        assert!(segments
+            .iter()
+            .all(|segment| segment.get_version() == ChunkVersion::V1));
+
+        let act_chunk = chunk_from_segments(segments).unwrap();
+        assert_eq!(act_chunk.get_version(), ChunkVersion::V1);
+        assert_eq!(act_chunk.get_chunk_id(), 10);
+        assert_eq!(act_chunk.get_batches(), batches);
+
+        // batches must be consecutive
+        let mut gap_batches = batches.clone();
+        gap_batches.remove(1);
+        assert!(ChunkV1::new(gap_batches).is_err());
+        assert!(ChunkV1::new(vec![]).is_err());
+
+        // batch root must match
+        let mut tampered_chunk = ChunkV1::new(batches.clone()).unwrap();
+        tampered_chunk.batches.truncate(3);
+        assert!(tampered_chunk.verify().is_err());
+    }
+
+    fn random_batches(
+        keypair: &RoochKeyPair,
+        block_numbers: std::ops::Range<u128>,
+    ) -> Vec<DABatch> {
+        let mut batches = Vec::new();
+        let mut tx_order_start = 1;
+        for block_number in block_numbers {
+            let tx_cnt = 4;
+            let tx_list = (0..tx_cnt)
+                .map(|_| random_ledger_transaction())
+                .collect::<Vec<_>>();
+            let tx_order_end = tx_order_start + tx_cnt - 1;
+            batches.push(DABatch::new(
+                block_number,
+                tx_order_start,
+                tx_order_end,
+                &tx_list,
+                keypair.copy(),
+            ));
+            tx_order_start = tx_order_end + 1;
+        }
+        batches
+    }
+
+    #[test]
+    fn test_chunk_v0_and_v1_side_by_side() {
+        let keypair = RoochKeyPair::generate_secp256k1();
+        let batches = random_batches(&keypair, 0..6);
+
+        // block 0, 1 in ChunkV0, block [2, 5] in a ChunkV1
+        let chunk_v0_0 = ChunkV0::from(batches[0].clone());
+        let chunk_v0_1 = ChunkV0::from(batches[1].clone());
+        let chunk_v1 = ChunkV1::new(batches[2..].to_vec())
+            .unwrap()
+            .with_compression_level(3);
+        let chunks: Vec<Box<dyn Chunk>> = vec![
+            Box::new(chunk_v0_0.clone()),
+            Box::new(chunk_v0_1.clone()),
+            Box::new(chunk_v1.clone()),
+        ];
+
+        let mut act_batches = Vec::new();
+        for chunk in &chunks {
+            let act_chunk = chunk_from_segments(chunk.to_segments(256)).unwrap();
+            assert_eq!(act_chunk.get_version(), chunk.get_version());
+            assert_eq!(act_chunk.get_chunk_id(), chunk.get_chunk_id());
+            act_batches.extend(act_chunk.get_batches());
+        }
+        assert_eq!(act_batches, batches);
+
+        // segments of different versions can't make up a chunk
+        let mut mixed_segments = chunk_v0_0.to_segments(256);
+        mixed_segments.pop();
+        mixed_segments.extend(chunk_v1.to_segments(256).into_iter().skip(1));
+        assert!(chunk_from_segments(mixed_segments).is_err());
+
+        // segments of different chunks can't make up a chunk
+        let mut v0_segments = chunk_v0_0.to_segments(256);
+        assert!(v0_segments.len() > 1);
+        v0_segments.pop();
+        v0_segments.extend(chunk_v0_1.to_segments(256).into_iter().last());
+        assert!(chunk_from_segments(v0_segments).is_err());
+        let mut v1_segments = chunk_v1.to_segments(256);
+        v1_segments.remove(0);
+        assert!(ChunkV1::from_segments(v1_segments).is_err());
+
+        // the chunk_id of segments must be the chunk_id of the chunk
+        let segments = split_into_segments(3, &chunk_v1.to_bytes(), 256, |segment| {
+            Box::new(SegmentV1(segment))
+        });
+        assert!(chunk_from_segments(segments).is_err());
+        let segments =
+            split_into_segments(1, &chunk_v0_0.to_bytes(), 256, |segment| Box::new(segment));
+        assert!(ChunkV0::from_segments(segments).is_err());
+    }
+
+    #[test]
+    fn test_chunk_v1_limits() {
+        let keypair = RoochKeyPair::generate_secp256k1();
+        let batches = random_batches(&keypair, 0..(CHUNK_V1_MAX_BATCHES as u128 + 1));
+        assert!(ChunkV1::new(batches[..CHUNK_V1_MAX_BATCHES].to_vec()).is_ok());
+        assert!(ChunkV1::new(batches).is_err());
+
+        assert!(ChunkV1::check_compression_level(DEFAULT_CHUNK_V1_COMPRESSION_LEVEL).is_ok());
+        assert!(ChunkV1::check_compression_level(1).is_ok());
+        assert!(ChunkV1::check_compression_level(22).is_ok());
+        assert!(ChunkV1::check_compression_level(23).is_err());
     }
 }
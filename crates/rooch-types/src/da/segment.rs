@@ -84,10 +84,11 @@ impl SegmentV0 {
     }
 }
 
-impl Segment for SegmentV0 {
-    fn to_bytes(&self) -> Vec<u8> {
+impl SegmentV0 {
+    // encode segment with the given version byte, segments of all versions share the same layout at present
+    fn encode(&self, version: ChunkVersion) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(SEGMENT_V0_DATA_OFFSET + self.data_len as usize);
-        bytes.push(ChunkVersion::V0.into()); // version
+        bytes.push(version.into()); // version
         bytes.extend_from_slice(&self.id.chunk_id.to_le_bytes());
         bytes.extend_from_slice(&self.id.segment_number.to_le_bytes());
         bytes.push(self.is_last as u8);
@@ -99,6 +100,12 @@ impl Segment for SegmentV0 {
         bytes.extend_from_slice(&self.data);
         bytes
     }
+}
+
+impl Segment for SegmentV0 {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.encode(ChunkVersion::V0)
+    }
 
     fn get_version(&self) -> ChunkVersion {
         ChunkVersion::V0
@@ -117,9 +124,44 @@ impl Segment for SegmentV0 {
     }
 }
 
+// SegmentV1 has the same layout as SegmentV0, the version tells how to decode the chunk (ChunkV1) from segments.
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct SegmentV1(pub SegmentV0);
+
+impl SegmentV1 {
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self(SegmentV0::from_bytes(bytes)?))
+    }
+}
+
+impl Segment for SegmentV1 {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.encode(ChunkVersion::V1)
+    }
+
+    fn get_version(&self) -> ChunkVersion {
+        ChunkVersion::V1
+    }
+
+    fn get_id(&self) -> SegmentID {
+        self.0.id
+    }
+
+    fn get_data(&self) -> Vec<u8> {
+        self.0.data.clone()
+    }
+
+    fn is_last(&self) -> bool {
+        self.0.is_last
+    }
+}
+
 pub fn get_data_offset(version: ChunkVersion) -> usize {
     match version {
-        ChunkVersion::V0 => SEGMENT_V0_DATA_OFFSET,
+        ChunkVersion::V0 | ChunkVersion::V1 => SEGMENT_V0_DATA_OFFSET,
         ChunkVersion::Unknown(_) => panic!("unsupported segment version"),
     }
 }
@@ -129,12 +171,13 @@ pub fn segment_from_bytes(bytes: &[u8]) -> anyhow::Result<Box<dyn Segment>> {
 
     match ChunkVersion::from(version) {
         ChunkVersion::V0 => Ok(Box::new(SegmentV0::from_bytes(bytes)?)),
+        ChunkVersion::V1 => Ok(Box::new(SegmentV1::from_bytes(bytes)?)),
         // ...
         ChunkVersion::Unknown(_) => Err(anyhow::anyhow!("unsupported segment version")),
     }
 }
 
-#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+#[derive(Serialize, Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub struct SegmentID {
     // chunk id represents the sequential order of extents within a stream, commencing from 0 and incrementing successively.
     pub chunk_id: u128,
@@ -199,7 +242,10 @@ mod tests {
             data: vec![1, 2, 3, 4, 5],
         };
 
-        let segments: Vec<Box<dyn Segment>> = vec![Box::new(segment_v0.clone())];
+        let segments: Vec<Box<dyn Segment>> = vec![
+            Box::new(segment_v0.clone()),
+            Box::new(SegmentV1(segment_v0.clone())),
+        ];
 
         for segment in segments {
             let bytes = segment.to_bytes();
@@ -213,6 +259,13 @@ mod tests {
                     segment_v0.data_checksum = recovered_segment.data_checksum;
                     assert_eq!(&segment_v0, &recovered_segment)
                 }
+                ChunkVersion::V1 => {
+                    let recovered_segment =
+                        segment_from_bytes(&bytes).expect("successful deserialization");
+                    assert_eq!(recovered_segment.get_version(), ChunkVersion::V1);
+                    assert_eq!(recovered_segment.get_id(), segment_v0.id);
+                    assert_eq!(recovered_segment.get_data(), segment_v0.data);
+                }
 
                 _ => panic!("unsupported segment version"),
             };
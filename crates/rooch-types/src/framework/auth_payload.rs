@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    address::EthereumAddress,
     crypto::{RoochSignature, Signature, SignatureScheme},
+    framework::ethereum_validator::EthereumSignData,
     transaction::RoochTransactionData,
 };
 use anyhow::{ensure, Result};
@@ -11,9 +13,11 @@ use bitcoin::{
     io::BufRead,
 };
 use fastcrypto::{
-    hash::Sha256,
-    secp256k1::{Secp256k1PublicKey, Secp256k1Signature},
-    traits::ToFromBytes,
+    hash::{Keccak256, Sha256},
+    secp256k1::{
+        recoverable::Secp256k1RecoverableSignature, Secp256k1PublicKey, Secp256k1Signature,
+    },
+    traits::{RecoverableSignature, ToFromBytes},
 };
 use framework_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
@@ -32,6 +36,8 @@ pub const MESSAGE_INFO_PREFIX: &[u8] = b"Bitcoin Signed Message:\n";
 pub const MESSAGE_INFO: &[u8] = b"Rooch Transaction:\n";
 
 const TX_HASH_HEX_LENGTH: usize = 64;
+/// The recoverable signature of the Ethereum wallet, in form (r, s, v)
+const ETHEREUM_SIGNATURE_LENGTH: usize = 65;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SignData {
@@ -184,6 +190,23 @@ impl AuthPayload {
         }
     }
 
+    /// Create the auth payload of the Ethereum wallet, the signature is the 65 bytes recoverable signature
+    pub fn new_ethereum(
+        sign_data: EthereumSignData,
+        signature: Vec<u8>,
+        public_key: Vec<u8>,
+        ethereum_address: String,
+    ) -> Self {
+        let message_info = sign_data.message_info_without_tx_hash();
+        AuthPayload {
+            signature,
+            message_prefix: sign_data.message_prefix,
+            message_info,
+            public_key,
+            from_address: ethereum_address.into_bytes(),
+        }
+    }
+
     pub fn verify(&self, tx_data: &RoochTransactionData) -> Result<()> {
         let pk = Secp256k1PublicKey::from_bytes(&self.public_key)?;
         let sign_data = SignData::new(
@@ -198,6 +221,38 @@ impl AuthPayload {
         Ok(())
     }
 
+    /// Verify the recoverable signature of the Ethereum wallet, the recovered public key must be the payload's,
+    /// and the from address must be the Ethereum address of it.
+    pub fn verify_ethereum(&self, tx_data: &RoochTransactionData) -> Result<()> {
+        let sign_data = EthereumSignData::new_with_payload(
+            self.message_prefix.clone(),
+            self.message_info.clone(),
+            tx_data,
+        )?;
+        ensure!(
+            self.signature.len() == ETHEREUM_SIGNATURE_LENGTH,
+            "Invalid Ethereum signature length: {}",
+            self.signature.len()
+        );
+        let mut signature = self.signature.clone();
+        // The Ethereum wallets use 27 and 28 as the recovery id
+        let v = &mut signature[ETHEREUM_SIGNATURE_LENGTH - 1];
+        if *v == 27 || *v == 28 {
+            *v -= 27;
+        }
+        let signature = Secp256k1RecoverableSignature::from_bytes(&signature)?;
+        let pk = signature.recover_with_hash::<Keccak256>(&sign_data.encode())?;
+        ensure!(
+            pk.as_bytes() == self.public_key.as_slice(),
+            "The recovered public key mismatches the public key of the Ethereum auth payload"
+        );
+        ensure!(
+            self.from_address()?.to_lowercase() == EthereumAddress::from(pk).to_string(),
+            "The from address mismatches the public key of the Ethereum auth payload"
+        );
+        Ok(())
+    }
+
     pub fn verify_without_tx_hash(&self) -> Result<()> {
         let pk = Secp256k1PublicKey::from_bytes(&self.public_key)?;
         let sign_data =
@@ -249,6 +304,26 @@ impl MultisignAuthPayload {
             message_info,
         })
     }
+
+    /// Verify all the signatures over the sign data, the participants of the multisign account are checked by the validator on chain
+    pub fn verify(&self, tx_data: &RoochTransactionData) -> Result<()> {
+        ensure!(
+            !self.signatures.is_empty() && self.signatures.len() == self.public_keys.len(),
+            "The signatures and the public keys of the multisign payload mismatch"
+        );
+        let sign_data = SignData::new(
+            self.message_prefix.clone(),
+            self.message_info.clone(),
+            tx_data,
+        );
+        let message_hash = sha2_256_of(&sign_data.encode()).0.to_vec();
+        for (signature, public_key) in self.signatures.iter().zip(&self.public_keys) {
+            let pk = Secp256k1PublicKey::from_bytes(public_key)?;
+            let signature = Secp256k1Signature::from_bytes(signature)?;
+            pk.verify_with_hash::<Sha256>(&message_hash, &signature)?;
+        }
+        Ok(())
+    }
 }
 impl MoveStructType for MultisignAuthPayload {
     const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
@@ -300,4 +375,33 @@ mod tests {
         let auth_payload = bcs::from_bytes::<AuthPayload>(&auth.payload).unwrap();
         auth_payload.verify(&tx_data).unwrap();
     }
+
+    #[test]
+    pub fn test_multisign_verify() {
+        let tx_data = RoochTransactionData::new_for_test(
+            ROOCH_FRAMEWORK_ADDRESS.into(),
+            0,
+            Empty::empty_function_call().into(),
+        );
+        let payloads = (0..2)
+            .map(|_| {
+                let auth = Authenticator::bitcoin(&RoochKeyPair::generate_secp256k1(), &tx_data);
+                bcs::from_bytes::<AuthPayload>(&auth.payload).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let mut payload = MultisignAuthPayload::build_multisig_payload(payloads).unwrap();
+        payload.verify(&tx_data).unwrap();
+
+        let other_tx_data = RoochTransactionData::new_for_test(
+            ROOCH_FRAMEWORK_ADDRESS.into(),
+            1,
+            Empty::empty_function_call().into(),
+        );
+        assert!(payload.verify(&other_tx_data).is_err());
+
+        payload.public_keys.swap(0, 1);
+        assert!(payload.verify(&tx_data).is_err());
+        payload.public_keys.pop();
+        assert!(payload.verify(&tx_data).is_err());
+    }
 }
@@ -55,6 +55,8 @@ impl BuiltinAuthValidator {
     const SESSION_FLAG: u8 = 0x00;
     const BITCOIN_FLAG: u8 = 0x01;
     const BITCOIN_MULTISIGN: u8 = 0x02;
+    /// The Ethereum validator is registered by system after the bitcoin multisign validator,
+    /// the registration asserts the id is the same as this flag.
     const ETHEREUM_FLAG: u8 = 0x03;
 
     pub fn flag(&self) -> u8 {
@@ -105,7 +107,7 @@ impl BuiltinAuthValidator {
             },
             BuiltinAuthValidator::Ethereum => AuthValidator {
                 id: self.flag().into(),
-                module_address: ROOCH_NURSERY_ADDRESS,
+                module_address: ROOCH_FRAMEWORK_ADDRESS,
                 module_name: MoveString::from_str("ethereum_validator").expect("Should be valid"),
             },
         }
@@ -14,6 +14,7 @@ use move_core_types::{
 };
 use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_std::option::MoveOption,
     moveos_std::tx_context::TxContext,
     transaction::FunctionCall,
 };
@@ -100,7 +101,11 @@ pub struct EthereumModule<'a> {
 
 impl<'a> EthereumModule<'a> {
     pub const GET_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("get_block");
+    pub const GET_BLOCK_HASH_FUNCTION_NAME: &'static IdentStr = ident_str!("get_block_hash");
+    pub const GET_LATEST_BLOCK_NUMBER_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("get_latest_block_number");
     pub const EXECUTE_L1_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("execute_l1_block");
+    pub const EXECUTE_L1_TX_FUNCTION_NAME: &'static IdentStr = ident_str!("execute_l1_tx");
 
     pub fn get_block(&self, block_number: u64) -> Result<BlockHeader> {
         let call = FunctionCall::new(
@@ -121,6 +126,45 @@ impl<'a> EthereumModule<'a> {
         Ok(block_header)
     }
 
+    pub fn get_block_hash(&self, block_number: u64) -> Result<Option<Vec<u8>>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::GET_BLOCK_HASH_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::U64(block_number).simple_serialize().unwrap()],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let block_hash_option =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<MoveOption<Vec<u8>>>(&value.value)
+                        .expect("should be a valid MoveOption<vector<u8>>")
+                })?;
+        Ok(block_hash_option.into())
+    }
+
+    /// The latest relayed block number, None if no block has been relayed
+    pub fn get_latest_block_number(&self) -> Result<Option<u64>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::GET_LATEST_BLOCK_NUMBER_FUNCTION_NAME),
+            vec![],
+            vec![],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let block_number_option =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<MoveOption<u64>>(&value.value)
+                        .expect("should be a valid MoveOption<u64>")
+                })?;
+        Ok(block_number_option.into())
+    }
+
     pub fn create_execute_l1_block_call(block_header: &BlockHeader) -> FunctionCall {
         Self::create_function_call(
             Self::EXECUTE_L1_BLOCK_FUNCTION_NAME,
@@ -138,6 +182,14 @@ impl<'a> EthereumModule<'a> {
             vec![MoveValue::vector_u8(block_header)],
         )
     }
+
+    pub fn create_execute_l1_tx_call(block_hash: Vec<u8>, txid: Vec<u8>) -> FunctionCall {
+        Self::create_function_call(
+            Self::EXECUTE_L1_TX_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(block_hash), MoveValue::vector_u8(txid)],
+        )
+    }
 }
 
 impl<'a> ModuleBinding<'a> for EthereumModule<'a> {
@@ -1,29 +1,199 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::auth_validator::BuiltinAuthValidator;
-use anyhow::Result;
-use framework_types::addresses::ROOCH_NURSERY_ADDRESS;
+use super::{auth_payload::MESSAGE_INFO, auth_validator::BuiltinAuthValidator};
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use crate::transaction::RoochTransactionData;
+use anyhow::{ensure, Result};
 use move_core_types::{
     account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
 };
 use moveos_types::{
+    h256::H256,
     module_binding::{ModuleBinding, MoveFunctionCaller},
     moveos_std::tx_context::TxContext,
     state::MoveStructType,
     transaction::FunctionCall,
 };
+use serde_json::json;
+use sha3::{Digest, Keccak256};
+
+/// The EIP-191 `personal_sign` message prefix, followed by the decimal length of the message
+pub const ETHEREUM_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+/// The EIP-712 typed data message prefix
+pub const EIP712_MESSAGE_PREFIX: &[u8] = b"\x19\x01";
+pub const EIP712_DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,uint256 chainId)";
+pub const EIP712_DOMAIN_NAME: &str = "Rooch";
+pub const EIP712_DOMAIN_VERSION: &str = "1";
+pub const EIP712_TRANSACTION_TYPE: &str = "RoochTransaction(string message)";
+
+const TX_HASH_HEX_LENGTH: usize = 64;
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// The EIP-712 domain separator of Rooch on the given chain
+pub fn eip712_domain_separator(chain_id: u64) -> [u8; 32] {
+    let mut data = Vec::with_capacity(128);
+    data.extend_from_slice(&keccak256(EIP712_DOMAIN_TYPE.as_bytes()));
+    data.extend_from_slice(&keccak256(EIP712_DOMAIN_NAME.as_bytes()));
+    data.extend_from_slice(&keccak256(EIP712_DOMAIN_VERSION.as_bytes()));
+    // The uint256 is encoded as 32 bytes big-endian
+    let mut chain_id_bytes = [0u8; 32];
+    chain_id_bytes[24..].copy_from_slice(&chain_id.to_be_bytes());
+    data.extend_from_slice(&chain_id_bytes);
+    keccak256(&data)
+}
+
+/// How the Ethereum wallet signs the Rooch transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EthereumSignType {
+    /// EIP-191 `personal_sign`
+    #[default]
+    PersonalSign,
+    /// EIP-712 `eth_signTypedData_v4`
+    TypedData,
+}
+
+/// The message of the Rooch transaction signed by the Ethereum wallet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EthereumSignData {
+    pub sign_type: EthereumSignType,
+    pub chain_id: u64,
+    /// The EIP-191 prefix with the message length, or the EIP-712 prefix
+    pub message_prefix: Vec<u8>,
+    /// The message info, include the tx_hash hex
+    pub message_info: Vec<u8>,
+}
+
+impl EthereumSignData {
+    pub fn new(sign_type: EthereumSignType, tx_data: &RoochTransactionData) -> Self {
+        let mut message_info = MESSAGE_INFO.to_vec();
+        message_info.extend_from_slice(hex::encode(tx_data.tx_hash().as_bytes()).as_bytes());
+        let message_prefix = match sign_type {
+            EthereumSignType::PersonalSign => {
+                let mut message_prefix = ETHEREUM_MESSAGE_PREFIX.to_vec();
+                message_prefix.extend_from_slice(message_info.len().to_string().as_bytes());
+                message_prefix
+            }
+            EthereumSignType::TypedData => EIP712_MESSAGE_PREFIX.to_vec(),
+        };
+        Self {
+            sign_type,
+            chain_id: tx_data.chain_id,
+            message_prefix,
+            message_info,
+        }
+    }
+
+    /// The sign data of the auth payload, it is checked the same as the Ethereum validator on chain
+    pub fn new_with_payload(
+        message_prefix: Vec<u8>,
+        message_info: Vec<u8>,
+        tx_data: &RoochTransactionData,
+    ) -> Result<Self> {
+        ensure!(
+            message_info.starts_with(MESSAGE_INFO),
+            "The message info of the Ethereum auth payload must start with {:?}",
+            String::from_utf8_lossy(MESSAGE_INFO)
+        );
+        let mut message_info = message_info;
+        message_info.extend_from_slice(hex::encode(tx_data.tx_hash().as_bytes()).as_bytes());
+        let sign_type = if message_prefix == EIP712_MESSAGE_PREFIX {
+            EthereumSignType::TypedData
+        } else {
+            let mut expected_prefix = ETHEREUM_MESSAGE_PREFIX.to_vec();
+            expected_prefix.extend_from_slice(message_info.len().to_string().as_bytes());
+            ensure!(
+                message_prefix == expected_prefix,
+                "Invalid message prefix of the Ethereum auth payload"
+            );
+            EthereumSignType::PersonalSign
+        };
+        Ok(Self {
+            sign_type,
+            chain_id: tx_data.chain_id,
+            message_prefix,
+            message_info,
+        })
+    }
+
+    pub fn new_personal_sign(tx_data: &RoochTransactionData) -> Self {
+        Self::new(EthereumSignType::PersonalSign, tx_data)
+    }
+
+    pub fn new_typed_data(tx_data: &RoochTransactionData) -> Self {
+        Self::new(EthereumSignType::TypedData, tx_data)
+    }
+
+    /// The message info without tx hash, the verifier should append the tx hash to the message info
+    pub fn message_info_without_tx_hash(&self) -> Vec<u8> {
+        self.message_info[..self.message_info.len() - TX_HASH_HEX_LENGTH].to_vec()
+    }
+
+    /// The EIP-712 `hashStruct` of the `RoochTransaction` message
+    pub fn eip712_hash_struct(&self) -> [u8; 32] {
+        let mut data = Vec::with_capacity(64);
+        data.extend_from_slice(&keccak256(EIP712_TRANSACTION_TYPE.as_bytes()));
+        data.extend_from_slice(&keccak256(&self.message_info));
+        keccak256(&data)
+    }
+
+    /// Encode the full message, the Ethereum wallet signs the keccak256 hash of it
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = self.message_prefix.clone();
+        match self.sign_type {
+            EthereumSignType::PersonalSign => data.extend_from_slice(&self.message_info),
+            EthereumSignType::TypedData => {
+                data.extend_from_slice(&eip712_domain_separator(self.chain_id));
+                data.extend_from_slice(&self.eip712_hash_struct());
+            }
+        }
+        data
+    }
+
+    pub fn data_hash(&self) -> H256 {
+        H256::from(keccak256(&self.encode()))
+    }
+
+    /// The EIP-712 typed data for the `eth_signTypedData_v4` request of the Ethereum wallet
+    pub fn typed_data(&self) -> serde_json::Value {
+        json!({
+            "types": {
+                "EIP712Domain": [
+                    {"name": "name", "type": "string"},
+                    {"name": "version", "type": "string"},
+                    {"name": "chainId", "type": "uint256"},
+                ],
+                "RoochTransaction": [
+                    {"name": "message", "type": "string"},
+                ],
+            },
+            "primaryType": "RoochTransaction",
+            "domain": {
+                "name": EIP712_DOMAIN_NAME,
+                "version": EIP712_DOMAIN_VERSION,
+                "chainId": self.chain_id,
+            },
+            "message": {
+                "message": String::from_utf8_lossy(&self.message_info),
+            },
+        })
+    }
+}
 
 pub struct EthereumValidator {}
 
 impl EthereumValidator {
+    /// The id is asserted when the validator is registered on chain, see `ethereum_validator::init_for_upgrade`.
     pub fn auth_validator_id() -> u64 {
         BuiltinAuthValidator::Ethereum.flag().into()
     }
 }
 
 impl MoveStructType for EthereumValidator {
-    const ADDRESS: AccountAddress = ROOCH_NURSERY_ADDRESS;
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
     const MODULE_NAME: &'static IdentStr = EthereumValidatorModule::MODULE_NAME;
     const STRUCT_NAME: &'static IdentStr = ident_str!("EthereumValidator");
 }
@@ -54,7 +224,7 @@ impl<'a> EthereumValidatorModule<'a> {
 
 impl<'a> ModuleBinding<'a> for EthereumValidatorModule<'a> {
     const MODULE_NAME: &'static IdentStr = ident_str!("ethereum_validator");
-    const MODULE_ADDRESS: AccountAddress = ROOCH_NURSERY_ADDRESS;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
 
     fn new(caller: &'a impl MoveFunctionCaller) -> Self
     where
@@ -63,3 +233,117 @@ impl<'a> ModuleBinding<'a> for EthereumValidatorModule<'a> {
         Self { caller }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crypto::RoochKeyPair,
+        framework::{auth_payload::AuthPayload, empty::Empty},
+        transaction::Authenticator,
+    };
+    use fastcrypto::{
+        hash::Keccak256,
+        secp256k1::recoverable::Secp256k1RecoverableSignature,
+        traits::{RecoverableSignature, ToFromBytes},
+    };
+
+    #[test]
+    pub fn test_ethereum_address() {
+        let mut secret_key = [0u8; 32];
+        secret_key[31] = 1;
+        let kp = RoochKeyPair::from_secp256k1_bytes(&secret_key).unwrap();
+        assert_eq!(
+            kp.public().ethereum_address().unwrap().to_string(),
+            "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+        );
+    }
+
+    #[test]
+    pub fn test_sign_and_recover() {
+        let kp = RoochKeyPair::generate_secp256k1();
+        let tx_data = RoochTransactionData::new_for_test(
+            kp.public().rooch_address().unwrap(),
+            0,
+            Empty::empty_function_call().into(),
+        );
+        for (sign_type, auth) in [
+            (
+                EthereumSignType::PersonalSign,
+                Authenticator::ethereum(&kp, &tx_data).unwrap(),
+            ),
+            (
+                EthereumSignType::TypedData,
+                Authenticator::ethereum_typed_data(&kp, &tx_data).unwrap(),
+            ),
+        ] {
+            assert_eq!(
+                auth.auth_validator_id,
+                EthereumValidator::auth_validator_id()
+            );
+            let auth_payload = bcs::from_bytes::<AuthPayload>(&auth.payload).unwrap();
+            assert_eq!(auth_payload.message_info, MESSAGE_INFO.to_vec());
+            assert_eq!(
+                auth_payload.from_address,
+                kp.public()
+                    .ethereum_address()
+                    .unwrap()
+                    .to_string()
+                    .into_bytes()
+            );
+
+            let sign_data = EthereumSignData::new(sign_type, &tx_data);
+            let signature =
+                Secp256k1RecoverableSignature::from_bytes(&auth_payload.signature).unwrap();
+            let public_key = signature
+                .recover_with_hash::<Keccak256>(&sign_data.encode())
+                .unwrap();
+            assert_eq!(public_key.as_bytes(), auth_payload.public_key.as_slice());
+            auth_payload.verify_ethereum(&tx_data).unwrap();
+
+            // The Ethereum wallets use 27 and 28 as the recovery id
+            let mut wallet_payload = auth_payload.clone();
+            *wallet_payload.signature.last_mut().unwrap() += 27;
+            wallet_payload.verify_ethereum(&tx_data).unwrap();
+
+            let mut bad_payload = auth_payload.clone();
+            bad_payload.signature[1] ^= 0xff;
+            assert!(bad_payload.verify_ethereum(&tx_data).is_err());
+            let other_tx_data = RoochTransactionData::new_for_test(
+                tx_data.sender,
+                1,
+                Empty::empty_function_call().into(),
+            );
+            assert!(auth_payload.verify_ethereum(&other_tx_data).is_err());
+        }
+    }
+
+    #[test]
+    pub fn test_sign_with_ed25519() {
+        let kp = RoochKeyPair::generate_ed25519();
+        let tx_data = RoochTransactionData::new_for_test(
+            kp.public().rooch_address().unwrap(),
+            0,
+            Empty::empty_function_call().into(),
+        );
+        assert!(Authenticator::ethereum(&kp, &tx_data).is_err());
+        assert!(Authenticator::ethereum_typed_data(&kp, &tx_data).is_err());
+    }
+
+    #[test]
+    pub fn test_personal_sign_message() {
+        let tx_data = RoochTransactionData::new_for_test(
+            ROOCH_FRAMEWORK_ADDRESS.into(),
+            0,
+            Empty::empty_function_call().into(),
+        );
+        let sign_data = EthereumSignData::new_personal_sign(&tx_data);
+        // `Rooch Transaction:\n` and the 64 bytes tx hash hex
+        assert_eq!(
+            sign_data.message_prefix,
+            b"\x19Ethereum Signed Message:\n83".to_vec()
+        );
+        let message = String::from_utf8(sign_data.encode()).unwrap();
+        assert!(message.ends_with(&hex::encode(tx_data.tx_hash().as_bytes())));
+    }
+}
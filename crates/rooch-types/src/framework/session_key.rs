@@ -4,9 +4,12 @@
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use crate::authentication_key::AuthenticationKey;
 use anyhow::Result;
+use move_core_types::language_storage::TypeTag;
+use move_core_types::u256::U256;
 use move_core_types::value::MoveValue;
 use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
 use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::moveos_std::simple_map::SimpleMap;
 use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
     move_std::option::MoveOption,
@@ -183,6 +186,72 @@ impl MoveStructState for SessionKey {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CoinLimit {
+    pub max_amount: U256,
+    pub spent_amount: U256,
+}
+
+impl MoveStructType for CoinLimit {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("CoinLimit");
+}
+
+impl MoveStructState for CoinLimit {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U256,
+            move_core_types::value::MoveTypeLayout::U256,
+        ])
+    }
+}
+
+/// The spending limits and call budgets of a session key, the limit with zero value means no limit
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SessionKeyLimits {
+    pub max_gas: U256,
+    pub gas_used: U256,
+    pub max_tx_count: u64,
+    pub tx_count: u64,
+    /// The absolute expiry time in seconds
+    pub expire_time: u64,
+    /// The coin type name to the coin limit, the coin types not in the map are not limited
+    pub coin_limits: SimpleMap<MoveString, CoinLimit>,
+}
+
+impl SessionKeyLimits {
+    pub fn is_expired(&self) -> bool {
+        if self.expire_time == 0 {
+            return false;
+        }
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("should get system time")
+            .as_secs();
+        now > self.expire_time
+    }
+}
+
+impl MoveStructType for SessionKeyLimits {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("SessionKeyLimits");
+}
+
+impl MoveStructState for SessionKeyLimits {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U256,
+            move_core_types::value::MoveTypeLayout::U256,
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::U64,
+            SimpleMap::<MoveString, CoinLimit>::type_layout(),
+        ])
+    }
+}
+
 /// Rust bindings for RoochFramework session_key module
 pub struct SessionKeyModule<'a> {
     caller: &'a dyn MoveFunctionCaller,
@@ -194,6 +263,14 @@ impl<'a> SessionKeyModule<'a> {
         ident_str!("create_session_key_entry");
     pub const GET_SESSION_KEYS_HANDLE_FUNCTION_NAME: &'static IdentStr =
         ident_str!("get_session_keys_handle");
+    pub const GET_SESSION_KEY_LIMITS_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("get_session_key_limits");
+    pub const CREATE_SESSION_KEY_WITH_LIMITS_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("create_session_key_with_limits_entry");
+    pub const SET_SESSION_KEY_LIMITS_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("set_session_key_limits_entry");
+    pub const SET_SESSION_KEY_COIN_LIMIT_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("set_session_key_coin_limit_entry");
 
     pub fn get_session_key(
         &self,
@@ -251,6 +328,37 @@ impl<'a> SessionKeyModule<'a> {
         Ok(obj_id)
     }
 
+    pub fn get_session_key_limits(
+        &self,
+        account_address: AccountAddress,
+        auth_key: &AuthenticationKey,
+    ) -> Result<Option<SessionKeyLimits>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::GET_SESSION_KEY_LIMITS_FUNCTION_NAME),
+            vec![],
+            vec![
+                MoveValue::Address(account_address)
+                    .simple_serialize()
+                    .unwrap(),
+                MoveValue::vector_u8(auth_key.as_ref().to_vec())
+                    .simple_serialize()
+                    .unwrap(),
+            ],
+        );
+        let ctx = TxContext::new_readonly_ctx(account_address);
+        let limits = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<MoveOption<SessionKeyLimits>>(&value.value)
+                    .expect("should be a valid MoveOption<SessionKeyLimits>")
+                    .into()
+            })?;
+        Ok(limits)
+    }
+
     pub fn create_session_key_action(
         app_name: MoveString,
         app_url: MoveString,
@@ -272,6 +380,93 @@ impl<'a> SessionKeyModule<'a> {
             ],
         )
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_session_key_with_limits_action(
+        app_name: MoveString,
+        app_url: MoveString,
+        authentication_key: Vec<u8>,
+        scopes: Vec<SessionScope>,
+        max_inactive_interval: u64,
+        max_gas: U256,
+        max_tx_count: u64,
+        expire_time: u64,
+        coin_limits: Vec<(TypeTag, U256)>,
+    ) -> MoveAction {
+        // The coin limits are keyed by the canonical type name of the coin, the same as `type_info::type_name` in Move
+        let (coin_types, coin_max_amounts): (Vec<_>, Vec<_>) = coin_limits
+            .into_iter()
+            .map(|(coin_type, max_amount)| {
+                (
+                    MoveString::from(coin_type.to_canonical_string()).to_move_value(),
+                    MoveValue::U256(max_amount),
+                )
+            })
+            .unzip();
+        let (module_addresses, (module_names, function_names)): (Vec<_>, (Vec<_>, Vec<_>)) = scopes
+            .into_iter()
+            .map(|scope| {
+                (
+                    scope.module_address.to_move_value(),
+                    (
+                        scope.module_name.to_move_value(),
+                        scope.function_name.to_move_value(),
+                    ),
+                )
+            })
+            .unzip();
+        Self::create_move_action(
+            Self::CREATE_SESSION_KEY_WITH_LIMITS_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                app_name.to_move_value(),
+                app_url.to_move_value(),
+                MoveValue::vector_u8(authentication_key),
+                MoveValue::Vector(module_addresses),
+                MoveValue::Vector(module_names),
+                MoveValue::Vector(function_names),
+                MoveValue::U64(max_inactive_interval),
+                MoveValue::U256(max_gas),
+                MoveValue::U64(max_tx_count),
+                MoveValue::U64(expire_time),
+                MoveValue::Vector(coin_types),
+                MoveValue::Vector(coin_max_amounts),
+            ],
+        )
+    }
+
+    pub fn set_session_key_limits_action(
+        authentication_key: Vec<u8>,
+        max_gas: U256,
+        max_tx_count: u64,
+        expire_time: u64,
+    ) -> MoveAction {
+        Self::create_move_action(
+            Self::SET_SESSION_KEY_LIMITS_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::vector_u8(authentication_key),
+                MoveValue::U256(max_gas),
+                MoveValue::U64(max_tx_count),
+                MoveValue::U64(expire_time),
+            ],
+        )
+    }
+
+    pub fn set_session_key_coin_limit_action(
+        coin_type: TypeTag,
+        authentication_key: Vec<u8>,
+        max_amount: U256,
+    ) -> MoveAction {
+        Self::create_move_action(
+            Self::SET_SESSION_KEY_COIN_LIMIT_ENTRY_FUNCTION_NAME,
+            vec![coin_type],
+            vec![
+                MoveValue::vector_u8(authentication_key),
+                MoveValue::U256(max_amount),
+            ],
+        )
+    }
 }
 
 impl<'a> ModuleBinding<'a> for SessionKeyModule<'a> {
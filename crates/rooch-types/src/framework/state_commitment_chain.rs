@@ -0,0 +1,77 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use crate::block::Block;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_std::option::MoveOption,
+    moveos_std::tx_context::TxContext,
+    transaction::MoveAction,
+};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("state_commitment_chain");
+
+/// Rust bindings for RoochFramework state_commitment_chain module
+pub struct StateCommitmentChainModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> StateCommitmentChainModule<'a> {
+    pub const LAST_BLOCK_NUMBER_FUNCTION_NAME: &'static IdentStr = ident_str!("last_block_number");
+    pub const PROPOSE_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("propose_block");
+
+    /// The number of the last block in the on-chain SCC
+    pub fn last_block_number(&self) -> Result<Option<u128>> {
+        let call =
+            Self::create_function_call(Self::LAST_BLOCK_NUMBER_FUNCTION_NAME, vec![], vec![]);
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let block_number_option =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<MoveOption<u128>>(&value.value)
+                        .expect("should be a valid MoveOption<u128>")
+                })?;
+        Ok(block_number_option.into())
+    }
+
+    /// Whether the action calls `propose_block`
+    pub fn is_propose_block_action(action: &MoveAction) -> bool {
+        matches!(action, MoveAction::Function(call)
+            if call.function_id == Self::function_id(Self::PROPOSE_BLOCK_FUNCTION_NAME))
+    }
+
+    pub fn propose_block_action(block: &Block) -> MoveAction {
+        Self::create_move_action(
+            Self::PROPOSE_BLOCK_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::U128(block.block_number),
+                MoveValue::U64(block.batch_size),
+                MoveValue::vector_u8(block.batch_hash.0.to_vec()),
+                MoveValue::vector_u8(block.prev_tx_accumulator_root.0.to_vec()),
+                MoveValue::vector_u8(block.tx_accumulator_root.0.to_vec()),
+                MoveValue::vector_u8(block.state_root.0.to_vec()),
+            ],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for StateCommitmentChainModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
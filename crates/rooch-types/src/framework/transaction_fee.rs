@@ -0,0 +1,37 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
+use moveos_types::{
+    moveos_std::object::{self, ObjectID},
+    state::{MoveState, MoveStructState, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("transaction_fee");
+
+/// The named Object of the transaction fee pool, the gas revenue stores are the fields of the Object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionFeePool {
+    /// The CoinStore of the gas paid by the transactions
+    pub fee: ObjectID,
+}
+
+impl TransactionFeePool {
+    pub fn object_id() -> ObjectID {
+        object::named_object_id(&Self::struct_tag())
+    }
+}
+
+impl MoveStructType for TransactionFeePool {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("TransactionFeePool");
+}
+
+impl MoveStructState for TransactionFeePool {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![ObjectID::type_layout()])
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    authentication_key::AuthenticationKey,
+    crypto::{PublicKey, SignatureScheme},
+};
+use anyhow::{ensure, Result};
+use fastcrypto::{
+    encoding::{Base64, Encoding},
+    secp256r1::{Secp256r1PublicKey, Secp256r1Signature},
+    traits::{ToFromBytes, VerifyingKey},
+};
+use framework_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
+use moveos_types::{
+    h256::{sha2_256_of, H256},
+    state::{MoveStructState, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("webauthn");
+
+/// The minimum length of the authenticator data: rpIdHash(32) + flags(1) + signCount(4)
+const AUTHENTICATOR_DATA_MIN_LENGTH: usize = 37;
+const AUTHENTICATOR_DATA_FLAGS_INDEX: usize = 32;
+const FLAG_USER_PRESENT: u8 = 0x01;
+
+/// The WebAuthn(passkey) assertion of a transaction, the challenge of the assertion is the tx hash.
+/// It is sent as the session authenticator payload with the Secp256r1 scheme flag.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebauthnAuthPayload {
+    /// The 64-bytes (r, s) signature, the s must be normalized to the lower half of the curve order
+    pub signature: Vec<u8>,
+    /// The 33-bytes compressed Secp256r1 public key
+    pub public_key: Vec<u8>,
+    pub authenticator_data: Vec<u8>,
+    pub client_data_json: Vec<u8>,
+}
+
+impl MoveStructType for WebauthnAuthPayload {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("WebauthnAuthPayload");
+}
+
+impl MoveStructState for WebauthnAuthPayload {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+        ])
+    }
+}
+
+impl WebauthnAuthPayload {
+    pub fn new(
+        signature: Vec<u8>,
+        public_key: Vec<u8>,
+        authenticator_data: Vec<u8>,
+        client_data_json: Vec<u8>,
+    ) -> Self {
+        Self {
+            signature,
+            public_key,
+            authenticator_data,
+            client_data_json,
+        }
+    }
+
+    /// The challenge passed to `navigator.credentials.get`, the base64url encoded tx hash without padding
+    pub fn challenge(tx_hash: &H256) -> String {
+        Base64::encode(tx_hash.as_bytes())
+            .trim_end_matches('=')
+            .replace('+', "-")
+            .replace('/', "_")
+    }
+
+    /// The clientDataJSON must start with the prefix, as the limited verification algorithm of WebAuthn
+    fn client_data_prefix(tx_hash: &H256) -> String {
+        format!(
+            r#"{{"type":"webauthn.get","challenge":"{}""#,
+            Self::challenge(tx_hash)
+        )
+    }
+
+    /// The message signed by the authenticator: `authenticatorData || sha256(clientDataJSON)`
+    pub fn signed_message(&self) -> Vec<u8> {
+        let mut message = self.authenticator_data.clone();
+        message.extend_from_slice(sha2_256_of(&self.client_data_json).as_bytes());
+        message
+    }
+
+    pub fn verify(&self, tx_hash: &H256) -> Result<()> {
+        ensure!(
+            self.authenticator_data.len() >= AUTHENTICATOR_DATA_MIN_LENGTH,
+            "Invalid authenticator data length"
+        );
+        ensure!(
+            self.authenticator_data[AUTHENTICATOR_DATA_FLAGS_INDEX] & FLAG_USER_PRESENT != 0,
+            "The user present flag is not set"
+        );
+        ensure!(
+            self.client_data_json
+                .starts_with(Self::client_data_prefix(tx_hash).as_bytes()),
+            "The challenge of the client data does not match the tx hash"
+        );
+        let pk = Secp256r1PublicKey::from_bytes(&self.public_key)?;
+        let signature = Secp256r1Signature::from_bytes(&self.signature)?;
+        pk.verify(&self.signed_message(), &signature)?;
+        Ok(())
+    }
+
+    pub fn public_key(&self) -> Result<PublicKey> {
+        PublicKey::from_secp256r1_bytes(&self.public_key)
+    }
+
+    /// The authentication key of the passkey, it is used to register the passkey as a session key
+    pub fn authentication_key(&self) -> Result<AuthenticationKey> {
+        Ok(self.public_key()?.authentication_key())
+    }
+
+    /// Encode as the session authenticator payload: `flag || bcs(payload)`
+    pub fn to_session_payload(&self) -> Vec<u8> {
+        let mut payload = vec![SignatureScheme::Secp256r1.flag()];
+        payload.extend(bcs::to_bytes(self).expect("Serialize WebauthnAuthPayload should success"));
+        payload
+    }
+
+    pub fn from_session_payload(payload: &[u8]) -> Result<Self> {
+        ensure!(
+            payload.first() == Some(&SignatureScheme::Secp256r1.flag()),
+            "Invalid WebAuthn payload scheme"
+        );
+        Ok(bcs::from_bytes(&payload[1..])?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::sign_webauthn_payload;
+    use fastcrypto::secp256r1::Secp256r1KeyPair;
+    use fastcrypto::traits::KeyPair;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_challenge() {
+        let tx_hash = H256::from_slice(
+            &hex::decode("5415b18de0b880bb2af5dfe1ee27fd19ae8a0c99b5328e8b4b44f4c86cc7176a")
+                .unwrap(),
+        );
+        assert_eq!(
+            WebauthnAuthPayload::challenge(&tx_hash),
+            "VBWxjeC4gLsq9d_h7if9Ga6KDJm1Mo6LS0T0yGzHF2o"
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let kp = Secp256r1KeyPair::generate(&mut StdRng::from_seed([0; 32]));
+        let tx_hash = H256::random();
+        let payload = sign_webauthn_payload(&kp, &tx_hash);
+        payload.verify(&tx_hash).unwrap();
+        assert!(payload.verify(&H256::random()).is_err());
+
+        let decoded =
+            WebauthnAuthPayload::from_session_payload(&payload.to_session_payload()).unwrap();
+        assert_eq!(decoded, payload);
+        assert_eq!(
+            payload.public_key().unwrap().scheme(),
+            SignatureScheme::Secp256r1
+        );
+    }
+}
@@ -8,7 +8,7 @@ use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::effects::Op;
 use move_core_types::language_storage::{StructTag, TypeTag};
-use moveos_types::move_types::type_tag_match;
+use moveos_types::move_types::{struct_tag_match, type_tag_match};
 use moveos_types::moveos_std::object::{is_dynamic_field_type, ObjectID, ObjectMeta};
 use moveos_types::state::{MoveStructType, MoveType, ObjectChange, StateChangeSet};
 use once_cell::sync::Lazy;
@@ -361,16 +361,26 @@ pub enum ObjectStateFilter {
 }
 
 impl ObjectStateFilter {
+    /// Match the object state in the same way as the indexer query:
+    /// 1. an object type without type params matches all its instantiations,
+    ///    e.g. `0x3::coin_store::CoinStore` matches `0x3::coin_store::CoinStore<0x3::gas_coin::RGas>`.
+    /// 2. `ObjectTypeWithOwner` with `filter_out` matches the objects of the owner which are not of the type.
+    /// 3. `ObjectId` matches any of the object ids.
     fn try_matches(&self, item: &IndexerObjectState) -> Result<bool> {
         Ok(match self {
             ObjectStateFilter::ObjectTypeWithOwner {
-                object_type, owner, ..
-            } => object_type == item.object_struct_tag() && owner == &item.metadata.owner,
-            ObjectStateFilter::ObjectType(object_type) => object_type == item.object_struct_tag(),
-            ObjectStateFilter::Owner(owner) => owner == &item.metadata.owner,
-            ObjectStateFilter::ObjectId(object_ids) => {
-                object_ids.len() == 1 && object_ids[0] == item.metadata.id
+                object_type,
+                owner,
+                filter_out,
+            } => {
+                owner == &item.metadata.owner
+                    && struct_tag_match(object_type, item.object_struct_tag()) != *filter_out
+            }
+            ObjectStateFilter::ObjectType(object_type) => {
+                struct_tag_match(object_type, item.object_struct_tag())
             }
+            ObjectStateFilter::Owner(owner) => owner == &item.metadata.owner,
+            ObjectStateFilter::ObjectId(object_ids) => object_ids.contains(&item.metadata.id),
         })
     }
 }
@@ -387,3 +397,72 @@ pub struct IndexerStateChangeSet {
     pub state_change_set: StateChangeSet,
     pub created_at: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::Filter;
+    use std::str::FromStr;
+
+    fn new_object_state(object_type: &str, owner: AccountAddress) -> IndexerObjectState {
+        let object_type = StructTag::from_str(object_type).unwrap();
+        let metadata = ObjectMeta::new(
+            ObjectID::random(),
+            owner,
+            0,
+            None,
+            0,
+            0,
+            0,
+            TypeTag::Struct(Box::new(object_type)),
+        );
+        IndexerObjectState::new(metadata, 0, 0)
+    }
+
+    #[test]
+    fn test_object_state_filter_matches() {
+        let owner = AccountAddress::random();
+        let other_owner = AccountAddress::random();
+        let coin_store_type = "0x3::coin_store::CoinStore<0x3::gas_coin::RGas>";
+        let state = new_object_state(coin_store_type, owner);
+        let other_state = new_object_state("0x3::session_key::SessionKeys", owner);
+        let coin_store = StructTag::from_str(coin_store_type).unwrap();
+        let generic_coin_store = StructTag::from_str("0x3::coin_store::CoinStore").unwrap();
+        let other_coin_store =
+            StructTag::from_str("0x3::coin_store::CoinStore<0x3::gas_coin::GasCoin>").unwrap();
+
+        // exact type and the type without type params
+        assert!(ObjectStateFilter::ObjectType(coin_store.clone()).matches(&state));
+        assert!(ObjectStateFilter::ObjectType(generic_coin_store.clone()).matches(&state));
+        assert!(!ObjectStateFilter::ObjectType(other_coin_store.clone()).matches(&state));
+        assert!(!ObjectStateFilter::ObjectType(generic_coin_store.clone()).matches(&other_state));
+
+        let type_with_owner =
+            |object_type: &StructTag, owner, filter_out| ObjectStateFilter::ObjectTypeWithOwner {
+                object_type: object_type.clone(),
+                owner,
+                filter_out,
+            };
+        assert!(type_with_owner(&generic_coin_store, owner, false).matches(&state));
+        assert!(!type_with_owner(&generic_coin_store, other_owner, false).matches(&state));
+        assert!(!type_with_owner(&other_coin_store, owner, false).matches(&state));
+        // filter_out matches the objects of the owner not in the type
+        assert!(!type_with_owner(&generic_coin_store, owner, true).matches(&state));
+        assert!(type_with_owner(&generic_coin_store, owner, true).matches(&other_state));
+        assert!(type_with_owner(&other_coin_store, owner, true).matches(&state));
+        assert!(!type_with_owner(&other_coin_store, other_owner, true).matches(&state));
+
+        assert!(ObjectStateFilter::Owner(owner).matches(&state));
+        assert!(!ObjectStateFilter::Owner(other_owner).matches(&state));
+
+        // any of the object ids
+        let state_id = state.metadata.id.clone();
+        let other_state_id = other_state.metadata.id.clone();
+        assert!(ObjectStateFilter::ObjectId(vec![state_id.clone()]).matches(&state));
+        let object_ids = ObjectStateFilter::ObjectId(vec![other_state_id, state_id]);
+        assert!(object_ids.matches(&state));
+        assert!(object_ids.matches(&other_state));
+        assert!(!ObjectStateFilter::ObjectId(vec![ObjectID::random()]).matches(&state));
+        assert!(!ObjectStateFilter::ObjectId(vec![]).matches(&state));
+    }
+}
@@ -5,7 +5,7 @@ use accumulator::accumulator_info::AccumulatorInfo;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Eq, PartialEq, Hash, Deserialize, Serialize, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Deserialize, Serialize, Clone)]
 pub struct SequencerInfo {
     pub last_order: u64,
     pub last_accumulator_info: AccumulatorInfo,
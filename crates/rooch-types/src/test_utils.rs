@@ -2,11 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::address::{RoochAddress, RoochSupportedAddress};
+use crate::framework::webauthn::WebauthnAuthPayload;
 use crate::transaction::authenticator::Authenticator;
 use crate::transaction::rooch::{RoochTransaction, RoochTransactionData};
 use crate::transaction::{LedgerTransaction, TransactionSequenceInfo};
 use accumulator::accumulator_info::AccumulatorInfo;
 use ethers::types::H256;
+use fastcrypto::secp256r1::{Secp256r1KeyPair, Secp256r1Signature};
+use fastcrypto::traits::{KeyPair, Signer, ToFromBytes};
 use move_core_types::account_address::AccountAddress;
 use moveos_types::moveos_std::object::ObjectID;
 use rand::{thread_rng, Rng};
@@ -121,3 +124,29 @@ pub fn random_remove_object_states() -> Vec<String> {
 
     remove_object_states
 }
+
+/// Simulate the passkey to sign the tx hash, returns the WebAuthn assertion
+pub fn sign_webauthn_payload(
+    kp: &Secp256r1KeyPair,
+    tx_hash: &moveos_types::h256::H256,
+) -> WebauthnAuthPayload {
+    let mut authenticator_data = moveos_types::h256::sha2_256_of(b"rooch.network")
+        .as_bytes()
+        .to_vec();
+    // flags: user present and user verified, sign count: 0
+    authenticator_data.extend_from_slice(&[0x05, 0, 0, 0, 0]);
+    let client_data_json = format!(
+        r#"{{"type":"webauthn.get","challenge":"{}","origin":"https://rooch.network","crossOrigin":false}}"#,
+        WebauthnAuthPayload::challenge(tx_hash)
+    )
+    .into_bytes();
+    let mut payload = WebauthnAuthPayload::new(
+        vec![],
+        kp.public().as_bytes().to_vec(),
+        authenticator_data,
+        client_data_json,
+    );
+    let signature: Secp256r1Signature = kp.sign(&payload.signed_message());
+    payload.signature = signature.as_bytes().to_vec();
+    payload
+}
@@ -10,6 +10,7 @@
 use anyhow::Result;
 #[cfg(any(test, feature = "fuzzing"))]
 use fastcrypto::ed25519::Ed25519KeyPair;
+use fastcrypto::hash::Keccak256;
 #[cfg(any(test, feature = "fuzzing"))]
 use fastcrypto::traits::KeyPair;
 #[cfg(any(test, feature = "fuzzing"))]
@@ -20,10 +21,12 @@ use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 
 use crate::{
-    crypto::{RoochKeyPair, Signature, SignatureScheme},
+    crypto::{RecoverableSigner, RoochKeyPair, Signature, SignatureScheme},
     framework::{
         auth_payload::{AuthPayload, MultisignAuthPayload, SignData},
         auth_validator::BuiltinAuthValidator,
+        ethereum_validator::{EthereumSignData, EthereumSignType},
+        webauthn::WebauthnAuthPayload,
     },
     rooch_network::{BuiltinChainID, RoochNetwork},
 };
@@ -101,6 +104,28 @@ where
     }
 }
 
+/// The WebAuthn(passkey) authenticator, it is validated by the session validator,
+/// so the passkey must be registered as a session key of the sender.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WebauthnAuthenticator {
+    pub payload: WebauthnAuthPayload,
+}
+
+impl WebauthnAuthenticator {
+    pub fn new(payload: WebauthnAuthPayload) -> Self {
+        Self { payload }
+    }
+}
+
+impl BuiltinAuthenticator for WebauthnAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::Session.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        self.payload.to_session_payload()
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct BitcoinAuthenticator {
     pub payload: AuthPayload,
@@ -142,6 +167,54 @@ impl BuiltinAuthenticator for BitcoinAuthenticator {
     }
 }
 
+/// The Ethereum wallet authenticator, the transaction is signed via EIP-191 `personal_sign`
+/// or EIP-712 typed data, it is validated by the Ethereum validator.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EthereumAuthenticator {
+    pub payload: AuthPayload,
+}
+
+impl EthereumAuthenticator {
+    pub fn new(payload: AuthPayload) -> Self {
+        Self { payload }
+    }
+
+    pub fn sign(
+        kp: &RoochKeyPair,
+        tx_data: &RoochTransactionData,
+        sign_type: EthereumSignType,
+    ) -> Result<Self> {
+        let sign_data = EthereumSignData::new(sign_type, tx_data);
+        let signature = match kp {
+            RoochKeyPair::Secp256k1(kp) => {
+                kp.sign_recoverable_with_hash::<Keccak256>(&sign_data.encode())
+            }
+            RoochKeyPair::Ed25519(_) => {
+                anyhow::bail!("Ethereum authenticator only supports the secp256k1 key pair")
+            }
+        };
+        let public_key = kp.public();
+        let ethereum_address = public_key.ethereum_address()?;
+        Ok(EthereumAuthenticator {
+            payload: AuthPayload::new_ethereum(
+                sign_data,
+                signature.as_ref().to_vec(),
+                public_key.as_ref().to_vec(),
+                ethereum_address.to_string(),
+            ),
+        })
+    }
+}
+
+impl BuiltinAuthenticator for EthereumAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::Ethereum.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(&self.payload).expect("Serialize EthereumAuthenticator should success")
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct BitcoinMultisignAuthenticator {
     pub payload: MultisignAuthPayload,
@@ -193,9 +266,9 @@ impl Authenticator {
     }
 
     pub fn sign(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Self {
-        match kp.public().scheme() {
-            SignatureScheme::Ed25519 => Self::session(kp, tx_data),
-            SignatureScheme::Secp256k1 => Self::bitcoin(kp, tx_data),
+        match kp {
+            RoochKeyPair::Ed25519(_) => Self::session(kp, tx_data),
+            RoochKeyPair::Secp256k1(_) => Self::bitcoin(kp, tx_data),
         }
     }
 
@@ -210,6 +283,22 @@ impl Authenticator {
         BitcoinAuthenticator::sign(kp, tx_data).into()
     }
 
+    /// Create an Ethereum authenticator for RoochTransaction
+    /// We simulate the Ethereum Wallet EIP-191 `personal_sign` message signature
+    pub fn ethereum(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Result<Self> {
+        EthereumAuthenticator::sign(kp, tx_data, EthereumSignType::PersonalSign).map(Into::into)
+    }
+
+    /// Create an Ethereum authenticator for RoochTransaction with the EIP-712 typed data signature
+    pub fn ethereum_typed_data(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Result<Self> {
+        EthereumAuthenticator::sign(kp, tx_data, EthereumSignType::TypedData).map(Into::into)
+    }
+
+    /// Create a WebAuthn authenticator with the passkey assertion of the tx hash
+    pub fn webauthn(payload: WebauthnAuthPayload) -> Self {
+        WebauthnAuthenticator::new(payload).into()
+    }
+
     /// Create a bitcoin multisign authenticator for RoochTransaction
     pub fn bitcoin_multisign(authenticators: Vec<BitcoinAuthenticator>) -> Result<Self> {
         BitcoinMultisignAuthenticator::build_multisig_authenticator(authenticators).map(Into::into)
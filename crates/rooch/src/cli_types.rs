@@ -91,7 +91,9 @@ pub struct TransactionOptions {
     pub(crate) sequence_number: Option<u64>,
 
     /// Custom the transaction's gas limit.
-    /// [default: 1_000_000_000] [alias: "gas-limit"]
+    /// If not set, estimate it via dry run, the gas used multiplied by the `gas_estimate_multiplier` in the client config(default 1.5),
+    /// capped by the default max gas amount. The transaction is not submitted if the dry run fails.
+    /// [alias: "gas-limit"]
     #[clap(long, alias = "gas-limit")]
     pub(crate) max_gas_amount: Option<u64>,
 
@@ -34,6 +34,7 @@ pub struct LocalAccountView {
     pub address: String,
     pub hex_address: String,
     pub bitcoin_address: String,
+    pub ethereum_address: String,
     pub nostr_public_key: String,
     pub public_key: String,
     pub has_session_key: bool,
@@ -48,6 +49,7 @@ impl LocalAccountView {
                 .bitcoin_address
                 .format(btc_network)
                 .expect("Failed to format bitcoin address"),
+            ethereum_address: account.ethereum_address.to_string(),
             nostr_public_key: account.nostr_bech32_public_key,
             public_key: account.public_key.to_string(),
             has_session_key: account.has_session_key,
@@ -122,12 +124,14 @@ impl CommandAction<Option<AccountsView>> for ListCommand {
                     "Address",
                     "Hex Address",
                     "Bitcoin Address",
+                    "Ethereum Address",
                     "Nostr Public Key",
                 ];
                 let values = [
                     &account.local_account.address,
                     &account.local_account.hex_address,
                     &account.local_account.bitcoin_address,
+                    &account.local_account.ethereum_address,
                     &account.local_account.nostr_public_key,
                 ];
 
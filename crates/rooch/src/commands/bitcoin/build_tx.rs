@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::transaction_builder::TransactionBuilder;
+use super::utxo_selector::CoinSelection;
 use super::FileOutput;
 use crate::cli_types::{CommandAction, WalletContextOptions};
 use crate::commands::bitcoin::FileOutputData;
@@ -102,6 +103,10 @@ pub struct BuildTx {
     #[clap(long, value_parser=ParsedAddress::parse)]
     change_address: Option<ParsedAddress>,
 
+    /// The strategy to select the UTXOs of the sender when the inputs are not specified
+    #[clap(long, value_enum, default_value_t = CoinSelection::OldestFirst)]
+    coin_selection: CoinSelection,
+
     /// Skip check seal of the UTXOs, default is false
     /// If set to true, some UTXO which carries other asserts, such as Inscription, maybe unexpected spent.
     #[clap(long)]
@@ -140,6 +145,7 @@ impl CommandAction<FileOutput> for BuildTx {
         )
         .await?;
 
+        tx_builder = tx_builder.with_coin_selection(self.coin_selection);
         if let Some(fee_rate) = self.fee_rate {
             tx_builder = tx_builder.with_fee_rate(fee_rate);
         }
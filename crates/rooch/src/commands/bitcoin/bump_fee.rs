@@ -0,0 +1,84 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::transaction_builder::TransactionBuilder;
+use super::{parse_tx, FileOutput, FileOutputData};
+use crate::cli_types::{CommandAction, FileOrHexInput, WalletContextOptions};
+use async_trait::async_trait;
+use bitcoin::FeeRate;
+use clap::Parser;
+use rooch_types::address::ParsedAddress;
+use rooch_types::error::{RoochError, RoochResult};
+use tracing::debug;
+
+/// Replace an unconfirmed transaction with a higher fee rate(RBF), the new psbt should be signed by `sign-tx`
+#[derive(Debug, Parser)]
+pub struct BumpFee {
+    /// The unconfirmed transaction to replace, the psbt or the transaction file path or hex string
+    input: FileOrHexInput,
+
+    /// The sender address of the transaction, if not specified, the active address will be used
+    /// The UTXOs of the sender are added if the change output is not enough to pay the fee
+    #[clap(long, short = 's', value_parser=ParsedAddress::parse, default_value = "default")]
+    sender: ParsedAddress,
+
+    /// The new fee rate of the transaction
+    #[clap(long)]
+    fee_rate: FeeRate,
+
+    /// The index of the change output which pays the increased fee
+    /// If not specified, the output to the sender's address will be used
+    #[clap(long)]
+    change_output: Option<usize>,
+
+    /// Skip check seal of the UTXOs, default is false
+    /// If set to true, some UTXO which carries other asserts, such as Inscription, maybe unexpected spent.
+    #[clap(long)]
+    skip_check_seal: bool,
+
+    /// The output file path for the psbt
+    /// If not specified, the output will write to temp directory.
+    #[clap(long)]
+    output_file: Option<String>,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<FileOutput> for BumpFee {
+    async fn execute(self) -> RoochResult<FileOutput> {
+        let context = self.context_options.build_require_password()?;
+        let client = context.get_client().await?;
+
+        let bitcoin_network = context.get_bitcoin_network().await?;
+        let sender = context
+            .resolve_bitcoin_address(self.sender)
+            .await?
+            .to_bitcoin_address(bitcoin_network)?;
+
+        let original = parse_tx(&self.input.data)?;
+        let change_output = match self.change_output {
+            Some(change_output) => change_output,
+            None => original
+                .output
+                .iter()
+                .position(|txout| txout.script_pubkey == sender.script_pubkey())
+                .ok_or_else(|| {
+                    RoochError::CommandArgumentError(
+                        "Can not find the change output of the sender, please specify --change-output"
+                            .to_string(),
+                    )
+                })?,
+        };
+
+        let tx_builder =
+            TransactionBuilder::new(&context, client, sender, vec![], self.skip_check_seal)
+                .await?
+                .with_fee_rate(self.fee_rate);
+        let psbt = tx_builder.build_bump_fee(&original, change_output).await?;
+        debug!("PSBT: {}", serde_json::to_string_pretty(&psbt).unwrap());
+        let fileout = FileOutput::write_to_file(FileOutputData::Psbt(psbt), self.output_file)?;
+        Ok(fileout)
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::transaction_builder::TransactionBuilder;
+use super::{parse_tx, FileOutput, FileOutputData};
+use crate::cli_types::{CommandAction, FileOrHexInput, WalletContextOptions};
+use async_trait::async_trait;
+use bitcoin::FeeRate;
+use clap::Parser;
+use rooch_types::address::ParsedAddress;
+use rooch_types::error::{RoochError, RoochResult};
+use tracing::debug;
+
+/// Accelerate an unconfirmed transaction by spending its change output with a child transaction(CPFP),
+/// the child pays the fee of both transactions, the psbt should be signed by `sign-tx`
+#[derive(Debug, Parser)]
+pub struct Cpfp {
+    /// The unconfirmed parent transaction, the psbt or the transaction file path or hex string
+    input: FileOrHexInput,
+
+    /// The sender address of the child transaction, if not specified, the active address will be used
+    /// The UTXOs of the sender are added if the spent output is not enough to pay the fee
+    #[clap(long, short = 's', value_parser=ParsedAddress::parse, default_value = "default")]
+    sender: ParsedAddress,
+
+    /// The fee rate of the parent and child transactions as a package
+    #[clap(long)]
+    fee_rate: FeeRate,
+
+    /// The index of the parent output to spend
+    /// If not specified, the output to the sender's address will be used
+    #[clap(long)]
+    vout: Option<u32>,
+
+    /// The change address of the child transaction, if not specified, the change address will be the sender's address
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    change_address: Option<ParsedAddress>,
+
+    /// Skip check seal of the UTXOs, default is false
+    /// If set to true, some UTXO which carries other asserts, such as Inscription, maybe unexpected spent.
+    #[clap(long)]
+    skip_check_seal: bool,
+
+    /// The output file path for the psbt
+    /// If not specified, the output will write to temp directory.
+    #[clap(long)]
+    output_file: Option<String>,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<FileOutput> for Cpfp {
+    async fn execute(self) -> RoochResult<FileOutput> {
+        let context = self.context_options.build_require_password()?;
+        let client = context.get_client().await?;
+
+        let bitcoin_network = context.get_bitcoin_network().await?;
+        let sender = context
+            .resolve_bitcoin_address(self.sender)
+            .await?
+            .to_bitcoin_address(bitcoin_network)?;
+
+        let parent = parse_tx(&self.input.data)?;
+        let vout = match self.vout {
+            Some(vout) => vout,
+            None => parent
+                .output
+                .iter()
+                .position(|txout| txout.script_pubkey == sender.script_pubkey())
+                .map(|vout| vout as u32)
+                .ok_or_else(|| {
+                    RoochError::CommandArgumentError(
+                        "Can not find the output of the sender, please specify --vout".to_string(),
+                    )
+                })?,
+        };
+
+        let mut tx_builder =
+            TransactionBuilder::new(&context, client, sender, vec![], self.skip_check_seal)
+                .await?
+                .with_fee_rate(self.fee_rate);
+        if let Some(change_address) = self.change_address {
+            let change_address = context.resolve_bitcoin_address(change_address).await?;
+            tx_builder =
+                tx_builder.with_change_address(change_address.to_bitcoin_address(bitcoin_network)?);
+        }
+        let psbt = tx_builder.build_cpfp(&parent, vout).await?;
+        debug!("PSBT: {}", serde_json::to_string_pretty(&psbt).unwrap());
+        let fileout = FileOutput::write_to_file(FileOutputData::Psbt(psbt), self.output_file)?;
+        Ok(fileout)
+    }
+}
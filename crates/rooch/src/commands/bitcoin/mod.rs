@@ -7,7 +7,9 @@ use async_trait::async_trait;
 use bitcoin::{consensus::Encodable, Psbt, Transaction, Txid};
 use broadcast_tx::BroadcastTx;
 use build_tx::BuildTx;
+use bump_fee::BumpFee;
 use clap::{Parser, Subcommand};
+use cpfp::Cpfp;
 use rooch_types::error::RoochResult;
 use serde::{Deserialize, Serialize};
 use sign_tx::SignTx;
@@ -16,6 +18,8 @@ use transfer::Transfer;
 
 pub mod broadcast_tx;
 pub mod build_tx;
+pub mod bump_fee;
+pub mod cpfp;
 pub mod sign_tx;
 pub mod transaction_builder;
 pub mod transfer;
@@ -34,6 +38,8 @@ pub enum BitcoinCommands {
     SignTx(SignTx),
     BroadcastTx(BroadcastTx),
     Transfer(Transfer),
+    BumpFee(BumpFee),
+    Cpfp(Cpfp),
 }
 
 #[async_trait]
@@ -44,10 +50,20 @@ impl CommandAction<String> for Bitcoin {
             BitcoinCommands::SignTx(sign_tx) => sign_tx.execute_serialized().await,
             BitcoinCommands::BroadcastTx(broadcast_tx) => broadcast_tx.execute_serialized().await,
             BitcoinCommands::Transfer(transfer) => transfer.execute_serialized().await,
+            BitcoinCommands::BumpFee(bump_fee) => bump_fee.execute_serialized().await,
+            BitcoinCommands::Cpfp(cpfp) => cpfp.execute_serialized().await,
         }
     }
 }
 
+/// Parse the transaction from the psbt or the consensus encoded transaction
+pub(crate) fn parse_tx(data: &[u8]) -> Result<Transaction> {
+    match Psbt::deserialize(data) {
+        Ok(psbt) => Ok(psbt.unsigned_tx),
+        Err(_) => Ok(bitcoin::consensus::deserialize(data)?),
+    }
+}
+
 pub(crate) enum FileOutputData {
     Psbt(Psbt),
     Tx(Transaction),
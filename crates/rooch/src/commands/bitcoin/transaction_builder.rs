@@ -1,8 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::utxo_selector::UTXOSelector;
-use anyhow::{anyhow, bail, Result};
+use super::utxo_selector::{CoinSelection, UTXOSelector};
+use anyhow::{anyhow, bail, ensure, Result};
 use bitcoin::{
     absolute::LockTime, bip32::Fingerprint, transaction::Version, Address, Amount, FeeRate,
     OutPoint, Psbt, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Witness,
@@ -10,7 +10,9 @@ use bitcoin::{
 use moveos_types::{module_binding::MoveFunctionCaller, moveos_std::object::ObjectID};
 use rooch_rpc_api::jsonrpc_types::btc::utxo::UTXOObjectView;
 use rooch_rpc_client::{wallet_context::WalletContext, Client};
+use rooch_types::address::BitcoinAddress;
 use rooch_types::bitcoin::multisign_account::{self};
+use std::collections::HashSet;
 use tracing::debug;
 
 #[derive(Debug)]
@@ -21,12 +23,15 @@ pub struct TransactionBuilder<'a> {
     fee_rate: FeeRate,
     change_address: Address,
     lock_time: Option<LockTime>,
+    coin_selection: CoinSelection,
 }
 
 impl<'a> TransactionBuilder<'a> {
     const ADDITIONAL_INPUT_VBYTES: usize = 58;
     const ADDITIONAL_OUTPUT_VBYTES: usize = 43;
     const SCHNORR_SIGNATURE_SIZE: usize = 64;
+    /// The default incremental relay fee rate of Bitcoin Core, 1 sat/vB
+    const INCREMENTAL_RELAY_FEE_RATE: FeeRate = FeeRate::from_sat_per_kwu(250);
 
     pub async fn new(
         wallet_context: &'a WalletContext,
@@ -44,6 +49,7 @@ impl<'a> TransactionBuilder<'a> {
             fee_rate: FeeRate::from_sat_per_vb(10).unwrap(),
             change_address: sender,
             lock_time: None,
+            coin_selection: CoinSelection::default(),
         })
     }
 
@@ -62,6 +68,28 @@ impl<'a> TransactionBuilder<'a> {
         self
     }
 
+    pub fn with_coin_selection(mut self, coin_selection: CoinSelection) -> Self {
+        self.coin_selection = coin_selection;
+        self
+    }
+
+    fn fee(&self, vbytes: usize) -> Result<Amount> {
+        self.fee_rate
+            .fee_vb(vbytes as u64)
+            .ok_or_else(|| anyhow!("Failed to estimate fee: {}", self.fee_rate))
+    }
+
+    /// Estimate the vsize of the transaction, the unsigned inputs are treated as taproot key path spends
+    fn estimate_vsize(tx: &Transaction) -> usize {
+        let mut tx = tx.clone();
+        for input in tx.input.iter_mut() {
+            if input.witness.is_empty() && input.script_sig.is_empty() {
+                input.witness = Witness::from_slice(&[&[0; Self::SCHNORR_SIGNATURE_SIZE]]);
+            }
+        }
+        tx.vsize()
+    }
+
     fn estimate_vbytes_with(inputs: usize, outputs: Vec<Address>) -> usize {
         Transaction {
             version: Version::TWO,
@@ -90,6 +118,24 @@ impl<'a> TransactionBuilder<'a> {
     }
 
     pub async fn build(mut self, outputs: Vec<(Address, Amount)>) -> Result<Psbt> {
+        let (tx, utxos) = if self.coin_selection == CoinSelection::BranchAndBound
+            && self.utxo_selector.specific_utxos().is_empty()
+        {
+            self.build_tx_bnb(outputs).await?
+        } else {
+            self.build_tx(outputs).await?
+        };
+        let prevouts = utxos
+            .iter()
+            .map(Self::utxo_to_txout)
+            .collect::<Result<Vec<_>>>()?;
+        self.build_psbt(tx, prevouts).await
+    }
+
+    async fn build_tx(
+        &mut self,
+        outputs: Vec<(Address, Amount)>,
+    ) -> Result<(Transaction, Vec<UTXOObjectView>)> {
         let total_output = outputs.iter().map(|(_, amount)| *amount).sum::<Amount>();
         let output_address = outputs
             .iter()
@@ -159,23 +205,296 @@ impl<'a> TransactionBuilder<'a> {
                 script_pubkey: self.change_address.script_pubkey(),
             });
         }
+        Ok((tx, utxos))
+    }
+
+    /// Select the UTXOs by branch and bound, avoid the change output if possible.
+    async fn build_tx_bnb(
+        &mut self,
+        outputs: Vec<(Address, Amount)>,
+    ) -> Result<(Transaction, Vec<UTXOObjectView>)> {
+        let total_output = outputs.iter().map(|(_, amount)| *amount).sum::<Amount>();
+        let output_address = outputs
+            .iter()
+            .map(|(address, _)| address.clone())
+            .collect::<Vec<_>>();
+        let target =
+            total_output + self.fee(Self::estimate_vbytes_with(0, output_address.clone()))?;
+        let input_fee = self.fee(Self::ADDITIONAL_INPUT_VBYTES)?;
+        let change_output_fee = self.fee(Self::ADDITIONAL_OUTPUT_VBYTES)?;
+        // The change output is worth creating only if it is not dust and can pay the fee to spend it later
+        let change_dust = self.change_address.script_pubkey().minimal_non_dust();
+        let cost_of_change = change_output_fee + input_fee.max(change_dust);
+        let (utxos, need_change) = self
+            .utxo_selector
+            .select_utxos_bnb(target, input_fee, cost_of_change)
+            .await?;
+        let total_input = utxos.iter().map(|utxo| utxo.amount()).sum::<Amount>();
+
+        let mut tx = Transaction {
+            version: Version::TWO,
+            lock_time: self.lock_time.unwrap_or(LockTime::ZERO),
+            input: utxos.iter().map(Self::utxo_to_txin).collect(),
+            output: outputs
+                .into_iter()
+                .map(|(address, amount)| TxOut {
+                    value: amount,
+                    script_pubkey: address.script_pubkey(),
+                })
+                .collect(),
+        };
+        if need_change {
+            let mut change_outputs = output_address;
+            change_outputs.push(self.change_address.clone());
+            let fee = self.fee(Self::estimate_vbytes_with(utxos.len(), change_outputs))?;
+            let change = total_input
+                .checked_sub(total_output + fee)
+                .ok_or_else(|| anyhow!("not enough BTC funds"))?;
+            tx.output.push(TxOut {
+                value: change,
+                script_pubkey: self.change_address.script_pubkey(),
+            });
+        }
+        Ok((tx, utxos))
+    }
+
+    /// Build a transaction replacing the unconfirmed `original` transaction with a higher fee rate(BIP125 RBF).
+    /// The increased fee is paid by the change output `change_index`,
+    /// more UTXOs of the sender are added if the change output is not enough.
+    pub async fn build_bump_fee(
+        mut self,
+        original: &Transaction,
+        change_index: usize,
+    ) -> Result<Psbt> {
+        ensure!(
+            original.input.iter().any(|input| input.sequence.is_rbf()),
+            "The transaction {} does not signal RBF",
+            original.compute_txid()
+        );
+        ensure!(
+            change_index < original.output.len(),
+            "The change output index {} is out of range",
+            change_index
+        );
+        let mut prevouts = self.get_unspent_prevouts(original).await?;
+        let original_fee = Self::tx_fee(original, &prevouts)?;
+        let excluded = original
+            .input
+            .iter()
+            .map(|input| input.previous_output)
+            .collect::<HashSet<_>>();
+
+        let mut tx = original.clone();
+        for input in tx.input.iter_mut() {
+            input.script_sig = ScriptBuf::new();
+            input.witness = Witness::default();
+        }
+        let other_outputs = Self::total_value(
+            tx.output
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| *idx != change_index)
+                .map(|(_, txout)| txout),
+        )?;
+        let change_dust = tx.output[change_index].script_pubkey.minimal_non_dust();
+        loop {
+            let total_input = Self::total_value(&prevouts)?;
+            if let Some(change) = Self::bump_fee_change(
+                self.fee_rate,
+                original_fee,
+                Self::estimate_vsize(&tx),
+                total_input,
+                other_outputs,
+                change_dust,
+            )? {
+                tx.output[change_index].value = change;
+                break;
+            }
+            let utxo = self
+                .next_utxo_excluding(&excluded)
+                .await?
+                .ok_or_else(|| anyhow!("not enough BTC funds to bump the fee"))?;
+            tx.input.push(Self::utxo_to_txin(&utxo));
+            prevouts.push(Self::utxo_to_txout(&utxo)?);
+        }
+        self.build_psbt(tx, prevouts).await
+    }
+
+    /// Build a child transaction spending the output `vout` of the unconfirmed `parent` transaction(CPFP),
+    /// the child pays the fee of both transactions at the fee rate to the change address.
+    /// More UTXOs of the sender are added if the output is not enough.
+    pub async fn build_cpfp(mut self, parent: &Transaction, vout: u32) -> Result<Psbt> {
+        let parent_output = parent
+            .output
+            .get(vout as usize)
+            .cloned()
+            .ok_or_else(|| anyhow!("The output index {} is out of range", vout))?;
+        let parent_prevouts = self.get_unspent_prevouts(parent).await?;
+        let parent_fee = Self::tx_fee(parent, &parent_prevouts)?;
+        let parent_vsize = Self::estimate_vsize(parent);
+        // The inputs of the parent are still unspent before it is confirmed, do not spend them again
+        let excluded = parent
+            .input
+            .iter()
+            .map(|input| input.previous_output)
+            .collect::<HashSet<_>>();
+
+        let mut tx = Transaction {
+            version: Version::TWO,
+            lock_time: self.lock_time.unwrap_or(LockTime::ZERO),
+            input: vec![TxIn {
+                previous_output: OutPoint {
+                    txid: parent.compute_txid(),
+                    vout,
+                },
+                script_sig: ScriptBuf::default(),
+                sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                witness: Witness::default(),
+            }],
+            output: vec![TxOut {
+                value: Amount::ZERO,
+                script_pubkey: self.change_address.script_pubkey(),
+            }],
+        };
+        let mut prevouts = vec![parent_output];
+        let change_dust = self.change_address.script_pubkey().minimal_non_dust();
+        loop {
+            let total_input = Self::total_value(&prevouts)?;
+            if let Some(change) = Self::cpfp_change(
+                self.fee_rate,
+                parent_fee,
+                parent_vsize,
+                Self::estimate_vsize(&tx),
+                total_input,
+                change_dust,
+            )? {
+                tx.output[0].value = change;
+                break;
+            }
+            let utxo = self
+                .next_utxo_excluding(&excluded)
+                .await?
+                .ok_or_else(|| anyhow!("not enough BTC funds to pay the fee of the child"))?;
+            tx.input.push(Self::utxo_to_txin(&utxo));
+            prevouts.push(Self::utxo_to_txout(&utxo)?);
+        }
+        self.build_psbt(tx, prevouts).await
+    }
+
+    /// The fee paid by the transaction, the `prevouts` are the outputs spent by it
+    fn tx_fee(tx: &Transaction, prevouts: &[TxOut]) -> Result<Amount> {
+        let total_input = Self::total_value(prevouts)?;
+        let total_output = Self::total_value(&tx.output)?;
+        total_input.checked_sub(total_output).ok_or_else(|| {
+            anyhow!(
+                "The outputs {} of the transaction {} exceed its inputs {}",
+                total_output,
+                tx.compute_txid(),
+                total_input
+            )
+        })
+    }
+
+    fn total_value<'b>(txouts: impl IntoIterator<Item = &'b TxOut>) -> Result<Amount> {
+        txouts.into_iter().try_fold(Amount::ZERO, |total, txout| {
+            total
+                .checked_add(txout.value)
+                .ok_or_else(|| anyhow!("The total value of the outputs overflows"))
+        })
+    }
+
+    /// The change of the replacement transaction with `vsize`, None if the inputs can not pay the fee with a non-dust change.
+    fn bump_fee_change(
+        fee_rate: FeeRate,
+        original_fee: Amount,
+        vsize: usize,
+        total_input: Amount,
+        other_outputs: Amount,
+        change_dust: Amount,
+    ) -> Result<Option<Amount>> {
+        // The replacement must pay more fee than the original, and pay for its own bandwidth at the incremental relay fee rate
+        let min_fee = Self::INCREMENTAL_RELAY_FEE_RATE
+            .fee_vb(vsize as u64)
+            .and_then(|fee| original_fee.checked_add(fee))
+            .ok_or_else(|| anyhow!("Failed to estimate fee"))?;
+        let fee = fee_rate
+            .fee_vb(vsize as u64)
+            .ok_or_else(|| anyhow!("Failed to estimate fee: {}", fee_rate))?
+            .max(min_fee);
+        Ok(other_outputs
+            .checked_add(fee)
+            .and_then(|spent| total_input.checked_sub(spent))
+            .filter(|change| *change >= change_dust))
+    }
+
+    /// The change of the child transaction with `child_vsize`, None if the inputs can not pay the fee with a non-dust change.
+    fn cpfp_change(
+        fee_rate: FeeRate,
+        parent_fee: Amount,
+        parent_vsize: usize,
+        child_vsize: usize,
+        total_input: Amount,
+        change_dust: Amount,
+    ) -> Result<Option<Amount>> {
+        let fee_vb = |vsize: usize| {
+            fee_rate
+                .fee_vb(vsize as u64)
+                .ok_or_else(|| anyhow!("Failed to estimate fee: {}", fee_rate))
+        };
+        let package_fee = fee_vb(parent_vsize + child_vsize)?;
+        // The child pays at least its own fee at the fee rate
+        let fee = package_fee
+            .checked_sub(parent_fee)
+            .unwrap_or(Amount::ZERO)
+            .max(fee_vb(child_vsize)?);
+        Ok(total_input
+            .checked_sub(fee)
+            .filter(|change| *change >= change_dust))
+    }
+
+    /// Get the outputs spent by the unconfirmed transaction, they are still unspent in the UTXO set
+    async fn get_unspent_prevouts(&self, tx: &Transaction) -> Result<Vec<TxOut>> {
+        let mut prevouts = vec![];
+        for input in tx.input.iter() {
+            let utxo = self
+                .utxo_selector
+                .get_utxo(&input.previous_output.into())
+                .await
+                .map_err(|e| {
+                    e.context(format!(
+                        "The transaction {} may be confirmed",
+                        tx.compute_txid()
+                    ))
+                })?;
+            prevouts.push(Self::utxo_to_txout(&utxo)?);
+        }
+        Ok(prevouts)
+    }
+
+    async fn next_utxo_excluding(
+        &mut self,
+        excluded: &HashSet<OutPoint>,
+    ) -> Result<Option<UTXOObjectView>> {
+        while let Some(utxo) = self.utxo_selector.next_utxo().await? {
+            let outpoint: OutPoint = utxo.outpoint().into();
+            if !excluded.contains(&outpoint) {
+                return Ok(Some(utxo));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn build_psbt(&self, tx: Transaction, prevouts: Vec<TxOut>) -> Result<Psbt> {
         let mut psbt = Psbt::from_unsigned_tx(tx)?;
 
         let multisign_account_module = self
             .client
             .as_module_binding::<multisign_account::MultisignAccountModule>();
-        for (idx, utxo) in utxos.iter().enumerate() {
-            let input = &mut psbt.inputs[idx];
-
-            let bitcoin_addr = utxo.owner_bitcoin_address().ok_or_else(|| {
-                anyhow!("Can not recognize the owner of UTXO {}", utxo.outpoint())
-            })?;
+        for (input, prevout) in psbt.inputs.iter_mut().zip(prevouts) {
+            let bitcoin_addr = BitcoinAddress::from(&prevout.script_pubkey);
 
             if bitcoin_addr.is_witness() {
-                input.witness_utxo = Some(TxOut {
-                    value: utxo.amount(),
-                    script_pubkey: bitcoin_addr.script_pubkey()?,
-                });
+                input.witness_utxo = Some(prevout);
             } else {
                 //TODO add non-witness utxo
                 bail!("Non-witness UTXO is not supported yet");
@@ -204,6 +523,16 @@ impl<'a> TransactionBuilder<'a> {
         Ok(psbt)
     }
 
+    fn utxo_to_txout(utxo: &UTXOObjectView) -> Result<TxOut> {
+        let bitcoin_addr = utxo
+            .owner_bitcoin_address()
+            .ok_or_else(|| anyhow!("Can not recognize the owner of UTXO {}", utxo.outpoint()))?;
+        Ok(TxOut {
+            value: utxo.amount(),
+            script_pubkey: bitcoin_addr.script_pubkey()?,
+        })
+    }
+
     fn utxo_to_txin(utxo: &UTXOObjectView) -> TxIn {
         TxIn {
             previous_output: utxo.outpoint().into(),
@@ -213,3 +542,92 @@ impl<'a> TransactionBuilder<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Builder<'a> = TransactionBuilder<'a>;
+
+    fn sat(value: u64) -> Amount {
+        Amount::from_sat(value)
+    }
+
+    fn txout(value: u64) -> TxOut {
+        TxOut {
+            value: sat(value),
+            script_pubkey: ScriptBuf::new(),
+        }
+    }
+
+    #[test]
+    fn test_tx_fee() {
+        let tx = Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![],
+            output: vec![txout(6000), txout(3000)],
+        };
+        assert_eq!(
+            Builder::tx_fee(&tx, &[txout(4000), txout(6000)]).unwrap(),
+            sat(1000)
+        );
+        // The outputs exceed the inputs, the prevouts do not match the transaction
+        assert!(Builder::tx_fee(&tx, &[txout(8000)]).is_err());
+        assert!(Builder::total_value(&[txout(u64::MAX), txout(1)]).is_err());
+    }
+
+    #[test]
+    fn test_bump_fee_change() {
+        let fee_rate = FeeRate::from_sat_per_vb(10).unwrap();
+        // The fee at the fee rate is less than the original fee plus the incremental relay fee
+        assert_eq!(
+            Builder::bump_fee_change(fee_rate, sat(3000), 200, sat(10000), sat(5000), sat(330))
+                .unwrap(),
+            Some(sat(1800))
+        );
+        let fee_rate = FeeRate::from_sat_per_vb(20).unwrap();
+        assert_eq!(
+            Builder::bump_fee_change(fee_rate, sat(3000), 200, sat(10000), sat(5000), sat(330))
+                .unwrap(),
+            Some(sat(1000))
+        );
+        // The change is dust
+        assert_eq!(
+            Builder::bump_fee_change(fee_rate, sat(3000), 200, sat(10000), sat(5000), sat(1500))
+                .unwrap(),
+            None
+        );
+        // The inputs can not pay the outputs and the fee
+        assert_eq!(
+            Builder::bump_fee_change(fee_rate, sat(3000), 200, sat(4000), sat(5000), sat(330))
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cpfp_change() {
+        let fee_rate = FeeRate::from_sat_per_vb(10).unwrap();
+        // The child pays the package fee not paid by the parent
+        assert_eq!(
+            Builder::cpfp_change(fee_rate, sat(1000), 200, 100, sat(5000), sat(330)).unwrap(),
+            Some(sat(3000))
+        );
+        // The parent pays more than the package fee, the child pays its own fee
+        assert_eq!(
+            Builder::cpfp_change(fee_rate, sat(5000), 200, 100, sat(5000), sat(330)).unwrap(),
+            Some(sat(4000))
+        );
+        // The change is dust
+        assert_eq!(
+            Builder::cpfp_change(fee_rate, sat(1000), 200, 100, sat(2200), sat(330)).unwrap(),
+            None
+        );
+        // The inputs can not pay the fee
+        assert_eq!(
+            Builder::cpfp_change(fee_rate, sat(1000), 200, 100, sat(500), sat(330)).unwrap(),
+            None
+        );
+    }
+}
@@ -3,6 +3,7 @@
 
 use super::sign_tx::SignOutput;
 use super::transaction_builder::TransactionBuilder;
+use super::utxo_selector::CoinSelection;
 use crate::cli_types::{CommandAction, WalletContextOptions};
 use crate::commands::bitcoin::sign_tx::sign_psbt;
 use async_trait::async_trait;
@@ -30,6 +31,10 @@ pub struct Transfer {
     #[clap(long)]
     fee_rate: Option<FeeRate>,
 
+    /// The strategy to select the UTXOs of the sender when the inputs are not specified
+    #[clap(long, value_enum, default_value_t = CoinSelection::OldestFirst)]
+    coin_selection: CoinSelection,
+
     /// Skip check seal of the UTXOs, default is false
     /// If set to true, some UTXO which carries other asserts, such as Inscription, maybe unexpected spent.
     #[clap(long)]
@@ -60,6 +65,7 @@ impl CommandAction<String> for Transfer {
         )
         .await?;
 
+        tx_builder = tx_builder.with_coin_selection(self.coin_selection);
         if let Some(fee_rate) = self.fee_rate {
             tx_builder = tx_builder.with_fee_rate(fee_rate);
         }
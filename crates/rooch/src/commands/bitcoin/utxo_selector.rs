@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::VecDeque;
+use std::collections::{BTreeSet, VecDeque};
 
 use anyhow::{bail, Result};
 use bitcoin::{Address, Amount};
@@ -14,6 +14,20 @@ use rooch_rpc_client::Client;
 use rooch_types::bitcoin::{types::OutPoint, utxo::derive_utxo_id};
 use tracing::debug;
 
+/// The max number of tries of the branch and bound search
+const BNB_TOTAL_TRIES: usize = 100_000;
+
+/// The strategy to select the UTXOs of the sender when the inputs are not specified
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum CoinSelection {
+    /// Select the oldest UTXOs first, avoid spending the immature coinbase UTXOs
+    #[default]
+    OldestFirst,
+    /// Search the UTXOs which match the target without change by branch and bound,
+    /// if there is no match, select the UTXOs with the least change
+    BranchAndBound,
+}
+
 #[derive(Debug)]
 pub struct UTXOSelector {
     client: Client,
@@ -138,6 +152,61 @@ impl UTXOSelector {
         Ok(utxos)
     }
 
+    /// Select the UTXOs by branch and bound, the effective value of a UTXO is its amount minus the `input_fee` to spend it.
+    /// The selected effective value is in `[target, target + cost_of_change]` if a change output is not needed,
+    /// otherwise it is at least `target + cost_of_change`.
+    /// Returns the selected UTXOs and whether a change output is needed.
+    pub async fn select_utxos_bnb(
+        &mut self,
+        target: Amount,
+        input_fee: Amount,
+        cost_of_change: Amount,
+    ) -> Result<(Vec<UTXOObjectView>, bool)> {
+        self.load_all_utxos().await?;
+        let candidates = std::mem::take(&mut self.candidate_utxos);
+        let values = candidates
+            .iter()
+            .map(|utxo| {
+                utxo.amount()
+                    .checked_sub(input_fee)
+                    .unwrap_or(Amount::ZERO)
+                    .to_sat()
+            })
+            .collect::<Vec<_>>();
+        let (selected, need_change) =
+            match branch_and_bound(&values, target.to_sat(), cost_of_change.to_sat()) {
+                Some(selected) => (selected, false),
+                None => match least_change(&values, (target + cost_of_change).to_sat()) {
+                    Some(selected) => (selected, true),
+                    None => {
+                        self.candidate_utxos = candidates;
+                        bail!("not enough BTC funds");
+                    }
+                },
+            };
+        let selected = selected.into_iter().collect::<BTreeSet<_>>();
+        let mut utxos = vec![];
+        // Keep the unselected UTXOs in order for the following selections
+        for (idx, utxo) in candidates.into_iter().enumerate() {
+            if selected.contains(&idx) {
+                utxos.push(utxo);
+            } else {
+                self.candidate_utxos.push_back(utxo);
+            }
+        }
+        Ok((utxos, need_change))
+    }
+
+    async fn load_all_utxos(&mut self) -> Result<()> {
+        while self
+            .loaded_page
+            .map_or(true, |(_, has_next_page)| has_next_page)
+        {
+            self.load_utxos().await?;
+        }
+        Ok(())
+    }
+
     pub fn specific_utxos(&self) -> &[ObjectID] {
         &self.specific_utxos
     }
@@ -152,6 +221,98 @@ impl UTXOSelector {
     }
 }
 
+/// The branch and bound coin selection of Bitcoin Core.
+/// Search the values in descending order depth first, find the selection whose sum is in `[target, target + cost_of_change]`
+/// with the least excess. Returns the indexes of the selected values.
+fn branch_and_bound(values: &[u64], target: u64, cost_of_change: u64) -> Option<Vec<usize>> {
+    let mut pool = (0..values.len())
+        .filter(|idx| values[*idx] > 0)
+        .collect::<Vec<_>>();
+    pool.sort_by(|a, b| values[*b].cmp(&values[*a]));
+    let pool_values = pool.iter().map(|idx| values[*idx]).collect::<Vec<_>>();
+    let upper_bound = target.saturating_add(cost_of_change);
+
+    let mut available = pool_values.iter().sum::<u64>();
+    if available < target {
+        return None;
+    }
+    let mut current_value = 0u64;
+    let mut current_selection: Vec<usize> = vec![];
+    let mut best: Option<(u64, Vec<usize>)> = None;
+    let mut index = 0;
+    for _ in 0..BNB_TOTAL_TRIES {
+        let mut backtrack = false;
+        if current_value + available < target || current_value > upper_bound {
+            backtrack = true;
+        } else if current_value >= target {
+            let excess = current_value - target;
+            if best
+                .as_ref()
+                .map_or(true, |(best_excess, _)| excess < *best_excess)
+            {
+                best = Some((excess, current_selection.clone()));
+                if excess == 0 {
+                    break;
+                }
+            }
+            backtrack = true;
+        }
+
+        if backtrack {
+            // Exclude the last selected value, and restore the values after it
+            let Some(last) = current_selection.pop() else {
+                break;
+            };
+            for value in &pool_values[last + 1..index] {
+                available += value;
+            }
+            current_value -= pool_values[last];
+            index = last;
+        } else {
+            let value = pool_values[index];
+            available -= value;
+            // Skip the value if the previous value is equal and excluded, the selection is the same
+            if current_selection.is_empty()
+                || current_selection.last() == Some(&(index - 1))
+                || value != pool_values[index - 1]
+            {
+                current_selection.push(index);
+                current_value += value;
+            }
+        }
+        index += 1;
+    }
+    best.map(|(_, selection)| selection.into_iter().map(|idx| pool[idx]).collect())
+}
+
+/// Select the smallest value which covers the target, if there is no such value,
+/// select the largest values until the target is covered. Returns the indexes of the selected values.
+fn least_change(values: &[u64], target: u64) -> Option<Vec<usize>> {
+    if let Some((idx, _)) = values
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| **value >= target)
+        .min_by_key(|(_, value)| **value)
+    {
+        return Some(vec![idx]);
+    }
+    let mut pool = (0..values.len()).collect::<Vec<_>>();
+    pool.sort_by(|a, b| values[*b].cmp(&values[*a]));
+    let mut selected = vec![];
+    let mut total = 0u64;
+    for idx in pool {
+        if total >= target {
+            break;
+        }
+        if values[idx] == 0 {
+            break;
+        }
+        selected.push(idx);
+        total += values[idx];
+    }
+    (total >= target).then_some(selected)
+}
+
 fn skip_utxo(utxo_state_view: &UTXOStateView, minimal_non_dust: Amount) -> bool {
     let utxo = &utxo_state_view.value;
     if !utxo.seals.is_empty() {
@@ -178,3 +339,37 @@ fn skip_utxo(utxo_state_view: &UTXOStateView, minimal_non_dust: Amount) -> bool
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sum(values: &[u64], selected: &[usize]) -> u64 {
+        selected.iter().map(|idx| values[*idx]).sum()
+    }
+
+    #[test]
+    fn test_branch_and_bound() {
+        let values = vec![1000, 2000, 3000, 5000, 8000];
+        let selected = branch_and_bound(&values, 7000, 0).unwrap();
+        assert_eq!(sum(&values, &selected), 7000);
+
+        let selected = branch_and_bound(&values, 10500, 600).unwrap();
+        let total = sum(&values, &selected);
+        assert!((10500..=11100).contains(&total));
+
+        // no exact match in the range
+        assert!(branch_and_bound(&values, 500, 100).is_none());
+        // not enough funds
+        assert!(branch_and_bound(&values, 20000, 1000).is_none());
+    }
+
+    #[test]
+    fn test_least_change() {
+        let values = vec![1000, 2000, 3000, 5000, 8000];
+        assert_eq!(least_change(&values, 4000), Some(vec![3]));
+        let selected = least_change(&values, 12000).unwrap();
+        assert_eq!(sum(&values, &selected), 13000);
+        assert!(least_change(&values, 20000).is_none());
+    }
+}
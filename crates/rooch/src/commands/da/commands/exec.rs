@@ -30,7 +30,7 @@ use rooch_types::bitcoin::types::Block as BitcoinBlock;
 use rooch_types::error::RoochResult;
 use rooch_types::rooch_network::RoochChainID;
 use rooch_types::transaction::{L1BlockWithBody, LedgerTransaction, LedgerTxData};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs::File;
 use std::io::{BufRead, BufReader, Read};
 use std::path::PathBuf;
@@ -94,6 +94,12 @@ pub struct ExecCommand {
         help = "rollback to tx order. If not set or ge executed_tx_order, start from executed_tx_order+1(nothing to do); otherwise, rollback to this order."
     )]
     pub rollback: Option<u64>,
+
+    #[clap(
+        long = "parallel-execution-window",
+        help = "Experimental: the max number of L2 transactions of different senders executed in parallel, executed sequentially if not set"
+    )]
+    pub parallel_execution_window: Option<usize>,
 }
 
 impl ExecCommand {
@@ -140,6 +146,7 @@ impl ExecCommand {
             done: Arc::new(AtomicU64::new(0)),
             executed_tx_order: Arc::new(AtomicU64::new(0)),
             rollback: self.rollback,
+            parallel_execution_window: self.parallel_execution_window.unwrap_or_default(),
             rooch_db,
         })
     }
@@ -175,6 +182,7 @@ struct ExecInner {
 
     rooch_db: RoochDB,
     rollback: Option<u64>,
+    parallel_execution_window: usize,
 
     // stats
     produced: Arc<AtomicU64>,
@@ -394,36 +402,50 @@ impl ExecInner {
         tracing::info!("Start to consume transactions");
         let mut executed_tx_order = 0;
         let mut last_record_time = std::time::Instant::now();
+        let mut pending = VecDeque::new();
         loop {
-            let exec_msg_opt = rx.recv().await;
-            if exec_msg_opt.is_none() {
-                break;
+            if pending.is_empty() {
+                match rx.recv().await {
+                    Some(exec_msg) => pending.push_back(exec_msg),
+                    None => break,
+                }
             }
-            let exec_msg = exec_msg_opt.unwrap();
-            let tx_order = exec_msg.tx_order;
-
-            self.execute(exec_msg).await.with_context(|| {
-                format!(
-                    "Error executing transaction: tx_order: {}, executed_tx_order: {}",
-                    tx_order, executed_tx_order
-                )
-            })?;
-
-            executed_tx_order = tx_order;
-            self.executed_tx_order
-                .store(executed_tx_order, std::sync::atomic::Ordering::Relaxed);
-            let done = self.done.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
-
-            if done % 10000 == 0 {
-                let elapsed = last_record_time.elapsed();
-                tracing::info!(
-                    "execute tx range: [{}, {}], cost: {:?}, avg: {:.3} ms/tx",
-                    tx_order + 1 - 10000, // add first, avoid overflow
-                    tx_order,
-                    elapsed,
-                    elapsed.as_millis() as f64 / 10000f64
-                );
-                last_record_time = std::time::Instant::now();
+            while pending.len() < self.parallel_execution_window {
+                match rx.try_recv() {
+                    Ok(exec_msg) => pending.push_back(exec_msg),
+                    Err(_) => break,
+                }
+            }
+            let tx_order = pending
+                .front()
+                .map(|exec_msg: &ExecMsg| exec_msg.tx_order)
+                .unwrap_or_default();
+
+            let executed_tx_orders =
+                self.execute_pending(&mut pending).await.with_context(|| {
+                    format!(
+                        "Error executing transaction: tx_order: {}, executed_tx_order: {}",
+                        tx_order, executed_tx_order
+                    )
+                })?;
+
+            for tx_order in executed_tx_orders {
+                executed_tx_order = tx_order;
+                self.executed_tx_order
+                    .store(executed_tx_order, std::sync::atomic::Ordering::Relaxed);
+                let done = self.done.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+
+                if done % 10000 == 0 {
+                    let elapsed = last_record_time.elapsed();
+                    tracing::info!(
+                        "execute tx range: [{}, {}], cost: {:?}, avg: {:.3} ms/tx",
+                        tx_order + 1 - 10000, // add first, avoid overflow
+                        tx_order,
+                        elapsed,
+                        elapsed.as_millis() as f64 / 10000f64
+                    );
+                    last_record_time = std::time::Instant::now();
+                }
             }
         }
         tracing::info!(
@@ -433,6 +455,68 @@ impl ExecInner {
         Ok(())
     }
 
+    /// Execute the pending txs from the front, returns the orders of the executed txs.
+    /// The L2 txs of different senders are validated against the same state and executed in parallel,
+    /// the others are executed one by one.
+    async fn execute_pending(&self, pending: &mut VecDeque<ExecMsg>) -> anyhow::Result<Vec<u64>> {
+        let is_l2_tx = pending
+            .front()
+            .is_some_and(|exec_msg| exec_msg.ledger_tx.data.is_l2_tx());
+        if self.parallel_execution_window < 2 || !is_l2_tx {
+            let Some(exec_msg) = pending.pop_front() else {
+                return Ok(vec![]);
+            };
+            let tx_order = exec_msg.tx_order;
+            self.execute(exec_msg).await?;
+            return Ok(vec![tx_order]);
+        }
+
+        let mut window = Vec::new();
+        let mut senders = HashSet::new();
+        while let Some(exec_msg) = pending.front() {
+            if window.len() >= self.parallel_execution_window {
+                break;
+            }
+            let LedgerTxData::L2Tx(l2_tx) = &exec_msg.ledger_tx.data else {
+                break;
+            };
+            if !senders.insert(l2_tx.sender()) {
+                break;
+            }
+            match self
+                .validate_ledger_transaction(exec_msg.ledger_tx.clone(), None)
+                .await
+            {
+                Ok(moveos_tx) => {
+                    let exec_msg = pending.pop_front().expect("pending tx should exist");
+                    window.push((exec_msg, moveos_tx));
+                }
+                // The first tx is validated against the latest state, same as the sequential execution
+                Err(err) if window.is_empty() => return Err(err),
+                Err(_) => break,
+            }
+        }
+
+        let (exec_msgs, moveos_txs): (Vec<_>, Vec<_>) = window.into_iter().unzip();
+        let results = self.executor.execute_transactions(moveos_txs).await?;
+        let mut executed_tx_orders = Vec::with_capacity(results.len());
+        for (exec_msg, result) in exec_msgs.iter().zip(results) {
+            let tx_order = exec_msg.tx_order;
+            let result = result.and_then(|(_output, execution_info)| {
+                self.check_state_root(tx_order, &execution_info)
+            });
+            if let Err(err) = result {
+                self.handle_execution_error(err, true, tx_order)?;
+            }
+            executed_tx_orders.push(tx_order);
+        }
+        // The executor stops at the failed tx, the following txs are executed later
+        for exec_msg in exec_msgs.into_iter().skip(executed_tx_orders.len()).rev() {
+            pending.push_front(exec_msg);
+        }
+        Ok(executed_tx_orders)
+    }
+
     async fn execute(&self, msg: ExecMsg) -> anyhow::Result<()> {
         let ExecMsg {
             tx_order,
@@ -517,7 +601,14 @@ impl ExecInner {
         let executor = self.executor.clone();
 
         let (_output, execution_info) = executor.execute_transaction(moveos_tx.clone()).await?;
+        self.check_state_root(tx_order, &execution_info)
+    }
 
+    fn check_state_root(
+        &self,
+        tx_order: u64,
+        execution_info: &TransactionExecutionInfo,
+    ) -> anyhow::Result<()> {
         let root = execution_info.root_metadata();
         let expected_root_opt = self.order_state_pair.get(&tx_order);
         match expected_root_opt {
@@ -540,7 +631,7 @@ impl ExecInner {
     }
 }
 
-async fn build_btc_client_proxy(
+pub(crate) async fn build_btc_client_proxy(
     btc_rpc_url: String,
     btc_rpc_user_name: String,
     btc_rpc_password: String,
@@ -602,6 +693,7 @@ async fn build_executor_and_store(
         root.clone(),
         moveos_store.clone(),
         rooch_store.clone(),
+        &registry_service.default_registry(),
         None,
     )?;
 
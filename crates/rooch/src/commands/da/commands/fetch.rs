@@ -0,0 +1,107 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use rooch_config::da_config::{derive_namespace_from_genesis, DAConfig};
+use rooch_da::backend::DABackends;
+use rooch_genesis::RoochGenesis;
+use rooch_types::crypto::PublicKey;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::rooch_network::{BuiltinChainID, RoochNetwork};
+use std::fs;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+/// Fetch batches from remote DA backends, verify and unpack them to human-readable LedgerTransaction List.
+#[derive(Debug, Parser)]
+pub struct FetchCommand {
+    /// DA config in JSON, same as `--da` of `rooch server start`.
+    /// Batches are read from backends in priority order.
+    #[clap(long = "da")]
+    pub da: DAConfig,
+    /// Namespace for backends which don't set it in config,
+    /// if not set, derive it from the builtin genesis of chain_id.
+    #[clap(long)]
+    pub namespace: Option<String>,
+    #[clap(long, short = 'n', default_value = "main")]
+    pub chain_id: BuiltinChainID,
+    #[clap(long = "from-block")]
+    pub from_block: u128,
+    /// Fetch until no batch found if not set (inclusive)
+    #[clap(long = "to-block")]
+    pub to_block: Option<u128>,
+    /// Check the batch meta is signed by this sequencer public key(hex with flag)
+    #[clap(long = "sequencer-public-key")]
+    pub sequencer_public_key: Option<String>,
+    #[clap(long = "batch-dir")]
+    pub batch_dir: PathBuf,
+}
+
+impl FetchCommand {
+    pub async fn execute(self) -> RoochResult<()> {
+        let namespace = match self.namespace {
+            Some(namespace) => namespace,
+            None => {
+                let genesis = RoochGenesis::load_or_build(RoochNetwork::builtin(self.chain_id))?;
+                derive_namespace_from_genesis(genesis.genesis_hash())
+            }
+        };
+        let sequencer_public_key = self
+            .sequencer_public_key
+            .map(|pk| PublicKey::from_hex(&pk))
+            .transpose()?;
+
+        let backends = DABackends::initialize(self.da.da_backend, namespace).await?;
+        if backends.backends.is_empty() {
+            return Err(RoochError::from(anyhow::anyhow!(
+                "no DA backend configured"
+            )));
+        }
+        if !self.batch_dir.exists() {
+            fs::create_dir_all(&self.batch_dir)?;
+        }
+
+        let mut block_number = self.from_block;
+        loop {
+            if let Some(to_block) = self.to_block {
+                if block_number > to_block {
+                    break;
+                }
+            }
+            let batch = match backends
+                .get_batch(block_number, sequencer_public_key.as_ref())
+                .await?
+            {
+                Some(batch) => batch,
+                None => {
+                    if self.to_block.is_some() {
+                        return Err(RoochError::from(anyhow::anyhow!(
+                            "batch: {} not found in any backend",
+                            block_number
+                        )));
+                    }
+                    break;
+                }
+            };
+
+            // write LedgerTx in batch to file, each line is a tx in json
+            let batch_file_path = self.batch_dir.join(block_number.to_string());
+            let file = fs::File::create(batch_file_path)?;
+            let mut writer = BufWriter::with_capacity(8 * 1024 * 1024, file.try_clone()?);
+            for tx in batch.get_tx_list() {
+                let tx_json = serde_json::to_string(&tx)?;
+                writeln!(writer, "{}", tx_json)?;
+            }
+            writer.flush()?;
+            file.sync_data()?;
+
+            block_number += 1;
+        }
+
+        println!(
+            "Fetched batches(block_number): [{}, {})",
+            self.from_block, block_number
+        );
+        Ok(())
+    }
+}
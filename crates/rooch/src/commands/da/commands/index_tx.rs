@@ -26,11 +26,13 @@ impl IndexTxCommand {
         let mut writer = BufWriter::with_capacity(8 * 1024 * 1024, file.try_clone().unwrap());
 
         loop {
-            if block_number > ledger_tx_loader.get_max_chunk_id() {
-                break;
-            }
-            let tx_list = ledger_tx_loader.load_ledger_tx_list(block_number, true)?;
-            let tx_list = tx_list.unwrap();
+            // the last chunk may have several batches (ChunkV1), so stop at the first missing block after it
+            let must_has = block_number <= ledger_tx_loader.get_max_chunk_id();
+            let tx_list = ledger_tx_loader.load_ledger_tx_list(block_number, must_has)?;
+            let tx_list = match tx_list {
+                Some(tx_list) => tx_list,
+                None => break,
+            };
             for mut ledger_tx in tx_list {
                 let tx_order = ledger_tx.sequence_info.tx_order;
                 let tx_hash = ledger_tx.tx_hash();
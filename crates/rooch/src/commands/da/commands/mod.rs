@@ -9,17 +9,20 @@ use moveos_types::transaction::TransactionExecutionInfo;
 use rooch_common::vec::find_last_true;
 use rooch_config::RoochOpt;
 use rooch_db::RoochDB;
+use rooch_types::da::batch::DABatch;
 use rooch_types::da::chunk::chunk_from_segments;
 use rooch_types::da::segment::{segment_from_bytes, SegmentID};
 use rooch_types::rooch_network::RoochChainID;
 use rooch_types::transaction::LedgerTransaction;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fs;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Read};
 use std::path::PathBuf;
+use std::sync::Mutex;
 
 pub mod exec;
+pub mod fetch;
 pub mod index_tx;
 pub mod namespace;
 pub mod unpack;
@@ -60,11 +63,15 @@ pub(crate) fn collect_chunks(
     Ok((chunks, min_chunk_id, max_chunk_id))
 }
 
-pub(crate) fn get_tx_list_from_chunk(
+// get all batches in the chunk, each batch is verified.
+// ChunkV0 has one batch, ChunkV1 may have several consecutive batches.
+pub(crate) fn get_batches_from_chunk(
     segment_dir: PathBuf,
     chunk_id: u128,
-    segment_numbers: Vec<u64>,
-) -> anyhow::Result<Vec<LedgerTransaction>> {
+    mut segment_numbers: Vec<u64>,
+) -> anyhow::Result<Vec<DABatch>> {
+    // segment numbers are collected from dir entries without order
+    segment_numbers.sort();
     let mut segments = Vec::new();
     for segment_number in segment_numbers {
         let segment_id = SegmentID {
@@ -77,9 +84,11 @@ pub(crate) fn get_tx_list_from_chunk(
         segments.push(segment);
     }
     let chunk = chunk_from_segments(segments)?;
-    let batch = chunk.get_batches().into_iter().next().unwrap();
-    batch.verify(true)?;
-    Ok(batch.get_tx_list())
+    let batches = chunk.get_batches();
+    for batch in &batches {
+        batch.verify(true)?;
+    }
+    Ok(batches)
 }
 
 pub(crate) fn build_rooch_db(
@@ -102,40 +111,69 @@ pub(crate) fn build_rooch_db(
 pub struct LedgerTxGetter {
     segment_dir: PathBuf,
     chunks: HashMap<u128, Vec<u64>>,
+    // sorted chunk ids for finding the chunk which the block belongs to
+    chunk_ids: BTreeSet<u128>,
     min_chunk_id: u128,
     max_chunk_id: u128,
+    // the last loaded chunk, ChunkV1 has several batches, avoid decoding it for each block
+    last_chunk: Mutex<Option<(u128, Vec<DABatch>)>>,
 }
 
 impl LedgerTxGetter {
     pub fn new(segment_dir: PathBuf) -> anyhow::Result<Self> {
         let (chunks, min_chunk_id, max_chunk_id) = collect_chunks(segment_dir.clone())?;
+        let chunk_ids = chunks.keys().cloned().collect();
 
         Ok(LedgerTxGetter {
             segment_dir,
             chunks,
+            chunk_ids,
             min_chunk_id,
             max_chunk_id,
+            last_chunk: Mutex::new(None),
         })
     }
 
+    // load tx list of the block,
+    // chunk_id is the first block number in the chunk, so the block belongs to the nearest chunk_id <= block_number
     pub fn load_ledger_tx_list(
         &self,
-        chunk_id: u128,
+        block_number: u128,
         must_has: bool,
     ) -> anyhow::Result<Option<Vec<LedgerTransaction>>> {
-        let segments = self.chunks.get(&chunk_id);
-        if segments.is_none() {
-            if must_has {
-                return Err(anyhow::anyhow!("No segment found in chunk {}", chunk_id));
+        let chunk_id = self.chunk_ids.range(..=block_number).next_back().cloned();
+        let batch = match chunk_id {
+            Some(chunk_id) => self
+                .load_batches(chunk_id)?
+                .into_iter()
+                .find(|batch| batch.meta.block_range.block_number == block_number),
+            None => None,
+        };
+        match batch {
+            Some(batch) => Ok(Some(batch.get_tx_list())),
+            None => {
+                if must_has {
+                    return Err(anyhow::anyhow!("No batch found for block {}", block_number));
+                }
+                Ok(None)
             }
-            return Ok(None);
         }
-        let tx_list = get_tx_list_from_chunk(
-            self.segment_dir.clone(),
-            chunk_id,
-            segments.unwrap().clone(),
-        )?;
-        Ok(Some(tx_list))
+    }
+
+    fn load_batches(&self, chunk_id: u128) -> anyhow::Result<Vec<DABatch>> {
+        let mut last_chunk = self.last_chunk.lock().unwrap();
+        if let Some((last_chunk_id, batches)) = last_chunk.as_ref() {
+            if *last_chunk_id == chunk_id {
+                return Ok(batches.clone());
+            }
+        }
+        let segments = self
+            .chunks
+            .get(&chunk_id)
+            .ok_or_else(|| anyhow::anyhow!("No segment found in chunk {}", chunk_id))?;
+        let batches = get_batches_from_chunk(self.segment_dir.clone(), chunk_id, segments.clone())?;
+        *last_chunk = Some((chunk_id, batches.clone()));
+        Ok(batches)
     }
 
     pub fn get_max_chunk_id(&self) -> u128 {
@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::commands::da::commands::{collect_chunks, get_tx_list_from_chunk};
+use crate::commands::da::commands::{collect_chunks, get_batches_from_chunk};
 use clap::Parser;
 use rooch_types::error::RoochResult;
 use std::collections::{HashMap, HashSet};
@@ -124,7 +124,7 @@ impl UnpackInner {
     }
 
     // unpack batches from segment_dir to batch_dir.
-    // warn: ChunkV0 only in present
+    // ChunkV0 and ChunkV1 are both supported, each batch is written to a file named by its block number.
     fn unpack(&mut self) -> anyhow::Result<()> {
         self.collect_unpacked()?;
         self.collect_chunks()?;
@@ -133,33 +133,37 @@ impl UnpackInner {
 
         for (chunk_id, segment_numbers) in &self.chunks {
             if self.unpacked.contains(chunk_id) {
-                // For ChunkV0, chunk_id is block_number
+                // chunk_id is the block_number of the first batch in chunk
                 continue;
             }
 
-            let tx_list = get_tx_list_from_chunk(
+            let batches = get_batches_from_chunk(
                 self.segment_dir.clone(),
                 *chunk_id,
                 segment_numbers.clone(),
             )?;
 
-            // write LedgerTx in batch to file, each line is a tx in json
-            let batch_file_path = self.batch_dir.join(chunk_id.to_string());
-            let file = fs::OpenOptions::new()
-                .create(true)
-                .write(true)
-                .truncate(true)
-                .open(batch_file_path)?;
-            let mut writer = BufWriter::with_capacity(8 * 1024 * 1024, file.try_clone().unwrap());
-
-            for tx in tx_list {
-                let tx_json = serde_json::to_string(&tx)?;
-                writeln!(writer, "{}", tx_json).expect("Unable to write line");
-            }
-            writer.flush().expect("Unable to flush writer");
-            file.sync_data().expect("Unable to sync file");
+            for batch in batches {
+                let block_number = batch.meta.block_range.block_number;
+                // write LedgerTx in batch to file, each line is a tx in json
+                let batch_file_path = self.batch_dir.join(block_number.to_string());
+                let file = fs::OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .truncate(true)
+                    .open(batch_file_path)?;
+                let mut writer =
+                    BufWriter::with_capacity(8 * 1024 * 1024, file.try_clone().unwrap());
+
+                for tx in batch.get_tx_list() {
+                    let tx_json = serde_json::to_string(&tx)?;
+                    writeln!(writer, "{}", tx_json).expect("Unable to write line");
+                }
+                writer.flush().expect("Unable to flush writer");
+                file.sync_data().expect("Unable to sync file");
 
-            new_unpacked.insert(*chunk_id);
+                new_unpacked.insert(block_number);
+            }
         }
 
         println!("Unpacked batches(block_number): {:?}", new_unpacked);
@@ -5,6 +5,7 @@ pub mod commands;
 
 use crate::cli_types::CommandAction;
 use crate::commands::da::commands::exec::ExecCommand;
+use crate::commands::da::commands::fetch::FetchCommand;
 use crate::commands::da::commands::index_tx::IndexTxCommand;
 use crate::commands::da::commands::namespace::NamespaceCommand;
 use crate::commands::da::commands::unpack::UnpackCommand;
@@ -27,6 +28,7 @@ impl CommandAction<String> for DA {
             DACommand::Namespace(namespace) => namespace.execute().map(|_| "".to_owned()),
             DACommand::Exec(exec) => exec.execute().await.map(|_| "".to_owned()),
             DACommand::IndexTx(index_tx) => index_tx.execute().map(|_| "".to_owned()),
+            DACommand::Fetch(fetch) => fetch.execute().await.map(|_| "".to_owned()),
         }
     }
 }
@@ -38,4 +40,5 @@ pub enum DACommand {
     Namespace(NamespaceCommand),
     Exec(Box<ExecCommand>),
     IndexTx(IndexTxCommand),
+    Fetch(FetchCommand),
 }
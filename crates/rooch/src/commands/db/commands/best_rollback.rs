@@ -3,7 +3,7 @@
 
 use bitcoin::hashes::Hash;
 use clap::Parser;
-use rooch_types::da::chunk::chunk_from_segments;
+use rooch_types::da::chunk::{chunk_from_segments, Chunk, CHUNK_V1_MAX_BATCHES};
 use rooch_types::da::segment::{segment_from_bytes, Segment};
 use rooch_types::error::RoochResult;
 use rooch_types::transaction::{LedgerTransaction, LedgerTxData};
@@ -122,7 +122,7 @@ async fn get_block_hash_from_da_rpc(
     let mut block_height_set = HashSet::new();
     for i in 0..=search_depth {
         let block_number = last_block_number - i;
-        let tx_list = get_tx_list_from_chunk(da_url, block_number).await?;
+        let tx_list = get_tx_list_of_block(da_url, block_number).await?;
         let block_hash_in_chunk = filter_l1block(tx_list);
         for block_hash in block_hash_in_chunk {
             if block_height_set.contains(&block_hash.block_height) {
@@ -135,14 +135,44 @@ async fn get_block_hash_from_da_rpc(
     Ok(block_hash_with_depth)
 }
 
-async fn get_tx_list_from_chunk(
+// chunk_id is the block number of the first batch in the chunk (ChunkV0 has only one batch),
+// so the block belongs to the nearest chunk at or before it, at most CHUNK_V1_MAX_BATCHES - 1 blocks before.
+async fn get_tx_list_of_block(
     da_url: &str,
-    chunk_id: u128,
+    block_number: u128,
 ) -> anyhow::Result<Vec<LedgerTransaction>> {
+    let min_chunk_id = block_number.saturating_sub(CHUNK_V1_MAX_BATCHES as u128 - 1);
+    for chunk_id in (min_chunk_id..=block_number).rev() {
+        let Some(chunk) = get_chunk(da_url, chunk_id).await? else {
+            continue;
+        };
+        let batch = chunk
+            .get_batches()
+            .into_iter()
+            .find(|batch| batch.meta.block_range.block_number == block_number)
+            .ok_or_else(|| anyhow::anyhow!("batch: {} not found in DA", block_number))?;
+        batch.verify(true)?;
+        return Ok(batch.get_tx_list());
+    }
+    Err(anyhow::anyhow!("batch: {} not found in DA", block_number))
+}
+
+// get chunk by chunk_id, return None if the first segment is not found
+async fn get_chunk(da_url: &str, chunk_id: u128) -> anyhow::Result<Option<Box<dyn Chunk>>> {
     let mut segments = Vec::new();
     let mut segment_id: u64 = 0;
     loop {
-        let segment = get_segment(da_url, chunk_id, segment_id).await?;
+        let segment = match get_segment(da_url, chunk_id, segment_id).await? {
+            Some(segment) => segment,
+            None if segments.is_empty() => return Ok(None),
+            None => {
+                return Err(anyhow::anyhow!(
+                    "missing segment: {}_{}, chunk is incomplete",
+                    chunk_id,
+                    segment_id
+                ))
+            }
+        };
         let is_last = segment.is_last();
         segments.push(segment);
         if is_last {
@@ -150,20 +180,22 @@ async fn get_tx_list_from_chunk(
         }
         segment_id += 1;
     }
-    let chunk = chunk_from_segments(segments)?;
-    let batch = chunk.get_batches().into_iter().next().unwrap();
-    batch.verify(true)?;
-    Ok(batch.get_tx_list())
+    chunk_from_segments(segments).map(Some)
 }
 
+// get segment by id, return None if not found
 async fn get_segment(
     url: &str,
     chunk_id: u128,
     segment_id: u64,
-) -> anyhow::Result<Box<dyn Segment>> {
+) -> anyhow::Result<Option<Box<dyn Segment>>> {
     let segment_url = format!("{}/{}_{}", url, chunk_id, segment_id);
     let res = reqwest::get(segment_url).await?;
-    segment_from_bytes(&res.bytes().await?)
+    if res.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    let res = res.error_for_status()?;
+    segment_from_bytes(&res.bytes().await?).map(Some)
 }
 
 async fn get_block_hash_from_btc_rpc(block_height: u64, main: bool) -> anyhow::Result<String> {
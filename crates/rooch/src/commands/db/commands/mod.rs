@@ -16,6 +16,7 @@ pub mod drop;
 pub mod dump_tx_root;
 pub mod get_changeset_by_order;
 pub mod get_execution_info_by_order;
+pub mod prune;
 pub mod repair;
 pub mod revert;
 pub mod rollback;
@@ -0,0 +1,106 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::commands::db::commands::init;
+use clap::Parser;
+use moveos_types::h256::H256;
+use rooch_config::pruner_config::{DEFAULT_PRUNER_BATCH_SIZE, DEFAULT_PRUNER_KEEP_RECENT_ROOTS};
+use rooch_config::R_OPT_NET_HELP;
+use rooch_db::pruner::StatePruner;
+use rooch_types::error::RoochResult;
+use rooch_types::rooch_network::RoochChainID;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Prune the stale state tree nodes offline.
+/// Keep the state roots of the recent tx orders and the pinned state roots, remove the nodes only referenced by the other state roots.
+/// With `--rebuild`, rebuild the node reference counts and keep only the latest state root and the pinned state roots.
+#[derive(Debug, Parser)]
+pub struct PruneCommand {
+    #[clap(
+        long,
+        help = "the number of recent state roots(by tx order) to keep, default is 10000"
+    )]
+    pub keep_recent_roots: Option<u64>,
+    #[clap(
+        long = "pin-state-root",
+        help = "the state root to keep, can be specified multiple times"
+    )]
+    pub pinned_roots: Vec<H256>,
+    #[clap(
+        long,
+        help = "the max number of stale state nodes checked in one round, default is 10000"
+    )]
+    pub batch_size: Option<u64>,
+    #[clap(
+        long,
+        help = "rebuild the state node reference counts, remove all the nodes not referenced by the latest state root and the pinned state roots, \
+        it also removes the nodes written before the reference counts are tracked"
+    )]
+    pub rebuild: bool,
+
+    #[clap(long = "data-dir", short = 'd')]
+    /// Path to data dir, this dir is base dir, the final data_dir is base_dir/chain_network_name
+    pub base_data_dir: Option<PathBuf>,
+
+    /// If local chainid, start the service with a temporary data store.
+    /// All data will be deleted when the service is stopped.
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PruneOutput {
+    pub last_pruned_order: Option<u64>,
+    pub stale_indexes: u64,
+    pub pruned_nodes: u64,
+    pub reclaimed_bytes: u64,
+}
+
+impl PruneCommand {
+    pub async fn execute(self) -> RoochResult<PruneOutput> {
+        let (_root, rooch_db, start_time) = init(self.base_data_dir, self.chain_id);
+        let pruner = StatePruner::new(
+            rooch_db.moveos_store.clone(),
+            rooch_db.rooch_store.clone(),
+            self.keep_recent_roots
+                .unwrap_or(DEFAULT_PRUNER_KEEP_RECENT_ROOTS),
+            self.pinned_roots,
+            self.batch_size.unwrap_or(DEFAULT_PRUNER_BATCH_SIZE),
+        );
+
+        let mut output = PruneOutput::default();
+        if self.rebuild {
+            let result = pruner.rebuild()?;
+            output.last_pruned_order = result.last_pruned_order;
+            output.stale_indexes = result.stale_indexes;
+            output.pruned_nodes = result.pruned_nodes;
+            output.reclaimed_bytes = result.reclaimed_bytes;
+            println!(
+                "rebuild finished, cost: {:?}",
+                start_time.elapsed().unwrap_or_default()
+            );
+            return Ok(output);
+        }
+        loop {
+            let result = pruner.prune_round()?;
+            output.last_pruned_order = result.last_pruned_order;
+            output.stale_indexes += result.stale_indexes;
+            output.pruned_nodes += result.pruned_nodes;
+            output.reclaimed_bytes += result.reclaimed_bytes;
+            if result.done {
+                break;
+            }
+            println!(
+                "pruned to tx_order: {:?}, pruned nodes: {}, reclaimed bytes: {}",
+                output.last_pruned_order, output.pruned_nodes, output.reclaimed_bytes
+            );
+        }
+
+        println!(
+            "prune finished, cost: {:?}",
+            start_time.elapsed().unwrap_or_default()
+        );
+        Ok(output)
+    }
+}
@@ -7,6 +7,7 @@ use crate::commands::db::commands::drop::DropCommand;
 use crate::commands::db::commands::dump_tx_root::DumpTxRootCommand;
 use crate::commands::db::commands::get_changeset_by_order::GetChangesetByOrderCommand;
 use crate::commands::db::commands::get_execution_info_by_order::GetExecutionInfoByOrderCommand;
+use crate::commands::db::commands::prune::PruneCommand;
 use crate::commands::db::commands::repair::RepairCommand;
 use crate::commands::db::commands::revert::RevertCommand;
 use async_trait::async_trait;
@@ -55,6 +56,9 @@ impl CommandAction<String> for DB {
             DBCommand::BestRollback(best_rollback) => best_rollback.execute().await.map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
+            DBCommand::Prune(prune) => prune.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
         }
     }
 }
@@ -70,4 +74,5 @@ pub enum DBCommand {
     DumpTxRoot(DumpTxRootCommand),
     GetExecutionInfoByOrder(GetExecutionInfoByOrderCommand),
     BestRollback(BestRollbackCommand),
+    Prune(PruneCommand),
 }
@@ -18,6 +18,7 @@ pub const BATCH_SIZE: usize = 5000;
 fn init_indexer(
     base_data_dir: Option<PathBuf>,
     chain_id: Option<RoochChainID>,
+    indexer_db_url: Option<String>,
 ) -> Result<(IndexerStore, IndexerReader)> {
     // Reconstruct RoochOpt
     let opt = RoochOpt::new_with_default(base_data_dir, chain_id, None)?;
@@ -26,11 +27,18 @@ fn init_indexer(
     let registry_service = RegistryService::default();
 
     let indexer_db_path = store_config.get_indexer_dir();
-    let indexer_store = IndexerStore::new(
+    // The PostgreSQL url from the command line takes precedence over the store config
+    let indexer_db_url = indexer_db_url.or_else(|| store_config.indexer_db_url.clone());
+    let indexer_store = IndexerStore::new_with_db_url(
         indexer_db_path.clone(),
+        indexer_db_url.as_deref(),
+        &registry_service.default_registry(),
+    )?;
+    let indexer_reader = IndexerReader::new_with_db_url(
+        indexer_db_path,
+        indexer_db_url.as_deref(),
         &registry_service.default_registry(),
     )?;
-    let indexer_reader = IndexerReader::new(indexer_db_path, &registry_service.default_registry())?;
 
     Ok((indexer_store, indexer_reader))
 }
@@ -152,6 +152,7 @@ impl CommandAction<()> for Init {
                     active_address: Some(result.address),
                     // make dev env as default env
                     active_env: Some(active_env_alias),
+                    gas_estimate_multiplier: None,
                 };
 
                 client_config
@@ -0,0 +1,766 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_cli::{base::reroot_path, Move};
+use move_core_types::account_address::AccountAddress;
+use moveos_types::access_path::AccessPath;
+use moveos_types::addresses::MOVEOS_STD_ADDRESS;
+use moveos_types::move_std::string::MoveString;
+use moveos_types::moveos_std::move_module::MoveModule;
+use moveos_types::state::ObjectState;
+use moveos_verifier::build::run_verifier;
+use rooch_rpc_api::jsonrpc_types::{
+    ModuleABIView, MoveABIStructTag, MoveABIType, MoveFunctionView, MoveStructView,
+};
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::function_arg::ParsedModuleId;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+/// Generate the typed Rust bindings of Move modules, from the package at `path` or the on-chain modules.
+/// Every module is written to `<OUTPUT_DIR>/<MODULE_NAME>.rs`, with a ModuleBinding, the entry function
+/// MoveAction builders, the view function wrappers and the structs of the module.
+#[derive(Parser)]
+pub struct GenBindingsCommand {
+    /// The on-chain modules to generate, as `<ADDRESS>::<MODULE_NAME>`.
+    /// If absent, generate the modules of the package at `path`.
+    ///
+    /// Example: `--module rooch_framework::session_key --module 0x3::coin`
+    #[clap(long = "module")]
+    pub modules: Vec<ParsedModuleId>,
+
+    /// The directory to write the generated Rust modules
+    #[clap(long, short = 'o')]
+    pub output_dir: PathBuf,
+
+    /// Also generate wrappers for the public functions with return values which are not #[view]
+    #[clap(long)]
+    pub include_public_functions: bool,
+
+    /// Named addresses for the move binary
+    ///
+    /// Example: alice=0x1234, bob=default, alice2=alice
+    #[clap(long, value_parser = crate::utils::parse_map::<String, String>, default_value = "")]
+    pub(crate) named_addresses: BTreeMap<String, String>,
+
+    #[clap(flatten)]
+    move_args: Move,
+
+    /// RPC client options.
+    #[clap(flatten)]
+    context: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<Vec<String>> for GenBindingsCommand {
+    async fn execute(self) -> RoochResult<Vec<String>> {
+        let context = self.context.build()?;
+
+        let modules = if self.modules.is_empty() {
+            let mut config = self.move_args.build_config;
+            config
+                .additional_named_addresses
+                .extend(context.parse_and_resolve_addresses(self.named_addresses)?);
+            let package_path = reroot_path(self.move_args.package_path)?;
+            let mut package = config
+                .clone()
+                .compile_package_no_exit(&package_path, &mut std::io::stderr())?;
+            // Run the verifier to save the #[view] functions to the module metadata
+            run_verifier(package_path, config, &mut package)?;
+            package
+                .root_modules_map()
+                .iter_modules_owned()
+                .into_iter()
+                .map(ModuleABIView::from)
+                .collect()
+        } else {
+            let address_mapping = context.address_mapping();
+            let client = context.get_client().await?;
+            let mut modules = vec![];
+            for parsed_module_id in self.modules {
+                let module_id = parsed_module_id.into_module_id(&address_mapping)?;
+                let state = client
+                    .rooch
+                    .get_states(AccessPath::module(&module_id), None)
+                    .await?
+                    .pop()
+                    .flatten()
+                    .ok_or_else(|| {
+                        RoochError::CommandArgumentError(format!("Module {} not found", module_id))
+                    })?;
+                let module = ObjectState::from(state).value_as_df::<MoveString, MoveModule>()?;
+                modules.push(ModuleABIView::try_parse_from_module_bytes(
+                    &module.value.byte_codes,
+                )?);
+            }
+            modules
+        };
+
+        std::fs::create_dir_all(&self.output_dir)?;
+        let generator = BindingGenerator::new(self.include_public_functions, &modules);
+        let mut files = vec![];
+        let mut module_names = vec![];
+        for abi in modules {
+            let module_name = abi.name.0.to_string();
+            let file = self.output_dir.join(format!("{}.rs", module_name));
+            std::fs::write(&file, generator.generate(&abi))?;
+            files.push(file);
+            module_names.push(module_name);
+        }
+        let mod_file = self.output_dir.join("mod.rs");
+        std::fs::write(&mod_file, generate_mod_file(&module_names))?;
+        files.push(mod_file);
+
+        // Format the generated code if rustfmt is available
+        if let Err(e) = std::process::Command::new("rustfmt")
+            .args(["--edition", "2021"])
+            .args(&files)
+            .status()
+        {
+            eprintln!(
+                "Failed to format the generated bindings with rustfmt: {}",
+                e
+            );
+        }
+
+        Ok(files
+            .into_iter()
+            .map(|file| file.display().to_string())
+            .collect())
+    }
+}
+
+const GENERATED_HEADER: &str =
+    "// This file is generated by `rooch move gen-bindings`, do not edit it manually.";
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try",
+    "typeof", "unsized", "virtual", "yield",
+];
+
+fn generate_mod_file(module_names: &[String]) -> String {
+    let mut out = String::new();
+    writeln!(out, "{}", GENERATED_HEADER).unwrap();
+    writeln!(out).unwrap();
+    for module_name in module_names {
+        writeln!(out, "pub mod {};", rust_ident(module_name)).unwrap();
+    }
+    out
+}
+
+/// Escape the Move identifier if it is a Rust keyword
+fn rust_ident(name: &str) -> String {
+    if RUST_KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Convert the snake_case Move name to the CamelCase Rust type name
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn address_literal(address: &AccountAddress) -> String {
+    let bytes = address
+        .to_vec()
+        .iter()
+        .map(|b| format!("{:#04x}", b))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("AccountAddress::new([{}])", bytes)
+}
+
+/// The supported structs of the generated modules, by the module address and name
+type SupportedStructs = BTreeMap<(AccountAddress, String), BTreeSet<String>>;
+
+/// Generate the Rust binding of a Move module from its ABI.
+/// The generic structs and the functions with unsupported parameter or return types are skipped,
+/// and a comment is left in the generated code for them.
+/// The structs of the other modules generated together are referred as `super::<MODULE_NAME>::<STRUCT_NAME>`.
+pub struct BindingGenerator {
+    include_public_functions: bool,
+    structs: SupportedStructs,
+}
+
+impl BindingGenerator {
+    pub fn new(include_public_functions: bool, abis: &[ModuleABIView]) -> Self {
+        Self {
+            include_public_functions,
+            structs: Self::supported_structs(abis),
+        }
+    }
+
+    pub fn generate(&self, abi: &ModuleABIView) -> String {
+        let address = abi.address.0;
+        let module_name = abi.name.0.to_string();
+        let structs = &self.structs;
+
+        let mut out = String::new();
+        writeln!(out, "{}", GENERATED_HEADER).unwrap();
+        writeln!(
+            out,
+            "// Source module: {}::{}",
+            address.to_hex_literal(),
+            module_name
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "#![allow(clippy::too_many_arguments)]").unwrap();
+        writeln!(out, "#![allow(unused_imports)]").unwrap();
+        writeln!(out).unwrap();
+        for import in [
+            "anyhow::Result",
+            "move_core_types::account_address::AccountAddress",
+            "move_core_types::ident_str",
+            "move_core_types::identifier::IdentStr",
+            "move_core_types::language_storage::TypeTag",
+            "move_core_types::u256::U256",
+            "moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller}",
+            "moveos_types::move_std::ascii::MoveAsciiString",
+            "moveos_types::move_std::option::MoveOption",
+            "moveos_types::move_std::string::MoveString",
+            "moveos_types::moveos_std::object::ObjectID",
+            "moveos_types::moveos_std::tx_context::TxContext",
+            "moveos_types::state::{MoveState, MoveStructState, MoveStructType}",
+            "moveos_types::transaction::MoveAction",
+            "serde::{Deserialize, Serialize}",
+        ] {
+            writeln!(out, "use {};", import).unwrap();
+        }
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "pub const MODULE_NAME: &IdentStr = ident_str!(\"{}\");",
+            module_name
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "pub const MODULE_ADDRESS: AccountAddress = {};",
+            address_literal(&address)
+        )
+        .unwrap();
+
+        for s in abi.structs.iter() {
+            writeln!(out).unwrap();
+            if is_supported_struct(structs, &address, &module_name, s.name.0.as_str()) {
+                self.generate_struct(&mut out, abi, structs, s);
+            } else {
+                writeln!(
+                    out,
+                    "// Skipped the struct {}, the generic, native or unsupported field types are not supported yet.",
+                    s.name.0
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(out).unwrap();
+        self.generate_module_binding(&mut out, abi, structs);
+        out
+    }
+
+    /// The non-generic structs of the modules whose fields can all be mapped to Rust types
+    fn supported_structs(abis: &[ModuleABIView]) -> SupportedStructs {
+        let mut supported: SupportedStructs = abis
+            .iter()
+            .map(|abi| {
+                let structs = abi
+                    .structs
+                    .iter()
+                    .filter(|s| !s.is_native && s.type_params.is_empty())
+                    .map(|s| s.name.0.to_string())
+                    .collect();
+                ((abi.address.0, abi.name.0.to_string()), structs)
+            })
+            .collect();
+        // Remove the structs with unsupported fields until no more struct is removed,
+        // as a struct is unsupported if it contains an unsupported struct of any module.
+        loop {
+            let mut unsupported = vec![];
+            for abi in abis {
+                let module = (abi.address.0, abi.name.0.to_string());
+                for s in abi.structs.iter() {
+                    if is_supported_struct(&supported, &module.0, &module.1, s.name.0.as_str())
+                        && s.fields
+                            .iter()
+                            .any(|field| rust_type(abi, &supported, &field.ty.0).is_none())
+                    {
+                        unsupported.push((module.clone(), s.name.0.to_string()));
+                    }
+                }
+            }
+            if unsupported.is_empty() {
+                return supported;
+            }
+            for (module, name) in unsupported {
+                if let Some(structs) = supported.get_mut(&module) {
+                    structs.remove(&name);
+                }
+            }
+        }
+    }
+
+    fn generate_struct(
+        &self,
+        out: &mut String,
+        abi: &ModuleABIView,
+        structs: &SupportedStructs,
+        s: &MoveStructView,
+    ) {
+        let name = s.name.0.as_str();
+        writeln!(out, "#[derive(Debug, Clone, Serialize, Deserialize)]").unwrap();
+        writeln!(out, "pub struct {} {{", name).unwrap();
+        let mut layouts = vec![];
+        for field in s.fields.iter() {
+            let ty = rust_type(abi, structs, &field.ty.0).expect("The field type is supported");
+            writeln!(
+                out,
+                "    pub {}: {},",
+                rust_ident(field.name.0.as_str()),
+                ty
+            )
+            .unwrap();
+            layouts.push(format!("<{} as MoveState>::type_layout()", ty));
+        }
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "impl MoveStructType for {} {{", name).unwrap();
+        writeln!(out, "    const ADDRESS: AccountAddress = MODULE_ADDRESS;").unwrap();
+        writeln!(
+            out,
+            "    const MODULE_NAME: &'static IdentStr = MODULE_NAME;"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "    const STRUCT_NAME: &'static IdentStr = ident_str!(\"{}\");",
+            name
+        )
+        .unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "impl MoveStructState for {} {{", name).unwrap();
+        writeln!(
+            out,
+            "    fn struct_layout() -> move_core_types::value::MoveStructLayout {{"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "        move_core_types::value::MoveStructLayout::new(vec![{}])",
+            layouts.join(", ")
+        )
+        .unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+
+    fn generate_module_binding(
+        &self,
+        out: &mut String,
+        abi: &ModuleABIView,
+        structs: &SupportedStructs,
+    ) {
+        let module_name = abi.name.0.as_str();
+        let binding_name = format!("{}Module", camel_case(module_name));
+        writeln!(
+            out,
+            "/// Rust bindings for the {}::{} module",
+            abi.address.0.to_hex_literal(),
+            module_name
+        )
+        .unwrap();
+        writeln!(out, "pub struct {}<'a> {{", binding_name).unwrap();
+        writeln!(out, "    caller: &'a dyn MoveFunctionCaller,").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "impl<'a> {}<'a> {{", binding_name).unwrap();
+
+        let functions = abi
+            .functions
+            .iter()
+            .filter(|f| f.is_entry || f.is_view || self.include_public_functions)
+            .collect::<Vec<_>>();
+        for f in functions.iter() {
+            writeln!(
+                out,
+                "    pub const {}_FUNCTION_NAME: &'static IdentStr = ident_str!(\"{}\");",
+                f.name.0.as_str().to_uppercase(),
+                f.name.0
+            )
+            .unwrap();
+        }
+        for f in functions {
+            if f.is_entry {
+                writeln!(out).unwrap();
+                self.generate_entry_function(out, abi, structs, f);
+            }
+            if !f.return_.is_empty()
+                && (f.is_view || (!f.is_entry && self.include_public_functions))
+            {
+                writeln!(out).unwrap();
+                self.generate_view_function(out, abi, structs, f);
+            }
+        }
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "impl<'a> ModuleBinding<'a> for {}<'a> {{",
+            binding_name
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "    const MODULE_NAME: &'static IdentStr = MODULE_NAME;"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "    const MODULE_ADDRESS: AccountAddress = MODULE_ADDRESS;"
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "    fn new(caller: &'a impl MoveFunctionCaller) -> Self\n    where\n        Self: Sized,\n    {{\n        Self {{ caller }}\n    }}"
+        )
+        .unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+
+    /// The Rust parameters of the function, the signer parameters are skipped.
+    /// Returns None if a parameter type is not supported.
+    fn function_params(
+        abi: &ModuleABIView,
+        structs: &SupportedStructs,
+        f: &MoveFunctionView,
+    ) -> Option<Vec<(String, String)>> {
+        let mut params = vec![];
+        for (idx, param) in f.params.iter().enumerate() {
+            let ty = match &param.0 {
+                MoveABIType::Signer => continue,
+                MoveABIType::Reference { mutable: false, to }
+                    if matches!(to.as_ref(), MoveABIType::Signer) =>
+                {
+                    continue
+                }
+                ty => rust_arg_type(abi, structs, ty)?,
+            };
+            params.push((format!("arg{}", idx), ty));
+        }
+        Some(params)
+    }
+
+    fn function_signature(f: &MoveFunctionView) -> String {
+        format!(
+            "{}({}){}",
+            f.name.0,
+            f.params
+                .iter()
+                .map(|p| p.0.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            match f.return_.len() {
+                0 => String::new(),
+                1 => format!(": {}", f.return_[0].0),
+                _ => format!(
+                    ": ({})",
+                    f.return_
+                        .iter()
+                        .map(|r| r.0.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }
+        )
+    }
+
+    fn generate_entry_function(
+        &self,
+        out: &mut String,
+        abi: &ModuleABIView,
+        structs: &SupportedStructs,
+        f: &MoveFunctionView,
+    ) {
+        let signature = Self::function_signature(f);
+        let params = match Self::function_params(abi, structs, f) {
+            Some(params) => params,
+            None => {
+                writeln!(
+                    out,
+                    "    // Skipped the entry function `{}`, the parameter types are not supported yet.",
+                    signature
+                )
+                .unwrap();
+                return;
+            }
+        };
+        let mut rust_params = vec![];
+        if !f.type_params.is_empty() {
+            rust_params.push("type_args: Vec<TypeTag>".to_string());
+        }
+        rust_params.extend(params.iter().map(|(name, ty)| format!("{}: {}", name, ty)));
+        writeln!(out, "    /// Build the MoveAction to call `{}`", signature).unwrap();
+        writeln!(
+            out,
+            "    pub fn {}_action({}) -> MoveAction {{",
+            f.name.0,
+            rust_params.join(", ")
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "        Self::create_move_action(Self::{}_FUNCTION_NAME, {}, vec![{}])",
+            f.name.0.as_str().to_uppercase(),
+            if f.type_params.is_empty() {
+                "vec![]"
+            } else {
+                "type_args"
+            },
+            params
+                .iter()
+                .map(|(name, _)| format!("{}.to_move_value()", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+        .unwrap();
+        writeln!(out, "    }}").unwrap();
+    }
+
+    fn generate_view_function(
+        &self,
+        out: &mut String,
+        abi: &ModuleABIView,
+        structs: &SupportedStructs,
+        f: &MoveFunctionView,
+    ) {
+        let signature = Self::function_signature(f);
+        let params = Self::function_params(abi, structs, f);
+        let return_types = f
+            .return_
+            .iter()
+            .map(|r| rust_type(abi, structs, &r.0))
+            .collect::<Option<Vec<_>>>();
+        let (params, return_types) = match (params, return_types) {
+            (Some(params), Some(return_types)) => (params, return_types),
+            _ => {
+                writeln!(
+                    out,
+                    "    // Skipped the function `{}`, the parameter or return types are not supported yet.",
+                    signature
+                )
+                .unwrap();
+                return;
+            }
+        };
+        let mut rust_params = vec!["&self".to_string()];
+        if !f.type_params.is_empty() {
+            rust_params.push("type_args: Vec<TypeTag>".to_string());
+        }
+        rust_params.extend(params.iter().map(|(name, ty)| format!("{}: {}", name, ty)));
+        let return_type = if return_types.len() == 1 {
+            return_types[0].clone()
+        } else {
+            format!("({})", return_types.join(", "))
+        };
+
+        writeln!(out, "    /// Call the function `{}`", signature).unwrap();
+        writeln!(
+            out,
+            "    pub fn {}({}) -> Result<{}> {{",
+            rust_ident(f.name.0.as_str()),
+            rust_params.join(", "),
+            return_type
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "        let call = Self::create_function_call(Self::{}_FUNCTION_NAME, {}, vec![{}]);",
+            f.name.0.as_str().to_uppercase(),
+            if f.type_params.is_empty() {
+                "vec![]"
+            } else {
+                "type_args"
+            },
+            params
+                .iter()
+                .map(|(name, _)| format!("{}.to_move_value()", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+        .unwrap();
+        writeln!(out, "        let ctx = TxContext::zero();").unwrap();
+        writeln!(
+            out,
+            "        let values = self.caller.call_function(&ctx, call)?.into_result()?;"
+        )
+        .unwrap();
+        let decoded = return_types
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| format!("<{} as MoveState>::from_bytes(&values[{}].value)?", ty, idx))
+            .collect::<Vec<_>>();
+        if decoded.len() == 1 {
+            writeln!(out, "        Ok({})", decoded[0]).unwrap();
+        } else {
+            writeln!(out, "        Ok(({}))", decoded.join(", ")).unwrap();
+        }
+        writeln!(out, "    }}").unwrap();
+    }
+}
+
+fn is_supported_struct(
+    structs: &SupportedStructs,
+    address: &AccountAddress,
+    module_name: &str,
+    struct_name: &str,
+) -> bool {
+    structs
+        .get(&(*address, module_name.to_string()))
+        .map(|structs| structs.contains(struct_name))
+        .unwrap_or(false)
+}
+
+/// Map the Move parameter type to the Rust argument type, the Object arguments are passed by the ObjectID
+fn rust_arg_type(
+    abi: &ModuleABIView,
+    structs: &SupportedStructs,
+    ty: &MoveABIType,
+) -> Option<String> {
+    let object_ty = match ty {
+        MoveABIType::Reference { to, .. } => to.as_ref(),
+        ty => ty,
+    };
+    match object_ty {
+        MoveABIType::Struct(tag) if is_object_struct(tag) => Some("ObjectID".to_string()),
+        _ => rust_type(abi, structs, ty),
+    }
+}
+
+fn is_object_struct(tag: &MoveABIStructTag) -> bool {
+    tag.address == MOVEOS_STD_ADDRESS
+        && tag.module.as_str() == "object"
+        && tag.name.as_str() == "Object"
+}
+
+/// Map the Move type to the Rust type of the binding, returns None if the type is not supported
+fn rust_type(abi: &ModuleABIView, structs: &SupportedStructs, ty: &MoveABIType) -> Option<String> {
+    Some(match ty {
+        MoveABIType::Bool => "bool".to_string(),
+        MoveABIType::U8 => "u8".to_string(),
+        MoveABIType::U16 => "u16".to_string(),
+        MoveABIType::U32 => "u32".to_string(),
+        MoveABIType::U64 => "u64".to_string(),
+        MoveABIType::U128 => "u128".to_string(),
+        MoveABIType::U256 => "U256".to_string(),
+        MoveABIType::Address => "AccountAddress".to_string(),
+        MoveABIType::Vector(item) => format!("Vec<{}>", rust_type(abi, structs, item)?),
+        MoveABIType::Struct(tag) => rust_struct_type(abi, structs, tag)?,
+        // Pass the immutable reference arguments by value
+        MoveABIType::Reference { mutable: false, to } => rust_type(abi, structs, to)?,
+        MoveABIType::Signer
+        | MoveABIType::GenericTypeParam { .. }
+        | MoveABIType::Reference { mutable: true, .. } => return None,
+    })
+}
+
+fn rust_struct_type(
+    abi: &ModuleABIView,
+    structs: &SupportedStructs,
+    tag: &MoveABIStructTag,
+) -> Option<String> {
+    let address = tag.address.to_hex_literal();
+    Some(
+        match (address.as_str(), tag.module.as_str(), tag.name.as_str()) {
+            ("0x1", "string", "String") => "MoveString".to_string(),
+            ("0x1", "ascii", "String") => "MoveAsciiString".to_string(),
+            ("0x1", "option", "Option") => format!(
+                "MoveOption<{}>",
+                rust_type(abi, structs, tag.type_params.first()?)?
+            ),
+            ("0x2", "object", "ObjectID") => "ObjectID".to_string(),
+            (_, module, name)
+                if tag.type_params.is_empty()
+                    && is_supported_struct(structs, &tag.address, module, name) =>
+            {
+                if tag.address == abi.address.0 && module == abi.name.0.as_str() {
+                    name.to_string()
+                } else {
+                    format!("super::{}::{}", rust_ident(module), name)
+                }
+            }
+            _ => return None,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use framework_builder::stdlib_version::StdlibVersion;
+    use rooch_framework::ROOCH_FRAMEWORK_ADDRESS;
+
+    #[test]
+    fn test_generate_framework_bindings() {
+        let stdlib = framework_release::load_stdlib(StdlibVersion::Latest).unwrap();
+        let abis = stdlib
+            .all_modules()
+            .unwrap()
+            .into_iter()
+            .filter(|m| *m.self_id().address() == ROOCH_FRAMEWORK_ADDRESS)
+            .map(ModuleABIView::from)
+            .collect::<Vec<_>>();
+        let generator = BindingGenerator::new(true, &abis);
+        let mut codes = BTreeMap::new();
+        for abi in abis.iter() {
+            let code = generator.generate(abi);
+            // The generated code must be valid Rust
+            if let Err(e) = syn::parse_file(&code) {
+                panic!("invalid bindings of module {}: {}\n{}", abi.name.0, e, code);
+            }
+            codes.insert(abi.name.0.to_string(), code);
+        }
+        syn::parse_file(&generate_mod_file(
+            &codes.keys().cloned().collect::<Vec<_>>(),
+        ))
+        .unwrap();
+
+        let session_key = &codes["session_key"];
+        assert!(session_key.contains("pub struct SessionKeyModule<'a>"));
+        assert!(session_key.contains("pub struct SessionScope {"));
+        assert!(session_key.contains("pub fn remove_session_key_entry_action("));
+        assert!(session_key.contains("impl MoveStructState for SessionScope"));
+        // The Object argument is passed by the ObjectID
+        assert!(codes["oracle"].contains("pub fn submit_decimal_data_action(arg0: ObjectID,"));
+        // The struct of the other module is referred from the generated module
+        assert!(codes["auth_validator"]
+            .contains("pub bitcoin_address: super::bitcoin_address::BitcoinAddress,"));
+    }
+
+    #[test]
+    fn test_camel_case() {
+        assert_eq!(camel_case("session_key"), "SessionKey");
+        assert_eq!(camel_case("coin"), "Coin");
+        assert_eq!(camel_case("bitcoin_block_store"), "BitcoinBlockStore");
+    }
+}
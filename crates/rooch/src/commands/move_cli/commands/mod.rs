@@ -7,6 +7,7 @@ pub mod disassemble;
 pub mod docgen;
 pub mod errmap;
 pub mod explain;
+pub mod gen_bindings;
 pub mod info;
 pub mod integration_test;
 pub mod new;
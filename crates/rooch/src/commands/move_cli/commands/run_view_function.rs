@@ -10,6 +10,7 @@ use move_core_types::language_storage::TypeTag;
 use moveos_types::transaction::FunctionCall;
 use rooch_rpc_api::jsonrpc_types::AnnotatedFunctionResultView;
 use rooch_types::{
+    address::ParsedAddress,
     error::{RoochError, RoochResult},
     function_arg::{FunctionArg, ParsedFunctionId},
 };
@@ -40,6 +41,10 @@ pub struct RunViewFunction {
     #[clap(long = "args")]
     pub args: Vec<FunctionArg>,
 
+    /// The caller of the view function, it is the sender of the TxContext, default is 0x0.
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    pub caller: Option<ParsedAddress>,
+
     /// RPC client options.
     #[clap(flatten)]
     context: WalletContextOptions,
@@ -66,12 +71,17 @@ impl CommandAction<AnnotatedFunctionResultView> for RunViewFunction {
             })
             .collect::<Result<Vec<_>>>()?;
 
+        let caller = self
+            .caller
+            .map(|caller| caller.into_account_address(&address_mapping))
+            .transpose()?;
+
         let function_call = FunctionCall::new(function_id, type_args, args);
 
         let client = context.get_client().await?;
         client
             .rooch
-            .execute_view_function(function_call)
+            .execute_view_function_with_caller(function_call, caller)
             .await
             .map_err(|e| RoochError::ViewFunctionError(e.to_string()))
     }
@@ -6,16 +6,41 @@ use async_trait::async_trait;
 use clap::Parser;
 use moveos_types::moveos_std::object::ObjectID;
 use rooch_oracle::aggregator_stream::AggregateStrategy;
+use rooch_oracle::datasource::custom::CustomSource;
 use rooch_oracle::datasource::{DataSourceType, Ticker};
+use rooch_oracle::multi_source::ReportPolicy;
 use rooch_oracle::reporter::Reporter;
 use rooch_types::error::{RoochError, RoochResult};
+use std::path::PathBuf;
+use std::time::Duration;
 
 /// Start a Oracle data reporter
 #[derive(Debug, Parser)]
 pub struct ReporterCommand {
-    /// Report interval in seconds
+    /// The interval in seconds to aggregate the quotes and check whether to report,
+    /// the data is reported only when the deviation threshold or the heartbeat interval is reached
     #[clap(long, default_value = "10")]
-    pub report_interval: u64,
+    pub check_interval: u64,
+
+    /// Report when the price moves more than the threshold from the last reported price, in basis points
+    #[clap(long, default_value = "50")]
+    pub deviation_threshold_bps: u64,
+
+    /// Report when the time since the last report exceeds the heartbeat interval in seconds, even if the price does not move
+    #[clap(long, default_value = "3600")]
+    pub heartbeat_interval: u64,
+
+    /// Drop the quote older than the max staleness in seconds
+    #[clap(long, default_value = "60")]
+    pub max_staleness: u64,
+
+    /// Drop the quote which deviates from the median of all sources more than the threshold, in basis points
+    #[clap(long, default_value = "500")]
+    pub outlier_threshold_bps: u64,
+
+    /// The min number of valid quotes of the data sources to report
+    #[clap(long, default_value = "1")]
+    pub min_sources: usize,
 
     /// The Oracle ObjectID
     #[clap(long, env = "ROOCH_ORACLE_ID")]
@@ -25,9 +50,14 @@ pub struct ReporterCommand {
     #[clap(long, env = "ROOCH_ORACLE_ADMIN_ID")]
     pub oracle_admin_id: ObjectID,
 
-    /// The DataSource Type
-    #[clap(long)]
-    pub data_source: DataSourceType,
+    /// The builtin DataSource Types, the quotes of all the data sources are aggregated
+    #[clap(long = "data-source", num_args = 1..)]
+    pub data_sources: Vec<DataSourceType>,
+
+    /// The config files of the custom data sources, in TOML or JSON format
+    /// The config gives the url, the JSON path of the price and timestamp, and the decimal of each ticker
+    #[clap(long = "custom-data-source", num_args = 1..)]
+    pub custom_data_sources: Vec<PathBuf>,
 
     /// The ticker of the price data source
     #[clap(long, default_value_t)]
@@ -39,7 +69,7 @@ pub struct ReporterCommand {
     #[clap(long)]
     pub stop_on_error: bool,
 
-    /// Stop after report N times
+    /// Stop after the data is submitted successfully N times
     #[clap(long)]
     pub stop_after_report_times: Option<u64>,
 
@@ -57,15 +87,31 @@ impl CommandAction<String> for ReporterCommand {
         let sender = wallet_context
             .resolve_address(self.tx_options.sender)?
             .into();
+        let mut data_sources = self.data_sources;
+        for path in self.custom_data_sources {
+            data_sources.push(DataSourceType::Custom(CustomSource::load(path)?));
+        }
+        if data_sources.is_empty() {
+            return Err(RoochError::CommandArgumentError(
+                "At least one of --data-source or --custom-data-source is required".to_string(),
+            ));
+        }
         let reporter = Reporter::new(
             wallet_context,
             sender,
             self.oracle_id,
             self.oracle_admin_id,
-            self.report_interval,
             self.ticker,
-            self.data_source,
+            data_sources,
             self.aggregate_strategy,
+            ReportPolicy {
+                check_interval: Duration::from_secs(self.check_interval),
+                deviation_threshold_bps: self.deviation_threshold_bps,
+                heartbeat_interval: Duration::from_secs(self.heartbeat_interval),
+                max_staleness: Duration::from_secs(self.max_staleness),
+                outlier_threshold_bps: self.outlier_threshold_bps,
+                min_sources: self.min_sources,
+            },
             self.stop_on_error,
             self.stop_after_report_times,
         );
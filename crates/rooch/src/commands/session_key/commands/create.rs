@@ -3,6 +3,9 @@
 
 use crate::cli_types::{TransactionOptions, WalletContextOptions};
 use clap::Parser;
+use move_command_line_common::types::ParsedStructType;
+use move_core_types::language_storage::TypeTag;
+use move_core_types::u256::U256;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_std::string::MoveString;
 use rooch_key::keystore::account_keystore::AccountKeystore;
@@ -31,6 +34,24 @@ pub struct CreateCommand {
     #[clap(long, default_value = "3600")]
     pub max_inactive_interval: u64,
 
+    /// The max gas(in RGas) the session key can spend, 0 means no limit
+    #[clap(long, default_value = "0")]
+    pub max_gas: U256,
+
+    /// The max number of transactions the session key can send, 0 means no limit
+    #[clap(long, default_value = "0")]
+    pub max_tx_count: u64,
+
+    /// The absolute expiry time of the session key, unix timestamp in seconds, 0 means no expiry time
+    #[clap(long, default_value = "0")]
+    pub expire_time: u64,
+
+    /// The max amount of the coin the session key can transfer, format: <COIN_TYPE>=<AMOUNT>.
+    /// Can be specified multiple times, the coin types not specified are not limited.
+    /// For example: 0x3::gas_coin::RGas=100000000
+    #[clap(long, value_parser=parse_coin_limit)]
+    pub coin_limit: Vec<(ParsedStructType, U256)>,
+
     #[clap(flatten)]
     pub tx_options: TransactionOptions,
 
@@ -45,17 +66,47 @@ impl CreateCommand {
         let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
         let max_gas_amount: Option<u64> = self.tx_options.max_gas_amount;
 
+        let mapping = context.address_mapping();
+        let coin_limits = self
+            .coin_limit
+            .into_iter()
+            .map(|(coin_type, max_amount)| {
+                Ok((
+                    TypeTag::from(coin_type.into_struct_tag(&mapping)?),
+                    max_amount,
+                ))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
         let session_auth_key = context.generate_session_key(&sender)?;
         let session_scope = self.scope;
 
-        let action =
-            rooch_types::framework::session_key::SessionKeyModule::create_session_key_action(
+        // The session key and all its limits are created in one transaction
+        let has_limits = self.max_gas != U256::zero()
+            || self.max_tx_count != 0
+            || self.expire_time != 0
+            || !coin_limits.is_empty();
+        let action = if has_limits {
+            SessionKeyModule::create_session_key_with_limits_action(
                 self.app_name,
                 self.app_url,
                 session_auth_key.as_ref().to_vec(),
-                session_scope.clone(),
+                vec![session_scope],
                 self.max_inactive_interval,
-            );
+                self.max_gas,
+                self.max_tx_count,
+                self.expire_time,
+                coin_limits,
+            )
+        } else {
+            SessionKeyModule::create_session_key_action(
+                self.app_name,
+                self.app_url,
+                session_auth_key.as_ref().to_vec(),
+                session_scope,
+                self.max_inactive_interval,
+            )
+        };
 
         println!("Generated new session key {session_auth_key} for address [{sender}]",);
 
@@ -64,6 +115,7 @@ impl CreateCommand {
             .await?;
         let result = context.sign_and_execute(sender, tx_data).await?;
         context.assert_execute_success(result)?;
+
         let client = context.get_client().await?;
         let session_key_module = client.as_module_binding::<SessionKeyModule>();
         let session_key = session_key_module
@@ -80,3 +132,10 @@ impl CreateCommand {
         Ok(session_key)
     }
 }
+
+fn parse_coin_limit(s: &str) -> anyhow::Result<(ParsedStructType, U256)> {
+    let (coin_type, amount) = s
+        .rsplit_once('=')
+        .ok_or_else(|| anyhow::anyhow!("Invalid coin limit: {}, expect <COIN_TYPE>=<AMOUNT>", s))?;
+    Ok((ParsedStructType::parse(coin_type)?, amount.parse::<U256>()?))
+}
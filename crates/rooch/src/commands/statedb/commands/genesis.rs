@@ -251,7 +251,7 @@ impl GenesisCommand {
     }
 }
 
-fn calc_file_checksum(file_path: &PathBuf) -> u64 {
+pub(crate) fn calc_file_checksum(file_path: &PathBuf) -> u64 {
     let mut file = File::open(file_path).unwrap();
     let mut hasher = xxhash_rust::xxh3::Xxh3::default();
     let mut buf = [0u8; 1024 * 1024];
@@ -0,0 +1,852 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::commands::statedb::commands::genesis::calc_file_checksum;
+use crate::commands::statedb::commands::{
+    apply_fields, apply_nodes, init_rooch_db, parse_states_csv_fields, ExportWriter,
+};
+use accumulator::{AccumulatorNode, AccumulatorTreeStore};
+use anyhow::{anyhow, bail, ensure, Result};
+use clap::Parser;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_store::MoveOSStore;
+use moveos_types::genesis_info::GenesisInfo;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::{ObjectID, GENESIS_STATE_ROOT};
+use moveos_types::startup_info::StartupInfo;
+use moveos_types::state::{FieldKey, ObjectState};
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_config::R_OPT_NET_HELP;
+use rooch_db::RoochDB;
+use rooch_store::da_store::DAMetaStore;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::proposer_store::ProposerStore;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::da::batch::BlockSubmitState;
+use rooch_types::error::RoochResult;
+use rooch_types::rooch_network::RoochChainID;
+use rooch_types::sequencer::SequencerInfo;
+use rooch_types::transaction::LedgerTransaction;
+use serde::{Deserialize, Serialize};
+use smt::{NodeReader, UpdateSet};
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Instant;
+
+pub const SNAPSHOT_VERSION: u32 = 2;
+pub const SNAPSHOT_MANIFEST_FILE: &str = "manifest.json";
+
+/// The manifest of a state snapshot, it records the state at `tx_order` and the chunks of every object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    pub version: u32,
+    pub tx_order: u64,
+    pub tx_hash: H256,
+    /// The global state root after executing the tx of `tx_order`
+    pub state_root: H256,
+    /// The global state size after executing the tx of `tx_order`
+    pub size: u64,
+    pub genesis_info: GenesisInfo,
+    /// The sequencer info at `tx_order`, the restored node sequences from `tx_order + 1`
+    pub sequencer_info: SequencerInfo,
+    /// The ledger tx of `tx_order` and its execution info, the node reads them as the last executed tx
+    pub ledger_tx: LedgerTransaction,
+    pub execution_info: TransactionExecutionInfo,
+    /// The frozen subtree root nodes of the tx accumulator at `tx_order`, the new txs are appended to them
+    pub accumulator_nodes: Vec<AccumulatorNode>,
+    /// The submitted DA block which ends at `tx_order`, the restored node makes new blocks after it.
+    /// It's None if the snapshot is at genesis.
+    pub da_block: Option<BlockSubmitState>,
+    /// The objects which have fields, in post order: the child objects are before the parent, the root object is the last.
+    pub objects: Vec<SnapshotObject>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotObject {
+    pub object_id: ObjectID,
+    pub state_root: H256,
+    pub size: u64,
+    pub chunks: Vec<SnapshotChunk>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotChunk {
+    pub file_name: String,
+    pub count: u64,
+    /// xxh3 checksum of the chunk file, in hex
+    pub checksum: String,
+}
+
+impl SnapshotManifest {
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(SNAPSHOT_MANIFEST_FILE);
+        let file = File::open(&path)
+            .map_err(|e| anyhow!("Failed to open manifest {}: {}", path.display(), e))?;
+        let manifest: SnapshotManifest = serde_json::from_reader(BufReader::new(file))?;
+        ensure!(
+            manifest.version == SNAPSHOT_VERSION,
+            "Unsupported snapshot version: {}, expected: {}",
+            manifest.version,
+            SNAPSHOT_VERSION
+        );
+        Ok(manifest)
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let file = File::create(dir.join(SNAPSHOT_MANIFEST_FILE))?;
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+}
+
+/// Create or restore the state snapshot, for bootstrapping a new node without replaying from genesis.
+#[derive(Debug, Parser)]
+pub struct SnapshotCommand {
+    #[clap(subcommand)]
+    pub cmd: SnapshotSubCommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum SnapshotSubCommand {
+    Create(CreateSnapshotCommand),
+    Restore(RestoreSnapshotCommand),
+}
+
+impl SnapshotCommand {
+    pub async fn execute(self) -> RoochResult<()> {
+        match self.cmd {
+            SnapshotSubCommand::Create(create) => create.execute(),
+            SnapshotSubCommand::Restore(restore) => restore.execute(),
+        }
+    }
+}
+
+/// Create a snapshot of the state at a tx_order, the tx_order must be the end of a submitted DA block.
+/// The state of an old tx_order may be unavailable if the stale nodes have been pruned.
+#[derive(Debug, Parser)]
+pub struct CreateSnapshotCommand {
+    /// The tx_order of the snapshot, default is the last tx_order submitted to DA
+    #[clap(long)]
+    pub tx_order: Option<u64>,
+    /// The output dir of the snapshot, it must be empty or not exist
+    #[clap(long, short = 'o')]
+    pub output: PathBuf,
+    #[clap(
+        long,
+        default_value = "1048576",
+        help = "max field count of a chunk file"
+    )]
+    pub chunk_size: usize,
+
+    #[clap(long = "data-dir", short = 'd')]
+    /// Path to data dir, this dir is base dir, the final data_dir is base_dir/chain_network_name
+    pub base_data_dir: Option<PathBuf>,
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+impl CreateSnapshotCommand {
+    pub fn execute(self) -> RoochResult<()> {
+        Ok(self.create()?)
+    }
+
+    fn create(self) -> Result<()> {
+        let start_time = Instant::now();
+        ensure!(self.chunk_size > 0, "chunk size must be greater than 0");
+        if self.output.exists() {
+            ensure!(
+                std::fs::read_dir(&self.output)?.next().is_none(),
+                "output dir {} is not empty",
+                self.output.display()
+            );
+        }
+        std::fs::create_dir_all(&self.output)?;
+
+        let rooch_db = init_rooch_db(self.base_data_dir.clone(), self.chain_id.clone());
+        let (manifest, chunks) =
+            create_snapshot(&rooch_db, self.tx_order, &self.output, self.chunk_size)?;
+
+        println!(
+            "Done. snapshot at tx_order: {}, state_root: {:?}, objects: {}, chunks: {}. cost: {:?}",
+            manifest.tx_order,
+            manifest.state_root,
+            manifest.objects.len(),
+            chunks,
+            start_time.elapsed()
+        );
+        Ok(())
+    }
+}
+
+/// Export the state at `tx_order` into the `output` dir, return the manifest and the number of the chunks.
+fn create_snapshot(
+    rooch_db: &RoochDB,
+    tx_order: Option<u64>,
+    output: &Path,
+    chunk_size: usize,
+) -> Result<(SnapshotManifest, usize)> {
+    let rooch_store = &rooch_db.rooch_store;
+    let last_sequencer_info = rooch_store
+        .get_sequencer_info()?
+        .ok_or_else(|| anyhow!("sequencer info not found, genesis must be initialed"))?;
+    let tx_order = match tx_order {
+        Some(tx_order) => tx_order,
+        None => last_submitted_order(rooch_store)?,
+    };
+    ensure!(
+        tx_order <= last_sequencer_info.last_order,
+        "tx_order {} is greater than the last tx_order {}",
+        tx_order,
+        last_sequencer_info.last_order
+    );
+    // the genesis tx is not in any DA block
+    let da_block = if tx_order == 0 {
+        None
+    } else {
+        let da_block = find_da_block(rooch_store, tx_order)?.ok_or_else(|| {
+            anyhow!(
+                "no DA block ends at tx_order {}, the snapshot must be at the end of a DA block",
+                tx_order
+            )
+        })?;
+        ensure!(
+            da_block.done,
+            "DA block {} which ends at tx_order {} is not submitted",
+            da_block.block_range.block_number,
+            tx_order
+        );
+        Some(da_block)
+    };
+
+    let tx_hash = rooch_store
+        .transaction_store
+        .get_tx_hashes(vec![tx_order])?
+        .pop()
+        .flatten()
+        .ok_or_else(|| anyhow!("tx_hash not found for tx_order {}", tx_order))?;
+    let ledger_tx = rooch_store
+        .transaction_store
+        .get_transaction_by_hash(tx_hash)?
+        .ok_or_else(|| anyhow!("ledger tx not found for tx_hash {:?}", tx_hash))?;
+    let execution_info = rooch_db
+        .moveos_store
+        .transaction_store
+        .get_tx_execution_info(tx_hash)?
+        .ok_or_else(|| anyhow!("execution info not found for tx_hash {:?}", tx_hash))?;
+    let genesis_info = rooch_db
+        .moveos_store
+        .config_store
+        .get_genesis()?
+        .ok_or_else(|| anyhow!("genesis info not found"))?;
+    let accumulator_info = ledger_tx.sequence_info.tx_accumulator_info();
+    let accumulator_store = rooch_store.get_transaction_accumulator_store();
+    let accumulator_nodes = accumulator_info
+        .frozen_subtree_roots
+        .iter()
+        .map(|hash| {
+            accumulator_store
+                .get_node(*hash)?
+                .ok_or_else(|| anyhow!("tx accumulator node not found: {:?}", hash))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut writer = SnapshotWriter::new(&rooch_db.moveos_store, output.to_path_buf(), chunk_size);
+    writer.export_object(
+        ObjectID::root(),
+        execution_info.state_root,
+        execution_info.size,
+    )?;
+
+    let manifest = SnapshotManifest {
+        version: SNAPSHOT_VERSION,
+        tx_order,
+        tx_hash,
+        state_root: execution_info.state_root,
+        size: execution_info.size,
+        genesis_info,
+        sequencer_info: SequencerInfo::new(tx_order, accumulator_info),
+        ledger_tx,
+        execution_info,
+        accumulator_nodes,
+        da_block,
+        objects: writer.objects,
+    };
+    manifest.save(output)?;
+    Ok((manifest, writer.chunk_index))
+}
+
+/// The end tx_order of the last DA block submitted by the background submitter, or genesis if there is none.
+fn last_submitted_order(rooch_store: &RoochStore) -> Result<u64> {
+    match rooch_store.get_background_submit_block_cursor()? {
+        Some(block_number) => Ok(rooch_store
+            .get_block_state(block_number)?
+            .block_range
+            .tx_order_end),
+        None => Ok(0),
+    }
+}
+
+/// Binary search the DA block which ends at `tx_order`, the blocks are sorted by the tx orders.
+fn find_da_block(rooch_store: &RoochStore, tx_order: u64) -> Result<Option<BlockSubmitState>> {
+    let Some(last_block_number) = rooch_store.get_last_block_number()? else {
+        return Ok(None);
+    };
+    let (mut low, mut high) = (0u128, last_block_number);
+    while low <= high {
+        let mid = low + (high - low) / 2;
+        let Some(block_state) = rooch_store.try_get_block_state(mid)? else {
+            // the blocks before the restored snapshot don't exist
+            low = mid + 1;
+            continue;
+        };
+        match block_state.block_range.tx_order_end.cmp(&tx_order) {
+            Ordering::Equal => return Ok(Some(block_state)),
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater if mid == 0 => break,
+            Ordering::Greater => high = mid - 1,
+        }
+    }
+    Ok(None)
+}
+
+/// Restore the state from a snapshot into an empty node.
+/// The restore can be resumed, the objects which have been rebuilt are skipped.
+#[derive(Debug, Parser)]
+pub struct RestoreSnapshotCommand {
+    /// The dir of the snapshot
+    #[clap(long, short = 'i')]
+    pub input: PathBuf,
+
+    #[clap(long = "data-dir", short = 'd')]
+    /// Path to data dir, this dir is base dir, the final data_dir is base_dir/chain_network_name
+    pub base_data_dir: Option<PathBuf>,
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+impl RestoreSnapshotCommand {
+    pub fn execute(self) -> RoochResult<()> {
+        Ok(self.restore()?)
+    }
+
+    fn restore(self) -> Result<()> {
+        let start_time = Instant::now();
+        let rooch_db = init_rooch_db(self.base_data_dir.clone(), self.chain_id.clone());
+        let manifest = restore_snapshot(&rooch_db, &self.input)?;
+
+        println!(
+            "Done. snapshot restored at tx_order: {}, state_root: {:?}. cost: {:?}",
+            manifest.tx_order,
+            manifest.state_root,
+            start_time.elapsed()
+        );
+        Ok(())
+    }
+}
+
+/// Restore the snapshot in the `input` dir into an empty node, return the manifest.
+/// The state, the last tx, the tx accumulator and the DA meta are restored consistently,
+/// so the node continues sequencing, executing and submitting to DA from `tx_order + 1`.
+fn restore_snapshot(rooch_db: &RoochDB, input: &Path) -> Result<SnapshotManifest> {
+    let manifest = SnapshotManifest::load(input)?;
+    ensure!(
+        manifest.ledger_tx.sequence_info.tx_order == manifest.tx_order
+            && manifest.execution_info.tx_hash == manifest.tx_hash
+            && manifest.execution_info.state_root == manifest.state_root,
+        "the last tx of the snapshot mismatch the tx_order {}",
+        manifest.tx_order
+    );
+    if let Some(da_block) = &manifest.da_block {
+        ensure!(
+            da_block.done && da_block.block_range.tx_order_end == manifest.tx_order,
+            "the DA block of the snapshot must be submitted and end at tx_order {}",
+            manifest.tx_order
+        );
+    }
+
+    let config_store = &rooch_db.moveos_store.config_store;
+    let rooch_store = &rooch_db.rooch_store;
+    ensure!(
+        config_store.get_startup_info()?.is_none(),
+        "statedb is not empty, the snapshot can only be restored into a new node"
+    );
+    if let Some(genesis_info) = config_store.get_genesis()? {
+        ensure!(
+            genesis_info == manifest.genesis_info,
+            "genesis info mismatch, the snapshot is not for this chain"
+        );
+    }
+
+    let state_root = restore_objects(&rooch_db.moveos_store, input, &manifest)?;
+    ensure!(
+        state_root == manifest.state_root,
+        "state root mismatch, expected: {:?}, actual: {:?}",
+        manifest.state_root,
+        state_root
+    );
+
+    // save the metadata at last, so the node does not start with a partial state.
+    // the metadata saved by an interrupted restore is checked and skipped.
+    if config_store.get_genesis()?.is_none() {
+        config_store.save_genesis(manifest.genesis_info.clone())?;
+    }
+    rooch_db
+        .moveos_store
+        .transaction_store
+        .save_tx_execution_info(manifest.execution_info.clone())?;
+    match rooch_store.get_sequencer_info()? {
+        // the ledger tx, the sequencer info and the accumulator nodes are saved atomically
+        None => rooch_store.save_sequenced_tx(
+            manifest.tx_hash,
+            manifest.ledger_tx.clone(),
+            manifest.sequencer_info.clone(),
+            Some(manifest.accumulator_nodes.clone()),
+        )?,
+        Some(sequencer_info) => ensure!(
+            sequencer_info == manifest.sequencer_info,
+            "sequencer info mismatch, expected: {:?}, actual: {:?}",
+            manifest.sequencer_info,
+            sequencer_info
+        ),
+    }
+    if let Some(da_block) = &manifest.da_block {
+        let block_number = da_block.block_range.block_number;
+        match rooch_store.get_last_block_number()? {
+            None => rooch_store.restore_last_block(da_block.clone())?,
+            Some(last_block_number) => ensure!(
+                last_block_number == block_number,
+                "DA last block number mismatch, expected: {}, actual: {}",
+                block_number,
+                last_block_number
+            ),
+        }
+        // the blocks before the snapshot can't be proposed by the restored node
+        rooch_store.set_last_proposed(block_number)?;
+    }
+    config_store.save_startup_info(StartupInfo::new(manifest.state_root, manifest.size))?;
+    Ok(manifest)
+}
+
+struct SnapshotWriter<'a> {
+    moveos_store: &'a MoveOSStore,
+    output: PathBuf,
+    chunk_size: usize,
+    chunk_index: usize,
+    objects: Vec<SnapshotObject>,
+}
+
+impl<'a> SnapshotWriter<'a> {
+    fn new(moveos_store: &'a MoveOSStore, output: PathBuf, chunk_size: usize) -> Self {
+        Self {
+            moveos_store,
+            output,
+            chunk_size,
+            chunk_index: 0,
+            objects: vec![],
+        }
+    }
+
+    // export the fields of the object, then the child objects, the object is recorded after its children
+    fn export_object(&mut self, object_id: ObjectID, state_root: H256, size: u64) -> Result<()> {
+        let mut chunks = vec![];
+        let mut children = vec![];
+        let mut chunk: Option<(String, ExportWriter)> = None;
+        let mut count = 0u64;
+
+        let moveos_store = self.moveos_store;
+        let iter = moveos_store.get_state_store().iter(state_root, None)?;
+        for item in iter {
+            let (k, v) = item?;
+            if v.metadata.has_fields() {
+                children.push((v.metadata.id.clone(), v.state_root(), v.size()));
+            }
+            let (_, writer) = chunk.get_or_insert_with(|| self.new_chunk());
+            writer.write_record(&k, &v)?;
+            count += 1;
+            if count as usize == self.chunk_size {
+                let (file_name, writer) = chunk.take().expect("chunk must be existed");
+                chunks.push(self.finish_chunk(file_name, writer, count)?);
+                count = 0;
+            }
+        }
+        if let Some((file_name, writer)) = chunk.take() {
+            chunks.push(self.finish_chunk(file_name, writer, count)?);
+        }
+
+        for (child_id, child_state_root, child_size) in children {
+            self.export_object(child_id, child_state_root, child_size)?;
+        }
+
+        println!(
+            "exported object_id: {:?}, state_root: {:?}, size: {}, chunks: {}",
+            object_id,
+            state_root,
+            size,
+            chunks.len()
+        );
+        self.objects.push(SnapshotObject {
+            object_id,
+            state_root,
+            size,
+            chunks,
+        });
+        Ok(())
+    }
+
+    fn new_chunk(&mut self) -> (String, ExportWriter) {
+        let file_name = format!("chunk-{:08}", self.chunk_index);
+        self.chunk_index += 1;
+        let writer = ExportWriter::new(Some(self.output.join(&file_name)), None);
+        (file_name, writer)
+    }
+
+    fn finish_chunk(
+        &self,
+        file_name: String,
+        mut writer: ExportWriter,
+        count: u64,
+    ) -> Result<SnapshotChunk> {
+        writer.flush()?;
+        drop(writer);
+        let checksum = calc_file_checksum(&self.output.join(&file_name));
+        Ok(SnapshotChunk {
+            file_name,
+            count,
+            checksum: format!("{:x}", checksum),
+        })
+    }
+}
+
+/// Rebuild the objects of the snapshot, return the state root of the root object.
+/// The state root of every object is checked against the manifest.
+fn restore_objects(
+    moveos_store: &MoveOSStore,
+    dir: &Path,
+    manifest: &SnapshotManifest,
+) -> Result<H256> {
+    let root = manifest
+        .objects
+        .last()
+        .ok_or_else(|| anyhow!("no object in snapshot"))?;
+    ensure!(
+        root.object_id == ObjectID::root(),
+        "the last object of the snapshot must be the root object"
+    );
+
+    let total = manifest.objects.len();
+    for (index, object) in manifest.objects.iter().enumerate() {
+        // the tree root node is written after all the other nodes of the object, so it is restored if existed
+        if moveos_store
+            .state_store
+            .node_store
+            .get(&object.state_root)?
+            .is_some()
+        {
+            tracing::info!("object {:?} existed, skip", object.object_id);
+            continue;
+        }
+        let loop_time = Instant::now();
+        let mut state_root = *GENESIS_STATE_ROOT;
+        for chunk in &object.chunks {
+            let update_set = load_chunk(dir, chunk)?;
+            let tree_change_set = apply_fields(moveos_store, state_root, update_set)?;
+            apply_nodes(moveos_store, tree_change_set.nodes)?;
+            state_root = tree_change_set.state_root;
+        }
+        ensure!(
+            state_root == object.state_root,
+            "state root mismatch of object {:?}, expected: {:?}, actual: {:?}",
+            object.object_id,
+            object.state_root,
+            state_root
+        );
+        println!(
+            "[{}/{}] restored object_id: {:?}, state_root: {:?}, size: {}. cost: {:?}",
+            index + 1,
+            total,
+            object.object_id,
+            object.state_root,
+            object.size,
+            loop_time.elapsed()
+        );
+    }
+    Ok(root.state_root)
+}
+
+fn load_chunk(dir: &Path, chunk: &SnapshotChunk) -> Result<UpdateSet<FieldKey, ObjectState>> {
+    let path = dir.join(&chunk.file_name);
+    let checksum = format!("{:x}", calc_file_checksum(&path));
+    if checksum != chunk.checksum {
+        bail!(
+            "checksum mismatch of chunk {}, expected: {}, actual: {}",
+            chunk.file_name,
+            chunk.checksum,
+            checksum
+        );
+    }
+
+    let reader = BufReader::with_capacity(8 * 1024 * 1024, File::open(&path)?);
+    let mut update_set = UpdateSet::new();
+    let mut count = 0u64;
+    for line in reader.lines() {
+        let line = line?;
+        let (c1, c2) = parse_states_csv_fields(&line)?;
+        update_set.put(FieldKey::from_str(&c1)?, ObjectState::from_str(&c2)?);
+        count += 1;
+    }
+    ensure!(
+        count == chunk.count,
+        "field count mismatch of chunk {}, expected: {}, actual: {}",
+        chunk.file_name,
+        chunk.count,
+        count
+    );
+    Ok(update_set)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use metrics::RegistryService;
+    use move_core_types::vm_status::KeptVMStatus;
+    use moveos_types::state_resolver::StatelessResolver;
+    use moveos_types::test_utils::{random_raw_object, random_raw_object_for_child_object};
+    use rooch_config::RoochOpt;
+    use rooch_executor::actor::executor::ExecutorActor;
+    use rooch_genesis::RoochGenesis;
+    use rooch_sequencer::actor::sequencer::SequencerActor;
+    use rooch_types::crypto::RoochKeyPair;
+    use rooch_types::framework::empty::Empty;
+    use rooch_types::rooch_network::{BuiltinChainID, RoochNetwork};
+    use rooch_types::service_status::ServiceStatus;
+    use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+    use rooch_types::transaction::{LedgerTxData, TransactionSequenceInfo};
+    use tempfile::tempdir;
+
+    fn put_fields(moveos_store: &MoveOSStore, states: Vec<ObjectState>) -> H256 {
+        let mut update_set = UpdateSet::new();
+        for state in states {
+            update_set.put(state.metadata.id.field_key(), state);
+        }
+        let tree_change_set = apply_fields(moveos_store, *GENESIS_STATE_ROOT, update_set).unwrap();
+        apply_nodes(moveos_store, tree_change_set.nodes).unwrap();
+        tree_change_set.state_root
+    }
+
+    fn new_manifest(state_root: H256, objects: Vec<SnapshotObject>) -> SnapshotManifest {
+        let tx_hash = H256::random();
+        SnapshotManifest {
+            version: SNAPSHOT_VERSION,
+            tx_order: 1,
+            tx_hash,
+            state_root,
+            size: 3,
+            genesis_info: GenesisInfo::new(H256::random(), vec![]),
+            sequencer_info: SequencerInfo::new(1, Default::default()),
+            ledger_tx: LedgerTransaction::new(
+                LedgerTxData::L2Tx(RoochTransaction::mock()),
+                TransactionSequenceInfo::random(),
+            ),
+            execution_info: TransactionExecutionInfo::new(
+                tx_hash,
+                state_root,
+                3,
+                H256::random(),
+                0,
+                KeptVMStatus::Executed,
+            ),
+            accumulator_nodes: vec![],
+            da_block: Some(BlockSubmitState::new_done(0, 1, 1, H256::random())),
+            objects,
+        }
+    }
+
+    fn new_rooch_db() -> (RoochDB, RoochOpt) {
+        let opt = RoochOpt::new_with_temp_store().unwrap();
+        let registry_service = RegistryService::default();
+        let rooch_db =
+            RoochDB::init(opt.store_config(), &registry_service.default_registry()).unwrap();
+        (rooch_db, opt)
+    }
+
+    fn empty_tx(kp: &RoochKeyPair, sequence_number: u64) -> RoochTransaction {
+        let tx_data = RoochTransactionData::new_for_test(
+            kp.public().rooch_address().unwrap(),
+            sequence_number,
+            Empty::empty_function_call().into(),
+        );
+        tx_data.sign(kp)
+    }
+
+    // sequence and execute the tx like the pipeline processor
+    fn sequence_and_execute(
+        sequencer: &mut SequencerActor,
+        executor: &mut ExecutorActor,
+        tx: RoochTransaction,
+    ) -> LedgerTransaction {
+        let ledger_tx = sequencer.sequence(LedgerTxData::L2Tx(tx.clone())).unwrap();
+        let mut verified_tx = executor.validate_l2_tx(tx).unwrap();
+        verified_tx
+            .ctx
+            .add(ledger_tx.sequence_info.clone())
+            .unwrap();
+        let result = executor.execute(verified_tx).unwrap();
+        assert_eq!(result.transaction_info.status, KeptVMStatus::Executed);
+        ledger_tx
+    }
+
+    #[test]
+    fn test_snapshot_export_and_restore() {
+        let (moveos_store, _tmpdir) = MoveOSStore::mock_moveos_store().unwrap();
+        let mut parent = random_raw_object();
+        let child_states = (0..5)
+            .map(|_| random_raw_object_for_child_object(parent.metadata.id.clone()))
+            .collect::<Vec<_>>();
+        parent.metadata.state_root = Some(put_fields(&moveos_store, child_states.clone()));
+        parent.metadata.size = 5;
+        let state_root = put_fields(
+            &moveos_store,
+            vec![parent.clone(), random_raw_object(), random_raw_object()],
+        );
+
+        let output = tempdir().unwrap();
+        let mut writer = SnapshotWriter::new(&moveos_store, output.path().to_path_buf(), 2);
+        writer
+            .export_object(ObjectID::root(), state_root, 3)
+            .unwrap();
+        assert_eq!(writer.objects.len(), 2);
+        assert_eq!(writer.objects[0].object_id, parent.metadata.id);
+        assert_eq!(writer.objects[0].chunks.len(), 3);
+        assert_eq!(writer.objects[1].object_id, ObjectID::root());
+
+        let manifest = new_manifest(state_root, writer.objects);
+        manifest.save(output.path()).unwrap();
+        assert_eq!(SnapshotManifest::load(output.path()).unwrap(), manifest);
+
+        let (new_moveos_store, _new_tmpdir) = MoveOSStore::mock_moveos_store().unwrap();
+        let restored_root = restore_objects(&new_moveos_store, output.path(), &manifest).unwrap();
+        assert_eq!(restored_root, state_root);
+        let child = &child_states[0];
+        assert_eq!(
+            new_moveos_store
+                .get_field_at(parent.state_root(), &child.metadata.id.field_key())
+                .unwrap(),
+            Some(child.clone())
+        );
+
+        // resume the restore, all the objects are skipped
+        let restored_root = restore_objects(&new_moveos_store, output.path(), &manifest).unwrap();
+        assert_eq!(restored_root, state_root);
+    }
+
+    #[test]
+    fn test_snapshot_restore_checksum_mismatch() {
+        let (moveos_store, _tmpdir) = MoveOSStore::mock_moveos_store().unwrap();
+        let state_root = put_fields(&moveos_store, vec![random_raw_object()]);
+
+        let output = tempdir().unwrap();
+        let mut writer = SnapshotWriter::new(&moveos_store, output.path().to_path_buf(), 2);
+        writer
+            .export_object(ObjectID::root(), state_root, 1)
+            .unwrap();
+        let mut manifest = new_manifest(state_root, writer.objects);
+        manifest.objects[0].chunks[0].checksum = "0".to_string();
+
+        let (new_moveos_store, _new_tmpdir) = MoveOSStore::mock_moveos_store().unwrap();
+        assert!(restore_objects(&new_moveos_store, output.path(), &manifest).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_restore_and_boot() {
+        let kp = RoochKeyPair::generate_secp256k1();
+        let mut network: RoochNetwork = BuiltinChainID::Local.into();
+        network.mock_genesis_account(&kp).unwrap();
+        let genesis = RoochGenesis::build(network).unwrap();
+
+        // the source node executes a tx and submits it to DA
+        let (rooch_db, _opt) = new_rooch_db();
+        let root = genesis.init_genesis(&rooch_db).unwrap();
+        let registry_service = RegistryService::default();
+        let mut sequencer = SequencerActor::new(
+            kp.copy(),
+            rooch_db.rooch_store.clone(),
+            ServiceStatus::Active,
+            &registry_service.default_registry(),
+            None,
+        )
+        .unwrap();
+        let mut executor = ExecutorActor::new(
+            root,
+            rooch_db.moveos_store.clone(),
+            rooch_db.rooch_store.clone(),
+            &registry_service.default_registry(),
+            None,
+        )
+        .unwrap();
+        sequence_and_execute(&mut sequencer, &mut executor, empty_tx(&kp, 0));
+        let rooch_store = &rooch_db.rooch_store;
+        rooch_store.append_submitting_block(1, 1).unwrap();
+        rooch_store
+            .set_submitting_block_done(0, 1, 1, H256::random())
+            .unwrap();
+        rooch_store.set_background_submit_block_cursor(0).unwrap();
+
+        let output = tempdir().unwrap();
+        let (manifest, _) = create_snapshot(&rooch_db, None, output.path(), 1024).unwrap();
+        assert_eq!(manifest.tx_order, 1);
+        assert_eq!(
+            manifest.da_block,
+            Some(rooch_store.get_block_state(0).unwrap())
+        );
+
+        let (new_rooch_db, _new_opt) = new_rooch_db();
+        restore_snapshot(&new_rooch_db, output.path()).unwrap();
+        // the restored node can't be restored again
+        assert!(restore_snapshot(&new_rooch_db, output.path()).is_err());
+
+        // boot the restored node like the rpc server: the DA meta is consistent with the last tx_order
+        let new_rooch_store = &new_rooch_db.rooch_store;
+        assert_eq!(
+            new_rooch_store.try_repair_da_meta(1, false).unwrap(),
+            (0, 0)
+        );
+        assert_eq!(new_rooch_store.get_last_block_number().unwrap(), Some(0));
+        let new_root = new_rooch_db.latest_root().unwrap().unwrap();
+        assert_eq!(new_root.state_root(), manifest.state_root);
+        let new_registry_service = RegistryService::default();
+        let mut new_sequencer = SequencerActor::new(
+            kp.copy(),
+            new_rooch_store.clone(),
+            ServiceStatus::Active,
+            &new_registry_service.default_registry(),
+            None,
+        )
+        .unwrap();
+        let mut new_executor = ExecutorActor::new(
+            new_root,
+            new_rooch_db.moveos_store.clone(),
+            new_rooch_store.clone(),
+            &new_registry_service.default_registry(),
+            None,
+        )
+        .unwrap();
+        let tx = empty_tx(&kp, 1);
+        let new_ledger_tx = sequence_and_execute(&mut new_sequencer, &mut new_executor, tx.clone());
+        assert_eq!(new_ledger_tx.sequence_info.tx_order, 2);
+        assert_eq!(new_rooch_store.append_submitting_block(2, 2).unwrap(), 1);
+
+        // the tx accumulator continues from the snapshot as the source node
+        let ledger_tx = sequence_and_execute(&mut sequencer, &mut executor, tx);
+        assert_eq!(
+            new_ledger_tx.sequence_info.tx_accumulator_root,
+            ledger_tx.sequence_info.tx_accumulator_root
+        );
+        // the tx_order of a snapshot must be the end of a DA block
+        let other_output = tempdir().unwrap();
+        assert!(create_snapshot(&rooch_db, Some(2), other_output.path(), 1024).is_err());
+    }
+}
@@ -13,6 +13,7 @@ use crate::commands::statedb::commands::genesis_ord::GenesisOrdCommand;
 use crate::commands::statedb::commands::genesis_utxo::GenesisUTXOCommand;
 use crate::commands::statedb::commands::genesis_verify::GenesisVerifyCommand;
 use crate::commands::statedb::commands::re_genesis::ReGenesisCommand;
+use crate::commands::statedb::commands::snapshot::SnapshotCommand;
 
 pub mod commands;
 
@@ -47,6 +48,9 @@ impl CommandAction<String> for Statedb {
             StatedbCommand::ReGenesis(re_genesis) => re_genesis.execute().await.map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
+            StatedbCommand::Snapshot(snapshot) => snapshot.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
         }
     }
 }
@@ -60,4 +64,5 @@ pub enum StatedbCommand {
     GenesisOrd(GenesisOrdCommand),
     GenesisVerify(GenesisVerifyCommand),
     ReGenesis(ReGenesisCommand),
+    Snapshot(SnapshotCommand),
 }
@@ -13,6 +13,7 @@ pub mod build;
 pub mod get_transactions_by_hash;
 pub mod get_transactions_by_order;
 pub mod query;
+pub mod replay;
 pub mod sign;
 pub mod submit;
 
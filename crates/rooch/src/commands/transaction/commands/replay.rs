@@ -0,0 +1,364 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::commands::da::commands::exec::build_btc_client_proxy;
+use accumulator::accumulator_info::AccumulatorInfo;
+use accumulator::inmemory::InMemoryAccumulator;
+use anyhow::{anyhow, bail, ensure, Result};
+use async_trait::async_trait;
+use bitcoin::hashes::Hash;
+use clap::Parser;
+use coerce::actor::system::ActorSystem;
+use moveos::moveos::{MoveOS, MoveOSConfig};
+use moveos_gas_profiling::profiler::{new_verified_gas_profiler, ProfileGasMeter};
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::ObjectMeta;
+use moveos_types::transaction::{CallTrace, VMErrorInfo};
+use rooch_executor::actor::executor::{
+    l1_block_to_verified_tx, l1_tx_to_verified_tx, ResolverFunctionCaller,
+};
+use rooch_genesis::FrameworksGasParameters;
+use rooch_rpc_api::jsonrpc_types::event_view::TransactionEventView;
+use rooch_rpc_api::jsonrpc_types::transaction_view::LedgerTxDataView;
+use rooch_rpc_api::jsonrpc_types::{
+    KeptVMStatusView, StateChangeSetView, StrView, TransactionSequenceInfoView,
+};
+use rooch_rpc_client::ClientResolver;
+use rooch_types::bitcoin::types::Block as BitcoinBlock;
+use rooch_types::error::RoochResult;
+use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
+use rooch_types::multichain_id::MultiChainID;
+use rooch_types::transaction::{L1Block, L1BlockWithBody, L1Transaction, TransactionSequenceInfo};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Replay a historical transaction against the state of the Rooch node via RPC with the gas profiler,
+/// print the Move call trace and the state diff,
+/// and check the status, the gas used and the events are the same as the recorded execution info.
+/// The state root is not recalculated, the state tree nodes are not available via RPC.
+/// The state of an old transaction may be unavailable if the node has pruned the stale nodes.
+#[derive(Debug, Parser)]
+pub struct ReplayCommand {
+    /// The tx hash or the tx order of the transaction to replay
+    #[clap(name = "tx")]
+    pub tx: String,
+
+    /// Write the gas profiling html report to the path
+    #[clap(long)]
+    pub gas_report: Option<PathBuf>,
+
+    /// The bitcoin node RPC to get the block body, required to replay an L1 block
+    #[clap(
+        long = "btc-rpc-url",
+        requires_all = ["btc_rpc_user_name", "btc_rpc_password"]
+    )]
+    pub btc_rpc_url: Option<String>,
+    #[clap(long = "btc-rpc-user-name")]
+    pub btc_rpc_user_name: Option<String>,
+    #[clap(long = "btc-rpc-password")]
+    pub btc_rpc_password: Option<String>,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayOutput {
+    pub tx_order: StrView<u64>,
+    pub tx_hash: H256,
+    pub status: KeptVMStatusView,
+    pub recorded_status: KeptVMStatusView,
+    pub gas_used: StrView<u64>,
+    pub recorded_gas_used: StrView<u64>,
+    pub event_root: H256,
+    pub recorded_event_root: H256,
+    /// Whether the status, gas used and event root are all the same as recorded
+    pub matched: bool,
+    pub vm_error_info: Option<VMErrorInfo>,
+    /// The Move call trace with the gas used by each call, the children are indented under the caller
+    pub call_trace: Vec<String>,
+    pub events: Vec<TransactionEventView>,
+    pub state_diff: StateChangeSetView,
+}
+
+#[async_trait]
+impl CommandAction<ReplayOutput> for ReplayCommand {
+    async fn execute(self) -> RoochResult<ReplayOutput> {
+        Ok(self.replay().await?)
+    }
+}
+
+impl ReplayCommand {
+    async fn replay(self) -> Result<ReplayOutput> {
+        let client = self.context_options.build()?.get_client().await?;
+
+        let tx_order = match self.tx.parse::<u64>() {
+            Ok(tx_order) => tx_order,
+            Err(_) => {
+                let tx_hash = H256::from_str(&self.tx)
+                    .map_err(|e| anyhow!("Invalid tx hash or tx order {}: {}", self.tx, e))?;
+                client
+                    .rooch
+                    .get_transactions_by_hash(vec![tx_hash])
+                    .await?
+                    .pop()
+                    .flatten()
+                    .ok_or_else(|| anyhow!("transaction not found for tx_hash {:?}", tx_hash))?
+                    .transaction
+                    .sequence_info
+                    .tx_order
+                    .0
+            }
+        };
+        ensure!(tx_order > 0, "The genesis transaction can not be replayed");
+
+        // The transaction and the previous one in descending order, the cursor is exclusive.
+        // The pre-state of the transaction is the state after executing the previous transaction.
+        let mut txs = client
+            .rooch
+            .get_transactions_by_order(Some(tx_order.saturating_add(1)), Some(2), Some(true))
+            .await?
+            .data;
+        ensure!(
+            txs.len() == 2 && txs[0].transaction.sequence_info.tx_order.0 == tx_order,
+            "transaction not found for tx_order {}",
+            tx_order
+        );
+        let prev_tx = txs.pop().expect("The previous tx must exist");
+        let tx = txs.pop().expect("The tx must exist");
+        let execution_info = tx
+            .execution_info
+            .ok_or_else(|| anyhow!("execution info not found for tx_order {}", tx_order))?;
+        let prev_execution_info = prev_tx
+            .execution_info
+            .ok_or_else(|| anyhow!("execution info not found for tx_order {}", tx_order - 1))?;
+        let tx_hash = execution_info.tx_hash.0;
+
+        // The RPC does not return the size of the root object, it only changes the state root
+        let pre_root = ObjectMeta::root_metadata(prev_execution_info.state_root.0, 0);
+        let resolver = ClientResolver::new(client, pre_root.clone());
+        let gas_parameters = FrameworksGasParameters::load_from_chain(&resolver)?;
+        // MoveOS reads the states from the resolver, the store is never read
+        let (moveos_store, _store_dir) = MoveOSStore::mock_moveos_store()?;
+        let moveos = MoveOS::new(
+            moveos_store,
+            gas_parameters.all_natives(),
+            MoveOSConfig::default(),
+            system_pre_execute_functions(),
+            system_post_execute_functions(),
+        )?;
+
+        let mut moveos_tx = match tx.transaction.data {
+            LedgerTxDataView::L1Block(block) => {
+                let block = L1Block {
+                    chain_id: MultiChainID::new(block.chain_id.0),
+                    block_height: block.block_height.0,
+                    block_hash: block.block_hash.0,
+                };
+                let block_body = self.get_l1_block_body(&block).await?;
+                l1_block_to_verified_tx(pre_root, L1BlockWithBody::new(block, block_body))?
+            }
+            LedgerTxDataView::L1Tx(l1_tx) => l1_tx_to_verified_tx(
+                pre_root,
+                L1Transaction::new(
+                    MultiChainID::new(l1_tx.chain_id.0),
+                    l1_tx.block_hash.0,
+                    l1_tx.txid.0,
+                ),
+            )?,
+            LedgerTxDataView::L2Tx(l2_tx) => ResolverFunctionCaller::new(&moveos, &resolver)
+                .validate_l2_tx(bcs::from_bytes(&l2_tx.raw.0)?)?,
+        };
+        moveos_tx
+            .ctx
+            .add(sequence_info(tx.transaction.sequence_info))?;
+
+        let mut profiler = None;
+        let action = moveos_tx.action.clone();
+        let (output, vm_error_info, _read_set) =
+            moveos.execute_with_resolver(&resolver, moveos_tx, |gas_meter| {
+                let gas_profiler = new_verified_gas_profiler(&action, gas_meter);
+                profiler = Some(gas_profiler.clone());
+                gas_profiler
+            })?;
+        let gas_log = profiler
+            .expect("The gas profiler must be created before execution")
+            .finish();
+
+        let event_hashes = output
+            .events
+            .iter()
+            .map(|event| event.hash())
+            .collect::<Vec<_>>();
+        let event_root = InMemoryAccumulator::from_leaves(event_hashes.as_slice()).root_hash();
+
+        let mut call_trace = vec![];
+        render_call_trace(
+            &gas_log.exec_io.call_graph.to_call_trace(),
+            0,
+            &mut call_trace,
+        );
+        if let Some(path) = self.gas_report {
+            gas_log.generate_html_report(path, format!("Rooch Gas Profiling {:?}", tx_hash))?;
+        }
+
+        let status = KeptVMStatusView::from(output.status);
+        let matched = status == execution_info.status
+            && output.gas_used == execution_info.gas_used.0
+            && event_root == execution_info.event_root.0;
+        Ok(ReplayOutput {
+            tx_order: StrView(tx_order),
+            tx_hash,
+            status,
+            recorded_status: execution_info.status,
+            gas_used: StrView(output.gas_used),
+            recorded_gas_used: execution_info.gas_used,
+            event_root,
+            recorded_event_root: execution_info.event_root.0,
+            matched,
+            vm_error_info,
+            call_trace,
+            events: output.events.into_iter().map(Into::into).collect(),
+            state_diff: output.changeset.into(),
+        })
+    }
+
+    async fn get_l1_block_body(&self, block: &L1Block) -> Result<Vec<u8>> {
+        ensure!(
+            block.chain_id.is_bitcoin(),
+            "Replay the L1 block of chain {} is not supported",
+            block.chain_id
+        );
+        let (Some(btc_rpc_url), Some(btc_rpc_user_name), Some(btc_rpc_password)) = (
+            self.btc_rpc_url.clone(),
+            self.btc_rpc_user_name.clone(),
+            self.btc_rpc_password.clone(),
+        ) else {
+            bail!(
+                "Replay the L1 block {} requires the bitcoin node RPC to get the block body, please set --btc-rpc-url",
+                block.block_height
+            );
+        };
+        let actor_system = ActorSystem::global_system();
+        let bitcoin_client_proxy = build_btc_client_proxy(
+            btc_rpc_url,
+            btc_rpc_user_name,
+            btc_rpc_password,
+            None,
+            &actor_system,
+        )
+        .await?;
+        let block_hash = bitcoin::block::BlockHash::from_slice(&block.block_hash)?;
+        let btc_block = bitcoin_client_proxy.get_block(block_hash).await?;
+        Ok(BitcoinBlock::from(btc_block).encode())
+    }
+}
+
+/// Call the Move functions against the states of the resolver, to validate the transaction as the node does.
+/// The RPC does not return the accumulator info of the transaction,
+/// it is not read by the Move functions.
+fn sequence_info(view: TransactionSequenceInfoView) -> TransactionSequenceInfo {
+    TransactionSequenceInfo::new(
+        view.tx_order.0,
+        view.tx_order_signature.0,
+        AccumulatorInfo::new(view.tx_accumulator_root.0, vec![], 0, 0),
+        view.tx_timestamp.0,
+    )
+}
+
+/// Render the call and its callees as indented lines.
+fn render_call_trace(call: &CallTrace, depth: usize, lines: &mut Vec<String>) {
+    let function = call
+        .function
+        .as_ref()
+        .map(ToString::to_string)
+        .unwrap_or_else(|| "<script>".to_string());
+    let ty_args = if call.ty_args.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "<{}>",
+            call.ty_args
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+    let native = if call.is_native { " [native]" } else { "" };
+    lines.push(format!(
+        "{}{}{}{} gas: {}",
+        "  ".repeat(depth),
+        function,
+        ty_args,
+        native,
+        call.gas_used
+    ));
+    for callee in &call.calls {
+        render_call_trace(callee, depth + 1, lines);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moveos_types::move_types::FunctionId;
+    use moveos_types::state::MoveType;
+    use rooch_types::framework::gas_coin::RGas;
+
+    fn call(function: &str, gas_used: u64, calls: Vec<CallTrace>) -> CallTrace {
+        CallTrace {
+            function: Some(FunctionId::from_str(function).unwrap()),
+            ty_args: vec![],
+            is_native: false,
+            gas_used,
+            calls,
+        }
+    }
+
+    #[test]
+    fn test_render_call_trace() {
+        let mut hash = call("0x1::hash::sha3_256", 2, vec![]);
+        hash.is_native = true;
+        let mut trace = call(
+            "0x3::transfer::transfer_coin",
+            10,
+            vec![call("0x3::coin::withdraw", 5, vec![hash])],
+        );
+        trace.ty_args = vec![RGas::type_tag()];
+        let mut lines = vec![];
+        render_call_trace(&trace, 0, &mut lines);
+        assert_eq!(
+            lines,
+            vec![
+                format!(
+                    "{}<{}> gas: 10",
+                    FunctionId::from_str("0x3::transfer::transfer_coin").unwrap(),
+                    RGas::type_tag()
+                ),
+                format!(
+                    "  {} gas: 5",
+                    FunctionId::from_str("0x3::coin::withdraw").unwrap()
+                ),
+                format!(
+                    "    {} [native] gas: 2",
+                    FunctionId::from_str("0x1::hash::sha3_256").unwrap()
+                ),
+            ]
+        );
+
+        let script = CallTrace {
+            function: None,
+            ty_args: vec![],
+            is_native: false,
+            gas_used: 1,
+            calls: vec![],
+        };
+        let mut lines = vec![];
+        render_call_trace(&script, 1, &mut lines);
+        assert_eq!(lines, vec!["  <script> gas: 1".to_string()]);
+    }
+}
@@ -11,6 +11,7 @@ use rooch_types::{
     address::{ParsedAddress, RoochAddress},
     bitcoin::multisign_account::MultisignAccountModule,
     error::RoochResult,
+    framework::ethereum_validator::EthereumSignType,
     transaction::{
         authenticator::BitcoinAuthenticator, rooch::PartiallySignedRoochTransaction,
         RoochTransaction, RoochTransactionData,
@@ -73,6 +74,18 @@ impl From<SignOutput> for FileOutputData {
     }
 }
 
+/// The authenticator to sign the transaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum SignAuth {
+    /// Sign as the Bitcoin wallet message
+    #[default]
+    Bitcoin,
+    /// Sign as the Ethereum wallet EIP-191 `personal_sign` message
+    Ethereum,
+    /// Sign as the Ethereum wallet EIP-712 typed data
+    EthereumTypedData,
+}
+
 /// Get transactions by order
 #[derive(Debug, clap::Parser)]
 pub struct SignCommand {
@@ -91,6 +104,10 @@ pub struct SignCommand {
     #[clap(long, short = 'o')]
     output: Option<String>,
 
+    /// The authenticator to sign the transaction, the multisign account transaction only supports bitcoin
+    #[clap(long, value_enum, default_value_t = SignAuth::Bitcoin)]
+    auth: SignAuth,
+
     /// Automatically answer 'yes' to all prompts
     #[clap(long = "yes", short = 'y')]
     answer_yes: bool,
@@ -111,6 +128,11 @@ impl SignCommand {
         let sign_input = SignInput::try_from(self.input)?;
         let sender = sign_input.sender();
         let output = if multisign_account_module.is_multisign_account(sender.into())? {
+            if self.auth != SignAuth::Bitcoin {
+                return Err(anyhow::anyhow!(
+                    "The multisign account transaction only supports the bitcoin authenticator"
+                ));
+            }
             let threshold = multisign_account_module.threshold(sender.into())?;
 
             let mut psrt = match sign_input {
@@ -175,7 +197,20 @@ impl SignCommand {
                     ))
                 }
             };
-            SignOutput::SignedRoochTransaction(context.sign_transaction(sender, tx_data)?)
+            let tx = match self.auth {
+                SignAuth::Bitcoin => context.sign_transaction(sender, tx_data)?,
+                SignAuth::Ethereum => context.sign_transaction_ethereum(
+                    sender,
+                    tx_data,
+                    EthereumSignType::PersonalSign,
+                )?,
+                SignAuth::EthereumTypedData => context.sign_transaction_ethereum(
+                    sender,
+                    tx_data,
+                    EthereumSignType::TypedData,
+                )?,
+            };
+            SignOutput::SignedRoochTransaction(tx)
         };
         Ok(output)
     }
@@ -5,7 +5,7 @@ use crate::cli_types::CommandAction;
 use crate::commands::transaction::commands::{
     build::BuildCommand, get_transactions_by_hash::GetTransactionsByHashCommand,
     get_transactions_by_order::GetTransactionsByOrderCommand, query::QueryCommand,
-    sign::SignCommand, submit::SubmitCommand,
+    replay::ReplayCommand, sign::SignCommand, submit::SubmitCommand,
 };
 use async_trait::async_trait;
 use clap::{Parser, Subcommand};
@@ -27,6 +27,7 @@ impl CommandAction<String> for Transaction {
             TransactionCommand::GetTransactionsByOrder(cmd) => cmd.execute_serialized().await,
             TransactionCommand::GetTransactionsByHash(cmd) => cmd.execute_serialized().await,
             TransactionCommand::Query(cmd) => cmd.execute_serialized().await,
+            TransactionCommand::Replay(cmd) => cmd.execute_serialized().await,
             TransactionCommand::Build(cmd) => cmd.execute_serialized().await,
             TransactionCommand::Sign(cmd) => cmd.execute_serialized().await,
             TransactionCommand::Submit(cmd) => cmd.execute_serialized().await,
@@ -40,6 +41,7 @@ pub enum TransactionCommand {
     GetTransactionsByOrder(GetTransactionsByOrderCommand),
     GetTransactionsByHash(GetTransactionsByHashCommand),
     Query(QueryCommand),
+    Replay(ReplayCommand),
     Sign(SignCommand),
     Submit(SubmitCommand),
 }
@@ -79,6 +79,13 @@ Feature: Rooch CLI integration tests
       Then assert: "'{{$.transaction[-1]}}' not_contains error"
       Then cmd: "transaction submit {{$.transaction[-1].path}}"
       Then assert: "{{$.transaction[-1].execution_info.status.type}} == executed"
+      Then cmd: "rpc request --method rooch_traceTransaction --params '["{{$.transaction[-1].execution_info.tx_hash}}"]' --json"
+      Then assert: "{{$.rpc[-1].status.type}} == executed"
+      Then assert: "'{{$.rpc[-1].call_trace.function}}' contains empty::empty"
+      Then cmd: "transaction build --function rooch_framework::empty::empty --json"
+      Then cmd: "rpc request --method rooch_traceDryRun --params '["0x{{$.transaction[-1].content}}"]' --json"
+      Then assert: "{{$.rpc[-1].status.type}} == executed"
+      Then assert: "'{{$.rpc[-1].call_trace.function}}' contains empty::empty"
       Then cmd: "transaction query --sender default --limit 1"
       Then assert: "'{{$.transaction[-1]}}' not_contains error"
       Then cmd: "transaction query --tx-hashes {{$.transaction[-1].data[0].execution_info.tx_hash}}"
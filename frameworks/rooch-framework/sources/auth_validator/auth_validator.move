@@ -38,6 +38,8 @@ module rooch_framework::auth_validator {
     const ErrorValidateSessionIsExpired: u64 = 1012;
     /// The function call is beyond the session's scope
     const ErrorValidateFunctionCallBeyondSessionScope: u64 = 1013;
+    /// The transaction exceeds the session key's limits, such as the gas, transaction count or expiry time
+    const ErrorValidateSessionKeyLimitExceeded: u64 = 1014;
 
     public fun error_validate_sequence_number_too_old(): u64 {
         ErrorValidateSequenceNuberTooOld
@@ -91,6 +93,10 @@ module rooch_framework::auth_validator {
         ErrorValidateFunctionCallBeyondSessionScope
     }
 
+    public fun error_validate_session_key_limit_exceeded(): u64 {
+        ErrorValidateSessionKeyLimitExceeded
+    }
+
     /// The Authentication Validator
     struct AuthValidator has store, copy, drop {
         id: u64,
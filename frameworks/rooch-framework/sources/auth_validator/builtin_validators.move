@@ -6,6 +6,7 @@ module rooch_framework::builtin_validators{
     use rooch_framework::auth_validator_registry;
     use rooch_framework::session_validator;
     use rooch_framework::bitcoin_validator;
+    use rooch_framework::ethereum_validator;
 
     friend rooch_framework::genesis;
 
@@ -29,6 +30,8 @@ module rooch_framework::builtin_validators{
     public fun is_builtin_auth_validator(auth_validator_id: u64): bool {
         auth_validator_id == SESSION_VALIDATOR_ID || 
         auth_validator_id == BITCOIN_VALIDATOR_ID || 
-        auth_validator_id == BITCOIN_MULTISIGN_VALIDATOR_ID
+        auth_validator_id == BITCOIN_MULTISIGN_VALIDATOR_ID ||
+        // The Ethereum validator is registered after the genesis, it is checked by the validator type
+        ethereum_validator::is_ethereum_validator(auth_validator_id)
     }
 }
@@ -0,0 +1,183 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements Ethereum validator with the ECDSA recoverable signature over Secp256k1.
+/// It supports the EIP-191 `personal_sign` message and the EIP-712 typed data of the Rooch transaction.
+module rooch_framework::ethereum_validator {
+
+    use std::vector;
+    use std::string;
+    use moveos_std::bcs;
+    use moveos_std::hash;
+    use moveos_std::hex;
+    use moveos_std::signer;
+    use moveos_std::string_utils;
+    use moveos_std::tx_context;
+    use moveos_std::features;
+    use rooch_framework::chain_id;
+    use rooch_framework::multichain_address::{Self, MultiChainAddress};
+    use rooch_framework::auth_payload::{Self, AuthPayload};
+    use rooch_framework::ecdsa_k1;
+    use rooch_framework::auth_validator;
+    use rooch_framework::auth_validator_registry;
+    use rooch_framework::bitcoin_address;
+    use rooch_framework::ethereum_address::{Self, ETHAddress};
+
+    const ErrorGenesisInitError: u64 = 1;
+
+    /// there defines auth validator id for each auth validator
+    const ETHEREUM_AUTH_VALIDATOR_ID: u64 = 3;
+
+    /// The signature length of the Ethereum wallet, in form (r, s, v)
+    const SIGNATURE_LENGTH: u64 = 65;
+
+    const MessageInfoPrefix: vector<u8> = b"Rooch Transaction:\n";
+    /// The EIP-191 `personal_sign` prefix, followed by the decimal length of the message
+    const EthereumMessagePrefix: vector<u8> = b"\x19Ethereum Signed Message:\n";
+    /// The EIP-712 typed data prefix, the auth payload with this message prefix is validated as EIP-712 typed data
+    const EIP712MessagePrefix: vector<u8> = x"1901";
+    const EIP712DomainType: vector<u8> = b"EIP712Domain(string name,string version,uint256 chainId)";
+    const EIP712DomainName: vector<u8> = b"Rooch";
+    const EIP712DomainVersion: vector<u8> = b"1";
+    const EIP712TransactionType: vector<u8> = b"RoochTransaction(string message)";
+
+    struct EthereumValidator has store, drop {}
+
+    public fun auth_validator_id(): u64 {
+        ETHEREUM_AUTH_VALIDATOR_ID
+    }
+
+    /// Check the auth validator id is the Ethereum validator's, it is false before the Ethereum validator is registered.
+    public fun is_ethereum_validator(validator_id: u64): bool {
+        auth_validator_registry::is_registered<EthereumValidator>() && validator_id == ETHEREUM_AUTH_VALIDATOR_ID
+    }
+
+    /// Init function called by the rooch_nursery genesis and the upgrade.
+    /// The validator is registered by system after the bitcoin_move validators, so the ids of them are not changed.
+    public fun init_for_upgrade() {
+        if (!auth_validator_registry::is_registered<EthereumValidator>()) {
+            let system = signer::module_signer<EthereumValidator>();
+            let id = auth_validator_registry::register_by_system<EthereumValidator>(&system);
+            assert!(id == ETHEREUM_AUTH_VALIDATOR_ID, ErrorGenesisInitError);
+        }
+    }
+
+    /// The EIP-712 domain separator of Rooch on the given chain
+    public fun eip712_domain_separator(chain_id: u64): vector<u8> {
+        let data = hash::keccak256(&EIP712DomainType);
+        vector::append(&mut data, hash::keccak256(&EIP712DomainName));
+        vector::append(&mut data, hash::keccak256(&EIP712DomainVersion));
+        // The bcs encoding of u256 is little-endian, but the EIP-712 encoding is big-endian
+        let chain_id_bytes = bcs::to_bytes(&(chain_id as u256));
+        vector::reverse(&mut chain_id_bytes);
+        vector::append(&mut data, chain_id_bytes);
+        hash::keccak256(&data)
+    }
+
+    fun starts_with(haystack: &vector<u8>, needle: &vector<u8>): bool {
+        let needle_len = vector::length(needle);
+        if (needle_len > vector::length(haystack)) {
+            return false
+        };
+        let i = 0;
+        while (i < needle_len) {
+            if (vector::borrow(haystack, i) != vector::borrow(needle, i)) {
+                return false
+            };
+            i = i + 1;
+        };
+        true
+    }
+
+    /// The signed message, `message_info` appended with the tx hash hex
+    fun signed_message(payload: &AuthPayload, tx_hash: vector<u8>): vector<u8> {
+        let message = auth_payload::message_info(payload);
+        // The signature description must start with Rooch Transaction:\n
+        assert!(starts_with(&message, &MessageInfoPrefix), auth_validator::error_validate_invalid_authenticator());
+        vector::append(&mut message, hex::encode(tx_hash));
+        message
+    }
+
+    /// Encode the EIP-191 `personal_sign` message: `\x19Ethereum Signed Message:\n{len(message)}{message}`
+    fun encode_eip191_message(payload: &AuthPayload, tx_hash: vector<u8>): vector<u8> {
+        let message = signed_message(payload, tx_hash);
+        let full_message = EthereumMessagePrefix;
+        vector::append(&mut full_message, *string::bytes(&string_utils::to_string_u64(vector::length(&message))));
+        assert!(
+            auth_payload::message_prefix(payload) == full_message,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        vector::append(&mut full_message, message);
+        full_message
+    }
+
+    /// Encode the EIP-712 typed data message: `\x19\x01 || domainSeparator || hashStruct(RoochTransaction)`
+    fun encode_eip712_message(payload: &AuthPayload, tx_hash: vector<u8>): vector<u8> {
+        let message = signed_message(payload, tx_hash);
+        let struct_data = hash::keccak256(&EIP712TransactionType);
+        vector::append(&mut struct_data, hash::keccak256(&message));
+        let full_message = EIP712MessagePrefix;
+        vector::append(&mut full_message, eip712_domain_separator(chain_id::chain_id()));
+        vector::append(&mut full_message, hash::keccak256(&struct_data));
+        full_message
+    }
+
+    /// Only validate the authenticator's signature.
+    public fun validate_signature(payload: &AuthPayload, tx_hash: vector<u8>): ETHAddress {
+        let message = if (auth_payload::message_prefix(payload) == EIP712MessagePrefix) {
+            encode_eip712_message(payload, tx_hash)
+        } else {
+            encode_eip191_message(payload, tx_hash)
+        };
+
+        let signature = auth_payload::signature(payload);
+        assert!(
+            vector::length(&signature) == SIGNATURE_LENGTH,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        // The Ethereum wallets use 27 and 28 as the recovery id
+        let v = vector::borrow_mut(&mut signature, SIGNATURE_LENGTH - 1);
+        if (*v == 27 || *v == 28) {
+            *v = *v - 27;
+        };
+
+        let pk = ecdsa_k1::ecrecover(&signature, &message, ecdsa_k1::keccak256());
+        assert!(
+            vector::length(&pk) == ecdsa_k1::public_key_length(),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        // The public key in the payload is used to derive the bitcoin address of the sender
+        assert!(
+            pk == auth_payload::public_key(payload),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+
+        let address = ethereum_address::new(pk);
+        let expected_from_address = b"0x";
+        vector::append(&mut expected_from_address, hex::encode(*ethereum_address::as_bytes(&address)));
+        assert!(
+            expected_from_address == *string::bytes(&string_utils::to_lower_case(&auth_payload::from_address(payload))),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+
+        address
+    }
+
+    public fun validate(authenticator_payload: vector<u8>): MultiChainAddress {
+        features::ensure_testnet_enabled();
+
+        let sender = tx_context::sender();
+        let tx_hash = tx_context::tx_hash();
+        let payload = auth_payload::from_bytes(authenticator_payload);
+        let eth_addr = validate_signature(&payload, tx_hash);
+
+        // Check if the sender is related to the public key, the Rooch address is derived from the taproot address
+        let bitcoin_addr = bitcoin_address::derive_bitcoin_taproot_address_from_pubkey(&auth_payload::public_key(&payload));
+        assert!(
+            sender == bitcoin_address::to_rooch_address(&bitcoin_addr),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+
+        multichain_address::from_eth(eth_addr)
+    }
+}
@@ -11,6 +11,7 @@ module rooch_framework::session_validator {
     use rooch_framework::ed25519;
     use rooch_framework::auth_validator;
     use rooch_framework::session_key;
+    use rooch_framework::webauthn;
 
     friend rooch_framework::transaction_validator;
 
@@ -18,6 +19,8 @@ module rooch_framework::session_validator {
     const SESSION_VALIDATOR_ID: u64 = 0;
 
     const SIGNATURE_SCHEME_ED25519: u8 = 0;
+    /// The WebAuthn(passkey) signature over Secp256r1
+    const SIGNATURE_SCHEME_SECP256R1: u8 = 2;
 
 
     struct SessionValidator has store, drop {}
@@ -61,6 +64,15 @@ module rooch_framework::session_validator {
 
     // validate the signature of the authenticator payload and return auth key
     fun validate_signature(authenticator_payload: &vector<u8>, tx_hash: &vector<u8>) : vector<u8> {
+        assert!(!vector::is_empty(authenticator_payload), auth_validator::error_validate_invalid_authenticator());
+        let scheme = *vector::borrow(authenticator_payload, 0);
+        if (scheme == SIGNATURE_SCHEME_SECP256R1) {
+            // The payload after the scheme is the WebAuthn payload
+            let webauthn_payload = *authenticator_payload;
+            vector::remove(&mut webauthn_payload, 0);
+            let public_key = webauthn::validate_signature(webauthn_payload, tx_hash);
+            return public_key_to_authentication_key(SIGNATURE_SCHEME_SECP256R1, public_key)
+        };
         let (signature, public_key) = validate_authenticator_payload(authenticator_payload);
         assert!(
             ed25519::verify(
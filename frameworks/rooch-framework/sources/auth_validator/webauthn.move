@@ -0,0 +1,148 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the WebAuthn(passkey) signature verification over Secp256r1.
+/// The passkey is used as a session key, the session validator dispatches the payload with the Secp256r1 scheme to this module.
+module rooch_framework::webauthn {
+
+    use std::vector;
+    use moveos_std::bcs;
+    use moveos_std::base64;
+    use moveos_std::hash;
+    use rooch_framework::ecdsa_r1;
+    use rooch_framework::auth_validator;
+
+    friend rooch_framework::session_validator;
+
+    /// The minimum length of the authenticator data: rpIdHash(32) + flags(1) + signCount(4)
+    const AUTHENTICATOR_DATA_MIN_LENGTH: u64 = 37;
+    const AUTHENTICATOR_DATA_FLAGS_INDEX: u64 = 32;
+    /// The User Present flag of the authenticator data
+    const FLAG_USER_PRESENT: u8 = 0x01;
+
+    /// The clientDataJSON must start with the type and the challenge, see the limited verification algorithm of WebAuthn
+    const CLIENT_DATA_PREFIX: vector<u8> = b"{\"type\":\"webauthn.get\",\"challenge\":\"";
+
+    #[data_struct]
+    struct WebauthnAuthPayload has copy, store, drop {
+        /// The 64-bytes (r, s) signature, converted from the DER signature of the authenticator
+        signature: vector<u8>,
+        /// The 33-bytes compressed Secp256r1 public key of the passkey
+        public_key: vector<u8>,
+        /// The raw authenticatorData returned by the authenticator
+        authenticator_data: vector<u8>,
+        /// The raw clientDataJSON returned by the browser, the challenge is the base64url encoded tx hash
+        client_data_json: vector<u8>,
+    }
+
+    public fun from_bytes(bytes: vector<u8>): WebauthnAuthPayload {
+        bcs::from_bytes<WebauthnAuthPayload>(bytes)
+    }
+
+    public fun public_key(payload: &WebauthnAuthPayload): vector<u8> {
+        payload.public_key
+    }
+
+    /// Encode the bytes with base64url without padding, which is the challenge format of the clientDataJSON
+    public fun base64url_encode(input: &vector<u8>): vector<u8> {
+        let encoded = base64::encode(input);
+        let result = vector::empty<u8>();
+        let i = 0;
+        let len = vector::length(&encoded);
+        while (i < len) {
+            let c = *vector::borrow(&encoded, i);
+            if (c == 43) {
+                // '+' -> '-'
+                vector::push_back(&mut result, 45);
+            } else if (c == 47) {
+                // '/' -> '_'
+                vector::push_back(&mut result, 95);
+            } else if (c != 61) {
+                // skip the '=' padding
+                vector::push_back(&mut result, c);
+            };
+            i = i + 1;
+        };
+        result
+    }
+
+    /// Check the clientDataJSON is for `webauthn.get` and the challenge is the tx hash
+    fun verify_client_data(client_data_json: &vector<u8>, tx_hash: &vector<u8>): bool {
+        let expected = CLIENT_DATA_PREFIX;
+        vector::append(&mut expected, base64url_encode(tx_hash));
+        // the closing quote of the challenge
+        vector::push_back(&mut expected, 34);
+
+        let expected_len = vector::length(&expected);
+        if (vector::length(client_data_json) < expected_len) {
+            return false
+        };
+        let i = 0;
+        while (i < expected_len) {
+            if (vector::borrow(client_data_json, i) != vector::borrow(&expected, i)) {
+                return false
+            };
+            i = i + 1;
+        };
+        true
+    }
+
+    /// Validate the WebAuthn payload against the tx hash, return the public key of the passkey
+    public(friend) fun validate_signature(payload_bytes: vector<u8>, tx_hash: &vector<u8>): vector<u8> {
+        let payload = from_bytes(payload_bytes);
+        assert!(
+            vector::length(&payload.authenticator_data) >= AUTHENTICATOR_DATA_MIN_LENGTH,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        let flags = *vector::borrow(&payload.authenticator_data, AUTHENTICATOR_DATA_FLAGS_INDEX);
+        assert!(
+            flags & FLAG_USER_PRESENT == FLAG_USER_PRESENT,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        assert!(
+            verify_client_data(&payload.client_data_json, tx_hash),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+
+        // The authenticator signs `authenticatorData || sha256(clientDataJSON)` with sha256
+        let message = payload.authenticator_data;
+        vector::append(&mut message, hash::sha2_256(payload.client_data_json));
+        assert!(
+            vector::length(&payload.signature) == ecdsa_r1::signature_length() &&
+                vector::length(&payload.public_key) == ecdsa_r1::public_key_length(),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        assert!(
+            ecdsa_r1::verify(
+                &payload.signature,
+                &payload.public_key,
+                &message,
+                ecdsa_r1::sha256()
+            ),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        payload.public_key
+    }
+
+    #[test]
+    fun test_base64url_encode() {
+        let tx_hash = x"5415b18de0b880bb2af5dfe1ee27fd19ae8a0c99b5328e8b4b44f4c86cc7176a";
+        assert!(base64url_encode(&tx_hash) == b"VBWxjeC4gLsq9d_h7if9Ga6KDJm1Mo6LS0T0yGzHF2o", 1);
+    }
+
+    #[test]
+    fun test_validate_signature_success() {
+        let tx_hash = x"5415b18de0b880bb2af5dfe1ee27fd19ae8a0c99b5328e8b4b44f4c86cc7176a";
+        let payload_bytes = x"40cace06020b2e1c0ccc2ac5dc8567880918bb1f03ebeec93707321a33fb943d9319df0c22f3b82bbd93260a0ad50940f04a5d242d8d3ee8ea4a28d0783587320b2102471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c25701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d050000000086017b2274797065223a22776562617574686e2e676574222c226368616c6c656e6765223a22564257786a654334674c737139645f68376966394761364b444a6d314d6f364c5330543079477a4846326f222c226f726967696e223a2268747470733a2f2f726f6f63682e6e6574776f726b222c2263726f73734f726967696e223a66616c73657d";
+        let public_key = validate_signature(payload_bytes, &tx_hash);
+        assert!(public_key == x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c", 1);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 1010)]
+    fun test_validate_signature_fail_with_other_tx_hash() {
+        let tx_hash = x"5515b18de0b880bb2af5dfe1ee27fd19ae8a0c99b5328e8b4b44f4c86cc7176a";
+        let payload_bytes = x"40cace06020b2e1c0ccc2ac5dc8567880918bb1f03ebeec93707321a33fb943d9319df0c22f3b82bbd93260a0ad50940f04a5d242d8d3ee8ea4a28d0783587320b2102471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c25701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d050000000086017b2274797065223a22776562617574686e2e676574222c226368616c6c656e6765223a22564257786a654334674c737139645f68376966394761364b444a6d314d6f364c5330543079477a4846326f222c226f726967696e223a2268747470733a2f2f726f6f63682e6e6574776f726b222c2263726f73734f726967696e223a66616c73657d";
+        validate_signature(payload_bytes, &tx_hash);
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+module rooch_framework::ecdsa_r1 {
+
+    /// constant codes
+    const ECDSA_R1_COMPRESSED_PUBKEY_LENGTH: u64 = 33;
+    const ECDSA_R1_SIG_LENGTH: u64 = 64;
+
+    /// Hash function name that are valid for verify.
+    const KECCAK256: u8 = 0;
+    const SHA256: u8 = 1;
+
+    /// Error if the signature is invalid.
+    const ErrorInvalidSignature: u64 = 1;
+
+    /// Error if the public key is invalid.
+    const ErrorInvalidPubKey: u64 = 2;
+
+    /// Error if the gas parameters of the native function are not in the on-chain gas schedule.
+    const ErrorGasParametersNotSet: u64 = 3;
+
+    /// built-in functions
+    public fun public_key_length(): u64 {
+        ECDSA_R1_COMPRESSED_PUBKEY_LENGTH
+    }
+
+    public fun signature_length(): u64 {
+        ECDSA_R1_SIG_LENGTH
+    }
+
+    public fun keccak256(): u8 {
+        KECCAK256
+    }
+
+    public fun sha256(): u8 {
+        SHA256
+    }
+
+    /// @param signature: A 64-bytes signature in form (r, s) that is signed using
+    /// Ecdsa over Secp256r1, the s must be in the lower half of the curve order.
+    /// @param public_key: A 33-bytes compressed public key that is used to sign messages.
+    /// @param msg: The message that the signature is signed against.
+    /// @param hash: The hash function used to hash the message when signing.
+    ///
+    /// If the signature is valid to the pubkey and hashed message, return true. Else false.
+    native public fun verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        msg: &vector<u8>,
+        hash: u8
+    ): bool;
+
+    #[test]
+    fun test_verify_success() {
+        let msg = x"00010203";
+        let pubkey = x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c";
+        let sig = x"e0b759e05816d0e59b5bedc36254cb8c3ae28cd2df69d32e9ac8e603d41a37430e395d03dd4bc8698979b65be861aeff8a637023b4ad5a6a46a36d2434afea6c";
+        let result = verify(&sig, &pubkey, &msg, SHA256);
+        assert!(result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_wrong_msg() {
+        let msg = x"00010204";
+        let pubkey = x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c";
+        let sig = x"e0b759e05816d0e59b5bedc36254cb8c3ae28cd2df69d32e9ac8e603d41a37430e395d03dd4bc8698979b65be861aeff8a637023b4ad5a6a46a36d2434afea6c";
+        let result = verify(&sig, &pubkey, &msg, SHA256);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidSignature)]
+    fun test_verify_fails_invalid_sig() {
+        let msg = x"00010203";
+        let pubkey = x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c";
+        let sig = x"";
+        verify(&sig, &pubkey, &msg, SHA256);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidPubKey)]
+    fun test_verify_fails_invalid_pubkey() {
+        let msg = x"00010203";
+        let pubkey = x"";
+        let sig = x"e0b759e05816d0e59b5bedc36254cb8c3ae28cd2df69d32e9ac8e603d41a37430e395d03dd4bc8698979b65be861aeff8a637023b4ad5a6a46a36d2434afea6c";
+        verify(&sig, &pubkey, &msg, SHA256);
+    }
+}
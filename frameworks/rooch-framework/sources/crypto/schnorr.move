@@ -0,0 +1,111 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// BIP-340 Schnorr signature verification over Secp256k1, used by the Bitcoin Taproot.
+module rooch_framework::schnorr {
+
+    /// constant codes
+    const SCHNORR_XONLY_PUBKEY_LENGTH: u64 = 32;
+    const SCHNORR_SIG_LENGTH: u64 = 64;
+    const SCHNORR_MSG_LENGTH: u64 = 32;
+
+    /// Error if the signature is invalid.
+    const ErrorInvalidSignature: u64 = 1;
+
+    /// Error if the public key is invalid.
+    const ErrorInvalidPubKey: u64 = 2;
+
+    /// Error if the message is not 32 bytes.
+    const ErrorInvalidMessage: u64 = 3;
+
+    /// Error if the gas parameters of the native function are not in the on-chain gas schedule.
+    const ErrorGasParametersNotSet: u64 = 4;
+
+    /// built-in functions
+    public fun public_key_length(): u64 {
+        SCHNORR_XONLY_PUBKEY_LENGTH
+    }
+
+    public fun signature_length(): u64 {
+        SCHNORR_SIG_LENGTH
+    }
+
+    public fun message_length(): u64 {
+        SCHNORR_MSG_LENGTH
+    }
+
+    /// @param signature: A 64-bytes BIP-340 Schnorr signature.
+    /// @param public_key: A 32-bytes x-only public key.
+    /// @param msg: The 32-bytes message that the signature is signed against, usually a tagged hash.
+    ///
+    /// If the signature is valid to the pubkey and message, return true. Else false.
+    native public fun verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        msg: &vector<u8>
+    ): bool;
+
+    /// The BIP-340 tagged hash: `sha256(sha256(tag) || sha256(tag) || msg)`.
+    /// @param tag: The tag of the hash, such as `BIP0340/challenge` or `TapLeaf`.
+    /// @param msg: The message to hash.
+    native public fun tagged_hash(tag: &vector<u8>, msg: &vector<u8>): vector<u8>;
+
+    #[test]
+    fun test_verify_success() {
+        let msg = x"0000000000000000000000000000000000000000000000000000000000000000";
+        let pubkey = x"f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+        let sig = x"e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(result, 0);
+
+        let msg = x"243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89";
+        let pubkey = x"dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+        let sig = x"6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(result, 1);
+    }
+
+    #[test]
+    fun test_verify_fails_wrong_msg() {
+        let msg = x"0000000000000000000000000000000000000000000000000000000000000001";
+        let pubkey = x"f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+        let sig = x"e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidSignature)]
+    fun test_verify_fails_invalid_sig() {
+        let msg = x"0000000000000000000000000000000000000000000000000000000000000000";
+        let pubkey = x"f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+        let sig = x"";
+        verify(&sig, &pubkey, &msg);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidPubKey)]
+    fun test_verify_fails_invalid_pubkey() {
+        let msg = x"0000000000000000000000000000000000000000000000000000000000000000";
+        let pubkey = x"02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+        let sig = x"e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0";
+        verify(&sig, &pubkey, &msg);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidMessage)]
+    fun test_verify_fails_invalid_msg() {
+        let msg = x"00010203";
+        let pubkey = x"f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+        let sig = x"e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0";
+        verify(&sig, &pubkey, &msg);
+    }
+
+    #[test]
+    fun test_tagged_hash() {
+        let hash = tagged_hash(&b"TapLeaf", &b"rooch");
+        assert!(hash == x"9cade3e39820f4ede144aad0dac63bb27f221eb23faf232a2f0d6307997462ec", 0);
+        let hash = tagged_hash(&b"BIP0340/challenge", &b"");
+        assert!(hash == x"c216d352f5818b7b4beacd4ae0a26fe888080823d2a598856661bcd54f1b3713", 1);
+    }
+}
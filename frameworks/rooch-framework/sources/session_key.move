@@ -5,16 +5,20 @@ module rooch_framework::session_key {
     use std::vector;
     use std::option::{Self, Option};
     use std::signer;
+    use std::string::String;
     use moveos_std::object::ObjectID;
     use moveos_std::account;
     use moveos_std::tx_context; 
     use moveos_std::table::{Self, Table};
     use moveos_std::tx_meta::{Self, FunctionCallMeta};
+    use moveos_std::simple_map::{Self, SimpleMap};
+    use moveos_std::type_info;
     use rooch_framework::auth_validator;
     use moveos_std::timestamp;
 
     friend rooch_framework::transaction_validator;
     friend rooch_framework::session_validator;
+    friend rooch_framework::account_coin_store;
 
     const MAX_INACTIVE_INTERVAL: u64 = 3600 * 24 * 30; // 30 days
 
@@ -28,6 +32,12 @@ module rooch_framework::session_key {
     const ErrorSessionScopePartLengthNotMatch: u64 = 4;
     /// The max inactive interval is invalid
     const ErrorInvalidMaxInactiveInterval: u64 = 5;
+    /// The coin amount transferred by the session key exceeds the limit
+    const ErrorSessionKeyCoinLimitExceeded: u64 = 6;
+    /// The session key limits can not be changed by the session key
+    const ErrorSessionKeyLimitsPermissionDenied: u64 = 7;
+    /// The length of the coin types and the max amounts of the coin limits is not match
+    const ErrorCoinLimitsLengthNotMatch: u64 = 8;
 
     /// The session's scope
     struct SessionScope has store,copy,drop {
@@ -62,6 +72,36 @@ module rooch_framework::session_key {
         keys: Table<vector<u8>, SessionKey>,
     }
 
+    /// The coin amount limit of the session key
+    struct CoinLimit has store,copy,drop {
+        /// The max amount of the coin can be transferred by the session key
+        max_amount: u256,
+        /// The amount of the coin transferred by the session key
+        spent_amount: u256,
+    }
+
+    /// The spending limits and call budgets of the session key, the limit with zero value means no limit.
+    struct SessionKeyLimits has store,copy,drop {
+        /// The max gas(in RGas) can be spent by the session key
+        max_gas: u256,
+        /// The gas(in RGas) spent by the session key
+        gas_used: u256,
+        /// The max number of transactions can be sent by the session key
+        max_tx_count: u64,
+        /// The number of transactions sent by the session key
+        tx_count: u64,
+        /// The absolute expiry time of the session key, in seconds
+        expire_time: u64,
+        /// The coin amount limits, the key is the coin type name.
+        /// The coin types not in the limits are not limited
+        coin_limits: SimpleMap<String, CoinLimit>,
+    }
+
+    /// The limits of the session keys of an account, the key is the session key's authentication key
+    struct SessionKeysLimits has key {
+        limits: Table<vector<u8>, SessionKeyLimits>,
+    }
+
     public fun new_session_scope(module_address: address, module_name: std::string::String, function_name: std::string::String) : SessionScope {
         SessionScope {
             module_address: module_address,
@@ -85,7 +125,16 @@ module rooch_framework::session_key {
         };
 
         let session_key = option::extract(&mut session_key_option);
-        is_expired(&session_key)
+        is_expired(&session_key) || is_limits_expired(account_address, authentication_key)
+    }
+
+    fun is_limits_expired(account_address: address, authentication_key: vector<u8>) : bool {
+        let limits_option = get_session_key_limits(account_address, authentication_key);
+        if (option::is_none(&limits_option)){
+            return false
+        };
+        let limits = option::extract(&mut limits_option);
+        limits.expire_time > 0 && limits.expire_time < timestamp::now_seconds()
     }
 
     public fun has_session_key(account_address: address) : bool {
@@ -109,6 +158,49 @@ module rooch_framework::session_key {
         }
     }
 
+    /// Get the limits of the session key, returns none if the session key has no limits
+    public fun get_session_key_limits(account_address: address, authentication_key: vector<u8>) : Option<SessionKeyLimits> {
+        if (!account::exists_resource<SessionKeysLimits>(account_address)){
+            return option::none()
+        };
+        let session_keys_limits = account::borrow_resource<SessionKeysLimits>(account_address);
+        if (!table::contains(&session_keys_limits.limits, authentication_key)){
+            option::none()
+        }else{
+            option::some(*table::borrow(&session_keys_limits.limits, authentication_key))
+        }
+    }
+
+    public fun limits_max_gas(limits: &SessionKeyLimits) : u256 {
+        limits.max_gas
+    }
+
+    public fun limits_gas_used(limits: &SessionKeyLimits) : u256 {
+        limits.gas_used
+    }
+
+    public fun limits_max_tx_count(limits: &SessionKeyLimits) : u64 {
+        limits.max_tx_count
+    }
+
+    public fun limits_tx_count(limits: &SessionKeyLimits) : u64 {
+        limits.tx_count
+    }
+
+    public fun limits_expire_time(limits: &SessionKeyLimits) : u64 {
+        limits.expire_time
+    }
+
+    /// Get the remaining amount of `CoinType` can be transferred by the session key, returns none if the coin type is not limited
+    public fun limits_coin_remaining<CoinType>(limits: &SessionKeyLimits) : Option<u256> {
+        let coin_type = type_info::type_name<CoinType>();
+        if (!simple_map::contains_key(&limits.coin_limits, &coin_type)){
+            return option::none()
+        };
+        let coin_limit = simple_map::borrow(&limits.coin_limits, &coin_type);
+        option::some(coin_limit.max_amount - coin_limit.spent_amount)
+    }
+
     public fun create_session_key(
         sender: &signer,
         app_name: std::string::String,
@@ -158,6 +250,166 @@ module rooch_framework::session_key {
         }), max_inactive_interval);
     }
 
+    /// Set the gas, transaction count and expiry limits of the session key, the zero value means no limit.
+    /// The coin limits are kept, and the used gas and transaction count are not reset.
+    public fun set_session_key_limits(
+        sender: &signer,
+        authentication_key: vector<u8>,
+        max_gas: u256,
+        max_tx_count: u64,
+        expire_time: u64) {
+        let limits = borrow_mut_or_create_limits(sender, authentication_key);
+        limits.max_gas = max_gas;
+        limits.max_tx_count = max_tx_count;
+        limits.expire_time = expire_time;
+    }
+
+    /// Set the max amount of `CoinType` can be transferred by the session key, the transferred amount is not reset.
+    public fun set_session_key_coin_limit<CoinType: key>(
+        sender: &signer,
+        authentication_key: vector<u8>,
+        max_amount: u256) {
+        let limits = borrow_mut_or_create_limits(sender, authentication_key);
+        set_coin_limit(limits, type_info::type_name<CoinType>(), max_amount);
+    }
+
+    fun set_coin_limit(limits: &mut SessionKeyLimits, coin_type: String, max_amount: u256) {
+        if (simple_map::contains_key(&limits.coin_limits, &coin_type)){
+            let coin_limit = simple_map::borrow_mut(&mut limits.coin_limits, &coin_type);
+            coin_limit.max_amount = max_amount;
+        }else{
+            simple_map::add(&mut limits.coin_limits, coin_type, CoinLimit{
+                max_amount,
+                spent_amount: 0,
+            });
+        };
+    }
+
+    public entry fun set_session_key_limits_entry(
+        sender: &signer,
+        authentication_key: vector<u8>,
+        max_gas: u256,
+        max_tx_count: u64,
+        expire_time: u64) {
+        set_session_key_limits(sender, authentication_key, max_gas, max_tx_count, expire_time);
+    }
+
+    public entry fun set_session_key_coin_limit_entry<CoinType: key>(
+        sender: &signer,
+        authentication_key: vector<u8>,
+        max_amount: u256) {
+        set_session_key_coin_limit<CoinType>(sender, authentication_key, max_amount);
+    }
+
+    /// Create a session key with multi scopes and all the limits in one transaction, so the session key is never usable without the limits.
+    /// The `coin_types` are the type names of the limited coins, the same as `type_info::type_name`,
+    /// such as `0000000000000000000000000000000000000000000000000000000000000003::gas_coin::RGas`.
+    public entry fun create_session_key_with_limits_entry(
+        sender: &signer,
+        app_name: std::string::String,
+        app_url: std::string::String,
+        authentication_key: vector<u8>,
+        scope_module_addresses: vector<address>,
+        scope_module_names: vector<std::string::String>,
+        scope_function_names: vector<std::string::String>,
+        max_inactive_interval: u64,
+        max_gas: u256,
+        max_tx_count: u64,
+        expire_time: u64,
+        coin_types: vector<String>,
+        coin_max_amounts: vector<u256>) {
+        assert!(vector::length(&coin_types) == vector::length(&coin_max_amounts), ErrorCoinLimitsLengthNotMatch);
+        create_session_key_with_multi_scope_entry(sender, app_name, app_url, authentication_key, scope_module_addresses, scope_module_names, scope_function_names, max_inactive_interval);
+        set_session_key_limits(sender, authentication_key, max_gas, max_tx_count, expire_time);
+        let limits = borrow_mut_or_create_limits(sender, authentication_key);
+        let idx = 0;
+        while(idx < vector::length(&coin_types)){
+            set_coin_limit(limits, *vector::borrow(&coin_types, idx), *vector::borrow(&coin_max_amounts, idx));
+            idx = idx + 1;
+        };
+    }
+
+    fun borrow_mut_or_create_limits(sender: &signer, authentication_key: vector<u8>) : &mut SessionKeyLimits {
+        //Can not change the limits by the session key
+        assert!(!auth_validator::is_validate_via_session_key(), ErrorSessionKeyLimitsPermissionDenied);
+        let sender_addr = signer::address_of(sender);
+        assert!(exists_session_key(sender_addr, authentication_key), ErrorSessionKeyIsInvalid);
+        if (!account::exists_resource<SessionKeysLimits>(sender_addr)){
+            let limits = table::new<vector<u8>, SessionKeyLimits>();
+            account::move_resource_to<SessionKeysLimits>(sender, SessionKeysLimits{limits});
+        };
+        let session_keys_limits = account::borrow_mut_resource<SessionKeysLimits>(sender_addr);
+        if (!table::contains(&session_keys_limits.limits, authentication_key)){
+            table::add(&mut session_keys_limits.limits, authentication_key, SessionKeyLimits{
+                max_gas: 0,
+                gas_used: 0,
+                max_tx_count: 0,
+                tx_count: 0,
+                expire_time: 0,
+                coin_limits: simple_map::new(),
+            });
+        };
+        table::borrow_mut(&mut session_keys_limits.limits, authentication_key)
+    }
+
+    /// Check the current tx does not exceed the limits of the session key, `gas` is the max gas(in RGas) the tx can spend
+    public(friend) fun check_session_key_limits(account_address: address, authentication_key: vector<u8>, gas: u256) : bool {
+        let limits_option = get_session_key_limits(account_address, authentication_key);
+        if (option::is_none(&limits_option)){
+            return true
+        };
+        let limits = option::extract(&mut limits_option);
+        if (limits.expire_time > 0 && limits.expire_time < timestamp::now_seconds()){
+            return false
+        };
+        if (limits.max_tx_count > 0 && limits.tx_count >= limits.max_tx_count){
+            return false
+        };
+        if (limits.max_gas > 0 && limits.gas_used + gas > limits.max_gas){
+            return false
+        };
+        true
+    }
+
+    /// Record the transaction and the gas used by the session key
+    public(friend) fun record_session_key_usage(authentication_key: vector<u8>, gas_used: u256) {
+        let sender_addr = tx_context::sender();
+        if (!account::exists_resource<SessionKeysLimits>(sender_addr)){
+            return
+        };
+        let session_keys_limits = account::borrow_mut_resource<SessionKeysLimits>(sender_addr);
+        if (!table::contains(&session_keys_limits.limits, authentication_key)){
+            return
+        };
+        let limits = table::borrow_mut(&mut session_keys_limits.limits, authentication_key);
+        limits.tx_count = limits.tx_count + 1;
+        limits.gas_used = limits.gas_used + gas_used;
+    }
+
+    /// Record the coin withdrawn from the `account_address`, abort if the coin limit of the session key is exceeded.
+    /// Only the withdrawal from the sender in the transaction validated via the session key is recorded.
+    public(friend) fun record_coin_spent<CoinType>(account_address: address, amount: u256) {
+        if (!auth_validator::is_validate_via_session_key() || account_address != tx_context::sender()){
+            return
+        };
+        if (!account::exists_resource<SessionKeysLimits>(account_address)){
+            return
+        };
+        let authentication_key = auth_validator::get_session_key_from_ctx();
+        let session_keys_limits = account::borrow_mut_resource<SessionKeysLimits>(account_address);
+        if (!table::contains(&session_keys_limits.limits, authentication_key)){
+            return
+        };
+        let limits = table::borrow_mut(&mut session_keys_limits.limits, authentication_key);
+        let coin_type = type_info::type_name<CoinType>();
+        if (!simple_map::contains_key(&limits.coin_limits, &coin_type)){
+            return
+        };
+        let coin_limit = simple_map::borrow_mut(&mut limits.coin_limits, &coin_type);
+        assert!(coin_limit.spent_amount + amount <= coin_limit.max_amount, ErrorSessionKeyCoinLimitExceeded);
+        coin_limit.spent_amount = coin_limit.spent_amount + amount;
+    }
+
     public entry fun create_session_key_with_multi_scope_entry(
         sender: &signer,
         app_name: std::string::String,
@@ -254,12 +506,28 @@ module rooch_framework::session_key {
         active_session_key(authentication_key);
     }
 
+    #[test_only]
+    public fun check_session_key_limits_for_test(account_address: address, authentication_key: vector<u8>, gas: u256) : bool {
+        check_session_key_limits(account_address, authentication_key, gas)
+    }
+
+    #[test_only]
+    public fun record_session_key_usage_for_test(authentication_key: vector<u8>, gas_used: u256) {
+        record_session_key_usage(authentication_key, gas_used);
+    }
+
     public fun remove_session_key(sender: &signer, authentication_key: vector<u8>) {
         let sender_addr = signer::address_of(sender);
         assert!(account::exists_resource<SessionKeys>(sender_addr), ErrorSessionKeyIsInvalid);
         let session_keys = account::borrow_mut_resource<SessionKeys>(sender_addr);
         assert!(table::contains(&session_keys.keys, authentication_key), ErrorSessionKeyIsInvalid);
         table::remove(&mut session_keys.keys, authentication_key);
+        if (account::exists_resource<SessionKeysLimits>(sender_addr)){
+            let session_keys_limits = account::borrow_mut_resource<SessionKeysLimits>(sender_addr);
+            if (table::contains(&session_keys_limits.limits, authentication_key)){
+                table::remove(&mut session_keys_limits.limits, authentication_key);
+            };
+        };
     }
 
     public entry fun remove_session_key_entry(sender: &signer, authentication_key: vector<u8>) {
@@ -0,0 +1,190 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// State Commitment Chain(SCC) is the on-chain chain of blocks proposed by the proposer.
+/// Each block commits the tx accumulator root and the state root after the last transaction of the block,
+/// so that third parties could verify the transactions and states against it.
+module rooch_framework::state_commitment_chain {
+
+    use std::option::{Self, Option};
+    use moveos_std::object;
+    use moveos_std::event;
+    use moveos_std::signer;
+    use moveos_std::timestamp;
+    use rooch_framework::onchain_config;
+
+    const ErrorNotProposer: u64 = 1;
+    const ErrorInvalidBlockNumber: u64 = 2;
+    const ErrorInvalidPrevTxAccumulatorRoot: u64 = 3;
+    const ErrorBlockNotFound: u64 = 4;
+
+    /// The block committed to the SCC, it's the same as the `Block` in Rust
+    struct Block has store, copy, drop {
+        block_number: u128,
+        /// How many transactions in the block
+        batch_size: u64,
+        /// The hash of the batch submitted to DA
+        batch_hash: vector<u8>,
+        prev_tx_accumulator_root: vector<u8>,
+        tx_accumulator_root: vector<u8>,
+        /// The state root after the last transaction of the block
+        state_root: vector<u8>,
+        /// The timestamp in milliseconds when the block is proposed
+        proposed_at: u64,
+    }
+
+    /// The StateCommitmentChain in the global storage, blocks are stored as fields of the object.
+    struct StateCommitmentChain has key {
+        /// The proposer allowed to propose blocks, if none, the sequencer is the proposer.
+        proposer: Option<address>,
+        last_block_number: Option<u128>,
+        last_tx_accumulator_root: vector<u8>,
+    }
+
+    struct BlockProposedEvent has copy, drop {
+        block_number: u128,
+        batch_size: u64,
+        batch_hash: vector<u8>,
+        tx_accumulator_root: vector<u8>,
+        state_root: vector<u8>,
+        proposer: address,
+    }
+
+    fun ensure_scc() {
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        if (!object::exists_object_with_type<StateCommitmentChain>(object_id)) {
+            let scc = StateCommitmentChain {
+                proposer: option::none(),
+                last_block_number: option::none(),
+                last_tx_accumulator_root: vector[],
+            };
+            let obj = object::new_named_object(scc);
+            object::transfer_extend(obj, @rooch_framework);
+        }
+    }
+
+    fun borrow_scc(): &object::Object<StateCommitmentChain> {
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        object::borrow_object<StateCommitmentChain>(object_id)
+    }
+
+    fun borrow_mut_scc(): &mut object::Object<StateCommitmentChain> {
+        ensure_scc();
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        object::borrow_mut_object_extend<StateCommitmentChain>(object_id)
+    }
+
+    fun is_initialized(): bool {
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        object::exists_object_with_type<StateCommitmentChain>(object_id)
+    }
+
+    /// The address allowed to propose blocks
+    public fun proposer(): address {
+        if (is_initialized()) {
+            let scc = object::borrow(borrow_scc());
+            if (option::is_some(&scc.proposer)) {
+                return *option::borrow(&scc.proposer)
+            };
+        };
+        onchain_config::sequencer()
+    }
+
+    /// The number of the last proposed block, none if no block has been proposed
+    public fun last_block_number(): Option<u128> {
+        if (!is_initialized()) {
+            return option::none()
+        };
+        object::borrow(borrow_scc()).last_block_number
+    }
+
+    public fun contains_block(block_number: u128): bool {
+        is_initialized() && object::contains_field(borrow_scc(), block_number)
+    }
+
+    public fun get_block(block_number: u128): Block {
+        assert!(contains_block(block_number), ErrorBlockNotFound);
+        *object::borrow_field(borrow_scc(), block_number)
+    }
+
+    /// Set the proposer, only the admin can do it
+    public entry fun set_proposer(account: &signer, proposer: address) {
+        onchain_config::ensure_admin(account);
+        let scc = object::borrow_mut(borrow_mut_scc());
+        scc.proposer = option::some(proposer);
+    }
+
+    /// Propose a new block, blocks must be proposed in order and linked by the tx accumulator root.
+    /// The block number may skip the blocks which only contain the proposer's own `propose_block` transactions,
+    /// the accumulator root of the new block still covers the transactions of the skipped blocks.
+    public entry fun propose_block(
+        account: &signer,
+        block_number: u128,
+        batch_size: u64,
+        batch_hash: vector<u8>,
+        prev_tx_accumulator_root: vector<u8>,
+        tx_accumulator_root: vector<u8>,
+        state_root: vector<u8>,
+    ) {
+        let sender = signer::address_of(account);
+        assert!(sender == proposer(), ErrorNotProposer);
+
+        let scc_obj = borrow_mut_scc();
+        let scc = object::borrow_mut(scc_obj);
+        // the first block could be any block, because the proposer may start proposing from an offset
+        if (option::is_some(&scc.last_block_number)) {
+            assert!(block_number > *option::borrow(&scc.last_block_number), ErrorInvalidBlockNumber);
+            assert!(prev_tx_accumulator_root == scc.last_tx_accumulator_root, ErrorInvalidPrevTxAccumulatorRoot);
+        };
+
+        scc.last_block_number = option::some(block_number);
+        scc.last_tx_accumulator_root = tx_accumulator_root;
+        let block = Block {
+            block_number,
+            batch_size,
+            batch_hash,
+            prev_tx_accumulator_root,
+            tx_accumulator_root,
+            state_root,
+            proposed_at: timestamp::now_milliseconds(),
+        };
+        object::add_field(scc_obj, block_number, block);
+
+        event::emit(BlockProposedEvent {
+            block_number,
+            batch_size,
+            batch_hash,
+            tx_accumulator_root,
+            state_root,
+            proposer: sender,
+        });
+    }
+
+    public fun block_number(self: &Block): u128 {
+        self.block_number
+    }
+
+    public fun batch_size(self: &Block): u64 {
+        self.batch_size
+    }
+
+    public fun batch_hash(self: &Block): vector<u8> {
+        self.batch_hash
+    }
+
+    public fun prev_tx_accumulator_root(self: &Block): vector<u8> {
+        self.prev_tx_accumulator_root
+    }
+
+    public fun tx_accumulator_root(self: &Block): vector<u8> {
+        self.tx_accumulator_root
+    }
+
+    public fun state_root(self: &Block): vector<u8> {
+        self.state_root
+    }
+
+    public fun proposed_at(self: &Block): u64 {
+        self.proposed_at
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the Ethereum validator is registered with the builtin id.
+module rooch_framework::builtin_validators_test{
+    use rooch_framework::auth_validator_registry;
+    use rooch_framework::builtin_validators;
+    use rooch_framework::ethereum_validator;
+
+    #[test_only]
+    struct TestMultisignValidator has store{
+    }
+    #[test_only]
+    struct TestAuthValidator has store{
+    }
+
+    #[test]
+    fun test_ethereum_validator_is_builtin(){
+        rooch_framework::genesis::init_for_test();
+        // The id 2 is taken by the bitcoin multisign validator
+        auth_validator_registry::register<TestMultisignValidator>();
+        assert!(!ethereum_validator::is_ethereum_validator(3), 1000);
+        assert!(!builtin_validators::is_builtin_auth_validator(3), 1001);
+
+        ethereum_validator::init_for_upgrade();
+        // Registering again is a no-op
+        ethereum_validator::init_for_upgrade();
+        let ethereum_validator_id = ethereum_validator::auth_validator_id();
+        assert!(ethereum_validator_id == 3, 1002);
+        assert!(ethereum_validator::is_ethereum_validator(ethereum_validator_id), 1003);
+        assert!(builtin_validators::is_builtin_auth_validator(ethereum_validator_id), 1004);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = rooch_framework::ethereum_validator)]
+    fun test_ethereum_validator_id_taken(){
+        rooch_framework::genesis::init_for_test();
+        auth_validator_registry::register<TestMultisignValidator>();
+        // The third-party validator takes the id of the Ethereum validator
+        let validator_id = auth_validator_registry::register<TestAuthValidator>();
+        assert!(validator_id == 3, 1000);
+        assert!(!builtin_validators::is_builtin_auth_validator(validator_id), 1001);
+        ethereum_validator::init_for_upgrade();
+    }
+}
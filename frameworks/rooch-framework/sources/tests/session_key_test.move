@@ -9,8 +9,10 @@ module rooch_framework::session_key_test{
     use std::option;
     use moveos_std::bcs;
     use moveos_std::tx_context;
+    use moveos_std::type_info;
     use rooch_framework::session_key;
     use moveos_std::timestamp;
+    use rooch_framework::gas_coin::RGas;
 
     #[test]
     fun test_session_key_end_to_end(){
@@ -40,4 +42,70 @@ module rooch_framework::session_key_test{
         
     }
 
+    #[test]
+    fun test_session_key_limits(){
+        rooch_framework::genesis::init_for_test();
+        let sender_addr = tx_context::sender();
+        let sender = moveos_std::account::create_signer_for_testing(sender_addr);
+        let scope = session_key::new_session_scope(@0x1, std::string::utf8(b"*"), std::string::utf8(b"*"));
+        let authentication_key = bcs::to_bytes(&sender_addr);
+        let app_name = std::string::utf8(b"test");
+        let app_url = std::string::utf8(b"https://test.rooch.network");
+        session_key::create_session_key(&sender, app_name, app_url,  authentication_key, vector::singleton(scope), 100);
+        assert!(option::is_none(&session_key::get_session_key_limits(sender_addr, authentication_key)), 1000);
+        assert!(session_key::check_session_key_limits_for_test(sender_addr, authentication_key, 1000000), 1001);
+
+        session_key::set_session_key_limits(&sender, authentication_key, 100, 2, timestamp::now_seconds() + 50);
+        session_key::set_session_key_coin_limit<RGas>(&sender, authentication_key, 1000);
+        let limits = option::destroy_some(session_key::get_session_key_limits(sender_addr, authentication_key));
+        assert!(session_key::limits_max_gas(&limits) == 100, 1002);
+        assert!(session_key::limits_coin_remaining<RGas>(&limits) == option::some(1000), 1003);
+
+        assert!(session_key::check_session_key_limits_for_test(sender_addr, authentication_key, 100), 1004);
+        assert!(!session_key::check_session_key_limits_for_test(sender_addr, authentication_key, 101), 1005);
+        session_key::record_session_key_usage_for_test(authentication_key, 60);
+        assert!(!session_key::check_session_key_limits_for_test(sender_addr, authentication_key, 50), 1006);
+        assert!(session_key::check_session_key_limits_for_test(sender_addr, authentication_key, 40), 1007);
+        session_key::record_session_key_usage_for_test(authentication_key, 10);
+        // The max tx count is reached
+        assert!(!session_key::check_session_key_limits_for_test(sender_addr, authentication_key, 0), 1008);
+
+        session_key::set_session_key_limits(&sender, authentication_key, 0, 0, timestamp::now_seconds() + 50);
+        assert!(session_key::check_session_key_limits_for_test(sender_addr, authentication_key, 1000000), 1009);
+        timestamp::fast_forward_seconds_for_test(51);
+        assert!(!session_key::check_session_key_limits_for_test(sender_addr, authentication_key, 0), 1010);
+        assert!(session_key::is_expired_session_key(sender_addr, authentication_key), 1011);
+
+        session_key::remove_session_key(&sender, authentication_key);
+        assert!(option::is_none(&session_key::get_session_key_limits(sender_addr, authentication_key)), 1012);
+    }
+
+    #[test]
+    fun test_create_session_key_with_limits(){
+        rooch_framework::genesis::init_for_test();
+        let sender_addr = tx_context::sender();
+        let sender = moveos_std::account::create_signer_for_testing(sender_addr);
+        let authentication_key = bcs::to_bytes(&sender_addr);
+        session_key::create_session_key_with_limits_entry(&sender, std::string::utf8(b"test"), std::string::utf8(b"https://test.rooch.network"),
+            authentication_key, vector::singleton(@0x3), vector::singleton(std::string::utf8(b"*")), vector::singleton(std::string::utf8(b"*")), 100,
+            100, 2, 0, vector::singleton(type_info::type_name<RGas>()), vector::singleton(1000));
+        assert!(option::is_some(&session_key::get_session_key(sender_addr, authentication_key)), 1000);
+        let limits = option::destroy_some(session_key::get_session_key_limits(sender_addr, authentication_key));
+        assert!(session_key::limits_max_gas(&limits) == 100, 1001);
+        assert!(session_key::limits_max_tx_count(&limits) == 2, 1002);
+        assert!(session_key::limits_coin_remaining<RGas>(&limits) == option::some(1000), 1003);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 8, location = rooch_framework::session_key)]
+    fun test_create_session_key_with_limits_length_not_match(){
+        rooch_framework::genesis::init_for_test();
+        let sender_addr = tx_context::sender();
+        let sender = moveos_std::account::create_signer_for_testing(sender_addr);
+        let authentication_key = bcs::to_bytes(&sender_addr);
+        session_key::create_session_key_with_limits_entry(&sender, std::string::utf8(b"test"), std::string::utf8(b"https://test.rooch.network"),
+            authentication_key, vector::singleton(@0x3), vector::singleton(std::string::utf8(b"*")), vector::singleton(std::string::utf8(b"*")), 100,
+            0, 0, 0, vector::singleton(type_info::type_name<RGas>()), vector::empty());
+    }
+
 }
@@ -0,0 +1,65 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+module rooch_framework::state_commitment_chain_test{
+    use std::option;
+    use moveos_std::account;
+    use rooch_framework::onchain_config;
+    use rooch_framework::state_commitment_chain;
+
+    #[test]
+    fun test_propose_block(){
+        rooch_framework::genesis::init_for_test();
+        let proposer = account::create_signer_for_testing(onchain_config::sequencer());
+        assert!(option::is_none(&state_commitment_chain::last_block_number()), 1000);
+
+        state_commitment_chain::propose_block(&proposer, 10, 2, x"01", x"00", x"0a", x"0b");
+        state_commitment_chain::propose_block(&proposer, 11, 3, x"02", x"0a", x"1a", x"1b");
+        assert!(state_commitment_chain::last_block_number() == option::some(11), 1001);
+
+        let block = state_commitment_chain::get_block(10);
+        assert!(state_commitment_chain::batch_size(&block) == 2, 1002);
+        assert!(state_commitment_chain::tx_accumulator_root(&block) == x"0a", 1003);
+        assert!(state_commitment_chain::state_root(&block) == x"0b", 1004);
+        assert!(!state_commitment_chain::contains_block(12), 1005);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = rooch_framework::state_commitment_chain)]
+    fun test_propose_block_not_proposer(){
+        rooch_framework::genesis::init_for_test();
+        let other = account::create_signer_for_testing(@0x42);
+        state_commitment_chain::propose_block(&other, 0, 1, x"01", x"00", x"0a", x"0b");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 2, location = rooch_framework::state_commitment_chain)]
+    fun test_propose_block_invalid_block_number(){
+        rooch_framework::genesis::init_for_test();
+        let proposer = account::create_signer_for_testing(onchain_config::sequencer());
+        state_commitment_chain::propose_block(&proposer, 1, 1, x"01", x"00", x"0a", x"0b");
+        state_commitment_chain::propose_block(&proposer, 1, 1, x"02", x"0a", x"1a", x"1b");
+    }
+
+    #[test]
+    fun test_propose_block_skip_block_number(){
+        rooch_framework::genesis::init_for_test();
+        let proposer = account::create_signer_for_testing(onchain_config::sequencer());
+        state_commitment_chain::propose_block(&proposer, 0, 1, x"01", x"00", x"0a", x"0b");
+        // block 1 only contains the proposal of block 0
+        state_commitment_chain::propose_block(&proposer, 2, 1, x"03", x"0a", x"2a", x"2b");
+        assert!(state_commitment_chain::last_block_number() == option::some(2), 1000);
+        assert!(!state_commitment_chain::contains_block(1), 1001);
+        assert!(state_commitment_chain::prev_tx_accumulator_root(&state_commitment_chain::get_block(2)) == x"0a", 1002);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 3, location = rooch_framework::state_commitment_chain)]
+    fun test_propose_block_invalid_prev_root(){
+        rooch_framework::genesis::init_for_test();
+        let proposer = account::create_signer_for_testing(onchain_config::sequencer());
+        state_commitment_chain::propose_block(&proposer, 0, 1, x"01", x"00", x"0a", x"0b");
+        state_commitment_chain::propose_block(&proposer, 1, 1, x"02", x"0b", x"1a", x"1b");
+    }
+}
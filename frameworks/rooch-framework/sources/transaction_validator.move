@@ -21,7 +21,10 @@ module rooch_framework::transaction_validator {
     use rooch_framework::transaction::{Self, TransactionSequenceInfo};
     use rooch_framework::session_validator;
     use rooch_framework::bitcoin_validator;
+    use rooch_framework::ethereum_validator;
     use rooch_framework::address_mapping;
+    use rooch_framework::auth_payload;
+    use rooch_framework::bitcoin_address;
     use rooch_framework::account_coin_store;
     use rooch_framework::builtin_validators;
     use rooch_framework::onchain_config;
@@ -92,6 +95,10 @@ module rooch_framework::transaction_validator {
         // Try the built-in auth validator first
         let (bitcoin_address, session_key, auth_validator)= if (auth_validator_id == session_validator::auth_validator_id()){
             let session_key = session_validator::validate(authenticator_payload);
+            assert!(
+                session_key::check_session_key_limits(sender, session_key, gas),
+                auth_validator::error_validate_session_key_limit_exceeded(),
+            );
             let bitcoin_address = address_mapping::resolve_bitcoin(sender);
             (bitcoin_address, option::some(session_key), option::none())
         }else if (auth_validator_id == bitcoin_validator::auth_validator_id()){
@@ -104,6 +111,12 @@ module rooch_framework::transaction_validator {
             assert!(builtin_validators::is_builtin_auth_validator(validator_id) || account_authentication::is_auth_validator_installed(sender, validator_id),
                     auth_validator::error_validate_not_installed_auth_validator());
             let bitcoin_address = address_mapping::resolve_bitcoin(sender);
+            // The Ethereum validator checks the sender is derived from the public key in the payload,
+            // so the new account's bitcoin address can be derived from the public key.
+            if (option::is_none(&bitcoin_address) && ethereum_validator::is_ethereum_validator(validator_id)) {
+                let payload = auth_payload::from_bytes(authenticator_payload);
+                bitcoin_address = option::some(bitcoin_address::derive_bitcoin_taproot_address_from_pubkey(&auth_payload::public_key(&payload)));
+            };
             (bitcoin_address, option::none(), option::some(*auth_validator))
         };
         //The bitcoin address must exist
@@ -156,7 +169,7 @@ module rooch_framework::transaction_validator {
 
         let session_key_opt = auth_validator::get_session_key_from_ctx_option();
         if (option::is_some(&session_key_opt)) {
-            let session_key = option::extract(&mut session_key_opt);
+            let session_key = *option::borrow(&session_key_opt);
             session_key::active_session_key(session_key);
         };
         // Increment sequence number
@@ -168,6 +181,12 @@ module rooch_framework::transaction_validator {
         let gas_used = tx_result::gas_used(&tx_result);
         let gas_used_after_scale = transaction_fee::calculate_gas(gas_used);
 
+        // Record the usage of the session key limits
+        if (option::is_some(&session_key_opt)) {
+            let session_key = option::extract(&mut session_key_opt);
+            session_key::record_session_key_usage(session_key, gas_used_after_scale);
+        };
+
         let max_gas_amount = tx_context::max_gas_amount();
         let paid_gas = transaction_fee::calculate_gas(max_gas_amount);
 
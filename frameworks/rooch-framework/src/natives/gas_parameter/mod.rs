@@ -9,6 +9,7 @@ mod bitcoin_address;
 pub mod bls12381;
 mod cbor;
 mod ecdsa_k1;
+mod ecdsa_r1;
 mod ed25519;
 mod events;
 pub mod evm;
@@ -23,6 +24,7 @@ pub mod nursery;
 mod object;
 mod object_list_field_keys;
 mod rlp;
+mod schnorr;
 mod signer;
 mod test_helper;
 mod tx_context;
@@ -0,0 +1,11 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::rooch_framework::crypto::schnorr::GasParameters;
+
+crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "schnorr", [
+    [.verify.base, optional "verify.base", 0],
+    [.verify.per_byte, optional "verify.per_byte", 0],
+    [.tagged_hash.base, optional "tagged_hash.base", 0],
+    [.tagged_hash.per_byte, optional "tagged_hash.per_byte", 0],
+]);
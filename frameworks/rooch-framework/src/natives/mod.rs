@@ -28,6 +28,8 @@ pub struct NativeGasParameters {
     pub moveos_stdlib: MoveOSStdlibGasParameters,
     pub ed25519: rooch_framework::crypto::ed25519::GasParameters,
     pub ecdsa_k1: rooch_framework::crypto::ecdsa_k1::GasParameters,
+    pub ecdsa_r1: rooch_framework::crypto::ecdsa_r1::GasParameters,
+    pub schnorr: rooch_framework::crypto::schnorr::GasParameters,
     pub bitcoin_address: rooch_framework::bitcoin_address::GasParameters,
 }
 
@@ -38,6 +40,8 @@ impl FromOnChainGasSchedule for NativeGasParameters {
                 .unwrap(),
             ed25519: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
             ecdsa_k1: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            ecdsa_r1: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            schnorr: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
             bitcoin_address: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)
                 .unwrap(),
         })
@@ -49,6 +53,8 @@ impl ToOnChainGasSchedule for NativeGasParameters {
         let mut entires = self.moveos_stdlib.to_on_chain_gas_schedule();
         entires.extend(self.ed25519.to_on_chain_gas_schedule());
         entires.extend(self.ecdsa_k1.to_on_chain_gas_schedule());
+        entires.extend(self.ecdsa_r1.to_on_chain_gas_schedule());
+        entires.extend(self.schnorr.to_on_chain_gas_schedule());
         entires.extend(self.bitcoin_address.to_on_chain_gas_schedule());
         entires
     }
@@ -60,6 +66,8 @@ impl InitialGasSchedule for NativeGasParameters {
             moveos_stdlib: InitialGasSchedule::initial(),
             ed25519: InitialGasSchedule::initial(),
             ecdsa_k1: InitialGasSchedule::initial(),
+            ecdsa_r1: InitialGasSchedule::initial(),
+            schnorr: InitialGasSchedule::initial(),
             bitcoin_address: InitialGasSchedule::initial(),
         }
     }
@@ -160,6 +168,8 @@ impl NativeGasParameters {
             moveos_stdlib: moveos_stdlib::natives::GasParameters::zeros(),
             ed25519: rooch_framework::crypto::ed25519::GasParameters::zeros(),
             ecdsa_k1: rooch_framework::crypto::ecdsa_k1::GasParameters::zeros(),
+            ecdsa_r1: rooch_framework::crypto::ecdsa_r1::GasParameters::zeros(),
+            schnorr: rooch_framework::crypto::schnorr::GasParameters::zeros(),
             bitcoin_address: rooch_framework::bitcoin_address::GasParameters::zeros(),
         }
     }
@@ -187,6 +197,14 @@ pub fn all_natives(gas_params: NativeGasParameters) -> NativeFunctionTable {
         "ecdsa_k1",
         rooch_framework::crypto::ecdsa_k1::make_all(gas_params.ecdsa_k1)
     );
+    add_natives!(
+        "ecdsa_r1",
+        rooch_framework::crypto::ecdsa_r1::make_all(gas_params.ecdsa_r1)
+    );
+    add_natives!(
+        "schnorr",
+        rooch_framework::crypto::schnorr::make_all(gas_params.schnorr)
+    );
     add_natives!(
         "bitcoin_address",
         rooch_framework::bitcoin_address::make_all(gas_params.bitcoin_address)
@@ -0,0 +1,124 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use fastcrypto::{
+    hash::{Keccak256, Sha256},
+    secp256r1::{Secp256r1PublicKey, Secp256r1Signature},
+    traits::ToFromBytes,
+};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INVALID_SIGNATURE: u64 = 1;
+pub const E_INVALID_PUBKEY: u64 = 2;
+/// The gas parameters of the native function are not in the on-chain gas schedule
+pub const E_GAS_PARAMETERS_NOT_SET: u64 = 3;
+
+pub const KECCAK256: u8 = 0;
+pub const SHA256: u8 = 1;
+
+/***************************************************************************************************
+ * native fun verify
+ * Implementation of the Move native function `ecdsa_r1::verify(signature: &vector<u8>, public_key: &vector<u8>, msg: &vector<u8>, hash: u8): bool;`
+ *   gas cost: verify.base + verify.per_byte * (msg.len() + signature.len() + public_key.len())
+ **************************************************************************************************/
+pub fn native_verify(
+    gas_params: &FromBytesGasParametersOptional,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 4);
+
+    let hash = pop_arg!(args, u8);
+
+    let msg = pop_arg!(args, VectorRef);
+    let public_key_bytes = pop_arg!(args, VectorRef);
+    let signature_bytes = pop_arg!(args, VectorRef);
+
+    let msg_ref = msg.as_bytes_ref();
+    let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
+    let signature_bytes_ref = signature_bytes.as_bytes_ref();
+
+    let (Some(base), Some(per_byte)) = (gas_params.base, gas_params.per_byte) else {
+        return Ok(NativeResult::err(
+            InternalGas::zero(),
+            E_GAS_PARAMETERS_NOT_SET,
+        ));
+    };
+    let cost = base
+        + per_byte * NumBytes::new(msg_ref.len() as u64)
+        + per_byte * NumBytes::new(signature_bytes_ref.len() as u64)
+        + per_byte * NumBytes::new(public_key_bytes_ref.len() as u64);
+
+    let Ok(sig) = <Secp256r1Signature as ToFromBytes>::from_bytes(&signature_bytes_ref) else {
+        return Ok(NativeResult::err(cost, E_INVALID_SIGNATURE));
+    };
+
+    let Ok(public_key) = <Secp256r1PublicKey as ToFromBytes>::from_bytes(&public_key_bytes_ref)
+    else {
+        return Ok(NativeResult::err(cost, E_INVALID_PUBKEY));
+    };
+
+    let result = match hash {
+        KECCAK256 => public_key
+            .verify_with_hash::<Keccak256>(&msg_ref, &sig)
+            .is_ok(),
+        SHA256 => public_key
+            .verify_with_hash::<Sha256>(&msg_ref, &sig)
+            .is_ok(),
+        _ => false,
+    };
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+// optional params, the gas entries are added to the on-chain gas schedule by the gas schedule upgrade
+#[derive(Debug, Clone)]
+pub struct FromBytesGasParametersOptional {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+}
+
+impl FromBytesGasParametersOptional {
+    pub fn zeros() -> Self {
+        Self {
+            base: None,
+            per_byte: None,
+        }
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub verify: FromBytesGasParametersOptional,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            verify: FromBytesGasParametersOptional::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [("verify", make_native(gas_params.verify, native_verify))];
+
+    make_module_natives(natives)
+}
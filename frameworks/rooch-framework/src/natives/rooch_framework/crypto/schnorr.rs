@@ -0,0 +1,164 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use bitcoin::secp256k1::{schnorr::Signature, Message, Secp256k1, XOnlyPublicKey};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INVALID_SIGNATURE: u64 = 1;
+pub const E_INVALID_PUBKEY: u64 = 2;
+pub const E_INVALID_MESSAGE: u64 = 3;
+/// The gas parameters of the native function are not in the on-chain gas schedule
+pub const E_GAS_PARAMETERS_NOT_SET: u64 = 4;
+
+/***************************************************************************************************
+ * native fun verify
+ * Implementation of the Move native function `schnorr::verify(signature: &vector<u8>, public_key: &vector<u8>, msg: &vector<u8>): bool;`
+ *   gas cost: verify.base + verify.per_byte * (msg.len() + signature.len() + public_key.len())
+ **************************************************************************************************/
+pub fn native_verify(
+    gas_params: &FromBytesGasParametersOptional,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let msg = pop_arg!(args, VectorRef);
+    let public_key_bytes = pop_arg!(args, VectorRef);
+    let signature_bytes = pop_arg!(args, VectorRef);
+
+    let msg_ref = msg.as_bytes_ref();
+    let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
+    let signature_bytes_ref = signature_bytes.as_bytes_ref();
+
+    let (Some(base), Some(per_byte)) = (gas_params.base, gas_params.per_byte) else {
+        return Ok(NativeResult::err(
+            InternalGas::zero(),
+            E_GAS_PARAMETERS_NOT_SET,
+        ));
+    };
+    let cost = base
+        + per_byte * NumBytes::new(msg_ref.len() as u64)
+        + per_byte * NumBytes::new(signature_bytes_ref.len() as u64)
+        + per_byte * NumBytes::new(public_key_bytes_ref.len() as u64);
+
+    let Ok(sig) = Signature::from_slice(&signature_bytes_ref) else {
+        return Ok(NativeResult::err(cost, E_INVALID_SIGNATURE));
+    };
+
+    let Ok(public_key) = XOnlyPublicKey::from_slice(&public_key_bytes_ref) else {
+        return Ok(NativeResult::err(cost, E_INVALID_PUBKEY));
+    };
+
+    let Ok(message) = Message::from_digest_slice(&msg_ref) else {
+        return Ok(NativeResult::err(cost, E_INVALID_MESSAGE));
+    };
+
+    let result = Secp256k1::verification_only()
+        .verify_schnorr(&sig, &message, &public_key)
+        .is_ok();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+/***************************************************************************************************
+ * native fun tagged_hash
+ * Implementation of the Move native function `schnorr::tagged_hash(tag: &vector<u8>, msg: &vector<u8>): vector<u8>;`
+ *   gas cost: tagged_hash.base + tagged_hash.per_byte * (tag.len() + msg.len())
+ **************************************************************************************************/
+pub fn native_tagged_hash(
+    gas_params: &FromBytesGasParametersOptional,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let msg = pop_arg!(args, VectorRef);
+    let tag = pop_arg!(args, VectorRef);
+
+    let msg_ref = msg.as_bytes_ref();
+    let tag_ref = tag.as_bytes_ref();
+
+    let (Some(base), Some(per_byte)) = (gas_params.base, gas_params.per_byte) else {
+        return Ok(NativeResult::err(
+            InternalGas::zero(),
+            E_GAS_PARAMETERS_NOT_SET,
+        ));
+    };
+    let cost = base + per_byte * NumBytes::new((msg_ref.len() + tag_ref.len()) as u64);
+
+    // sha256(sha256(tag) || sha256(tag) || msg), defined in BIP-340
+    let tag_hash = sha256::Hash::hash(&tag_ref);
+    let mut engine = sha256::Hash::engine();
+    engine.input(tag_hash.as_ref());
+    engine.input(tag_hash.as_ref());
+    engine.input(&msg_ref);
+    let hash = sha256::Hash::from_engine(engine);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(hash.to_byte_array())],
+    ))
+}
+
+// optional params, the gas entries are added to the on-chain gas schedule by the gas schedule upgrade
+#[derive(Debug, Clone)]
+pub struct FromBytesGasParametersOptional {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+}
+
+impl FromBytesGasParametersOptional {
+    pub fn zeros() -> Self {
+        Self {
+            base: None,
+            per_byte: None,
+        }
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub verify: FromBytesGasParametersOptional,
+    pub tagged_hash: FromBytesGasParametersOptional,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            verify: FromBytesGasParametersOptional::zeros(),
+            tagged_hash: FromBytesGasParametersOptional::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("verify", make_native(gas_params.verify, native_verify)),
+        (
+            "tagged_hash",
+            make_native(gas_params.tagged_hash, native_tagged_hash),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
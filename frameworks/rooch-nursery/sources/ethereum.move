@@ -3,6 +3,8 @@
 
 module rooch_nursery::ethereum{
 
+    use std::option::{Self, Option};
+    use std::vector;
     use moveos_std::timestamp;
     use moveos_std::account;
     use moveos_std::table::{Self, Table};
@@ -14,6 +16,7 @@ module rooch_nursery::ethereum{
     friend rooch_nursery::genesis;
 
     const ErrorBlockNotFound:u64 = 1;
+    const ErrorInvalidParentHash:u64 = 2;
 
     #[data_struct]
     struct BlockHeader has store, copy, drop {
@@ -51,6 +54,16 @@ module rooch_nursery::ethereum{
         blocks: Table<u64, BlockHeader>,
     }
 
+    /// The index of the relayed blocks and transactions, it is created when the first block is relayed.
+    struct BlockIndexStore has key {
+        /// block hash -> block number
+        block_numbers: Table<vector<u8>, u64>,
+        /// The latest relayed block number
+        latest_block_number: Option<u64>,
+        /// txid -> block hash, the relayed transactions
+        txs: Table<vector<u8>, vector<u8>>,
+    }
+
     public(friend) fun genesis_init(genesis_account: &signer){
         let block_store = BlockStore{
             blocks: table::new(),
@@ -58,19 +71,52 @@ module rooch_nursery::ethereum{
         account::move_resource_to(genesis_account, block_store);
     }
 
+    fun borrow_mut_block_index_store(): &mut BlockIndexStore{
+        if(!account::exists_resource<BlockIndexStore>(@rooch_framework)){
+            let module_signer = signer::module_signer<BlockIndexStore>();
+            let framework_signer = account::create_signer_for_system(&module_signer, @rooch_framework);
+            account::move_resource_to(&framework_signer, BlockIndexStore{
+                block_numbers: table::new(),
+                latest_block_number: option::none(),
+                txs: table::new(),
+            });
+        };
+        account::borrow_mut_resource<BlockIndexStore>(@rooch_framework)
+    }
+
     fun process_block(block_header_bytes: vector<u8>){
         let block_header = bcs::from_bytes<BlockHeader>(block_header_bytes);
         //TODO validate the block hash
         //TODO validate the block via ethereum consensus(pos validators)
         let block_store = account::borrow_mut_resource<BlockStore>(@rooch_framework);
-        if(table::contains(&block_store.blocks, block_header.number)){
-            //repeat block number
-            //TODO check if it is a soft fork.
-            return
+        let number = block_header.number;
+        let block_hash = block_header.hash;
+        let reorg = false;
+        if(table::contains(&block_store.blocks, number)){
+            if(table::borrow(&block_store.blocks, number).hash == block_hash){
+                //repeat block
+                return
+            };
+            reorg = true;
+        };
+        if(number > 0 && table::contains(&block_store.blocks, number - 1)){
+            let parent = table::borrow(&block_store.blocks, number - 1);
+            assert!(parent.hash == block_header.parent_hash, ErrorInvalidParentHash);
         };
-        table::add(&mut block_store.blocks, block_header.number, block_header);
-
         let timestamp_seconds = (block_header.timestamp as u64);
+        if(reorg){
+            //reorg, the block and all its descendants are replaced by the new chain
+            remove_blocks_from(number);
+        };
+        let block_store = account::borrow_mut_resource<BlockStore>(@rooch_framework);
+        table::add(&mut block_store.blocks, number, block_header);
+
+        let index_store = borrow_mut_block_index_store();
+        table::add(&mut index_store.block_numbers, block_hash, number);
+        if(option::is_none(&index_store.latest_block_number) || *option::borrow(&index_store.latest_block_number) < number){
+            index_store.latest_block_number = option::some(number);
+        };
+
         let module_signer = signer::module_signer<BlockStore>();
         timestamp::try_update_global_time(&module_signer, timestamp::seconds_to_milliseconds(timestamp_seconds));        
     }
@@ -81,10 +127,171 @@ module rooch_nursery::ethereum{
         process_block(block_header_bytes);
     }
 
+    /// Remove the block of `from_block_number` and all its descendants.
+    /// The latest block number is unknown if the blocks are relayed before the BlockIndexStore is created,
+    /// only the block of `from_block_number` is removed, the descendants are replaced when the new chain is relayed.
+    fun remove_blocks_from(from_block_number: u64){
+        let latest_block_number = option::get_with_default(&get_latest_block_number(), from_block_number);
+        let block_store = account::borrow_mut_resource<BlockStore>(@rooch_framework);
+        let removed_block_hashes = vector::empty<vector<u8>>();
+        let number = from_block_number;
+        while(number <= latest_block_number){
+            if(table::contains(&block_store.blocks, number)){
+                let removed = table::remove(&mut block_store.blocks, number);
+                vector::push_back(&mut removed_block_hashes, removed.hash);
+            };
+            number = number + 1;
+        };
+        let parent_exists = from_block_number > 0 && table::contains(&block_store.blocks, from_block_number - 1);
+
+        let index_store = borrow_mut_block_index_store();
+        while(!vector::is_empty(&removed_block_hashes)){
+            let block_hash = vector::pop_back(&mut removed_block_hashes);
+            if(table::contains(&index_store.block_numbers, block_hash)){
+                table::remove(&mut index_store.block_numbers, block_hash);
+            };
+        };
+        index_store.latest_block_number = if(parent_exists){
+            option::some(from_block_number - 1)
+        }else{
+            option::none()
+        };
+    }
+
+    /// The relay server submit a transaction of a relayed Ethereum block.
+    public entry fun execute_l1_tx(block_hash: vector<u8>, txid: vector<u8>){
+        features::ensure_localnet_enabled();
+        process_tx(block_hash, txid);
+    }
+
+    fun process_tx(block_hash: vector<u8>, txid: vector<u8>){
+        assert!(account::exists_resource<BlockIndexStore>(@rooch_framework), ErrorBlockNotFound);
+        let index_store = account::borrow_mut_resource<BlockIndexStore>(@rooch_framework);
+        assert!(table::contains(&index_store.block_numbers, block_hash), ErrorBlockNotFound);
+        //TODO process the transaction body, such as the bridge deposit
+        //The transaction may be relayed again after the relayer restart or the block is reorged
+        table::upsert(&mut index_store.txs, txid, block_hash);
+    }
+
     /// Get block via block_number
     public fun get_block(block_number: u64): &BlockHeader{
         let block_store = account::borrow_resource<BlockStore>(@rooch_framework);
         assert!(table::contains(&block_store.blocks, block_number), ErrorBlockNotFound);
         table::borrow(&block_store.blocks, block_number)
     }
+
+    /// Get the hash of the block via block_number, return none if the block is not relayed
+    public fun get_block_hash(block_number: u64): Option<vector<u8>>{
+        let block_store = account::borrow_resource<BlockStore>(@rooch_framework);
+        if(table::contains(&block_store.blocks, block_number)){
+            option::some(table::borrow(&block_store.blocks, block_number).hash)
+        }else{
+            option::none()
+        }
+    }
+
+    /// Get the latest relayed block number
+    public fun get_latest_block_number(): Option<u64>{
+        if(!account::exists_resource<BlockIndexStore>(@rooch_framework)){
+            return option::none()
+        };
+        let index_store = account::borrow_resource<BlockIndexStore>(@rooch_framework);
+        index_store.latest_block_number
+    }
+
+    /// Get the hash of the block which the transaction is relayed in
+    public fun get_tx_block_hash(txid: vector<u8>): Option<vector<u8>>{
+        if(!account::exists_resource<BlockIndexStore>(@rooch_framework)){
+            return option::none()
+        };
+        let index_store = account::borrow_resource<BlockIndexStore>(@rooch_framework);
+        if(table::contains(&index_store.txs, txid)){
+            option::some(*table::borrow(&index_store.txs, txid))
+        }else{
+            option::none()
+        }
+    }
+
+    #[test_only]
+    use rooch_framework::ethereum_address;
+
+    #[test_only]
+    fun new_block_header_for_test(number: u64, hash: vector<u8>, parent_hash: vector<u8>): BlockHeader{
+        BlockHeader{
+            hash,
+            parent_hash,
+            uncles_hash: x"",
+            author: ethereum_address::from_bytes(x"0000000000000000000000000000000000000001"),
+            state_root: x"",
+            transactions_root: x"",
+            receipts_root: x"",
+            logs_bloom: x"",
+            difficulty: 0,
+            number,
+            gas_limit: 0,
+            gas_used: 0,
+            timestamp: 1,
+            extra_data: x"",
+        }
+    }
+
+    #[test]
+    fun test_process_block_reorg(){
+        rooch_framework::genesis::init_for_test();
+        genesis_init(&account::create_signer_for_testing(@rooch_framework));
+        process_block(bcs::to_bytes(&new_block_header_for_test(1, x"01", x"00")));
+        process_block(bcs::to_bytes(&new_block_header_for_test(2, x"02", x"01")));
+        process_block(bcs::to_bytes(&new_block_header_for_test(3, x"03", x"02")));
+        process_tx(x"02", x"aa");
+        assert!(get_latest_block_number() == option::some(3), 1000);
+
+        //repeat block is ignored
+        process_block(bcs::to_bytes(&new_block_header_for_test(2, x"02", x"01")));
+        assert!(get_latest_block_number() == option::some(3), 1001);
+
+        //reorg at block 2
+        process_block(bcs::to_bytes(&new_block_header_for_test(2, x"12", x"01")));
+        assert!(get_latest_block_number() == option::some(2), 1002);
+        assert!(get_block_hash(2) == option::some(x"12"), 1003);
+        assert!(option::is_none(&get_block_hash(3)), 1004);
+
+        process_tx(x"12", x"aa");
+        assert!(get_tx_block_hash(x"aa") == option::some(x"12"), 1005);
+    }
+
+    #[test]
+    fun test_process_block_reorg_before_index_created(){
+        rooch_framework::genesis::init_for_test();
+        genesis_init(&account::create_signer_for_testing(@rooch_framework));
+        //the blocks relayed before the BlockIndexStore is created
+        let block_store = account::borrow_mut_resource<BlockStore>(@rooch_framework);
+        table::add(&mut block_store.blocks, 1, new_block_header_for_test(1, x"01", x"00"));
+        table::add(&mut block_store.blocks, 2, new_block_header_for_test(2, x"02", x"01"));
+        assert!(option::is_none(&get_latest_block_number()), 1000);
+
+        //reorg at block 2
+        process_block(bcs::to_bytes(&new_block_header_for_test(2, x"12", x"01")));
+        assert!(get_latest_block_number() == option::some(2), 1001);
+        assert!(get_block_hash(1) == option::some(x"01"), 1002);
+        assert!(get_block_hash(2) == option::some(x"12"), 1003);
+        process_block(bcs::to_bytes(&new_block_header_for_test(3, x"13", x"12")));
+        assert!(get_latest_block_number() == option::some(3), 1004);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorInvalidParentHash, location = Self)]
+    fun test_process_block_invalid_parent_hash(){
+        rooch_framework::genesis::init_for_test();
+        genesis_init(&account::create_signer_for_testing(@rooch_framework));
+        process_block(bcs::to_bytes(&new_block_header_for_test(1, x"01", x"00")));
+        process_block(bcs::to_bytes(&new_block_header_for_test(2, x"02", x"ff")));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorBlockNotFound, location = Self)]
+    fun test_process_tx_block_not_found(){
+        rooch_framework::genesis::init_for_test();
+        genesis_init(&account::create_signer_for_testing(@rooch_framework));
+        process_tx(x"01", x"aa");
+    }
 }
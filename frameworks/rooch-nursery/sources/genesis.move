@@ -3,6 +3,7 @@
 
 module rooch_nursery::genesis {
     use rooch_nursery::ethereum;
+    use rooch_framework::ethereum_validator;
     use rooch_nursery::tick_info;
     use rooch_nursery::inscribe_factory;
 
@@ -13,6 +14,7 @@ module rooch_nursery::genesis {
 
     fun init(genesis_account: &signer){
         ethereum::genesis_init(genesis_account);
+        ethereum_validator::init_for_upgrade();
         tick_info::genesis_init();
         inscribe_factory::genesis_init();
     }
@@ -7,6 +7,8 @@ use move_core_types::account_address::AccountAddress;
 use move_core_types::gas_algebra::InternalGas;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{ModuleId, TypeTag};
+use moveos_types::move_types::FunctionId;
+use moveos_types::transaction::CallTrace;
 use smallvec::{smallvec, SmallVec};
 
 /// An event occurred during the execution of a function, along with the
@@ -76,6 +78,58 @@ impl CallFrame {
             events: vec![],
         }
     }
+
+    /// Convert the frame to the call tree, the gas used by a call includes the callees.
+    pub fn to_call_trace(&self) -> CallTrace {
+        let (function, ty_args) = match &self.name {
+            FrameName::Script => (None, vec![]),
+            FrameName::Function {
+                module_id,
+                name,
+                ty_args,
+            } => (
+                Some(FunctionId::new(module_id.clone(), name.clone())),
+                ty_args.clone(),
+            ),
+        };
+        let mut gas_used = 0u64;
+        let mut calls = vec![];
+        for event in &self.events {
+            match event {
+                ExecutionGasEvent::Call(frame) => {
+                    let call = frame.to_call_trace();
+                    gas_used += call.gas_used;
+                    calls.push(call);
+                }
+                ExecutionGasEvent::CallNative {
+                    module_id,
+                    fn_name,
+                    ty_args,
+                    cost,
+                } => {
+                    gas_used += u64::from(*cost);
+                    calls.push(CallTrace {
+                        function: Some(FunctionId::new(module_id.clone(), fn_name.clone())),
+                        ty_args: ty_args.clone(),
+                        is_native: true,
+                        gas_used: u64::from(*cost),
+                        calls: vec![],
+                    });
+                }
+                ExecutionGasEvent::Bytecode { cost, .. }
+                | ExecutionGasEvent::LoadResource { cost, .. }
+                | ExecutionGasEvent::CreateTy { cost } => gas_used += u64::from(*cost),
+                ExecutionGasEvent::Loc(_) => {}
+            }
+        }
+        CallTrace {
+            function,
+            ty_args,
+            is_native: false,
+            gas_used,
+            calls,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -127,3 +181,90 @@ impl ExecutionAndIOCosts {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::ident_str;
+
+    fn module_id(name: &str) -> ModuleId {
+        ModuleId::new(AccountAddress::ONE, Identifier::new(name).unwrap())
+    }
+
+    #[test]
+    fn test_to_call_trace() {
+        let mut callee = CallFrame::new_function(
+            module_id("callee"),
+            ident_str!("inner").to_owned(),
+            vec![TypeTag::U64],
+        );
+        callee.events = vec![
+            ExecutionGasEvent::Loc(1),
+            ExecutionGasEvent::Bytecode {
+                op: Opcodes::LD_U64,
+                cost: InternalGas::new(3),
+            },
+            ExecutionGasEvent::CallNative {
+                module_id: module_id("native"),
+                fn_name: ident_str!("hash").to_owned(),
+                ty_args: vec![],
+                cost: InternalGas::new(5),
+            },
+        ];
+        let mut caller =
+            CallFrame::new_function(module_id("caller"), ident_str!("outer").to_owned(), vec![]);
+        caller.events = vec![
+            ExecutionGasEvent::Bytecode {
+                op: Opcodes::CALL,
+                cost: InternalGas::new(2),
+            },
+            ExecutionGasEvent::Call(callee),
+            ExecutionGasEvent::LoadResource {
+                addr: AccountAddress::ONE,
+                ty: TypeTag::Bool,
+                cost: InternalGas::new(7),
+            },
+            ExecutionGasEvent::CreateTy {
+                cost: InternalGas::new(11),
+            },
+        ];
+        let mut script = CallFrame::new_script();
+        script.events = vec![ExecutionGasEvent::Call(caller)];
+
+        let trace = script.to_call_trace();
+        assert_eq!(trace.function, None);
+        assert!(!trace.is_native);
+        assert_eq!(trace.gas_used, 28);
+        assert_eq!(trace.calls.len(), 1);
+
+        let caller = &trace.calls[0];
+        assert_eq!(
+            caller.function,
+            Some(FunctionId::new(
+                module_id("caller"),
+                ident_str!("outer").to_owned()
+            ))
+        );
+        // The gas used by the caller includes the callee
+        assert_eq!(caller.gas_used, 28);
+        assert_eq!(caller.calls.len(), 1);
+
+        let callee = &caller.calls[0];
+        assert_eq!(callee.ty_args, vec![TypeTag::U64]);
+        assert!(!callee.is_native);
+        assert_eq!(callee.gas_used, 8);
+        assert_eq!(callee.calls.len(), 1);
+
+        let native = &callee.calls[0];
+        assert_eq!(
+            native.function,
+            Some(FunctionId::new(
+                module_id("native"),
+                ident_str!("hash").to_owned()
+            ))
+        );
+        assert!(native.is_native);
+        assert_eq!(native.gas_used, 5);
+        assert!(native.calls.is_empty());
+    }
+}
@@ -12,7 +12,7 @@ use move_vm_types::gas::{GasMeter, SimpleInstruction};
 use move_vm_types::natives::function::PartialVMResult;
 use move_vm_types::views::{TypeView, ValueView};
 use moveos_common::types::{ClassifiedGasMeter, GasStatement, SwitchableGasMeter};
-use moveos_types::transaction::MoveAction;
+use moveos_types::transaction::{MoveAction, VerifiedMoveAction};
 use std::sync::{Arc, RwLock};
 
 #[derive(Debug, Clone)]
@@ -68,6 +68,14 @@ impl<G> GasProfiler<G> {
             metering: true,
         }
     }
+
+    pub fn new_script(base: G) -> Self {
+        Self {
+            base,
+            frames: Arc::new(RwLock::new(vec![CallFrame::new_script()])),
+            metering: true,
+        }
+    }
 }
 
 impl<G: GasMeter> GasProfiler<G> {
@@ -479,33 +487,34 @@ impl<G: GasMeter> ProfileGasMeter for GasProfiler<G> {
     }
 }
 
-impl<G: GasMeter> ClassifiedGasMeter for GasProfiler<G> {
-    fn charge_execution(&mut self, _gas_cost: u64) -> PartialVMResult<()> {
-        Ok(())
+// The classified charges and the metering switch are delegated to the base gas meter,
+// so the execution with the profiler produces the same output as the base gas meter.
+impl<G: GasMeter + ClassifiedGasMeter> ClassifiedGasMeter for GasProfiler<G> {
+    fn charge_execution(&mut self, gas_cost: u64) -> PartialVMResult<()> {
+        self.base.charge_execution(gas_cost)
     }
 
-    fn charge_io_write(&mut self, _data_size: u64) -> PartialVMResult<()> {
-        Ok(())
+    fn charge_io_write(&mut self, data_size: u64) -> PartialVMResult<()> {
+        self.base.charge_io_write(data_size)
     }
 
-    fn check_constrains(&self, _max_gas_amount: u64) -> PartialVMResult<()> {
-        Ok(())
+    fn check_constrains(&self, max_gas_amount: u64) -> PartialVMResult<()> {
+        self.base.check_constrains(max_gas_amount)
     }
 
     fn gas_statement(&self) -> GasStatement {
-        GasStatement {
-            execution_gas_used: InternalGas::zero(),
-            storage_gas_used: InternalGas::zero(),
-        }
+        self.base.gas_statement()
     }
 }
 
-impl<G: GasMeter> SwitchableGasMeter for GasProfiler<G> {
+impl<G: SwitchableGasMeter> SwitchableGasMeter for GasProfiler<G> {
     fn stop_metering(&mut self) {
+        self.base.stop_metering();
         self.metering = false;
     }
 
     fn start_metering(&mut self) {
+        self.base.start_metering();
         self.metering = true;
     }
 
@@ -526,3 +535,22 @@ pub fn new_gas_profiler<G>(action: MoveAction, base_gas_meter: G) -> GasProfiler
         MoveAction::ModuleBundle(_) => unreachable!("ModuleBundle payload is not supported yet"),
     }
 }
+
+/// Create the gas profiler for the verified action,
+/// the module bundle and the script are profiled under a script frame.
+pub fn new_verified_gas_profiler<G>(
+    action: &VerifiedMoveAction,
+    base_gas_meter: G,
+) -> GasProfiler<G> {
+    match action {
+        VerifiedMoveAction::Function { call, .. } => GasProfiler::new_function(
+            base_gas_meter,
+            call.function_id.module_id.clone(),
+            call.function_id.function_name.clone(),
+            call.ty_args.clone(),
+        ),
+        VerifiedMoveAction::Script { .. } | VerifiedMoveAction::ModuleBundle { .. } => {
+            GasProfiler::new_script(base_gas_meter)
+        }
+    }
+}
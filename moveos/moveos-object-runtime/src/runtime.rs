@@ -38,7 +38,10 @@ use moveos_types::{
     state::MoveState,
 };
 use parking_lot::RwLock;
-use std::{collections::BTreeMap, rc::Rc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    rc::Rc,
+};
 use tracing::debug;
 
 /// Ensure the error codes in this file is consistent with the error code in object.move
@@ -478,6 +481,16 @@ impl<'r> ObjectRuntime<'r> {
         Ok(())
     }
 
+    /// The ids of the Objects read from the state in this transaction, including the Objects which do not exist.
+    /// The ancestors of a loaded Object are loaded before it, so they are included,
+    /// and the Timestamp Object is always included because it is used to update the `updated_at` of the changed Objects.
+    pub fn read_set(&self) -> BTreeSet<ObjectID> {
+        let mut ids = BTreeSet::new();
+        self.root.collect_loaded_field_ids(&mut ids);
+        ids.insert(Timestamp::object_id());
+        ids
+    }
+
     // into inner
     pub fn into_inner(self) -> (TxContext, RuntimeObject) {
         let ObjectRuntime {
@@ -30,7 +30,7 @@ use moveos_types::{
     state::{FieldKey, MoveState, MoveType, ObjectChange, ObjectState},
     state_resolver::StatelessResolver,
 };
-use std::collections::{btree_map::Entry, BTreeMap};
+use std::collections::{btree_map::Entry, BTreeMap, BTreeSet};
 
 type ScanFieldList = Vec<(FieldKey, Value)>;
 type FieldList = Vec<(FieldKey, RuntimeObject, Option<Option<NumBytes>>)>;
@@ -354,6 +354,14 @@ impl RuntimeObject {
             .filter(|rt_obj| !rt_obj.is_none())
     }
 
+    /// Collect the ids of the loaded fields and their descendants, including the fields which do not exist.
+    pub fn collect_loaded_field_ids(&self, ids: &mut BTreeSet<ObjectID>) {
+        for field in self.fields.values() {
+            ids.insert(field.id().clone());
+            field.collect_loaded_field_ids(ids);
+        }
+    }
+
     pub fn into_change(self, timestamp: &Timestamp) -> PartialVMResult<Option<ObjectChange>> {
         let object_id = self.id().clone();
         let mut rt_meta = self.rt_meta;
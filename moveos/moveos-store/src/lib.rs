@@ -7,7 +7,7 @@
 use crate::config_store::{ConfigDBStore, ConfigStore, STARTUP_INFO_KEY};
 use crate::event_store::{EventDBStore, EventStore};
 use crate::state_store::statedb::StateDBStore;
-use crate::state_store::{nodes_to_write_batch, NodeDBStore};
+use crate::state_store::NodeDBStore;
 use crate::transaction_store::{TransactionDBStore, TransactionStore};
 use accumulator::inmemory::InMemoryAccumulator;
 use anyhow::{Error, Result};
@@ -31,8 +31,7 @@ use prometheus::Registry;
 use raw_store::metrics::DBMetrics;
 use raw_store::rocks::batch::{WriteBatch, WriteBatchCF};
 use raw_store::rocks::RocksDB;
-use raw_store::traits::DBStore;
-use raw_store::{ColumnFamilyName, SchemaStore, StoreInstance, WriteOp};
+use raw_store::{ColumnFamilyName, StoreInstance, WriteOp};
 use smt::NodeReader;
 use std::fmt::{Debug, Display, Formatter};
 use std::path::Path;
@@ -47,6 +46,11 @@ pub mod transaction_store;
 
 // pub const DEFAULT_COLUMN_FAMILY_NAME: ColumnFamilyName = "default";
 pub const STATE_NODE_COLUMN_FAMILY_NAME: ColumnFamilyName = "state_node";
+pub const STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME: ColumnFamilyName = "state_node_ref_count";
+pub const STATE_NODE_REF_COUNT_INFO_COLUMN_FAMILY_NAME: ColumnFamilyName =
+    "state_node_ref_count_info";
+pub const STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME: ColumnFamilyName = "state_node_stale_index";
+pub const STATE_ROOT_VERSION_COLUMN_FAMILY_NAME: ColumnFamilyName = "state_root_version";
 pub const TRANSACTION_EXECUTION_INFO_COLUMN_FAMILY_NAME: ColumnFamilyName =
     "transaction_execution_info";
 pub const EVENT_COLUMN_FAMILY_NAME: ColumnFamilyName = "event";
@@ -64,6 +68,10 @@ static VEC_COLUMN_FAMILY_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
         EVENT_HANDLE_COLUMN_FAMILY_NAME,
         CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME,
         CONFIG_GENESIS_COLUMN_FAMILY_NAME,
+        STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME,
+        STATE_NODE_REF_COUNT_INFO_COLUMN_FAMILY_NAME,
+        STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME,
+        STATE_ROOT_VERSION_COLUMN_FAMILY_NAME,
     ]
 });
 
@@ -101,9 +109,9 @@ impl MoveOSStore {
 
     pub fn new_with_instance(instance: StoreInstance, registry: &Registry) -> Result<Self> {
         let store_config = MoveOSStoreConfig::default();
-        let node_store = NodeDBStore::new(instance.clone());
         let state_store =
-            StateDBStore::new(node_store.clone(), registry, store_config.state_cache_size);
+            StateDBStore::new(instance.clone(), registry, store_config.state_cache_size);
+        let node_store = state_store.node_store.clone();
 
         let store = Self {
             node_store,
@@ -158,7 +166,8 @@ impl MoveOSStore {
         } = output;
 
         // node_store updates
-        let changed_nodes = self.state_store.change_set_to_nodes(&mut changeset)?;
+        let pre_state_root = changeset.state_root;
+        let tree_change_set = self.state_store.change_set_to_nodes(&mut changeset)?;
         // transaction_store updates
         let new_state_root = changeset.state_root;
         let size = changeset.global_size;
@@ -194,31 +203,31 @@ impl MoveOSStore {
         }
 
         // atomic save updates
-        let inner_store = self.node_store.get_store().store();
-        let mut cf_batches: Vec<WriteBatchCF> = Vec::new();
-        let write_batch = nodes_to_write_batch(changed_nodes);
-        cf_batches.push(WriteBatchCF {
-            batch: write_batch,
-            cf_name: STATE_NODE_COLUMN_FAMILY_NAME.to_string(),
-        });
-        cf_batches.push(WriteBatchCF {
-            batch: WriteBatch::new_with_rows(vec![(
-                to_bytes(STARTUP_INFO_KEY).unwrap(),
-                WriteOp::Value(to_bytes(&new_startup_info).unwrap()),
-            )]),
-            cf_name: CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME.to_string(),
-        });
-        cf_batches.push(WriteBatchCF {
-            batch: WriteBatch::new_with_rows(vec![(
-                to_bytes(&tx_hash).unwrap(),
-                WriteOp::Value(to_bytes(&execution_info).unwrap()),
-            )]),
-            cf_name: TRANSACTION_EXECUTION_INFO_COLUMN_FAMILY_NAME.to_string(),
-        });
+        let cf_batches: Vec<WriteBatchCF> = vec![
+            WriteBatchCF {
+                batch: WriteBatch::new_with_rows(vec![(
+                    to_bytes(STARTUP_INFO_KEY).unwrap(),
+                    WriteOp::Value(to_bytes(&new_startup_info).unwrap()),
+                )]),
+                cf_name: CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME.to_string(),
+            },
+            WriteBatchCF {
+                batch: WriteBatch::new_with_rows(vec![(
+                    to_bytes(&tx_hash).unwrap(),
+                    WriteOp::Value(to_bytes(&execution_info).unwrap()),
+                )]),
+                cf_name: TRANSACTION_EXECUTION_INFO_COLUMN_FAMILY_NAME.to_string(),
+            },
+        ];
         // use non-sync write here:
         // 1. we could replay tx from rooch store(which has sync write after sequenced) at startup.
         // 2. output write sequentially
-        inner_store.write_cf_batch(cf_batches, false)?;
+        self.state_store.write_tree_change_set(
+            pre_state_root,
+            tree_change_set,
+            cf_batches,
+            false,
+        )?;
 
         let out = TransactionOutput::new(status, changeset, events, gas_used, is_upgrade);
 
@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use metrics::metrics_util::LATENCY_SEC_BUCKETS;
-use prometheus::{register_histogram_vec_with_registry, HistogramVec, Registry};
+use prometheus::{
+    register_histogram_vec_with_registry, register_int_counter_with_registry, HistogramVec,
+    IntCounter, Registry,
+};
 
 #[derive(Debug)]
 pub struct StateDBMetrics {
@@ -17,6 +20,10 @@ pub struct StateDBMetrics {
     pub state_get_field_at_bytes: HistogramVec,
     pub state_list_fields_at_latency_seconds: HistogramVec,
     pub state_list_fields_at_bytes: HistogramVec,
+    pub state_write_tree_change_set_latency_seconds: HistogramVec,
+    pub state_prune_latency_seconds: HistogramVec,
+    pub state_pruned_nodes: IntCounter,
+    pub state_pruned_reclaimed_bytes: IntCounter,
 }
 
 impl StateDBMetrics {
@@ -119,6 +126,33 @@ impl StateDBMetrics {
                 registry,
             )
             .unwrap(),
+            state_write_tree_change_set_latency_seconds: register_histogram_vec_with_registry!(
+                "state_write_tree_change_set_latency_seconds",
+                "State write tree change set latency in seconds",
+                &["fn_name"],
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
+            state_prune_latency_seconds: register_histogram_vec_with_registry!(
+                "state_prune_latency_seconds",
+                "State prune latency in seconds",
+                &["fn_name"],
+                registry,
+            )
+            .unwrap(),
+            state_pruned_nodes: register_int_counter_with_registry!(
+                "state_pruned_nodes",
+                "The number of state nodes removed by the pruner",
+                registry,
+            )
+            .unwrap(),
+            state_pruned_reclaimed_bytes: register_int_counter_with_registry!(
+                "state_pruned_reclaimed_bytes",
+                "The bytes of state nodes reclaimed by the pruner",
+                registry,
+            )
+            .unwrap(),
         }
     }
 }
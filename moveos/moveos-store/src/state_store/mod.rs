@@ -4,17 +4,95 @@
 pub mod metrics;
 pub mod statedb;
 
-use crate::STATE_NODE_COLUMN_FAMILY_NAME;
-use anyhow::Result;
+use crate::{
+    STATE_NODE_COLUMN_FAMILY_NAME, STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME,
+    STATE_NODE_REF_COUNT_INFO_COLUMN_FAMILY_NAME, STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME,
+    STATE_ROOT_VERSION_COLUMN_FAMILY_NAME,
+};
+use anyhow::{format_err, Result};
 use moveos_types::h256::H256;
 use raw_store::rocks::batch::WriteBatch;
-use raw_store::CodecKVStore;
+use raw_store::traits::KVStore;
 use raw_store::{derive_store, WriteOp};
+use raw_store::{CodecKVStore, SchemaStore};
+use serde::{Deserialize, Serialize};
 use smt::{NodeReader, NodeWriter};
 use std::collections::BTreeMap;
 
+pub const NODE_REF_COUNT_INFO_KEY: &str = "node_ref_count_info";
+
 derive_store!(NodeDBStore, H256, Vec<u8>, STATE_NODE_COLUMN_FAMILY_NAME);
 
+derive_store!(
+    NodeRefCountDBStore,
+    H256,
+    NodeRefCount,
+    STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME
+);
+
+// The stale node index, (stale_since_version, node_hash) -> ()
+derive_store!(
+    StaleIndexDBStore,
+    StaleNodeIndex,
+    (),
+    STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME
+);
+
+derive_store!(
+    StateRootVersionDBStore,
+    H256,
+    u64,
+    STATE_ROOT_VERSION_COLUMN_FAMILY_NAME
+);
+
+derive_store!(
+    NodeRefCountInfoDBStore,
+    String,
+    NodeRefCountInfo,
+    STATE_NODE_REF_COUNT_INFO_COLUMN_FAMILY_NAME
+);
+
+/// The reference count of a state tree node, the number of the places the node is referenced in the latest state trees.
+/// The node without a reference count is written before the reference counts are tracked, it is never pruned.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NodeRefCount {
+    pub count: u64,
+    /// The state version since which the node is not referenced, it is only meaningful when the count is zero.
+    pub stale_since_version: u64,
+}
+
+/// The index of the node which is not referenced since the state version.
+/// The version is encoded in big-endian, so the index is ordered by the version in the store.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct StaleNodeIndex {
+    stale_since_version: [u8; 8],
+    pub node_hash: H256,
+}
+
+impl StaleNodeIndex {
+    pub fn new(stale_since_version: u64, node_hash: H256) -> Self {
+        Self {
+            stale_since_version: stale_since_version.to_be_bytes(),
+            node_hash,
+        }
+    }
+
+    pub fn stale_since_version(&self) -> u64 {
+        u64::from_be_bytes(self.stale_since_version)
+    }
+}
+
+/// The latest state tracked by the node reference counts.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NodeRefCountInfo {
+    pub state_root: H256,
+    /// The version of the `state_root`, it is increased by one for every applied change set.
+    pub version: u64,
+    /// A change set applied to another state root, such as after reverting a transaction,
+    /// makes the reference counts inconsistent, they must be rebuilt before pruning.
+    pub consistent: bool,
+}
+
 impl NodeDBStore {
     pub fn put(&self, key: H256, node: Vec<u8>) -> Result<()> {
         self.put_raw(key.as_bytes().to_vec(), node)
@@ -29,6 +107,46 @@ impl NodeDBStore {
         );
         self.write_batch_raw(batch)
     }
+
+    /// Get the nodes by the hashes, the nodes are stored with the raw hash bytes as the keys.
+    pub fn multiple_get_nodes(&self, nodes: &[H256]) -> Result<Vec<Option<Vec<u8>>>> {
+        KVStore::multiple_get(
+            self.get_store(),
+            nodes.iter().map(|node| node.as_bytes().to_vec()).collect(),
+        )
+    }
+
+    /// Get at most `limit` node hashes in order, starting from the `start_node`(inclusive).
+    pub fn node_hashes(&self, start_node: Option<H256>, limit: usize) -> Result<Vec<H256>> {
+        let db = self
+            .get_store()
+            .store()
+            .db()
+            .ok_or_else(|| format_err!("Only support scan on db store instance"))?;
+        let keys = db.raw_keys(
+            STATE_NODE_COLUMN_FAMILY_NAME,
+            start_node.as_ref().map(|node| node.as_bytes()),
+            limit,
+        )?;
+        Ok(keys.iter().map(|key| H256::from_slice(key)).collect())
+    }
+}
+
+impl StaleIndexDBStore {
+    /// Get at most `limit` stale node indexes in order, whose version is not greater than `max_version`.
+    pub fn get_stale_nodes(&self, max_version: u64, limit: usize) -> Result<Vec<StaleNodeIndex>> {
+        let mut iter = self.iter()?;
+        iter.seek_to_first();
+        let mut indexes = vec![];
+        for item in iter {
+            let (index, ()) = item?;
+            if indexes.len() >= limit || index.stale_since_version() > max_version {
+                break;
+            }
+            indexes.push(index);
+        }
+        Ok(indexes)
+    }
 }
 
 pub fn nodes_to_write_batch(nodes: BTreeMap<H256, Vec<u8>>) -> WriteBatch {
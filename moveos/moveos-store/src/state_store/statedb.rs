@@ -2,46 +2,93 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::state_store::metrics::StateDBMetrics;
-use crate::state_store::NodeDBStore;
-use anyhow::{Error, Ok, Result};
+use crate::state_store::{
+    nodes_to_write_batch, NodeDBStore, NodeRefCount, NodeRefCountDBStore, NodeRefCountInfo,
+    NodeRefCountInfoDBStore, StaleIndexDBStore, StaleNodeIndex, StateRootVersionDBStore,
+    NODE_REF_COUNT_INFO_KEY,
+};
+use crate::{
+    STATE_NODE_COLUMN_FAMILY_NAME, STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME,
+    STATE_NODE_REF_COUNT_INFO_COLUMN_FAMILY_NAME, STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME,
+    STATE_ROOT_VERSION_COLUMN_FAMILY_NAME,
+};
+use anyhow::{ensure, Error, Ok, Result};
+use bcs::to_bytes;
 use function_name::named;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::effects::Op;
 use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::ObjectID;
 use moveos_types::moveos_std::object::GENESIS_STATE_ROOT;
 use moveos_types::state::FieldKey;
 use moveos_types::state::ObjectChange;
 use moveos_types::state::ObjectState;
 use moveos_types::state::StateChangeSet;
+use moveos_types::state_proof::{StateProof, StateWithProof};
 use moveos_types::state_resolver::RootObjectResolver;
 use moveos_types::state_resolver::StateKV;
 use moveos_types::state_resolver::StateResolver;
 use moveos_types::state_resolver::StatelessResolver;
+use parking_lot::Mutex;
 use prometheus::Registry;
 use quick_cache::sync::Cache;
+use raw_store::rocks::batch::{WriteBatch, WriteBatchCF};
+use raw_store::traits::DBStore;
+use raw_store::{CodecKVStore, SchemaStore, StoreInstance, WriteOp};
 use smt::{SMTIterator, TreeChangeSet};
 use smt::{SMTree, UpdateSet};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 
 pub const STATEDB_DUMP_BATCH_SIZE: usize = 5000;
 
+/// The state tree nodes of a StateChangeSet
+#[derive(Debug, Clone, Default)]
+pub struct StateTreeChangeSet {
+    pub state_root: H256,
+    pub nodes: BTreeMap<H256, Vec<u8>>,
+    /// The changes of the node reference counts. A node is referenced once more by every state tree it is written to,
+    /// and once less by every state tree it is removed from.
+    pub ref_count_changes: BTreeMap<H256, i64>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct StatePruneResult {
+    /// The number of the checked stale node indexes
+    pub stale_indexes: u64,
+    pub pruned_nodes: u64,
+    pub reclaimed_bytes: u64,
+}
+
 /// StateDB provide state storage and state proof
 #[derive(Clone)]
 pub struct StateDBStore {
     pub node_store: NodeDBStore,
+    pub ref_count_store: NodeRefCountDBStore,
+    pub stale_index_store: StaleIndexDBStore,
+    pub root_version_store: StateRootVersionDBStore,
+    pub ref_count_info_store: NodeRefCountInfoDBStore,
     smt: SMTree<FieldKey, ObjectState, NodeDBStore>,
     metrics: Arc<StateDBMetrics>,
     cache: Arc<Cache<(H256, FieldKey), Option<ObjectState>>>,
+    /// The node writes and the node deletions of the pruner are serialized by this lock,
+    /// a node which is not referenced any more may be referenced again by a new change set.
+    node_lock: Arc<Mutex<()>>,
 }
 
 impl StateDBStore {
-    pub fn new(node_store: NodeDBStore, registry: &Registry, cache_size: usize) -> Self {
+    pub fn new(instance: StoreInstance, registry: &Registry, cache_size: usize) -> Self {
+        let node_store = NodeDBStore::new(instance.clone());
         Self {
             node_store: node_store.clone(),
+            ref_count_store: NodeRefCountDBStore::new(instance.clone()),
+            stale_index_store: StaleIndexDBStore::new(instance.clone()),
+            root_version_store: StateRootVersionDBStore::new(instance.clone()),
+            ref_count_info_store: NodeRefCountInfoDBStore::new(instance),
             smt: SMTree::new(node_store, registry),
             metrics: Arc::new(StateDBMetrics::new(registry)),
             cache: Arc::new(Cache::new(cache_size)),
+            node_lock: Arc::new(Mutex::new(())),
         }
     }
 
@@ -88,6 +135,7 @@ impl StateDBStore {
         &self,
         resolver: &dyn StateResolver,
         nodes: &mut BTreeMap<H256, Vec<u8>>,
+        ref_count_changes: &mut BTreeMap<H256, i64>,
         update_set: &mut UpdateSet<FieldKey, ObjectState>,
         field_key: FieldKey,
         obj_change: &mut ObjectChange,
@@ -98,10 +146,14 @@ impl StateDBStore {
                     ObjectState::new(obj_change.metadata.clone(), state.clone())
                 }
                 Op::Delete => {
-                    //TODO clean up the removed object fields
                     update_set.remove(field_key);
                     let pre_state_root = obj_change.metadata.state_root();
                     self.cache.remove(&(pre_state_root, field_key));
+                    // The state tree of the removed Object, including the child Objects, is not referenced any more
+                    self.visit_state_tree(pre_state_root, |node| {
+                        *ref_count_changes.entry(node).or_insert(0) -= 1;
+                        Ok(())
+                    })?;
                     return Ok(());
                 }
             },
@@ -121,14 +173,14 @@ impl StateDBStore {
             self.apply_object_change(
                 resolver,
                 nodes,
+                ref_count_changes,
                 &mut field_update_set,
                 *child_field_key,
                 child_change,
             )?;
         }
-        let mut tree_change_set = self.update_fields(obj.state_root(), field_update_set)?;
-        nodes.append(&mut tree_change_set.nodes);
-        let new_state_root = tree_change_set.state_root;
+        let tree_change_set = self.update_fields(obj.state_root(), field_update_set)?;
+        let new_state_root = merge_tree_change_set(nodes, ref_count_changes, tree_change_set);
         obj.update_state_root(new_state_root);
         obj_change.update_state_root(new_state_root);
         update_set.put(field_key, obj.clone());
@@ -141,7 +193,7 @@ impl StateDBStore {
     pub fn change_set_to_nodes(
         &self,
         state_change_set: &mut StateChangeSet,
-    ) -> Result<BTreeMap<H256, Vec<u8>>> {
+    ) -> Result<TreeChangeSet> {
         let fn_name = function_name!();
         let _timer = self
             .metrics
@@ -157,10 +209,12 @@ impl StateDBStore {
 
         let mut update_set = UpdateSet::new();
         let mut nodes = BTreeMap::new();
+        let mut ref_count_changes = BTreeMap::new();
         for (field_key, obj_change) in &mut state_change_set.changes {
             self.apply_object_change(
                 &resolver,
                 &mut nodes,
+                &mut ref_count_changes,
                 &mut update_set,
                 *field_key,
                 obj_change,
@@ -176,9 +230,11 @@ impl StateDBStore {
                 k_len + v_len
             })
             .sum::<usize>();
-        let mut tree_change_set = self.update_fields(pre_state_root, update_set)?;
-        let new_state_root = tree_change_set.state_root;
-        nodes.append(&mut tree_change_set.nodes);
+        let tree_change_set = self.update_fields(pre_state_root, update_set)?;
+        let new_state_root =
+            merge_tree_change_set(&mut nodes, &mut ref_count_changes, tree_change_set);
+        // The node removed from one Object may be written to another Object with the same content
+        ref_count_changes.retain(|_node, change| *change != 0);
         if tracing::enabled!(tracing::Level::DEBUG) {
             tracing::debug!(
                 "apply_change_set new_state_root: {:?}, smt nodes: {}, new_global_size: {}",
@@ -193,13 +249,166 @@ impl StateDBStore {
             .state_change_set_to_nodes_bytes
             .with_label_values(&[fn_name])
             .observe(size as f64);
-        Ok(nodes)
+        Ok(StateTreeChangeSet {
+            state_root: new_state_root,
+            nodes,
+            ref_count_changes,
+        })
     }
 
     pub fn apply_change_set(&self, state_change_set: &mut StateChangeSet) -> Result<()> {
-        let nodes = self.change_set_to_nodes(state_change_set)?;
-        self.node_store.write_nodes(nodes)?;
-        Ok(())
+        let pre_state_root = state_change_set.state_root;
+        let tree_change_set = self.change_set_to_nodes(state_change_set)?;
+        self.write_tree_change_set(pre_state_root, tree_change_set, vec![], false)
+    }
+
+    /// Write the nodes of the `tree_change_set` applied to the `pre_state_root` and the `extra_batches` atomically.
+    /// The reference counts of the nodes are updated in the same batch, the node which is not referenced any more
+    /// is added to the stale index with the version of the new state root.
+    #[named]
+    pub fn write_tree_change_set(
+        &self,
+        pre_state_root: H256,
+        tree_change_set: StateTreeChangeSet,
+        extra_batches: Vec<WriteBatchCF>,
+        sync: bool,
+    ) -> Result<()> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .state_write_tree_change_set_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let StateTreeChangeSet {
+            state_root,
+            nodes,
+            ref_count_changes,
+        } = tree_change_set;
+
+        let _guard = self.node_lock.lock();
+        let info = match self.get_ref_count_info()? {
+            Some(info) => NodeRefCountInfo {
+                state_root,
+                version: info.version + 1,
+                consistent: info.consistent && info.state_root == pre_state_root,
+            },
+            None => NodeRefCountInfo {
+                state_root,
+                version: 1,
+                consistent: true,
+            },
+        };
+        if !info.consistent {
+            tracing::warn!(
+                "The change set is applied to state root {:?}, the node reference counts are inconsistent",
+                pre_state_root
+            );
+        }
+
+        let (ref_count_rows, stale_index_rows) =
+            self.ref_count_rows(ref_count_changes, info.version)?;
+        let mut cf_batches = vec![
+            WriteBatchCF {
+                batch: nodes_to_write_batch(nodes),
+                cf_name: STATE_NODE_COLUMN_FAMILY_NAME.to_string(),
+            },
+            WriteBatchCF::new_with_rows(
+                ref_count_rows,
+                STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME.to_string(),
+            ),
+            WriteBatchCF::new_with_rows(
+                stale_index_rows,
+                STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME.to_string(),
+            ),
+            WriteBatchCF::new_with_rows(
+                vec![(
+                    to_bytes(&state_root)?,
+                    WriteOp::Value(to_bytes(&info.version)?),
+                )],
+                STATE_ROOT_VERSION_COLUMN_FAMILY_NAME.to_string(),
+            ),
+            WriteBatchCF::new_with_rows(
+                vec![(
+                    to_bytes(NODE_REF_COUNT_INFO_KEY)?,
+                    WriteOp::Value(to_bytes(&info)?),
+                )],
+                STATE_NODE_REF_COUNT_INFO_COLUMN_FAMILY_NAME.to_string(),
+            ),
+        ];
+        cf_batches.extend(extra_batches);
+        self.node_store
+            .get_store()
+            .store()
+            .write_cf_batch(cf_batches, sync)
+    }
+
+    /// The rows of the reference counts and the stale indexes after applying the `ref_count_changes` at the `version`.
+    /// It must be called with the node lock held.
+    #[allow(clippy::type_complexity)]
+    fn ref_count_rows(
+        &self,
+        ref_count_changes: BTreeMap<H256, i64>,
+        version: u64,
+    ) -> Result<(
+        Vec<(Vec<u8>, WriteOp<Vec<u8>>)>,
+        Vec<(Vec<u8>, WriteOp<Vec<u8>>)>,
+    )> {
+        let changed_nodes = ref_count_changes.keys().cloned().collect::<Vec<_>>();
+        let ref_counts = self.ref_count_store.multiple_get(changed_nodes.clone())?;
+        // The node without a reference count may be written before the reference counts are tracked,
+        // its references are unknown, so it is still not tracked.
+        let new_nodes = changed_nodes
+            .iter()
+            .zip(ref_counts.iter())
+            .filter(|(_node, ref_count)| ref_count.is_none())
+            .map(|(node, _ref_count)| *node)
+            .collect::<Vec<_>>();
+        let untracked_nodes = new_nodes
+            .iter()
+            .zip(self.node_store.multiple_get_nodes(&new_nodes)?)
+            .filter(|(_node, value)| value.is_some())
+            .map(|(node, _value)| *node)
+            .collect::<HashSet<_>>();
+
+        let mut ref_count_rows = vec![];
+        let mut stale_index_rows = vec![];
+        for ((node, change), ref_count) in ref_count_changes.into_iter().zip(ref_counts) {
+            if untracked_nodes.contains(&node) {
+                continue;
+            }
+            let mut ref_count = match ref_count {
+                Some(ref_count) => ref_count,
+                None if change < 0 => continue,
+                None => NodeRefCount::default(),
+            };
+            let count = (ref_count.count as i64).saturating_add(change);
+            if count < 0 {
+                // It should not happen, leave the node untracked so it is never pruned
+                tracing::warn!("The reference count of node {:?} is negative", node);
+                ref_count_rows.push((to_bytes(&node)?, WriteOp::Deletion));
+                continue;
+            }
+            ref_count.count = count as u64;
+            if ref_count.count == 0 {
+                ref_count.stale_since_version = version;
+                stale_index_rows.push((
+                    to_bytes(&StaleNodeIndex::new(version, node))?,
+                    WriteOp::Value(to_bytes(&())?),
+                ));
+            }
+            ref_count_rows.push((to_bytes(&node)?, WriteOp::Value(to_bytes(&ref_count)?)));
+        }
+        Ok((ref_count_rows, stale_index_rows))
+    }
+
+    pub fn get_ref_count_info(&self) -> Result<Option<NodeRefCountInfo>> {
+        self.ref_count_info_store
+            .kv_get(NODE_REF_COUNT_INFO_KEY.to_string())
+    }
+
+    /// The version of the state root, it is None if the state root is written before the versions are tracked.
+    pub fn get_state_root_version(&self, state_root: H256) -> Result<Option<u64>> {
+        self.root_version_store.kv_get(state_root)
     }
 
     #[named]
@@ -216,6 +425,286 @@ impl StateDBStore {
             .start_timer();
         self.smt.iter(state_root, starting_key)
     }
+
+    /// Remove the nodes which are not referenced since a version not greater than `max_version`,
+    /// at most `limit` stale node indexes are checked. The stale index is out of date if the node is referenced
+    /// again after the version, then only the index is removed.
+    /// The state roots of the versions before `max_version` are not readable after pruning.
+    #[named]
+    pub fn prune(&self, max_version: u64, limit: usize) -> Result<StatePruneResult> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .state_prune_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+
+        let indexes = self.stale_index_store.get_stale_nodes(max_version, limit)?;
+        let mut result = StatePruneResult {
+            stale_indexes: indexes.len() as u64,
+            ..Default::default()
+        };
+        if indexes.is_empty() {
+            return Ok(result);
+        }
+
+        let _guard = self.node_lock.lock();
+        ensure!(
+            self.get_ref_count_info()?
+                .map(|info| info.consistent)
+                .unwrap_or_default(),
+            "The node reference counts are inconsistent, rebuild them before pruning"
+        );
+        let nodes = indexes
+            .iter()
+            .map(|index| index.node_hash)
+            .collect::<Vec<_>>();
+        let ref_counts = self.ref_count_store.multiple_get(nodes.clone())?;
+        let values = self.node_store.multiple_get_nodes(&nodes)?;
+        let mut node_rows = vec![];
+        let mut ref_count_rows = vec![];
+        let mut stale_index_rows = vec![];
+        for ((index, ref_count), value) in indexes.iter().zip(ref_counts).zip(values) {
+            stale_index_rows.push((to_bytes(index)?, WriteOp::Deletion));
+            let is_stale = ref_count
+                .map(|ref_count| {
+                    ref_count.count == 0
+                        && ref_count.stale_since_version == index.stale_since_version()
+                })
+                .unwrap_or_default();
+            if !is_stale {
+                continue;
+            }
+            ref_count_rows.push((to_bytes(&index.node_hash)?, WriteOp::Deletion));
+            if let Some(value) = value {
+                result.pruned_nodes += 1;
+                result.reclaimed_bytes += (index.node_hash.as_bytes().len() + value.len()) as u64;
+                node_rows.push((index.node_hash.0.to_vec(), WriteOp::Deletion));
+            }
+        }
+        self.node_store.get_store().store().write_cf_batch(
+            vec![
+                WriteBatchCF::new_with_rows(node_rows, STATE_NODE_COLUMN_FAMILY_NAME.to_string()),
+                WriteBatchCF::new_with_rows(
+                    ref_count_rows,
+                    STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME.to_string(),
+                ),
+                WriteBatchCF::new_with_rows(
+                    stale_index_rows,
+                    STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME.to_string(),
+                ),
+            ],
+            false,
+        )?;
+
+        self.metrics.state_pruned_nodes.inc_by(result.pruned_nodes);
+        self.metrics
+            .state_pruned_reclaimed_bytes
+            .inc_by(result.reclaimed_bytes);
+        Ok(result)
+    }
+
+    /// Reference the nodes of the state tree of `state_root` once more, they are not pruned until it is unpinned.
+    /// The state root must not be pruned.
+    pub fn pin_state_root(&self, state_root: H256) -> Result<()> {
+        self.change_state_tree_ref_counts(state_root, 1)
+    }
+
+    /// Reference the nodes of the state tree of the pinned `state_root` once less.
+    pub fn unpin_state_root(&self, state_root: H256) -> Result<()> {
+        self.change_state_tree_ref_counts(state_root, -1)
+    }
+
+    fn change_state_tree_ref_counts(&self, state_root: H256, change: i64) -> Result<()> {
+        let mut ref_count_changes = BTreeMap::new();
+        self.visit_state_tree(state_root, |node| {
+            *ref_count_changes.entry(node).or_insert(0) += change;
+            if ref_count_changes.len() >= STATEDB_DUMP_BATCH_SIZE {
+                self.write_ref_count_changes(std::mem::take(&mut ref_count_changes))?;
+            }
+            Ok(())
+        })?;
+        self.write_ref_count_changes(ref_count_changes)
+    }
+
+    fn write_ref_count_changes(&self, ref_count_changes: BTreeMap<H256, i64>) -> Result<()> {
+        let _guard = self.node_lock.lock();
+        let version = self
+            .get_ref_count_info()?
+            .map(|info| info.version)
+            .unwrap_or_default();
+        let (ref_count_rows, stale_index_rows) = self.ref_count_rows(ref_count_changes, version)?;
+        self.node_store.get_store().store().write_cf_batch(
+            vec![
+                WriteBatchCF::new_with_rows(
+                    ref_count_rows,
+                    STATE_NODE_REF_COUNT_COLUMN_FAMILY_NAME.to_string(),
+                ),
+                WriteBatchCF::new_with_rows(
+                    stale_index_rows,
+                    STATE_NODE_STALE_INDEX_COLUMN_FAMILY_NAME.to_string(),
+                ),
+            ],
+            false,
+        )
+    }
+
+    /// Rebuild the node reference counts from the state trees of the `state_root` and the `pinned_roots`,
+    /// and remove all the other nodes, including the nodes written before the reference counts are tracked.
+    /// The other state roots are not readable after rebuilding.
+    /// It must be called when no change set is written, such as in the `rooch db prune --rebuild` command.
+    pub fn rebuild_ref_counts(
+        &self,
+        state_root: H256,
+        pinned_roots: &[H256],
+    ) -> Result<StatePruneResult> {
+        let _guard = self.node_lock.lock();
+        let mut result = StatePruneResult::default();
+
+        // Clear the reference counts and the stale indexes
+        loop {
+            let mut iter = self.ref_count_store.iter()?;
+            iter.seek_to_first();
+            let nodes = iter
+                .take(STATEDB_DUMP_BATCH_SIZE)
+                .map(|item| item.map(|(node, _ref_count)| node))
+                .collect::<Result<Vec<_>>>()?;
+            if nodes.is_empty() {
+                break;
+            }
+            self.ref_count_store.delete_all(nodes)?;
+        }
+        loop {
+            let indexes = self
+                .stale_index_store
+                .get_stale_nodes(u64::MAX, STATEDB_DUMP_BATCH_SIZE)?;
+            if indexes.is_empty() {
+                break;
+            }
+            result.stale_indexes += indexes.len() as u64;
+            self.stale_index_store.delete_all(indexes)?;
+        }
+
+        // Count the references of the nodes
+        let mut ref_counts: HashMap<H256, u64> = HashMap::new();
+        for root in std::iter::once(&state_root).chain(pinned_roots) {
+            self.visit_state_tree(*root, |node| {
+                *ref_counts.entry(node).or_insert(0) += 1;
+                if ref_counts.len() >= STATEDB_DUMP_BATCH_SIZE {
+                    self.add_ref_counts(std::mem::take(&mut ref_counts))?;
+                }
+                Ok(())
+            })?;
+        }
+        self.add_ref_counts(ref_counts)?;
+
+        // Remove the nodes without reference counts
+        let mut start_node = None;
+        loop {
+            let mut nodes = self
+                .node_store
+                .node_hashes(start_node, STATEDB_DUMP_BATCH_SIZE)?;
+            if start_node.is_some() && nodes.first() == start_node.as_ref() {
+                nodes.remove(0);
+            }
+            let Some(last_node) = nodes.last().cloned() else {
+                break;
+            };
+            let ref_counts = self.ref_count_store.multiple_get(nodes.clone())?;
+            let values = self.node_store.multiple_get_nodes(&nodes)?;
+            let mut node_rows = vec![];
+            for ((node, ref_count), value) in nodes.into_iter().zip(ref_counts).zip(values) {
+                if let (None, Some(value)) = (ref_count, value) {
+                    result.pruned_nodes += 1;
+                    result.reclaimed_bytes += (node.as_bytes().len() + value.len()) as u64;
+                    node_rows.push((node.0.to_vec(), WriteOp::Deletion));
+                }
+            }
+            self.node_store
+                .write_batch_raw(WriteBatch::new_with_rows(node_rows))?;
+            start_node = Some(last_node);
+        }
+
+        let info = NodeRefCountInfo {
+            state_root,
+            version: self
+                .get_ref_count_info()?
+                .map(|info| info.version)
+                .unwrap_or_default(),
+            consistent: true,
+        };
+        self.root_version_store.kv_put(state_root, info.version)?;
+        self.ref_count_info_store
+            .put_sync(NODE_REF_COUNT_INFO_KEY.to_string(), info)?;
+        self.metrics.state_pruned_nodes.inc_by(result.pruned_nodes);
+        self.metrics
+            .state_pruned_reclaimed_bytes
+            .inc_by(result.reclaimed_bytes);
+        Ok(result)
+    }
+
+    fn add_ref_counts(&self, ref_counts: HashMap<H256, u64>) -> Result<()> {
+        let nodes = ref_counts.keys().cloned().collect::<Vec<_>>();
+        let stored_ref_counts = self.ref_count_store.multiple_get(nodes)?;
+        let rows = ref_counts
+            .into_iter()
+            .zip(stored_ref_counts)
+            .map(|((node, count), stored)| {
+                let mut ref_count = stored.unwrap_or_default();
+                ref_count.count += count;
+                (node, ref_count)
+            })
+            .collect::<Vec<_>>();
+        self.ref_count_store.put_all(rows)
+    }
+
+    /// Visit the nodes of the state tree of `state_root`, including the nodes of the child Objects.
+    /// A node is visited once for every place it is referenced.
+    fn visit_state_tree<F>(&self, state_root: H256, mut visitor: F) -> Result<()>
+    where
+        F: FnMut(H256) -> Result<()>,
+    {
+        let mut roots = vec![state_root];
+        while let Some(root) = roots.pop() {
+            self.smt.visit_nodes(root, |node, value| {
+                visitor(node)?;
+                if let Some(obj) = value {
+                    roots.push(obj.state_root());
+                }
+                Ok(true)
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Get the field of the Object with the proof in the state tree of the `state_root`.
+    /// The Object and its ancestors are resolved from the `state_root` level by level,
+    /// if one of them does not exist, return None with the non-inclusion proof.
+    pub fn get_field_with_proof(
+        &self,
+        state_root: H256,
+        object_id: &ObjectID,
+        field_key: FieldKey,
+    ) -> Result<StateWithProof> {
+        let mut state_root = state_root;
+        let mut ancestors = vec![];
+        for ancestor_id in object_id.path_from_root() {
+            let (ancestor, proof) = self
+                .smt
+                .get_with_proof(state_root, ancestor_id.field_key())?;
+            match ancestor {
+                Some(ancestor) => {
+                    state_root = ancestor.state_root();
+                    ancestors.push((ancestor, proof));
+                }
+                None => {
+                    return Ok(StateWithProof::new(None, StateProof { ancestors, proof }));
+                }
+            }
+        }
+        let (state, proof) = self.smt.get_with_proof(state_root, field_key)?;
+        Ok(StateWithProof::new(state, StateProof { ancestors, proof }))
+    }
 }
 
 impl StatelessResolver for StateDBStore {
@@ -294,3 +783,24 @@ impl StatelessResolver for StateDBStore {
         Ok(result)
     }
 }
+
+/// Merge the nodes of the `tree_change_set` and count the references, returns the new state root.
+fn merge_tree_change_set(
+    nodes: &mut BTreeMap<H256, Vec<u8>>,
+    ref_count_changes: &mut BTreeMap<H256, i64>,
+    tree_change_set: TreeChangeSet,
+) -> H256 {
+    let TreeChangeSet {
+        state_root,
+        nodes: mut new_nodes,
+        stale_nodes,
+    } = tree_change_set;
+    for node in new_nodes.keys() {
+        *ref_count_changes.entry(*node).or_insert(0) += 1;
+    }
+    for node in stale_nodes {
+        *ref_count_changes.entry(node).or_insert(0) -= 1;
+    }
+    nodes.append(&mut new_nodes);
+    state_root
+}
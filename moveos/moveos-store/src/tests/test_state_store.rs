@@ -1,15 +1,22 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::state_store::statedb::{StateDBStore, StatePruneResult};
+use crate::state_store::StaleNodeIndex;
 use crate::{MoveOSStore, StoreMeta};
 use anyhow::Result;
+use move_core_types::effects::Op;
 use moveos_config::store_config::RocksdbConfig;
 use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::{FieldKey, ObjectChange, StateChangeSet};
 use moveos_types::test_utils::random_state_change_set;
 use raw_store::metrics::DBMetrics;
 use raw_store::rocks::RocksDB;
-use raw_store::{StoreInstance, CF_METRICS_REPORT_PERIOD_MILLIS};
+use raw_store::{CodecKVStore, StoreInstance, CF_METRICS_REPORT_PERIOD_MILLIS};
 use smt::NodeReader;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 #[tokio::test]
@@ -71,6 +78,362 @@ async fn test_statedb_state_root() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_statedb_get_field_with_proof() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let state_store = moveos_store.get_state_store();
+    let mut change_set = random_state_change_set();
+    state_store.apply_change_set(&mut change_set)?;
+    let state_root = change_set.state_root;
+
+    for (field_key, object_change) in change_set.changes.iter() {
+        let object_id = object_change.metadata.id.clone();
+        let object_with_proof =
+            state_store.get_field_with_proof(state_root, &ObjectID::root(), *field_key)?;
+        assert!(object_with_proof.state.is_some());
+        object_with_proof.verify(state_root, &ObjectID::root(), *field_key)?;
+        assert!(object_with_proof
+            .verify(H256::random(), &ObjectID::root(), *field_key)
+            .is_err());
+
+        for child_field_key in object_change.fields.keys() {
+            let field_with_proof =
+                state_store.get_field_with_proof(state_root, &object_id, *child_field_key)?;
+            assert!(field_with_proof.state.is_some());
+            assert_eq!(field_with_proof.proof.ancestors.len(), 1);
+            field_with_proof.verify(state_root, &object_id, *child_field_key)?;
+        }
+    }
+
+    // The object does not exist, verify the non-inclusion proof of the missing object
+    let missing_object_id = ObjectID::random();
+    let missing_field_key = FieldKey::random();
+    let missing_with_proof =
+        state_store.get_field_with_proof(state_root, &missing_object_id, missing_field_key)?;
+    assert!(missing_with_proof.state.is_none());
+    assert!(missing_with_proof.proof.ancestors.is_empty());
+    missing_with_proof.verify(state_root, &missing_object_id, missing_field_key)?;
+    Ok(())
+}
+
+/// Assert all the Objects and their fields of the `change_set` are readable from the `state_root`.
+fn assert_change_set_readable(
+    state_store: &StateDBStore,
+    state_root: H256,
+    change_set: &StateChangeSet,
+) -> Result<()> {
+    for (field_key, object_change) in change_set.changes.iter() {
+        let object_id = object_change.metadata.id.clone();
+        let object_with_proof =
+            state_store.get_field_with_proof(state_root, &ObjectID::root(), *field_key)?;
+        assert!(object_with_proof.state.is_some());
+        for child_field_key in object_change.fields.keys() {
+            let field_with_proof =
+                state_store.get_field_with_proof(state_root, &object_id, *child_field_key)?;
+            assert!(field_with_proof.state.is_some());
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statedb_prune() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let state_store = moveos_store.get_state_store();
+    let mut change_set = random_state_change_set();
+    state_store.apply_change_set(&mut change_set)?;
+    let pre_state_root = change_set.state_root;
+
+    let mut new_change_set = random_state_change_set();
+    new_change_set.state_root = pre_state_root;
+    new_change_set.global_size += change_set.global_size;
+    state_store.apply_change_set(&mut new_change_set)?;
+    let state_root = new_change_set.state_root;
+
+    assert_eq!(state_store.get_state_root_version(pre_state_root)?, Some(1));
+    assert_eq!(state_store.get_state_root_version(state_root)?, Some(2));
+    let info = state_store
+        .get_ref_count_info()?
+        .expect("ref count info should exist");
+    assert_eq!(info.state_root, state_root);
+    assert_eq!(info.version, 2);
+    assert!(info.consistent);
+
+    // The root node of the previous state tree is not referenced since version 2
+    let ref_count = state_store
+        .ref_count_store
+        .kv_get(pre_state_root)?
+        .expect("ref count should exist");
+    assert_eq!(ref_count.count, 0);
+    assert_eq!(ref_count.stale_since_version, 2);
+    let stale_nodes = state_store.stale_index_store.get_stale_nodes(2, 1000)?;
+    assert!(stale_nodes.contains(&StaleNodeIndex::new(2, pre_state_root)));
+    assert!(state_store
+        .stale_index_store
+        .get_stale_nodes(1, 1000)?
+        .is_empty());
+
+    // Nothing is pruned before the version
+    let result = state_store.prune(1, 1000)?;
+    assert_eq!(result, StatePruneResult::default());
+
+    let result = state_store.prune(2, 1000)?;
+    assert_eq!(result.stale_indexes, stale_nodes.len() as u64);
+    assert!(result.pruned_nodes > 0);
+    assert!(result.reclaimed_bytes > 0);
+    assert!(moveos_store
+        .get_state_node_store()
+        .get(&pre_state_root)?
+        .is_none());
+    assert!(state_store
+        .ref_count_store
+        .kv_get(pre_state_root)?
+        .is_none());
+    assert!(state_store
+        .stale_index_store
+        .get_stale_nodes(u64::MAX, 1000)?
+        .is_empty());
+
+    // All the fields are still readable from the latest state root
+    assert_change_set_readable(state_store, state_root, &change_set)?;
+    assert_change_set_readable(state_store, state_root, &new_change_set)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statedb_prune_pinned_state_root() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let state_store = moveos_store.get_state_store();
+    let mut change_set = random_state_change_set();
+    state_store.apply_change_set(&mut change_set)?;
+    let pinned_root = change_set.state_root;
+
+    let mut new_change_set = random_state_change_set();
+    new_change_set.state_root = pinned_root;
+    state_store.apply_change_set(&mut new_change_set)?;
+    let state_root = new_change_set.state_root;
+
+    state_store.pin_state_root(pinned_root)?;
+    let result = state_store.prune(2, 1000)?;
+    assert!(result.stale_indexes > 0);
+    assert_eq!(result.pruned_nodes, 0);
+    assert_change_set_readable(state_store, pinned_root, &change_set)?;
+
+    // The nodes only referenced by the pinned state root are pruned after unpinning
+    state_store.unpin_state_root(pinned_root)?;
+    let result = state_store.prune(2, 1000)?;
+    assert!(result.pruned_nodes > 0);
+    assert!(moveos_store
+        .get_state_node_store()
+        .get(&pinned_root)?
+        .is_none());
+    assert_change_set_readable(state_store, state_root, &change_set)?;
+    assert_change_set_readable(state_store, state_root, &new_change_set)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statedb_prune_deleted_object() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let state_store = moveos_store.get_state_store();
+    let mut change_set = random_state_change_set();
+    state_store.apply_change_set(&mut change_set)?;
+    let pre_state_root = change_set.state_root;
+
+    // Delete an Object with fields
+    let (field_key, object_change) = change_set
+        .changes
+        .iter()
+        .next()
+        .expect("change set should not be empty");
+    let object_state_root = object_change.metadata.state_root();
+    let field_state_roots = object_change
+        .fields
+        .values()
+        .map(|field_change| field_change.metadata.state_root())
+        .collect::<Vec<_>>();
+    let mut delete_change_set = StateChangeSet::new(pre_state_root, change_set.global_size);
+    delete_change_set.add_change(ObjectChange::new(
+        object_change.metadata.clone(),
+        Op::Delete,
+    ))?;
+    state_store.apply_change_set(&mut delete_change_set)?;
+    let state_root = delete_change_set.state_root;
+
+    // The state tree of the deleted Object is not referenced since version 2
+    let ref_count = state_store
+        .ref_count_store
+        .kv_get(object_state_root)?
+        .expect("ref count should exist");
+    assert_eq!(ref_count.count, 0);
+    assert_eq!(ref_count.stale_since_version, 2);
+
+    let result = state_store.prune(2, 1000)?;
+    assert!(result.pruned_nodes > 0);
+    let node_store = moveos_store.get_state_node_store();
+    assert!(node_store.get(&object_state_root)?.is_none());
+    for field_state_root in field_state_roots {
+        assert!(node_store.get(&field_state_root)?.is_none());
+    }
+    assert!(state_store
+        .stale_index_store
+        .get_stale_nodes(u64::MAX, 1000)?
+        .is_empty());
+
+    assert!(state_store
+        .get_field_with_proof(state_root, &ObjectID::root(), *field_key)?
+        .state
+        .is_none());
+    for (other_field_key, other_object_change) in change_set.changes.iter() {
+        if other_field_key == field_key {
+            continue;
+        }
+        let object_id = other_object_change.metadata.id.clone();
+        for child_field_key in other_object_change.fields.keys() {
+            let field_with_proof =
+                state_store.get_field_with_proof(state_root, &object_id, *child_field_key)?;
+            assert!(field_with_proof.state.is_some());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_statedb_prune_concurrently_with_writes() -> Result<()> {
+    let (moveos_store, _tmpdir) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let state_store = moveos_store.get_state_store().clone();
+    let objects = random_state_change_set();
+    let mut change_set = objects.clone();
+    state_store.apply_change_set(&mut change_set)?;
+    let mut state_root = change_set.state_root;
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let pruner = {
+        let state_store = state_store.clone();
+        let stop = stop.clone();
+        std::thread::spawn(move || -> Result<u64> {
+            let mut pruned_nodes = 0;
+            while !stop.load(Ordering::Relaxed) {
+                // Only the latest state root is kept
+                let version = state_store
+                    .get_ref_count_info()?
+                    .map(|info| info.version)
+                    .unwrap_or_default();
+                pruned_nodes += state_store.prune(version, 10)?.pruned_nodes;
+            }
+            Ok(pruned_nodes)
+        })
+    };
+
+    for i in 0..100 {
+        // Modify the Objects and change them back, the nodes of the Objects are referenced again after they are stale
+        let mut change_set = objects.clone();
+        if i % 2 == 1 {
+            for object_change in change_set.changes.values_mut() {
+                object_change.value = Some(Op::Modify(H256::random().0.to_vec()));
+            }
+        }
+        change_set.state_root = state_root;
+        state_store.apply_change_set(&mut change_set)?;
+        state_root = change_set.state_root;
+        assert_change_set_readable(&state_store, state_root, &objects)?;
+    }
+    stop.store(true, Ordering::Relaxed);
+    let mut pruned_nodes = pruner.join().expect("pruner thread should not panic")?;
+
+    let version = state_store
+        .get_ref_count_info()?
+        .expect("ref count info should exist")
+        .version;
+    loop {
+        let result = state_store.prune(version, 1000)?;
+        pruned_nodes += result.pruned_nodes;
+        if result.stale_indexes == 0 {
+            break;
+        }
+    }
+    assert!(pruned_nodes > 0);
+    assert_change_set_readable(&state_store, state_root, &objects)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statedb_rebuild_ref_counts() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let state_store = moveos_store.get_state_store();
+    // The nodes written without tracking the reference counts are never pruned
+    let mut untracked_change_set = random_state_change_set();
+    let tree_change_set = state_store.change_set_to_nodes(&mut untracked_change_set)?;
+    state_store.node_store.write_nodes(tree_change_set.nodes)?;
+    let untracked_root = untracked_change_set.state_root;
+
+    let mut change_set = random_state_change_set();
+    change_set.state_root = untracked_root;
+    state_store.apply_change_set(&mut change_set)?;
+    let state_root = change_set.state_root;
+    let version = state_store.get_state_root_version(state_root)?.unwrap();
+    let result = state_store.prune(version, 1000)?;
+    assert_eq!(result.pruned_nodes, 0);
+    assert!(moveos_store
+        .get_state_node_store()
+        .get(&untracked_root)?
+        .is_some());
+
+    let result = state_store.rebuild_ref_counts(state_root, &[])?;
+    assert!(result.pruned_nodes > 0);
+    assert!(moveos_store
+        .get_state_node_store()
+        .get(&untracked_root)?
+        .is_none());
+    assert_eq!(
+        state_store
+            .ref_count_store
+            .kv_get(state_root)?
+            .map(|ref_count| ref_count.count),
+        Some(1)
+    );
+    assert!(state_store.get_ref_count_info()?.unwrap().consistent);
+    assert_change_set_readable(state_store, state_root, &untracked_change_set)?;
+    assert_change_set_readable(state_store, state_root, &change_set)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statedb_ref_counts_inconsistent() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let state_store = moveos_store.get_state_store();
+    let mut change_set = random_state_change_set();
+    state_store.apply_change_set(&mut change_set)?;
+    let pre_state_root = change_set.state_root;
+    let mut new_change_set = random_state_change_set();
+    new_change_set.state_root = pre_state_root;
+    state_store.apply_change_set(&mut new_change_set)?;
+
+    // Apply a change set to the previous state root, such as after reverting a transaction
+    let mut revert_change_set = random_state_change_set();
+    revert_change_set.state_root = pre_state_root;
+    state_store.apply_change_set(&mut revert_change_set)?;
+    assert!(!state_store.get_ref_count_info()?.unwrap().consistent);
+    assert!(state_store.prune(3, 1000).is_err());
+
+    state_store.rebuild_ref_counts(revert_change_set.state_root, &[])?;
+    assert!(state_store.get_ref_count_info()?.unwrap().consistent);
+    assert_change_set_readable(state_store, revert_change_set.state_root, &change_set)?;
+    assert_change_set_readable(
+        state_store,
+        revert_change_set.state_root,
+        &revert_change_set,
+    )?;
+    Ok(())
+}
+
 // #[tokio::test]
 // async fn test_child_state_db_dump_and_apply() -> Result<()> {
 //     let mut moveos_store = MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
@@ -159,6 +159,12 @@ impl TransactionEvent {
             event_index,
         }
     }
+
+    /// The hash of the event, it is the same as the hash of the stored `Event`,
+    /// so the event root of a transaction can be calculated before the event ids are assigned.
+    pub fn hash(&self) -> H256 {
+        h256::sha3_256_of(&self.event_data)
+    }
 }
 
 /// The Event type in the event store
@@ -120,6 +120,14 @@ impl ObjectID {
         }
     }
 
+    /// Get the ObjectIDs from the child of the root Object down to the current ObjectID,
+    /// the result is empty if the current ObjectID is the root.
+    pub fn path_from_root(&self) -> Vec<Self> {
+        (1..=self.0.len())
+            .map(|len| Self(self.0[..len].to_vec()))
+            .collect()
+    }
+
     pub fn is_root(&self) -> bool {
         self.0.is_empty()
     }
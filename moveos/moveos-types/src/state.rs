@@ -28,7 +28,7 @@ use primitive_types::H256;
 use serde::{
     de::DeserializeOwned, de::Error as _, Deserialize, Deserializer, Serialize, Serializer,
 };
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::fmt::Debug;
 use std::str::FromStr;
@@ -948,6 +948,14 @@ impl ObjectChange {
     pub fn update_state_root(&mut self, new_state_root: H256) {
         self.metadata.update_state_root(new_state_root);
     }
+
+    /// Collect the ids of this Object and all the changed descendant Objects.
+    pub fn collect_object_ids(&self, ids: &mut BTreeSet<ObjectID>) {
+        ids.insert(self.metadata.id.clone());
+        for field_change in self.fields.values() {
+            field_change.collect_object_ids(ids);
+        }
+    }
 }
 
 /// Global State change set.
@@ -1019,6 +1027,15 @@ impl StateChangeSet {
         let change = ObjectChange::new(metadata, Op::New(value));
         self.add_change(change)
     }
+
+    /// The ids of all the changed Objects, the ancestors of a changed Object are also included.
+    pub fn write_set(&self) -> BTreeSet<ObjectID> {
+        let mut ids = BTreeSet::new();
+        for change in self.changes.values() {
+            change.collect_object_ids(&mut ids);
+        }
+        ids
+    }
 }
 
 impl Default for StateChangeSet {
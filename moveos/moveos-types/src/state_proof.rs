@@ -0,0 +1,81 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::h256::H256;
+use crate::moveos_std::object::ObjectID;
+use crate::state::{FieldKey, ObjectState};
+use anyhow::{ensure, Result};
+use serde::{Deserialize, Serialize};
+pub use smt::SparseMerkleProof;
+
+/// The proof of a field state in the global state tree.
+/// Every Object has its own state tree, so the proof contains the proofs of all the ancestor Objects,
+/// from the child of the root Object down to the parent Object of the field.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StateProof {
+    /// The ancestor Objects and their proofs in the state tree of their parent Object.
+    pub ancestors: Vec<(ObjectState, SparseMerkleProof)>,
+    /// The proof of the field in the state tree of its parent Object.
+    /// If an ancestor Object does not exist, it is the non-inclusion proof of the missing ancestor.
+    pub proof: SparseMerkleProof,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StateWithProof {
+    pub state: Option<ObjectState>,
+    pub proof: StateProof,
+}
+
+impl StateWithProof {
+    pub fn new(state: Option<ObjectState>, proof: StateProof) -> Self {
+        Self { state, proof }
+    }
+
+    /// Verify the field `field_key` of the Object `object_id` is the `state` in the state tree of the `state_root`.
+    /// If the `state` is None, verify the field or one of its ancestor Objects does not exist.
+    pub fn verify(
+        &self,
+        state_root: H256,
+        object_id: &ObjectID,
+        field_key: FieldKey,
+    ) -> Result<()> {
+        let path = object_id.path_from_root();
+        ensure!(
+            self.proof.ancestors.len() <= path.len(),
+            "Too many ancestors in the proof, expect at most {}, got {}",
+            path.len(),
+            self.proof.ancestors.len()
+        );
+
+        let mut state_root = state_root;
+        for (ancestor_id, (ancestor, proof)) in path.iter().zip(self.proof.ancestors.iter()) {
+            ensure!(
+                ancestor.id() == ancestor_id,
+                "Invalid ancestor in the proof, expect {}, got {}",
+                ancestor_id,
+                ancestor.id()
+            );
+            proof.verify(state_root, ancestor_id.field_key(), Some(ancestor.clone()))?;
+            state_root = ancestor.state_root();
+        }
+
+        match path.get(self.proof.ancestors.len()) {
+            Some(missing_ancestor_id) => {
+                ensure!(
+                    self.state.is_none(),
+                    "The ancestor Object {} is missing, but the state is not None",
+                    missing_ancestor_id
+                );
+                self.proof.proof.verify::<FieldKey, ObjectState>(
+                    state_root,
+                    missing_ancestor_id.field_key(),
+                    None,
+                )
+            }
+            None => self
+                .proof
+                .proof
+                .verify(state_root, field_key, self.state.clone()),
+        }
+    }
+}
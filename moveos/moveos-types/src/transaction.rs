@@ -400,6 +400,18 @@ impl VerifiedMoveOSTransaction {
     }
 }
 
+/// A call in the call tree of a traced transaction, the callees are in the order of the calls.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CallTrace {
+    /// The called function, None for the script
+    pub function: Option<FunctionId>,
+    pub ty_args: Vec<TypeTag>,
+    pub is_native: bool,
+    /// The gas used by the call, including the callees
+    pub gas_used: u64,
+    pub calls: Vec<CallTrace>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct VMErrorInfo {
     pub error_message: String,
@@ -87,6 +87,10 @@ pub enum ErrorCode {
     INVALID_DATA_STRUCT_FUNC_INCOMPATIBLE_WITH_EXISTS = 13008,
     INVALID_PRIVATE_GENERICS_INCOMPATIBLE_WITH_EXISTS = 13009,
 
+    INVALID_VIEW_FUNCTION_SIGNATURE = 14000,
+    INVALID_PARAM_TYPE_VIEW_FUNCTION = 14001,
+    INVALID_VIEW_FUNCTION_MODIFY_STATE = 14002,
+
     UNKNOWN_CODE = 18446744073709551615,
 }
 }
@@ -52,6 +52,29 @@ pub const DATA_STRUCT_ATTRIBUTE: &str = "data_struct";
 
 pub const DATA_STRUCT_FUNC_ATTRIBUTE: &str = "data_struct_func";
 
+pub const VIEW_FUNCTION_ATTRIBUTE: &str = "view";
+
+/// The native functions which change the global state or emit events,
+/// a #[view] function should not call them directly or indirectly.
+const STATE_MUTATING_FUNCTIONS: &[&str] = &[
+    "0x2::object::native_transfer_object",
+    "0x2::object::native_to_shared_object",
+    "0x2::object::native_to_frozen_object",
+    "0x2::object::native_borrow_mut_object",
+    "0x2::object::native_take_object",
+    "0x2::object::native_add_field",
+    "0x2::object::native_borrow_mut_field",
+    "0x2::object::native_remove_field",
+    "0x2::event::native_emit",
+    "0x2::tx_context::borrow_mut_inner",
+];
+
+/// The kind of the known attributes saved in the metadata
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnownAttributeKind {
+    ViewFunction = 1,
+}
+
 /// Enumeration of potentially known attributes
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct KnownAttribute {
@@ -59,6 +82,19 @@ pub struct KnownAttribute {
     args: Vec<String>,
 }
 
+impl KnownAttribute {
+    pub fn view_function() -> Self {
+        Self {
+            kind: KnownAttributeKind::ViewFunction as u8,
+            args: vec![],
+        }
+    }
+
+    pub fn is_view_function(&self) -> bool {
+        self.kind == KnownAttributeKind::ViewFunction as u8
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
 pub struct GasFreeFunction {
     pub gas_validate: String,
@@ -71,7 +107,7 @@ pub struct RuntimeModuleMetadataV1 {
     /// Attributes attached to structs.
     pub struct_attributes: BTreeMap<String, Vec<KnownAttribute>>,
 
-    /// Attributes attached to functions, by definition index.
+    /// Attributes attached to functions, by function name.
     pub fun_attributes: BTreeMap<String, Vec<KnownAttribute>>,
 
     /// The correspondence between private generics and their type parameters.
@@ -96,6 +132,22 @@ impl RuntimeModuleMetadataV1 {
             && self.data_struct_map.is_empty()
             && self.data_struct_func_map.is_empty()
     }
+
+    pub fn is_view_function(&self, func_name: &str) -> bool {
+        self.fun_attributes
+            .get(func_name)
+            .map(|attrs| attrs.iter().any(|attr| attr.is_view_function()))
+            .unwrap_or(false)
+    }
+
+    /// The names of the #[view] functions of the module
+    pub fn view_functions(&self) -> Vec<String> {
+        self.fun_attributes
+            .iter()
+            .filter(|(_, attrs)| attrs.iter().any(|attr| attr.is_view_function()))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
 }
 
 fn find_metadata<'a>(module: &'a CompiledModule, key: &[u8]) -> Option<&'a Metadata> {
@@ -146,6 +198,7 @@ impl<'a> ExtendedChecker<'a> {
                 self.check_global_storage_access(module);
                 self.check_gas_free_function(module);
                 self.check_data_struct(module);
+                self.check_view_functions(module);
             }
         }
     }
@@ -934,6 +987,95 @@ impl<'a> ExtendedChecker<'a> {
     }
 }
 
+// ----------------------------------------------------------------------------------
+// View Function
+
+impl<'a> ExtendedChecker<'a> {
+    fn check_view_functions(&mut self, module: &ModuleEnv) {
+        let mut view_functions = vec![];
+        for ref fun in module.get_functions() {
+            if !has_attribute(self.env, fun, VIEW_FUNCTION_ATTRIBUTE) {
+                continue;
+            }
+
+            if fun.get_return_count() == 0 {
+                self.env
+                    .error(&fun.get_loc(), "view function must return values")
+            }
+
+            for ty in fun.get_parameter_types() {
+                match &ty {
+                    Type::Primitive(PrimitiveType::Signer) => self.env.error(
+                        &fun.get_loc(),
+                        "view function should not have signer as parameter",
+                    ),
+                    Type::Reference(ReferenceKind::Immutable, bt)
+                        if bt.as_ref() == &Type::Primitive(PrimitiveType::Signer) =>
+                    {
+                        self.env.error(
+                            &fun.get_loc(),
+                            "view function should not have signer as parameter",
+                        )
+                    }
+                    Type::Reference(ReferenceKind::Mutable, _) => self.env.error(
+                        &fun.get_loc(),
+                        &format!(
+                            "view function should not have mutable reference type `{}` as parameter",
+                            ty.display(&self.env.get_type_display_ctx())
+                        ),
+                    ),
+                    _ => {}
+                }
+            }
+
+            // The called functions are all available in the model, so we check the whole call graph.
+            if let Some(called_functions) = fun.get_transitive_closure_of_called_functions() {
+                for callee_id in called_functions {
+                    let callee = self.env.get_function(callee_id);
+                    let callee_name = build_full_func_name(&callee, &callee.module_env, self.env);
+                    if is_state_mutating_function(&callee_name) {
+                        self.env.error(
+                            &fun.get_loc(),
+                            &format!(
+                                "view function should not modify the global state, but it calls {}",
+                                callee_name
+                            ),
+                        );
+                        break;
+                    }
+                }
+            }
+
+            view_functions.push(fun.get_simple_name_string().to_string());
+        }
+
+        if view_functions.is_empty() {
+            return;
+        }
+
+        let verified_module = match module.get_verified_module() {
+            None => {
+                self.env
+                    .error(&module.get_loc(), "The verified module was not found.");
+                return;
+            }
+            Some(module) => module,
+        };
+        let module_metadata = self.output.entry(verified_module.self_id()).or_default();
+        for view_function in view_functions {
+            module_metadata
+                .fun_attributes
+                .entry(view_function)
+                .or_default()
+                .push(KnownAttribute::view_function());
+        }
+    }
+}
+
+pub fn is_state_mutating_function(full_func_name: &str) -> bool {
+    STATE_MUTATING_FUNCTIONS.contains(&full_func_name)
+}
+
 pub fn extract_module_name(item: &str) -> Option<(String, String)> {
     let func_name_split = item.split("::");
     let parts_vec = func_name_split.collect::<Vec<&str>>();
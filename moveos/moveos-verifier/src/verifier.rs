@@ -25,7 +25,8 @@ use once_cell::sync::Lazy;
 use crate::error_code::ErrorCode;
 use crate::metadata::{
     check_metadata_format, extract_module_name, get_metadata_from_compiled_module,
-    is_allowed_input_struct, is_defined_or_allowed_in_current_module, is_std_option_type,
+    is_allowed_input_struct, is_defined_or_allowed_in_current_module, is_state_mutating_function,
+    is_std_option_type,
 };
 
 const MAX_DATA_STRUCT_TYPE_DEPTH: u64 = 16;
@@ -41,6 +42,7 @@ where
     for module in modules {
         verify_private_generics(module, &db, &mut verified_modules)?;
         verify_entry_function_at_publish(module)?;
+        verify_view_functions(module)?;
         verify_global_storage_access(module)?;
         verify_gas_free_function(module)?;
         verify_init_function(module)?;
@@ -208,6 +210,109 @@ pub fn verify_entry_function_at_publish(module: &CompiledModule) -> VMResult<boo
     Ok(true)
 }
 
+/// Verify the #[view] functions recorded in the metadata:
+/// - The function must exist in the module and return values
+/// - The function should not take signer or mutable reference parameters
+/// - The function should not call the state mutating functions directly
+/// The indirect calls are checked by the extended checker at compile time,
+/// and the readonly session rejects any state change at runtime.
+pub fn verify_view_functions(module: &CompiledModule) -> VMResult<bool> {
+    if let Err(err) = check_metadata_format(module) {
+        return Err(PartialVMError::new(StatusCode::ABORTED)
+            .with_sub_status(ErrorCode::MALFORMED_METADATA.into())
+            .with_message(err.to_string())
+            .finish(Location::Module(module.self_id())));
+    }
+
+    let metadata = match get_metadata_from_compiled_module(module) {
+        None => return Ok(true),
+        Some(metadata) => metadata,
+    };
+
+    let view = BinaryIndexedView::Module(module);
+    for view_function in metadata.view_functions() {
+        let full_func_name = format!(
+            "{}::{}",
+            module.self_id().short_str_lossless(),
+            view_function
+        );
+        let (func_exists, func_handle_index) =
+            check_if_function_exist_in_module(module, &full_func_name);
+        if !func_exists {
+            return generate_vm_error(
+                ErrorCode::FUNCTION_NOT_EXITS,
+                format!(
+                    "#[view] function {:?} not defined in module",
+                    full_func_name
+                ),
+                None,
+                module,
+            );
+        }
+
+        let func_handle = view.function_handle_at(func_handle_index);
+        if view.signature_at(func_handle.return_).is_empty() {
+            return generate_vm_error(
+                ErrorCode::INVALID_VIEW_FUNCTION_SIGNATURE,
+                "view function must return values".to_string(),
+                Some(func_handle_index),
+                module,
+            );
+        }
+
+        for (idx, ty) in view
+            .signature_at(func_handle.parameters)
+            .0
+            .iter()
+            .enumerate()
+        {
+            if is_signer(ty) || matches!(ty, SignatureToken::MutableReference(_)) {
+                return generate_vm_error(
+                    ErrorCode::INVALID_PARAM_TYPE_VIEW_FUNCTION,
+                    format!(
+                        "The type {} of the parameter {} is not allowed",
+                        signature_token_to_string(ty, &view),
+                        idx
+                    ),
+                    Some(func_handle_index),
+                    module,
+                );
+            }
+        }
+
+        let func_def = module
+            .function_defs
+            .iter()
+            .find(|def| def.function == func_handle_index)
+            .expect("The function definition must exist");
+        if let Some(code) = &func_def.code {
+            for instr in code.code.iter() {
+                let callee_handle_index = match instr {
+                    Bytecode::Call(fhandle_index) => *fhandle_index,
+                    Bytecode::CallGeneric(finst_index) => {
+                        view.function_instantiation_at(*finst_index).handle
+                    }
+                    _ => continue,
+                };
+                let callee_name = build_full_function_name(&callee_handle_index, &view);
+                if is_state_mutating_function(&callee_name) {
+                    return generate_vm_error(
+                        ErrorCode::INVALID_VIEW_FUNCTION_MODIFY_STATE,
+                        format!(
+                            "view function should not modify the global state, but it calls {}",
+                            callee_name
+                        ),
+                        Some(func_handle_index),
+                        module,
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(true)
+}
+
 fn is_signer(t: &SignatureToken) -> bool {
     matches!(t, SignatureToken::Signer)
         || matches!(t, SignatureToken::Reference(r) if matches!(**r, SignatureToken::Signer))
@@ -302,6 +407,40 @@ fn struct_full_name_from_sid(
     format!("0x{}::{}::{}", module_address, module_name, struct_name)
 }
 
+fn signature_token_to_string(ty: &SignatureToken, module_bin_view: &BinaryIndexedView) -> String {
+    match ty {
+        SignatureToken::Bool => "bool".to_string(),
+        SignatureToken::U8 => "u8".to_string(),
+        SignatureToken::U16 => "u16".to_string(),
+        SignatureToken::U32 => "u32".to_string(),
+        SignatureToken::U64 => "u64".to_string(),
+        SignatureToken::U128 => "u128".to_string(),
+        SignatureToken::U256 => "u256".to_string(),
+        SignatureToken::Address => "address".to_string(),
+        SignatureToken::Signer => "signer".to_string(),
+        SignatureToken::Vector(ty) => {
+            format!("vector<{}>", signature_token_to_string(ty, module_bin_view))
+        }
+        SignatureToken::Struct(sid) => struct_full_name_from_sid(sid, module_bin_view),
+        SignatureToken::StructInstantiation(sid, type_args) => format!(
+            "{}<{}>",
+            struct_full_name_from_sid(sid, module_bin_view),
+            type_args
+                .iter()
+                .map(|ty| signature_token_to_string(ty, module_bin_view))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        SignatureToken::Reference(ty) => {
+            format!("&{}", signature_token_to_string(ty, module_bin_view))
+        }
+        SignatureToken::MutableReference(ty) => {
+            format!("&mut {}", signature_token_to_string(ty, module_bin_view))
+        }
+        SignatureToken::TypeParameter(idx) => format!("T{}", idx),
+    }
+}
+
 fn check_transaction_input_type<S>(ety: &Type, session: &Session<S>) -> bool
 where
     S: TransactionCache,
@@ -22,7 +22,7 @@ use move_core_types::{
 use move_vm_runtime::config::VMConfig;
 use move_vm_runtime::data_cache::TransactionCache;
 use move_vm_runtime::native_functions::NativeFunction;
-use moveos_common::types::ClassifiedGasMeter;
+use moveos_common::types::{ClassifiedGasMeter, SwitchableGasMeter};
 use moveos_store::config_store::ConfigDBStore;
 use moveos_store::event_store::EventDBStore;
 use moveos_store::state_store::statedb::StateDBStore;
@@ -31,17 +31,22 @@ use moveos_store::{load_feature_store_object, MoveOSStore};
 use moveos_types::addresses::MOVEOS_STD_ADDRESS;
 use moveos_types::function_return_value::FunctionResult;
 use moveos_types::moveos_std::gas_schedule::{GasScheduleConfig, GasScheduleUpdated};
-use moveos_types::moveos_std::object::ObjectMeta;
+use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
+use moveos_types::moveos_std::onchain_features::FeatureStore;
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::moveos_std::tx_result::TxResult;
 use moveos_types::state::{MoveStructState, MoveStructType, ObjectState};
-use moveos_types::state_resolver::{GenesisResolver, RootObjectResolver};
+use moveos_types::state_resolver::{
+    GenesisResolver, MoveOSResolver, RootObjectResolver, StateResolver,
+};
 use moveos_types::transaction::{FunctionCall, VMErrorInfo};
 use moveos_types::transaction::{
     MoveOSTransaction, RawTransactionOutput, VerifiedMoveAction, VerifiedMoveOSTransaction,
 };
 use parking_lot::RwLock;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
 #[derive(thiserror::Error, Debug)]
@@ -52,6 +57,13 @@ pub enum VMPanicError {
     SystemCallPanicError(Error),
 }
 
+/// The execution output with the ids of the Objects read by the transaction.
+pub type ExecutionOutputWithReadSet = (
+    RawTransactionOutput,
+    Option<VMErrorInfo>,
+    BTreeSet<ObjectID>,
+);
+
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub struct GasPaymentAccount {
     pub account: AccountAddress,
@@ -182,7 +194,7 @@ impl MoveOS {
         Ok(raw_output)
     }
 
-    fn load_cost_table(&self, root: &ObjectMeta) -> VMResult<CostTable> {
+    fn load_cost_table<R: StateResolver>(&self, resolver: &R) -> VMResult<CostTable> {
         // We use a scoped lock here to avoid holding the lock for a long time.
         {
             let rlock = self.cost_table.read();
@@ -194,8 +206,7 @@ impl MoveOS {
         if tracing::enabled!(tracing::Level::TRACE) {
             tracing::trace!("load_cost_table from db");
         }
-        let resolver = RootObjectResolver::new(root.clone(), &self.db);
-        let gas_entries = get_gas_schedule_entries(&resolver).map_err(|e| {
+        let gas_entries = get_gas_schedule_entries(resolver).map_err(|e| {
             PartialVMError::new(StatusCode::STORAGE_ERROR)
                 .with_message(format!("Load gas schedule entries failed: {}", e))
                 .finish(Location::Undefined)
@@ -233,15 +244,24 @@ impl MoveOS {
     }
 
     pub fn verify(&self, tx: MoveOSTransaction) -> VMResult<VerifiedMoveOSTransaction> {
+        let resolver = RootObjectResolver::new(tx.root.clone(), &self.db);
+        self.verify_with_resolver(&resolver, tx)
+    }
+
+    /// Verify the transaction against the states read from the `resolver`, instead of the root of the transaction.
+    pub fn verify_with_resolver<R: MoveOSResolver>(
+        &self,
+        resolver: &R,
+        tx: MoveOSTransaction,
+    ) -> VMResult<VerifiedMoveOSTransaction> {
         let MoveOSTransaction { root, ctx, action } = tx;
-        let cost_table = self.load_cost_table(&root)?;
+        let cost_table = self.load_cost_table(resolver)?;
         let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount, false);
         gas_meter.set_metering(false);
 
-        let resolver = RootObjectResolver::new(root.clone(), &self.db);
         let session = self
             .vm
-            .new_readonly_session(&resolver, ctx.clone(), gas_meter);
+            .new_readonly_session(resolver, ctx.clone(), gas_meter);
 
         let verified_action = session.verify_move_action(action)?;
         let (_, _) = session.finish_with_extensions(KeptVMStatus::Executed)?;
@@ -256,7 +276,52 @@ impl MoveOS {
         &self,
         tx: VerifiedMoveOSTransaction,
     ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>)> {
-        let VerifiedMoveOSTransaction { root, ctx, action } = tx;
+        let (output, vm_error_info, _read_set) = self.execute_with_read_set(tx)?;
+        Ok((output, vm_error_info))
+    }
+
+    /// Execute the transaction and return the ids of the Objects read by the transaction,
+    /// the output is determined by the states of these Objects in the state tree of the transaction's root.
+    pub fn execute_with_read_set(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<ExecutionOutputWithReadSet> {
+        self.execute_with_gas_meter(tx, |gas_meter| gas_meter)
+    }
+
+    /// Execute the transaction with a gas meter wrapping the MoveOSGasMeter, such as the gas profiler.
+    /// The wrapper must delegate the charges to the MoveOSGasMeter, so the output is the same as `execute`.
+    pub fn execute_with_gas_meter<G, F>(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+        wrap_gas_meter: F,
+    ) -> Result<ExecutionOutputWithReadSet>
+    where
+        G: SwitchableGasMeter + ClassifiedGasMeter + Clone,
+        F: FnOnce(MoveOSGasMeter) -> G,
+    {
+        let resolver = RootObjectResolver::new(tx.root.clone(), &self.db);
+        self.execute_with_resolver(&resolver, tx, wrap_gas_meter)
+    }
+
+    /// Execute the transaction against the states read from the `resolver`, instead of the root of the transaction,
+    /// such as the states of a remote node. The output is not saved.
+    pub fn execute_with_resolver<R, G, F>(
+        &self,
+        resolver: &R,
+        tx: VerifiedMoveOSTransaction,
+        wrap_gas_meter: F,
+    ) -> Result<ExecutionOutputWithReadSet>
+    where
+        R: MoveOSResolver,
+        G: SwitchableGasMeter + ClassifiedGasMeter + Clone,
+        F: FnOnce(MoveOSGasMeter) -> G,
+    {
+        let VerifiedMoveOSTransaction {
+            root: _,
+            ctx,
+            action,
+        } = tx;
         let tx_hash = ctx.tx_hash();
         if tracing::enabled!(tracing::Level::DEBUG) {
             tracing::debug!(
@@ -272,21 +337,22 @@ impl MoveOS {
         // The variables in TxContext kv store before this executions should not be cleaned,
         // So we keep a backup here, and then insert to the TxContext kv store when session respawed.
         let system_env = ctx.map.clone();
+        // The FeatureStore is read outside the session
+        let mut read_set = BTreeSet::from([FeatureStore::feature_store_object_id()]);
 
-        let feature_resolver = RootObjectResolver::new(root.clone(), &self.db);
-        let feature_store_opt = load_feature_store_object(&feature_resolver);
+        let feature_store_opt = load_feature_store_object(resolver);
         let has_io_tired_write_feature = match feature_store_opt {
             None => false,
             Some(feature_store) => feature_store.has_value_size_gas_feature(),
         };
 
-        let cost_table = self.load_cost_table(&root)?;
+        let cost_table = self.load_cost_table(resolver)?;
         let mut gas_meter =
             MoveOSGasMeter::new(cost_table, ctx.max_gas_amount, has_io_tired_write_feature);
         gas_meter.charge_io_write(ctx.tx_size)?;
+        let gas_meter = wrap_gas_meter(gas_meter);
 
-        let resolver = RootObjectResolver::new(root, &self.db);
-        let mut session = self.vm.new_session(&resolver, ctx, gas_meter);
+        let mut session = self.vm.new_session(resolver, ctx, gas_meter);
 
         //We do not execute pre_execute and post_execute functions for system call
         if !is_system_call {
@@ -313,7 +379,7 @@ impl MoveOS {
                         status
                     );
                 }
-                self.execution_cleanup(is_system_call, session, status, None)
+                self.execution_cleanup(is_system_call, session, status, None, read_set)
             }
             Err(vm_err) => {
                 if tracing::enabled!(tracing::Level::WARN) {
@@ -331,6 +397,8 @@ impl MoveOS {
                         &session.session.data_cache,
                     )?,
                 };
+                // The failed execution determines the status, so its reads are kept after respawn.
+                read_set.extend(session.object_runtime.read().read_set());
                 // If it is a system call, we should not respawn the session.
                 if !is_system_call {
                     let mut s = session.respawn(system_env);
@@ -342,6 +410,7 @@ impl MoveOS {
                         s,
                         vm_err.into_vm_status(),
                         Some(vm_error_info),
+                        read_set,
                     )
                 } else {
                     self.execution_cleanup(
@@ -349,6 +418,7 @@ impl MoveOS {
                         session,
                         vm_err.into_vm_status(),
                         Some(vm_error_info),
+                        read_set,
                     )
                 }
             }
@@ -362,15 +432,33 @@ impl MoveOS {
         self.execute(tx)
     }
 
-    /// Execute readonly view function
+    /// Execute the transactions in parallel against the same `root`, the outputs are not applied.
+    /// The output of a transaction is valid after the previous transactions are applied
+    /// only if none of the Objects in its read set is changed by them.
+    pub fn execute_speculatively(
+        &self,
+        root: &ObjectMeta,
+        txs: Vec<VerifiedMoveOSTransaction>,
+    ) -> Vec<Result<ExecutionOutputWithReadSet>> {
+        txs.into_par_iter()
+            .map(|mut tx| {
+                tx.root = root.clone();
+                self.execute_with_read_set(tx)
+            })
+            .collect()
+    }
+
+    /// Execute readonly view function in a readonly session, the `caller` is the sender of the TxContext,
+    /// default is `AccountAddress::ZERO`.
+    /// The #[view] functions are verified at publish time, and other functions are also allowed,
+    /// the readonly session rejects the function if it changes the state.
     pub fn execute_view_function(
         &self,
         root: ObjectMeta,
         function_call: FunctionCall,
+        caller: Option<AccountAddress>,
     ) -> FunctionResult {
-        //TODO allow user to specify the sender
-        let tx_context = TxContext::new_readonly_ctx(AccountAddress::ZERO);
-        //TODO verify the view function
+        let tx_context = TxContext::new_readonly_ctx(caller.unwrap_or(AccountAddress::ZERO));
         self.execute_readonly_function(root, &tx_context, function_call)
     }
 
@@ -379,6 +467,17 @@ impl MoveOS {
         root: ObjectMeta,
         tx_context: &TxContext,
         function_call: FunctionCall,
+    ) -> FunctionResult {
+        let resolver = RootObjectResolver::new(root, &self.db);
+        self.execute_readonly_function_with_resolver(&resolver, tx_context, function_call)
+    }
+
+    /// Execute the readonly function against the states read from the `resolver`.
+    pub fn execute_readonly_function_with_resolver<R: MoveOSResolver>(
+        &self,
+        resolver: &R,
+        tx_context: &TxContext,
+        function_call: FunctionCall,
     ) -> FunctionResult {
         if tx_context.max_gas_amount > GasScheduleConfig::READONLY_MAX_GAS_AMOUNT {
             return FunctionResult::err(
@@ -387,15 +486,14 @@ impl MoveOS {
                     .finish(Location::Undefined),
             );
         }
-        let cost_table = match self.load_cost_table(&root) {
+        let cost_table = match self.load_cost_table(resolver) {
             Ok(cost_table) => cost_table,
             Err(e) => {
                 return FunctionResult::err(e);
             }
         };
 
-        let feature_resolver = RootObjectResolver::new(root.clone(), &self.db);
-        let feature_store_opt = load_feature_store_object(&feature_resolver);
+        let feature_store_opt = load_feature_store_object(resolver);
         let has_io_tired_write_feature = match feature_store_opt {
             None => false,
             Some(feature_store) => feature_store.has_value_size_gas_feature(),
@@ -407,10 +505,9 @@ impl MoveOS {
             has_io_tired_write_feature,
         );
         gas_meter.set_metering(true);
-        let resolver = RootObjectResolver::new(root, &self.db);
         let mut session = self
             .vm
-            .new_readonly_session(&resolver, tx_context.clone(), gas_meter);
+            .new_readonly_session(resolver, tx_context.clone(), gas_meter);
 
         let result = session.execute_function_bypass_visibility(function_call);
         match result {
@@ -433,21 +530,22 @@ impl MoveOS {
     // Execute action with pre_execute and post_execute.
     // Return the action execution status if success,
     // else return VMError and a bool which indicate if we should respawn the session.
-    fn execute_action(
+    fn execute_action<S: MoveOSResolver, G: SwitchableGasMeter + ClassifiedGasMeter>(
         &self,
-        session: &mut MoveOSSession<'_, '_, RootObjectResolver<MoveOSStore>, MoveOSGasMeter>,
+        session: &mut MoveOSSession<'_, '_, S, G>,
         action: VerifiedMoveAction,
     ) -> Result<(), VMError> {
         session.execute_move_action(action)
     }
 
-    fn execution_cleanup(
+    fn execution_cleanup<S: MoveOSResolver, G: SwitchableGasMeter + ClassifiedGasMeter>(
         &self,
         is_system_call: bool,
-        mut session: MoveOSSession<'_, '_, RootObjectResolver<MoveOSStore>, MoveOSGasMeter>,
+        mut session: MoveOSSession<'_, '_, S, G>,
         status: VMStatus,
         vm_error_info: Option<VMErrorInfo>,
-    ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>)> {
+        mut read_set: BTreeSet<ObjectID>,
+    ) -> Result<ExecutionOutputWithReadSet> {
         let kept_status = match status.keep_or_discard() {
             Ok(kept_status) => {
                 if is_system_call && kept_status != KeptVMStatus::Executed {
@@ -503,9 +601,15 @@ impl MoveOS {
             self.cost_table.write().take();
         }
 
+        read_set.extend(session.object_runtime.read().read_set());
         let (_ctx, mut output) = session.finish_with_extensions(kept_status)?;
         output.is_gas_upgrade = gas_upgrade;
-        Ok((output, vm_error_info))
+        Ok((output, vm_error_info, read_set))
+    }
+
+    /// Drop the cached cost table, it will be reloaded from the state of the next transaction.
+    pub fn flush_cost_table(&self) {
+        self.cost_table.write().take();
     }
 
     pub fn flush_module_cache(&self, is_upgrade: bool) -> Result<()> {
@@ -516,9 +620,9 @@ impl MoveOS {
     }
 }
 
-fn extract_execution_state(
+fn extract_execution_state<S: MoveOSResolver>(
     vm_err: VMError,
-    data_cache: &MoveosDataCache<RootObjectResolver<MoveOSStore>>,
+    data_cache: &MoveosDataCache<S>,
 ) -> Result<Vec<String>> {
     let mut execution_stack_trace = Vec::new();
     if let Some(exec_state) = vm_err.exec_state() {
@@ -543,10 +647,10 @@ fn extract_execution_state(
     Ok(execution_stack_trace)
 }
 
-fn func_name_from_db(
+fn func_name_from_db<S: MoveOSResolver>(
     module_id: &ModuleId,
     func_idx: &FunctionDefinitionIndex,
-    data_cache: &MoveosDataCache<RootObjectResolver<MoveOSStore>>,
+    data_cache: &MoveosDataCache<S>,
 ) -> Result<String> {
     let module_bytes = data_cache.load_module(module_id)?;
     let compiled_module = CompiledModule::deserialize(module_bytes.as_slice())?;
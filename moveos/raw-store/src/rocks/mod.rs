@@ -299,6 +299,31 @@ impl RocksDB {
         self.iter_with_direction(cf_name, ScanDirection::Backward)
     }
 
+    /// Returns at most `limit` raw keys of a certain schema in order, starting from the `start_key`(inclusive).
+    /// It is used to scan the schema whose keys are not encoded by the codec.
+    pub fn raw_keys(
+        &self,
+        cf_name: &str,
+        start_key: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<Vec<u8>>> {
+        let cf_handle = self.get_cf_handle(cf_name);
+        let mut db_iter = self
+            .db
+            .raw_iterator_cf_opt(&cf_handle, ReadOptions::default());
+        match start_key {
+            Some(start_key) => db_iter.seek(start_key),
+            None => db_iter.seek_to_first(),
+        }
+        let mut keys = vec![];
+        while db_iter.valid() && keys.len() < limit {
+            keys.push(db_iter.key().expect("Iterator must be valid.").to_vec());
+            db_iter.next();
+        }
+        db_iter.status()?;
+        Ok(keys)
+    }
+
     fn sync_write_options() -> WriteOptions {
         let mut opts = WriteOptions::new();
         opts.set_sync(true);
@@ -13,7 +13,7 @@ use parking_lot::RwLock;
 use primitive_types::H256;
 use prometheus::Registry;
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     marker::PhantomData,
     sync::Arc,
 };
@@ -100,11 +100,17 @@ impl NodeWriter for InMemoryNodeStore {
 pub struct TreeChangeSet {
     pub state_root: H256,
     pub nodes: BTreeMap<H256, Vec<u8>>,
+    /// The nodes removed from the tree by this change set, a node recreated with the same content is also in the `nodes`.
+    pub stale_nodes: BTreeSet<H256>,
 }
 
 impl TreeChangeSet {
     pub fn new(state_root: H256, nodes: BTreeMap<H256, Vec<u8>>) -> Self {
-        Self { state_root, nodes }
+        Self {
+            state_root,
+            nodes,
+            stale_nodes: BTreeSet::new(),
+        }
     }
 }
 
@@ -290,10 +296,7 @@ where
     ) -> Result<TreeChangeSet> {
         let updates: UpdateSet<K, V> = updates.into();
         if updates.is_empty() {
-            return Ok(TreeChangeSet {
-                state_root,
-                nodes: BTreeMap::default(),
-            });
+            return Ok(TreeChangeSet::new(state_root, BTreeMap::default()));
         }
 
         let tree = JellyfishMerkleTree::new(&self.node_reader);
@@ -306,14 +309,52 @@ where
             node_map.insert(nk.into(), n.encode()?);
         }
 
+        // A node recreated with the same content by this change set is both stale and new
+        let stale_nodes: BTreeSet<H256> = change_set
+            .stale_node_index_batch
+            .into_iter()
+            .map(|index| index.node_key.into())
+            .collect();
+
         let new_state_root: H256 = new_state_root.into();
 
         Ok(TreeChangeSet {
             state_root: new_state_root,
             nodes: node_map,
+            stale_nodes,
         })
     }
 
+    /// Visit all the nodes of the tree with the given state root in depth-first order.
+    /// The visitor is called with the node hash and the value if the node is a leaf node,
+    /// it returns false to skip the subtree of the node.
+    pub fn visit_nodes<F>(&self, state_root: H256, mut visitor: F) -> Result<()>
+    where
+        F: FnMut(H256, Option<&V>) -> Result<bool>,
+    {
+        let mut stack = vec![state_root];
+        while let Some(node_hash) = stack.pop() {
+            let node_key: NodeKey = node_hash.into();
+            match TreeReader::<K, V>::get_node(&self.node_reader, &node_key)? {
+                Node::Null => {}
+                Node::Internal(internal_node) => {
+                    if visitor(node_hash, None)? {
+                        stack.extend(
+                            internal_node
+                                .all_child()
+                                .into_iter()
+                                .map(Into::<H256>::into),
+                        );
+                    }
+                }
+                Node::Leaf(leaf_node) => {
+                    visitor(node_hash, Some(&leaf_node.value().origin))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Dump all (key, value) from the tree
     pub fn dump(&self, state_root: H256) -> Result<Vec<(K, V)>> {
         let iter = self.iter(state_root, None)?;
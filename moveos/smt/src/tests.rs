@@ -57,3 +57,56 @@ fn test_smt() {
     let iter = smt.iter(changeset3.state_root, None).unwrap();
     assert_eq!(iter.count(), 2);
 }
+
+#[test]
+fn test_stale_nodes_and_visit_nodes() {
+    let node_store = InMemoryNodeStore::default();
+    let registry = prometheus::Registry::new();
+    let smt = SMTree::new(node_store.clone(), &registry);
+    let genesis_root = *SPARSE_MERKLE_PLACEHOLDER_HASH;
+    let changeset = smt
+        .puts(
+            genesis_root,
+            vec![
+                (H256::random(), Some("value1".to_owned())),
+                (H256::random(), Some("value2".to_owned())),
+            ],
+        )
+        .unwrap();
+    assert!(changeset.stale_nodes.is_empty());
+    node_store.write_nodes(changeset.nodes.clone()).unwrap();
+
+    let key3 = H256::random();
+    let changeset2 = smt
+        .put(changeset.state_root, key3, "value3".to_owned())
+        .unwrap();
+    node_store.write_nodes(changeset2.nodes.clone()).unwrap();
+    assert!(changeset2.stale_nodes.contains(&changeset.state_root));
+
+    let mut nodes = BTreeSet::new();
+    let mut values = vec![];
+    smt.visit_nodes(changeset2.state_root, |node_hash, value| {
+        nodes.insert(node_hash);
+        if let Some(value) = value {
+            values.push(value.clone());
+        }
+        Ok(true)
+    })
+    .unwrap();
+    assert_eq!(values.len(), 3);
+    assert!(nodes.contains(&changeset2.state_root));
+    // The stale nodes still in the tree are recreated by the change set
+    assert!(nodes
+        .intersection(&changeset2.stale_nodes)
+        .all(|node_hash| changeset2.nodes.contains_key(node_hash)));
+
+    // Put the same value again, the recreated nodes are both stale and new
+    let changeset3 = smt
+        .put(changeset2.state_root, key3, "value3".to_owned())
+        .unwrap();
+    assert_eq!(changeset3.state_root, changeset2.state_root);
+    assert!(changeset3
+        .stale_nodes
+        .iter()
+        .all(|node_hash| changeset3.nodes.contains_key(node_hash)));
+}